@@ -54,12 +54,22 @@ impl Display for WheelFilename {
 impl WheelFilename {
     /// Returns `true` if the wheel is compatible with the given tags.
     pub fn is_compatible(&self, compatible_tags: &Tags) -> bool {
-        compatible_tags.is_compatible(&self.python_tag, &self.abi_tag, &self.platform_tag)
+        compatible_tags.is_package_compatible(
+            &self.name,
+            &self.python_tag,
+            &self.abi_tag,
+            &self.platform_tag,
+        )
     }
 
     /// Return the [`TagCompatibility`] of the wheel with the given tags
     pub fn compatibility(&self, compatible_tags: &Tags) -> TagCompatibility {
-        compatible_tags.compatibility(&self.python_tag, &self.abi_tag, &self.platform_tag)
+        compatible_tags.package_compatibility(
+            &self.name,
+            &self.python_tag,
+            &self.abi_tag,
+            &self.platform_tag,
+        )
     }
 
     /// Get the tag for this wheel.
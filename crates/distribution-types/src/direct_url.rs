@@ -131,6 +131,19 @@ impl TryFrom<&LocalFileUrl> for pypi_types::DirectUrl {
     type Error = Error;
 
     fn try_from(value: &LocalFileUrl) -> Result<Self, Self::Error> {
+        // Local archives (like a wheel or source distribution on disk) are recorded as archives,
+        // rather than as directories.
+        if !value.editable && value.url.to_file_path().is_ok_and(|path| path.is_file()) {
+            return Ok(Self::ArchiveUrl {
+                url: value.url.to_string(),
+                archive_info: pypi_types::ArchiveInfo {
+                    hash: None,
+                    hashes: None,
+                },
+                subdirectory: None,
+            });
+        }
+
         Ok(Self::LocalDirectory {
             url: value.url.to_string(),
             dir_info: pypi_types::DirInfo {
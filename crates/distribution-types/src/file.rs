@@ -91,3 +91,17 @@ impl Display for FileLocation {
         }
     }
 }
+
+impl FileLocation {
+    /// Convert the location to a URL.
+    ///
+    /// Relative URLs are resolved against the URL of the index page that listed the file, as
+    /// returned after following any redirects.
+    pub fn to_url(&self) -> Option<Url> {
+        match self {
+            Self::RelativeUrl(base, url) => pypi_types::base_url_join_relative(base, url).ok(),
+            Self::AbsoluteUrl(url) => Url::parse(url).ok(),
+            Self::Path(path) => Url::from_file_path(path).ok(),
+        }
+    }
+}
@@ -223,6 +223,7 @@ pub struct IndexLocations {
     extra_index: Vec<IndexUrl>,
     flat_index: Vec<FlatIndexLocation>,
     no_index: bool,
+    recursive_flat_index: bool,
 }
 
 impl Default for IndexLocations {
@@ -233,6 +234,7 @@ impl Default for IndexLocations {
             extra_index: Vec::new(),
             flat_index: Vec::new(),
             no_index: false,
+            recursive_flat_index: false,
         }
     }
 }
@@ -250,6 +252,17 @@ impl IndexLocations {
             extra_index,
             flat_index,
             no_index,
+            recursive_flat_index: false,
+        }
+    }
+
+    /// Set whether `--find-links` directories should be scanned recursively, such that
+    /// distributions in nested subdirectories are included.
+    #[must_use]
+    pub fn with_recursive_flat_index(self, recursive_flat_index: bool) -> Self {
+        Self {
+            recursive_flat_index,
+            ..self
         }
     }
 
@@ -272,6 +285,7 @@ impl IndexLocations {
             extra_index: self.extra_index.into_iter().chain(extra_index).collect(),
             flat_index: self.flat_index.into_iter().chain(flat_index).collect(),
             no_index: self.no_index || no_index,
+            recursive_flat_index: self.recursive_flat_index,
         }
     }
 }
@@ -312,6 +326,11 @@ impl<'a> IndexLocations {
         self.flat_index.iter()
     }
 
+    /// Return `true` if `--find-links` directories should be scanned recursively.
+    pub fn recursive_flat_index(&'a self) -> bool {
+        self.recursive_flat_index
+    }
+
     /// Clone the index locations into a [`IndexUrls`] instance.
     pub fn index_urls(&'a self) -> IndexUrls {
         IndexUrls {
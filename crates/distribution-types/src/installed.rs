@@ -7,6 +7,7 @@ use tracing::warn;
 use url::Url;
 
 use pep440_rs::Version;
+use pypi_types::{HashAlgorithm, HashDigest};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
 
@@ -53,8 +54,19 @@ impl InstalledDist {
                 return Ok(None);
             };
 
+            // Older installers don't escape the name, such that it may itself contain a dash (as in
+            // `Foo-Bar-1.0.dist-info`), in which case the version follows the last dash.
+            let (name, version) = match Version::from_str(version) {
+                Ok(version) => (name, version),
+                Err(err) => {
+                    let Some((name, version)) = file_stem.rsplit_once('-') else {
+                        return Err(anyhow!(err));
+                    };
+                    (name, Version::from_str(version).map_err(|_| anyhow!(err))?)
+                }
+            };
+
             let name = PackageName::from_str(name)?;
-            let version = Version::from_str(version).map_err(|err| anyhow!(err))?;
             return if let Some(direct_url) = Self::direct_url(path)? {
                 match Url::try_from(&direct_url) {
                     Ok(url) => Ok(Some(Self::Url(InstalledDirectUrlDist {
@@ -110,6 +122,29 @@ impl InstalledDist {
         Ok(Some(direct_url))
     }
 
+    /// Read the hashes recorded for the distribution, if any.
+    ///
+    /// Only archives installed from a direct URL record their hashes, in the `archive_info` of
+    /// their `direct_url.json`.
+    pub fn hashes(&self) -> Result<Vec<HashDigest>> {
+        let Some(pypi_types::DirectUrl::ArchiveUrl { archive_info, .. }) =
+            Self::direct_url(self.path())?
+        else {
+            return Ok(Vec::new());
+        };
+        Ok(archive_info
+            .hashes
+            .into_iter()
+            .flatten()
+            .filter_map(|(algorithm, digest)| {
+                Some(HashDigest {
+                    algorithm: HashAlgorithm::from_str(&algorithm).ok()?,
+                    digest: digest.into_boxed_str(),
+                })
+            })
+            .collect())
+    }
+
     /// Read the `METADATA` file from a `.dist-info` directory.
     pub fn metadata(&self) -> Result<pypi_types::Metadata23> {
         let path = self.path().join("METADATA");
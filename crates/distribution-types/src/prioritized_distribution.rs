@@ -69,6 +69,11 @@ impl Display for IncompatibleDist {
                         f.write_str("no wheels are available with a matching platform")
                     }
                 },
+                IncompatibleWheel::AllowedPlatformTag(platforms) => write!(
+                    f,
+                    "no wheels are available with a matching platform (allowed platform tags: {})",
+                    platforms.join(", ")
+                ),
                 IncompatibleWheel::Yanked(yanked) => match yanked {
                     Yanked::Bool(_) => f.write_str("it was yanked"),
                     Yanked::Reason(reason) => write!(
@@ -120,6 +125,9 @@ pub enum WheelCompatibility {
 pub enum IncompatibleWheel {
     ExcludeNewer(Option<i64>),
     Tag(IncompatibleTag),
+    /// No wheels matched the platform, including the additional platform tags allowed for the
+    /// package.
+    AllowedPlatformTag(Vec<String>),
     RequiresPython(VersionSpecifiers),
     Yanked(Yanked),
     NoBinary,
@@ -405,21 +413,34 @@ impl IncompatibleWheel {
                         timestamp_other < timestamp_self
                     }
                 },
-                Self::NoBinary | Self::RequiresPython(_) | Self::Tag(_) | Self::Yanked(_) => true,
+                Self::NoBinary
+                | Self::RequiresPython(_)
+                | Self::Tag(_)
+                | Self::AllowedPlatformTag(_)
+                | Self::Yanked(_) => true,
             },
+            // A wheel that doesn't match the allowed platform tags is ranked as any other wheel
+            // that doesn't match the platform.
+            Self::AllowedPlatformTag(_) => {
+                Self::Tag(IncompatibleTag::Platform).is_more_compatible(other)
+            }
             Self::Tag(tag_self) => match other {
                 Self::ExcludeNewer(_) => false,
                 Self::Tag(tag_other) => tag_other > tag_self,
+                Self::AllowedPlatformTag(_) => IncompatibleTag::Platform > *tag_self,
                 Self::NoBinary | Self::RequiresPython(_) | Self::Yanked(_) => true,
             },
             Self::RequiresPython(_) => match other {
-                Self::ExcludeNewer(_) | Self::Tag(_) => false,
+                Self::ExcludeNewer(_) | Self::Tag(_) | Self::AllowedPlatformTag(_) => false,
                 // Version specifiers cannot be reasonably compared
                 Self::RequiresPython(_) => false,
                 Self::NoBinary | Self::Yanked(_) => true,
             },
             Self::Yanked(_) => match other {
-                Self::ExcludeNewer(_) | Self::Tag(_) | Self::RequiresPython(_) => false,
+                Self::ExcludeNewer(_)
+                | Self::Tag(_)
+                | Self::AllowedPlatformTag(_)
+                | Self::RequiresPython(_) => false,
                 // Yanks with a reason are more helpful for errors
                 Self::Yanked(yanked_other) => matches!(yanked_other, Yanked::Reason(_)),
                 Self::NoBinary => true,
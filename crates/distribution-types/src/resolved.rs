@@ -3,7 +3,7 @@ use std::fmt::{Display, Formatter};
 use pep508_rs::PackageName;
 
 use crate::{
-    Dist, DistributionId, DistributionMetadata, Identifier, IndexUrl, InstalledDist, Name,
+    Dist, DistributionId, DistributionMetadata, File, Identifier, IndexUrl, InstalledDist, Name,
     ResourceId, VersionOrUrl,
 };
 
@@ -39,6 +39,14 @@ impl ResolvedDist {
             Self::Installed(_) => None,
         }
     }
+
+    /// Returns the [`File`] instance, if the distribution is from a registry.
+    pub fn file(&self) -> Option<&File> {
+        match self {
+            Self::Installable(dist) => dist.file(),
+            Self::Installed(_) => None,
+        }
+    }
 }
 
 impl ResolvedDistRef<'_> {
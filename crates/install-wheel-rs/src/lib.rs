@@ -11,15 +11,19 @@ use zip::result::ZipError;
 use pep440_rs::Version;
 use platform_tags::{Arch, Os};
 use pypi_types::Scheme;
-pub use uninstall::{uninstall_wheel, Uninstall};
+pub use uninstall::{
+    installed_paths, remove_empty_directories, uninstall_wheel, uninstall_wheels, Uninstall,
+};
 use uv_fs::Simplified;
 use uv_normalize::PackageName;
+pub use verify::{verify_record, RecordDiscrepancy};
 
 pub mod linker;
 pub mod metadata;
 mod record;
 mod script;
 mod uninstall;
+mod verify;
 mod wheel;
 
 /// The layout of the target environment into which a wheel can be installed.
@@ -202,6 +202,33 @@ fn parse_scripts(
     scripts_from_ini(extras, python_minor, ini)
 }
 
+/// Returns the names of the console and GUI entrypoints declared by an unzipped wheel.
+pub fn wheel_script_names(wheel: impl AsRef<Path>, python_minor: u8) -> Result<Vec<String>, Error> {
+    let dist_info_prefix = find_dist_info(&wheel)?;
+    dist_info_script_names(
+        wheel.as_ref().join(format!("{dist_info_prefix}.dist-info")),
+        python_minor,
+    )
+}
+
+/// Returns the names of the console and GUI entrypoints declared in a `.dist-info` directory.
+pub fn dist_info_script_names(
+    dist_info: impl AsRef<Path>,
+    python_minor: u8,
+) -> Result<Vec<String>, Error> {
+    // If the package doesn't declare any entry points, it can't own any scripts.
+    let Ok(ini) = fs::read_to_string(dist_info.as_ref().join("entry_points.txt")) else {
+        return Ok(Vec::new());
+    };
+
+    let (console_scripts, gui_scripts) = scripts_from_ini(None, python_minor, ini)?;
+    Ok(console_scripts
+        .into_iter()
+        .chain(gui_scripts)
+        .map(|script| script.name)
+        .collect())
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
@@ -213,6 +240,12 @@ pub enum LinkMode {
     Copy,
     /// Hard link packages from the wheel into the site packages.
     Hardlink,
+    /// Symbolically link packages from the wheel into the site packages.
+    ///
+    /// Any modification to an installed file will modify the cache, and removing the cache will
+    /// break the environment, so this mode should only be used with a read-only (or otherwise
+    /// persistent) cache.
+    Symlink,
 }
 
 impl Default for LinkMode {
@@ -237,6 +270,7 @@ impl LinkMode {
             Self::Clone => clone_wheel_files(site_packages, wheel),
             Self::Copy => copy_wheel_files(site_packages, wheel),
             Self::Hardlink => hardlink_wheel_files(site_packages, wheel),
+            Self::Symlink => symlink_wheel_files(site_packages, wheel),
         }
     }
 }
@@ -427,6 +461,46 @@ fn copy_wheel_files(
 fn hardlink_wheel_files(
     site_packages: impl AsRef<Path>,
     wheel: impl AsRef<Path>,
+) -> Result<usize, Error> {
+    link_wheel_files(
+        site_packages,
+        wheel,
+        "hardlink",
+        |original, link| fs::hard_link(original, link),
+        // The `RECORD` file is modified during installation, so we copy it instead of hard-linking.
+        |relative| relative.ends_with("RECORD"),
+    )
+}
+
+/// Extract a wheel by symbolically linking all of its files into site packages.
+///
+/// Only the package contents are linked. The `.dist-info` and `.data` directories are copied
+/// instead, as their contents are modified during installation (e.g., the `RECORD`), or moved
+/// out of site packages (e.g., scripts, which must always be real files such that their shebangs
+/// can be rewritten).
+fn symlink_wheel_files(
+    site_packages: impl AsRef<Path>,
+    wheel: impl AsRef<Path>,
+) -> Result<usize, Error> {
+    link_wheel_files(site_packages, wheel, "symlink", symlink_file, |relative| {
+        relative.components().next().is_some_and(|component| {
+            let name = component.as_os_str().to_string_lossy();
+            name.ends_with(".dist-info") || name.ends_with(".data")
+        })
+    })
+}
+
+/// Extract a wheel by linking all of its files into site packages with the given `link`
+/// function, falling back to copying if links aren't supported for this installation.
+///
+/// Files for which `copy` returns `true` (given their path relative to the wheel root) are always
+/// copied instead of linked.
+fn link_wheel_files(
+    site_packages: impl AsRef<Path>,
+    wheel: impl AsRef<Path>,
+    kind: &str,
+    link: impl Fn(&Path, &Path) -> std::io::Result<()>,
+    copy: impl Fn(&Path) -> bool,
 ) -> Result<usize, Error> {
     let mut attempt = Attempt::default();
     let mut count = 0usize;
@@ -444,19 +518,18 @@ fn hardlink_wheel_files(
             continue;
         }
 
-        // The `RECORD` file is modified during installation, so we copy it instead of hard-linking.
-        if path.ends_with("RECORD") {
+        if copy(relative) {
             fs::copy(path, &out_path)?;
             count += 1;
             continue;
         }
 
-        // Fallback to copying if hardlinks aren't supported for this installation.
+        // Fallback to copying if links aren't supported for this installation.
         match attempt {
             Attempt::Initial => {
                 // Once https://github.com/rust-lang/rust/issues/86442 is stable, use that.
                 attempt = Attempt::Subsequent;
-                if let Err(err) = fs::hard_link(path, &out_path) {
+                if let Err(err) = link(path, &out_path) {
                     // If the file already exists, remove it and try again.
                     if err.kind() == std::io::ErrorKind::AlreadyExists {
                         debug!(
@@ -466,11 +539,11 @@ fn hardlink_wheel_files(
                         // Removing and recreating would lead to race conditions.
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
-                        if fs::hard_link(path, &tempfile).is_ok() {
+                        if link(path, &tempfile).is_ok() {
                             fs_err::rename(&tempfile, &out_path)?;
                         } else {
                             debug!(
-                                "Failed to hardlink `{}` to `{}`, attempting to copy files as a fallback",
+                                "Failed to {kind} `{}` to `{}`, attempting to copy files as a fallback",
                                 out_path.display(),
                                 path.display()
                             );
@@ -479,7 +552,7 @@ fn hardlink_wheel_files(
                         }
                     } else {
                         debug!(
-                            "Failed to hardlink `{}` to `{}`, attempting to copy files as a fallback",
+                            "Failed to {kind} `{}` to `{}`, attempting to copy files as a fallback",
                             out_path.display(),
                             path.display()
                         );
@@ -489,7 +562,7 @@ fn hardlink_wheel_files(
                 }
             }
             Attempt::Subsequent => {
-                if let Err(err) = fs::hard_link(path, &out_path) {
+                if let Err(err) = link(path, &out_path) {
                     // If the file already exists, remove it and try again.
                     if err.kind() == std::io::ErrorKind::AlreadyExists {
                         debug!(
@@ -499,7 +572,7 @@ fn hardlink_wheel_files(
                         // Removing and recreating would lead to race conditions.
                         let tempdir = tempdir_in(&site_packages)?;
                         let tempfile = tempdir.path().join(entry.file_name());
-                        fs::hard_link(path, &tempfile)?;
+                        link(path, &tempfile)?;
                         fs_err::rename(&tempfile, &out_path)?;
                     } else {
                         return Err(err.into());
@@ -516,3 +589,15 @@ fn hardlink_wheel_files(
 
     Ok(count)
 }
+
+/// Create a symbolic link at `link` pointing to the file at `original`.
+#[cfg(unix)]
+fn symlink_file(original: &Path, link: &Path) -> std::io::Result<()> {
+    fs_err::os::unix::fs::symlink(original, link)
+}
+
+/// Create a symbolic link at `link` pointing to the file at `original`.
+#[cfg(windows)]
+fn symlink_file(original: &Path, link: &Path) -> std::io::Result<()> {
+    fs_err::os::windows::fs::symlink_file(original, link)
+}
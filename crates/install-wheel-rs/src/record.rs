@@ -11,6 +11,5 @@ use serde::{Deserialize, Serialize};
 pub(crate) struct RecordEntry {
     pub(crate) path: String,
     pub(crate) hash: Option<String>,
-    #[allow(dead_code)]
     pub(crate) size: Option<u64>,
 }
@@ -1,40 +1,79 @@
-use std::collections::BTreeSet;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Component, Path, PathBuf};
 
 use fs_err as fs;
 use tracing::debug;
 
-use crate::wheel::read_record_file;
+use crate::record::RecordEntry;
+use crate::wheel::{copy_and_hash, read_record_file};
 use crate::Error;
 
 /// Uninstall the wheel represented by the given `dist_info` directory.
-pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
-    let Some(site_packages) = dist_info.parent() else {
-        return Err(Error::BrokenVenv(
-            "dist-info directory is not in a site-packages directory".to_string(),
-        ));
-    };
+///
+/// Unless `force` is set, any file whose contents no longer match the hash recorded in the
+/// `RECORD` (i.e., a file that was modified after installation) is preserved, and reported in
+/// [`Uninstall::modified`].
+pub fn uninstall_wheel(dist_info: &Path, force: bool) -> Result<Uninstall, Error> {
+    let mut uninstalls = uninstall_wheels(&[dist_info], force)?;
+    Ok(uninstalls.pop().unwrap_or_default())
+}
 
-    // Read the RECORD file.
-    let record = {
-        let record_path = dist_info.join("RECORD");
-        let mut record_file = match fs::File::open(&record_path) {
-            Ok(record_file) => record_file,
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
-                return Err(Error::MissingRecord(record_path));
-            }
-            Err(err) => return Err(err.into()),
-        };
-        read_record_file(&mut record_file)?
-    };
+/// Uninstall the wheels represented by the given `dist_info` directories, returning a summary for
+/// each, in order.
+///
+/// The files of every wheel are removed before any empty directories are cleaned up, such that the
+/// `RECORD` files of the distributions that remain installed are only read once for the entire
+/// batch, rather than once per wheel.
+pub fn uninstall_wheels(dist_infos: &[&Path], force: bool) -> Result<Vec<Uninstall>, Error> {
+    let mut removed = Vec::with_capacity(dist_infos.len());
+    for dist_info in dist_infos {
+        removed.push(remove_files(dist_info, force)?);
+    }
+
+    // Determine the directories that contain files owned by the remaining distributions, once per
+    // `site-packages` directory.
+    let mut owned: BTreeMap<&Path, BTreeSet<PathBuf>> = BTreeMap::new();
+    let mut uninstalls = Vec::with_capacity(removed.len());
+    for (dist_info, (mut uninstall, visited)) in dist_infos.iter().zip(removed) {
+        if !visited.is_empty() {
+            let site_packages = site_packages(dist_info)?;
+            let owned = match owned.entry(site_packages) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(owned_directories(site_packages)?),
+            };
+            uninstall.dir_count += remove_unowned_directories(&visited, site_packages, owned)?;
+        }
+        uninstalls.push(uninstall);
+    }
+    Ok(uninstalls)
+}
+
+/// Remove the files of the wheel represented by the given `dist_info` directory, returning a
+/// summary along with the directories that contained the removed files.
+fn remove_files(dist_info: &Path, force: bool) -> Result<(Uninstall, BTreeSet<PathBuf>), Error> {
+    let site_packages = site_packages(dist_info)?;
+    let record = read_record(dist_info)?;
 
     let mut file_count = 0usize;
     let mut dir_count = 0usize;
+    let mut modified = Vec::new();
 
     // Uninstall the files, keeping track of any directories that are left empty.
     let mut visited = BTreeSet::new();
-    for entry in &record {
-        let path = site_packages.join(&entry.path);
+    for entry in record {
+        let path = normalize_path(&site_packages.join(&entry.path));
+
+        if !force {
+            if let Some(hash) = entry.hash.as_deref() {
+                if is_modified(&path, hash)? {
+                    debug!("Preserving modified file: {}", path.display());
+                    modified.push(path);
+                    continue;
+                }
+            }
+        }
+
         match fs::remove_file(&path) {
             Ok(()) => {
                 debug!("Removed file: {}", path.display());
@@ -55,8 +94,104 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
         }
     }
 
-    // If any directories were left empty, remove them. Iterate in reverse order such that we visit
-    // the deepest directories first.
+    // Remove the `.dist-info` directory itself, in case the `RECORD` doesn't list its contents
+    // (e.g., if it was written by an older installer under a different name).
+    match fs::remove_dir_all(dist_info) {
+        Ok(()) => {
+            debug!("Removed directory: {}", dist_info.display());
+            dir_count += 1;
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    Ok((
+        Uninstall {
+            file_count,
+            dir_count,
+            modified,
+        },
+        visited,
+    ))
+}
+
+/// Return the paths installed by the wheel represented by the given `dist_info` directory, as
+/// listed in its `RECORD` file.
+pub fn installed_paths(dist_info: &Path) -> Result<Vec<PathBuf>, Error> {
+    let site_packages = site_packages(dist_info)?;
+    let record = read_record(dist_info)?;
+
+    Ok(record
+        .iter()
+        .map(|entry| normalize_path(&site_packages.join(&entry.path)))
+        .collect())
+}
+
+/// Read the `RECORD` file in the given `dist_info` directory.
+pub(crate) fn read_record(dist_info: &Path) -> Result<Vec<RecordEntry>, Error> {
+    let record_path = dist_info.join("RECORD");
+    let mut record_file = match fs::File::open(&record_path) {
+        Ok(record_file) => record_file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(Error::MissingRecord(record_path));
+        }
+        Err(err) => return Err(err.into()),
+    };
+    read_record_file(&mut record_file)
+}
+
+/// Returns `true` if the file at the given path no longer matches the hash recorded in the
+/// `RECORD`.
+///
+/// Missing files, and hashes recorded with an algorithm other than SHA-256, are never considered
+/// modified.
+fn is_modified(path: &Path, hash: &str) -> Result<bool, Error> {
+    if !hash.starts_with("sha256=") {
+        return Ok(false);
+    }
+    match fs::metadata(path) {
+        // Directories (e.g., from a malformed `RECORD`) are handled by the caller.
+        Ok(metadata) if metadata.is_dir() => return Ok(false),
+        Ok(_) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(err.into()),
+    }
+    let mut file = fs::File::open(path)?;
+    let (_, actual) = copy_and_hash(&mut file, &mut std::io::sink())?;
+    Ok(actual != hash)
+}
+
+/// Remove any of the given directories (within `site-packages`) that were left empty, along with
+/// any parent directories that were left empty in turn, returning the number of directories that
+/// were removed.
+///
+/// A directory is only removed if none of the distributions that remain installed in
+/// `site-packages` own a file beneath it, as determined by the union of their `RECORD` files. This
+/// ensures that directories shared across distributions (e.g., PEP 420 namespace packages, which
+/// lack an `__init__.py`) are retained for as long as any of the distributions is installed.
+pub fn remove_empty_directories(
+    visited: &BTreeSet<PathBuf>,
+    site_packages: &Path,
+) -> Result<usize, Error> {
+    if visited.is_empty() {
+        return Ok(0);
+    }
+
+    // Determine the directories that contain files owned by the remaining distributions.
+    let owned = owned_directories(site_packages)?;
+    remove_unowned_directories(visited, site_packages, &owned)
+}
+
+/// Remove any of the given directories (within `site-packages`) that were left empty, skipping
+/// those in `owned`, which contain files owned by the remaining distributions.
+fn remove_unowned_directories(
+    visited: &BTreeSet<PathBuf>,
+    site_packages: &Path,
+    owned: &BTreeSet<PathBuf>,
+) -> Result<usize, Error> {
+    let mut dir_count = 0usize;
+
+    // Iterate in reverse order such that we visit the deepest directories first.
     for path in visited.iter().rev() {
         // No need to look at directories outside of `site-packages` (like `bin`).
         if !path.starts_with(site_packages) {
@@ -73,6 +208,16 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
                 break;
             }
 
+            // If another distribution owns a file beneath the directory, retain it (along with
+            // its parents).
+            if owned.contains(path) {
+                debug!(
+                    "Retaining directory owned by another distribution: {}",
+                    path.display()
+                );
+                break;
+            }
+
             // If the directory contains a `__pycache__` directory, always remove it. `__pycache__`
             // may or may not be listed in the RECORD, but installers are expected to be smart
             // enough to remove it either way.
@@ -86,20 +231,18 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
                 Err(err) => return Err(err.into()),
             }
 
-            // Try to read from the directory. If it doesn't exist, assume we deleted it in a
-            // previous iteration.
-            let mut read_dir = match fs::read_dir(path) {
-                Ok(read_dir) => read_dir,
+            // If the directory contains any files (e.g., files that were modified after
+            // installation, or that were never recorded), we're done. Otherwise, remove it,
+            // along with any empty subdirectories that would otherwise linger.
+            match contains_files(path) {
+                Ok(true) => break,
+                Ok(false) => {}
+                // If the directory doesn't exist, assume we deleted it in a previous iteration.
                 Err(err) if err.kind() == std::io::ErrorKind::NotFound => break,
                 Err(err) => return Err(err.into()),
-            };
-
-            // If the directory is not empty, we're done.
-            if read_dir.next().is_some() {
-                break;
             }
 
-            fs::remove_dir(path)?;
+            fs::remove_dir_all(path)?;
 
             debug!("Removed directory: {}", path.display());
             dir_count += 1;
@@ -112,9 +255,66 @@ pub fn uninstall_wheel(dist_info: &Path) -> Result<Uninstall, Error> {
         }
     }
 
-    Ok(Uninstall {
-        file_count,
-        dir_count,
+    Ok(dir_count)
+}
+
+/// Return the directories within `site-packages` that contain files owned by any of the
+/// distributions installed there, as listed in their `RECORD` files.
+///
+/// Distributions whose `RECORD` is missing or can't be read are skipped.
+fn owned_directories(site_packages: &Path) -> Result<BTreeSet<PathBuf>, Error> {
+    let mut owned = BTreeSet::new();
+    for entry in fs::read_dir(site_packages)? {
+        let entry = entry?;
+        let path = entry.path();
+        if !path
+            .extension()
+            .is_some_and(|extension| extension == "dist-info")
+        {
+            continue;
+        }
+
+        let record = match read_record(&path) {
+            Ok(record) => record,
+            Err(err) => {
+                debug!("Skipping unreadable `RECORD` in {}: {err}", path.display());
+                continue;
+            }
+        };
+        for entry in record {
+            let path = normalize_path(&site_packages.join(&entry.path));
+            for parent in path.ancestors().skip(1) {
+                if parent == site_packages || !parent.starts_with(site_packages) {
+                    break;
+                }
+                if !owned.insert(parent.to_path_buf()) {
+                    break;
+                }
+            }
+        }
+    }
+    Ok(owned)
+}
+
+/// Returns `true` if the given directory contains any files, at any depth.
+fn contains_files(path: &Path) -> std::io::Result<bool> {
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            if contains_files(&entry.path())? {
+                return Ok(true);
+            }
+        } else {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Return the `site-packages` directory that contains the given `dist_info` directory.
+pub(crate) fn site_packages(dist_info: &Path) -> Result<&Path, Error> {
+    dist_info.parent().ok_or_else(|| {
+        Error::BrokenVenv("dist-info directory is not in a site-packages directory".to_string())
     })
 }
 
@@ -124,12 +324,14 @@ pub struct Uninstall {
     pub file_count: usize,
     /// The number of directories that were removed during the uninstallation.
     pub dir_count: usize,
+    /// The files that were preserved, since they were modified after installation.
+    pub modified: Vec<PathBuf>,
 }
 
 /// Normalize a path, removing things like `.` and `..`.
 ///
 /// Source: <https://github.com/rust-lang/cargo/blob/b48c41aedbd69ee3990d62a0e2006edbb506a480/crates/cargo-util/src/paths.rs#L76C1-L109C2>
-fn normalize_path(path: &Path) -> PathBuf {
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
     let mut components = path.components().peekable();
     let mut ret = if let Some(c @ Component::Prefix(..)) = components.peek().copied() {
         components.next();
@@ -155,3 +357,99 @@ fn normalize_path(path: &Path) -> PathBuf {
     }
     ret
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use fs_err as fs;
+
+    use super::{uninstall_wheel, uninstall_wheels};
+
+    /// Install a fake distribution with the given files into `site-packages`, returning the path
+    /// to its `.dist-info` directory.
+    fn install(site_packages: &Path, name: &str, files: &[&str]) -> PathBuf {
+        let dist_info = site_packages.join(format!("{name}-1.0.dist-info"));
+        fs::create_dir_all(&dist_info).unwrap();
+        let mut record = String::new();
+        for file in files {
+            let path = site_packages.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, "").unwrap();
+            record.push_str(&format!("{file},,\n"));
+        }
+        record.push_str(&format!("{name}-1.0.dist-info/RECORD,,\n"));
+        fs::write(dist_info.join("RECORD"), record).unwrap();
+        dist_info
+    }
+
+    #[test]
+    fn uninstall_namespace_package() {
+        for reverse in [false, true] {
+            let temp_dir = tempfile::tempdir().unwrap();
+            let site_packages = temp_dir.path();
+
+            let foo = install(site_packages, "corp_foo", &["corp/foo/__init__.py"]);
+            let bar = install(site_packages, "corp_bar", &["corp/bar/__init__.py"]);
+            let (first, second, remaining) = if reverse {
+                (bar, foo, "corp/foo/__init__.py")
+            } else {
+                (foo, bar, "corp/bar/__init__.py")
+            };
+
+            // The shared namespace directory is retained while either distribution is installed.
+            uninstall_wheel(&first, true).unwrap();
+            assert!(site_packages.join(remaining).is_file());
+            assert_eq!(fs::read_dir(site_packages.join("corp")).unwrap().count(), 1);
+
+            // Once both are removed, nothing should linger.
+            uninstall_wheel(&second, true).unwrap();
+            assert!(!site_packages.join("corp").exists());
+            assert_eq!(fs::read_dir(site_packages).unwrap().count(), 0);
+        }
+    }
+
+    #[test]
+    fn uninstall_retains_owned_directory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let site_packages = temp_dir.path();
+
+        let foo = install(site_packages, "corp_foo", &["corp/foo/__init__.py"]);
+        install(site_packages, "corp_bar", &["corp/bar/__init__.py"]);
+
+        // Even if the other distribution's files are missing from disk, it still owns the shared
+        // namespace directory.
+        fs::remove_dir_all(site_packages.join("corp").join("bar")).unwrap();
+        uninstall_wheel(&foo, true).unwrap();
+        assert!(site_packages.join("corp").is_dir());
+    }
+
+    #[test]
+    fn uninstall_removes_empty_subdirectories() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let site_packages = temp_dir.path();
+
+        let foo = install(site_packages, "corp_foo", &["corp/foo/__init__.py"]);
+        fs::create_dir_all(site_packages.join("corp").join("stale").join("nested")).unwrap();
+
+        uninstall_wheel(&foo, true).unwrap();
+        assert!(!site_packages.join("corp").exists());
+    }
+
+    #[test]
+    fn uninstall_batch_skips_unreadable_record() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let site_packages = temp_dir.path();
+
+        let foo = install(site_packages, "corp_foo", &["corp/foo/__init__.py"]);
+        let bar = install(site_packages, "corp_bar", &["corp/bar/__init__.py"]);
+
+        // A remaining distribution with an unreadable `RECORD` doesn't block the uninstall.
+        let broken = site_packages.join("broken-1.0.dist-info");
+        fs::create_dir_all(broken.join("RECORD")).unwrap();
+
+        let uninstalls = uninstall_wheels(&[&foo, &bar], true).unwrap();
+        assert_eq!(uninstalls.len(), 2);
+        assert!(!site_packages.join("corp").exists());
+    }
+}
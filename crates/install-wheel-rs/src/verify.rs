@@ -0,0 +1,136 @@
+use std::path::{Path, PathBuf};
+
+use fs_err as fs;
+
+use crate::uninstall::{normalize_path, read_record, site_packages};
+use crate::wheel::copy_and_hash;
+use crate::Error;
+
+/// A discrepancy between a file listed in a `RECORD` and the file on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordDiscrepancy {
+    /// The file doesn't exist.
+    Missing { path: PathBuf },
+    /// The size of the file doesn't match the size recorded in the `RECORD`.
+    Size {
+        path: PathBuf,
+        expected: u64,
+        actual: u64,
+    },
+    /// The hash of the file doesn't match the hash recorded in the `RECORD`.
+    Hash {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl RecordDiscrepancy {
+    /// Return the path to the file with the discrepancy.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Missing { path } | Self::Size { path, .. } | Self::Hash { path, .. } => path,
+        }
+    }
+}
+
+/// Verify that the files listed in the `RECORD` of the given `dist_info` directory exist, and
+/// match their recorded sizes and hashes.
+///
+/// Entries without a recorded size or hash (like the `RECORD` itself) are only checked for
+/// existence, and hashes recorded with an algorithm other than SHA-256 are ignored.
+pub fn verify_record(dist_info: &Path) -> Result<Vec<RecordDiscrepancy>, Error> {
+    let site_packages = site_packages(dist_info)?;
+    let record = read_record(dist_info)?;
+
+    let mut discrepancies = Vec::new();
+    for entry in record {
+        let path = normalize_path(&site_packages.join(&entry.path));
+
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                discrepancies.push(RecordDiscrepancy::Missing { path });
+                continue;
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        // Directories (e.g., from a malformed `RECORD`) can't be verified.
+        if metadata.is_dir() {
+            continue;
+        }
+
+        if let Some(expected) = entry.size {
+            if metadata.len() != expected {
+                discrepancies.push(RecordDiscrepancy::Size {
+                    path,
+                    expected,
+                    actual: metadata.len(),
+                });
+                continue;
+            }
+        }
+
+        if let Some(expected) = entry.hash.filter(|hash| hash.starts_with("sha256=")) {
+            let mut file = fs::File::open(&path)?;
+            let (_, actual) = copy_and_hash(&mut file, &mut std::io::sink())?;
+            if actual != expected {
+                discrepancies.push(RecordDiscrepancy::Hash {
+                    path,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    Ok(discrepancies)
+}
+
+#[cfg(test)]
+mod test {
+    use fs_err as fs;
+
+    use crate::wheel::copy_and_hash;
+
+    use super::{verify_record, RecordDiscrepancy};
+
+    #[test]
+    fn verify_modified_and_missing_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let site_packages = temp_dir.path();
+
+        let dist_info = site_packages.join("example-1.0.dist-info");
+        fs::create_dir_all(&dist_info).unwrap();
+        fs::create_dir_all(site_packages.join("example")).unwrap();
+
+        let mut record = String::new();
+        for name in ["__init__.py", "a.py", "b.py"] {
+            let contents = format!("# {name}\n");
+            let (size, hash) =
+                copy_and_hash(&mut contents.as_bytes(), &mut std::io::sink()).unwrap();
+            fs::write(site_packages.join("example").join(name), &contents).unwrap();
+            record.push_str(&format!("example/{name},{hash},{size}\n"));
+        }
+        record.push_str("example-1.0.dist-info/RECORD,,\n");
+        fs::write(dist_info.join("RECORD"), record).unwrap();
+
+        assert!(verify_record(&dist_info).unwrap().is_empty());
+
+        // Modify one file (preserving its size), and remove another.
+        fs::write(site_packages.join("example").join("a.py"), "# A.py\n").unwrap();
+        fs::remove_file(site_packages.join("example").join("b.py")).unwrap();
+
+        let discrepancies = verify_record(&dist_info).unwrap();
+        assert_eq!(discrepancies.len(), 2);
+        assert!(matches!(
+            &discrepancies[0],
+            RecordDiscrepancy::Hash { path, .. } if path.ends_with("a.py")
+        ));
+        assert!(matches!(
+            &discrepancies[1],
+            RecordDiscrepancy::Missing { path } if path.ends_with("b.py")
+        ));
+    }
+}
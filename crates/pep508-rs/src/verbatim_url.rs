@@ -394,6 +394,11 @@ impl Scheme {
     pub fn is_file(self) -> bool {
         matches!(self, Self::File)
     }
+
+    /// Returns `true` if the scheme is a version control system (VCS) scheme (e.g., `git+https`).
+    pub fn is_vcs(self) -> bool {
+        !matches!(self, Self::File | Self::Http | Self::Https)
+    }
 }
 
 impl std::fmt::Display for Scheme {
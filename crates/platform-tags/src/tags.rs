@@ -5,6 +5,8 @@ use std::{cmp, num::NonZeroU32};
 
 use rustc_hash::FxHashMap;
 
+use uv_normalize::PackageName;
+
 use crate::{Arch, Os, Platform, PlatformError};
 
 #[derive(Debug, thiserror::Error)]
@@ -67,6 +69,8 @@ pub struct Tags {
     /// python_tag |--> abi_tag |--> platform_tag |--> priority
     #[allow(clippy::type_complexity)]
     map: Arc<FxHashMap<String, FxHashMap<String, FxHashMap<String, TagPriority>>>>,
+    /// package |--> additional platform tags that are compatible for that package
+    package_platforms: Arc<FxHashMap<PackageName, Vec<String>>>,
 }
 
 impl Tags {
@@ -84,7 +88,38 @@ impl Tags {
                 .entry(platform.to_string())
                 .or_insert(TagPriority::try_from(index).expect("valid tag priority"));
         }
-        Self { map: Arc::new(map) }
+        Self {
+            map: Arc::new(map),
+            package_platforms: Arc::default(),
+        }
+    }
+
+    /// Allow additional platform tags for specific packages.
+    ///
+    /// A wheel for one of the given packages is considered compatible if its Python and ABI tags
+    /// are compatible, and any of its platform tags is among those allowed for the package, even
+    /// if the platform itself doesn't support that tag. Such wheels are given the lowest priority.
+    #[must_use]
+    pub fn with_package_platforms(
+        self,
+        package_platforms: impl IntoIterator<Item = (PackageName, Vec<String>)>,
+    ) -> Self {
+        let package_platforms = package_platforms
+            .into_iter()
+            .filter(|(_, platforms)| !platforms.is_empty())
+            .collect::<FxHashMap<_, _>>();
+        Self {
+            map: self.map,
+            package_platforms: Arc::new(package_platforms),
+        }
+    }
+
+    /// Returns the additional platform tags that are allowed for the given package.
+    pub fn package_platforms(&self, package: &PackageName) -> &[String] {
+        self.package_platforms
+            .get(package)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
     }
 
     /// Returns the compatible tags for the given Python implementation (e.g., `cpython`), version,
@@ -255,6 +290,55 @@ impl Tags {
         }
         max_compatibility
     }
+
+    /// Returns the [`TagCompatibility`] of the given tags for a wheel of the given package,
+    /// accounting for any additional platform tags allowed for that package.
+    pub fn package_compatibility(
+        &self,
+        package: &PackageName,
+        wheel_python_tags: &[String],
+        wheel_abi_tags: &[String],
+        wheel_platform_tags: &[String],
+    ) -> TagCompatibility {
+        let compatibility =
+            self.compatibility(wheel_python_tags, wheel_abi_tags, wheel_platform_tags);
+
+        // If only the platform tag is incompatible (i.e., at least one Python and ABI tag pair is
+        // supported), accept any platform tag that's allowed for the package.
+        if compatibility == TagCompatibility::Incompatible(IncompatibleTag::Platform) {
+            let allowed = self.package_platforms(package);
+            if wheel_platform_tags
+                .iter()
+                .any(|wheel_platform| allowed.contains(wheel_platform))
+            {
+                return TagCompatibility::Compatible(
+                    TagPriority::try_from(0).expect("valid tag priority"),
+                );
+            }
+        }
+
+        compatibility
+    }
+
+    /// Returns true if a wheel of the given package with the given tags is compatible, accounting
+    /// for any additional platform tags allowed for that package.
+    pub fn is_package_compatible(
+        &self,
+        package: &PackageName,
+        wheel_python_tags: &[String],
+        wheel_abi_tags: &[String],
+        wheel_platform_tags: &[String],
+    ) -> bool {
+        self.is_compatible(wheel_python_tags, wheel_abi_tags, wheel_platform_tags)
+            || self
+                .package_compatibility(
+                    package,
+                    wheel_python_tags,
+                    wheel_abi_tags,
+                    wheel_platform_tags,
+                )
+                .is_compatible()
+    }
 }
 
 /// The priority of a platform tag.
@@ -478,9 +562,19 @@ fn compatible_tags(platform: &Platform) -> Result<Vec<String>, PlatformError> {
             vec!["win_amd64".to_string()]
         }
         (Os::Windows, Arch::Aarch64) => vec!["win_arm64".to_string()],
+        (Os::FreeBsd { release }, _) => {
+            // FreeBSD reports its own machine names (e.g., `amd64` rather than `x86_64`).
+            let machine = match arch {
+                Arch::X86_64 => "amd64".to_string(),
+                Arch::X86 => "i386".to_string(),
+                Arch::Aarch64 => "arm64".to_string(),
+                _ => arch.to_string(),
+            };
+            let release = release.replace(['.', '-'], "_");
+            vec![format!("freebsd_{release}_{machine}")]
+        }
         (
-            Os::FreeBsd { release }
-            | Os::NetBsd { release }
+            Os::NetBsd { release }
             | Os::OpenBsd { release }
             | Os::Dragonfly { release }
             | Os::Haiku { release },
@@ -602,6 +696,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_platform_tags_freebsd() {
+        let tags = compatible_tags(&Platform::new(
+            Os::FreeBsd {
+                release: "13.2-RELEASE".to_string(),
+            },
+            Arch::X86_64,
+        ))
+        .unwrap();
+        assert_debug_snapshot!(
+            tags,
+            @r###"
+        [
+            "freebsd_13_2_RELEASE_amd64",
+        ]
+        "###
+        );
+    }
+
+    #[test]
+    fn test_platform_tags_manylinux_s390x() {
+        let tags = compatible_tags(&Platform::new(
+            Os::Manylinux {
+                major: 2,
+                minor: 20,
+            },
+            Arch::S390X,
+        ))
+        .unwrap();
+        assert_debug_snapshot!(
+            tags,
+            @r###"
+        [
+            "manylinux_2_20_s390x",
+            "manylinux_2_19_s390x",
+            "manylinux_2_18_s390x",
+            "manylinux_2_17_s390x",
+            "manylinux2014_s390x",
+            "linux_s390x",
+        ]
+        "###
+        );
+    }
+
     #[test]
     fn test_platform_tags_macos() {
         let tags = compatible_tags(&Platform::new(
@@ -1547,6 +1685,130 @@ mod tests {
         );
     }
 
+    /// Check that `universal2` wheels are compatible with both macOS architectures.
+    #[test]
+    fn test_system_tags_macos_universal2() {
+        for arch in [Arch::X86_64, Arch::Aarch64] {
+            let tags = Tags::from_env(
+                &Platform::new(
+                    Os::Macos {
+                        major: 14,
+                        minor: 0,
+                    },
+                    arch,
+                ),
+                (3, 12),
+                "cpython",
+                (3, 12),
+                false,
+            )
+            .unwrap();
+            assert!(
+                tags.is_compatible(
+                    &["cp312".to_string()],
+                    &["cp312".to_string()],
+                    &["macosx_11_0_universal2".to_string()],
+                ),
+                "`macosx_11_0_universal2` should be compatible with {arch}"
+            );
+        }
+    }
+
+    /// Check that additional platform tags are only allowed for the given package.
+    #[test]
+    fn test_package_platforms() {
+        let tags = Tags::from_env(
+            &Platform::new(
+                Os::Macos {
+                    major: 14,
+                    minor: 0,
+                },
+                Arch::Aarch64,
+            ),
+            (3, 12),
+            "cpython",
+            (3, 12),
+            false,
+        )
+        .unwrap()
+        .with_package_platforms([(
+            PackageName::new("foo".to_string()).unwrap(),
+            vec!["linux_x86_64".to_string()],
+        )]);
+
+        let foo = PackageName::new("foo".to_string()).unwrap();
+        let bar = PackageName::new("bar".to_string()).unwrap();
+        let linux = ["linux_x86_64".to_string()];
+
+        assert!(tags.is_package_compatible(
+            &foo,
+            &["cp312".to_string()],
+            &["cp312".to_string()],
+            &linux,
+        ));
+        assert!(!tags.is_package_compatible(
+            &bar,
+            &["cp312".to_string()],
+            &["cp312".to_string()],
+            &linux,
+        ));
+
+        // The Python and ABI tags must still be compatible.
+        assert_eq!(
+            tags.package_compatibility(
+                &foo,
+                &["cp311".to_string()],
+                &["cp311".to_string()],
+                &linux,
+            ),
+            TagCompatibility::Incompatible(IncompatibleTag::Abi)
+        );
+    }
+
+    /// Check that `manylinux2014` wheels are compatible with `ppc64le` and `s390x`, for which
+    /// `manylinux2014` is the oldest supported `manylinux` tag.
+    #[test]
+    fn test_system_tags_manylinux_ppc64le_s390x() {
+        for arch in [Arch::Powerpc64Le, Arch::S390X] {
+            let tags = Tags::from_env(
+                &Platform::new(
+                    Os::Manylinux {
+                        major: 2,
+                        minor: 28,
+                    },
+                    arch,
+                ),
+                (3, 12),
+                "cpython",
+                (3, 12),
+                false,
+            )
+            .unwrap();
+            for platform_tag in [
+                format!("manylinux_2_28_{arch}"),
+                format!("manylinux2014_{arch}"),
+                format!("linux_{arch}"),
+            ] {
+                assert!(
+                    tags.is_compatible(
+                        &["cp312".to_string()],
+                        &["cp312".to_string()],
+                        &[platform_tag.clone()],
+                    ),
+                    "`{platform_tag}` should be compatible with {arch}"
+                );
+            }
+            assert!(
+                !tags.is_compatible(
+                    &["cp312".to_string()],
+                    &["cp312".to_string()],
+                    &[format!("manylinux1_{arch}")],
+                ),
+                "`manylinux1_{arch}` should not be compatible with {arch}"
+            );
+        }
+    }
+
     #[test]
     fn test_system_tags_macos() {
         let tags = Tags::from_env(
@@ -4,6 +4,8 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use crate::HashDigest;
+
 /// Metadata for a distribution that was installed via a direct URL.
 ///
 /// See: <https://packaging.python.org/en/latest/specifications/direct-url-data-structure/>
@@ -37,6 +39,28 @@ pub enum DirectUrl {
     },
 }
 
+impl DirectUrl {
+    /// Record the given hashes in the `archive_info` of an archive URL.
+    ///
+    /// PEP 610 only defines hashes for archives, so local directories and VCS URLs are returned
+    /// unchanged.
+    #[must_use]
+    pub fn with_hashes(self, hashes: &[HashDigest]) -> Self {
+        match self {
+            Self::ArchiveUrl {
+                url,
+                archive_info: _,
+                subdirectory,
+            } if !hashes.is_empty() => Self::ArchiveUrl {
+                url,
+                archive_info: ArchiveInfo::from(hashes),
+                subdirectory,
+            },
+            direct_url => direct_url,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct DirInfo {
@@ -53,6 +77,24 @@ pub struct ArchiveInfo {
     pub hashes: Option<HashMap<String, String>>,
 }
 
+impl From<&[HashDigest]> for ArchiveInfo {
+    fn from(hashes: &[HashDigest]) -> Self {
+        Self {
+            // The deprecated `hash` key is still read by older tools, so populate it alongside
+            // `hashes`.
+            hash: hashes
+                .first()
+                .map(|hash| format!("{}={}", hash.algorithm, hash.digest)),
+            hashes: Some(
+                hashes
+                    .iter()
+                    .map(|hash| (hash.algorithm.to_string(), hash.digest.to_string()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct VcsInfo {
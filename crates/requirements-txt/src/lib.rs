@@ -7,6 +7,7 @@
 //!  * `-r`
 //!  * `-c`
 //!  * `--hash` (postfix)
+//!  * `--pre` (postfix)
 //!  * `-e`
 //!
 //! Unsupported:
@@ -28,8 +29,9 @@
 //! # https://packaging.python.org/en/latest/specifications/name-normalization/#valid-non-normalized-names
 //! # This does not (yet?) support plain files or urls, we use a letter or a number as first
 //! # character to assume a PEP 508 requirement
-//! requirement = [a-zA-Z0-9] pep508_grammar_tail wrappable_whitespaces hashes
-//! hashes = ('--hash' ('=' | wrappable_whitespaces) [a-zA-Z0-9-_]+ ':' [a-zA-Z0-9-_] wrappable_whitespaces+)*
+//! requirement = [a-zA-Z0-9] pep508_grammar_tail wrappable_whitespaces options
+//! options = (hash | '--pre' wrappable_whitespaces+)*
+//! hash = '--hash' ('=' | wrappable_whitespaces) [a-zA-Z0-9-_]+ ':' [a-zA-Z0-9-_] wrappable_whitespaces+
 //! # This should indicate a single backslash before a newline
 //! wrappable_whitespaces = whitespace ('\\\n' | whitespace)*
 //! ```
@@ -75,6 +77,8 @@ enum RequirementsTxtStatement {
     RequirementEntry(RequirementEntry),
     /// `-e`
     EditableRequirement(EditableRequirement),
+    /// `-e` with a VCS URL, parsed as a regular requirement
+    EditableVcs(RequirementEntry),
     /// `--index-url`
     IndexUrl(VerbatimUrl),
     /// `--extra-index-url`
@@ -299,15 +303,117 @@ pub struct RequirementEntry {
     pub requirement: RequirementsTxtRequirement,
     /// Hashes of the downloadable packages
     pub hashes: Vec<String>,
+    /// Comments associated with the requirement in the requirements.txt
+    #[serde(default)]
+    pub comments: RequirementComments,
+    /// Whether pre-release versions are allowed for this requirement, as in `black --pre`
+    #[serde(default)]
+    pub prerelease: bool,
 }
 
 impl Display for RequirementEntry {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.requirement)?;
+        f.write_str(&self.to_requirements_txt_line())
+    }
+}
+
+impl RequirementEntry {
+    /// Serialize the entry as a single `requirements.txt` line, including the source of the
+    /// requirement (e.g., a Git, URL, or path dependency), its hashes, and `--pre`.
+    ///
+    /// The line parses back to an equal [`RequirementEntry`], with the exception of any comments,
+    /// which are omitted. Relative paths are written as absolute `file://` URLs, such that the line
+    /// doesn't depend on the directory of the file that it's written to.
+    pub fn to_requirements_txt_line(&self) -> String {
+        let mut line = self.requirement.to_string();
         for hash in &self.hashes {
-            write!(f, " --hash {hash}")?;
+            line.push_str(" --hash=");
+            line.push_str(hash);
         }
-        Ok(())
+        if self.prerelease {
+            line.push_str(" --pre");
+        }
+        line
+    }
+
+    /// Parse an editable requirement for a VCS URL (e.g., `git+https://...#egg=example`) as a
+    /// regular, non-editable requirement.
+    ///
+    /// The package name is taken from the `egg` fragment, if present; otherwise, the requirement
+    /// is unnamed. Returns `None` if the editable requirement isn't a VCS URL.
+    pub fn from_editable_vcs(
+        given: &str,
+        working_dir: impl AsRef<Path>,
+    ) -> Result<Option<Self>, RequirementsTxtParserError> {
+        let expanded = expand_env_vars(given);
+        if !split_scheme(&expanded)
+            .and_then(|(scheme, _)| Scheme::parse(scheme))
+            .is_some_and(Scheme::is_vcs)
+        {
+            return Ok(None);
+        }
+
+        // Remove the `egg` parameter from the fragment, retaining any others (e.g.,
+        // `subdirectory`).
+        let (url, egg) = match expanded.split_once('#') {
+            Some((url, fragment)) => {
+                let mut egg = None;
+                let fragment = fragment
+                    .split('&')
+                    .filter(|parameter| {
+                        if let Some(value) = parameter.strip_prefix("egg=") {
+                            egg = Some(value.to_string());
+                            false
+                        } else {
+                            true
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("&");
+                if fragment.is_empty() {
+                    (url.to_string(), egg)
+                } else {
+                    (format!("{url}#{fragment}"), egg)
+                }
+            }
+            None => (expanded.to_string(), None),
+        };
+
+        // Ex) `example[dev] @ git+https://...`
+        let requirement = if let Some(egg) = egg {
+            format!("{egg} @ {url}")
+        } else {
+            url
+        };
+        let requirement = RequirementsTxtRequirement::parse(&requirement, working_dir.as_ref())
+            .map_err(|err| RequirementsTxtParserError::Pep508 {
+                start: 0,
+                end: given.len(),
+                source: err,
+            })?;
+
+        Ok(Some(Self {
+            requirement,
+            hashes: vec![],
+            comments: RequirementComments::default(),
+            prerelease: false,
+        }))
+    }
+}
+
+/// The comments associated with a requirement, such that they can be re-emitted alongside it.
+#[derive(Debug, Default, Deserialize, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct RequirementComments {
+    /// The comment lines directly preceding the requirement, without the leading `#`.
+    pub leading: Vec<String>,
+    /// The comment at the end of the requirement, without the leading `#`.
+    pub trailing: Option<String>,
+}
+
+impl RequirementComments {
+    /// Returns `true` if there are no comments associated with the requirement.
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.trailing.is_none()
     }
 }
 
@@ -320,6 +426,9 @@ pub struct RequirementsTxt {
     pub constraints: Vec<Requirement>,
     /// Editables with `-e`.
     pub editables: Vec<EditableRequirement>,
+    /// Editables with `-e` for VCS URLs (e.g., `-e git+https://...#egg=example`), which can't be
+    /// installed in editable mode, parsed as regular requirements.
+    pub editable_vcs: Vec<RequirementEntry>,
     /// The index URL, specified with `--index-url`.
     pub index_url: Option<VerbatimUrl>,
     /// The extra index URLs, specified with `--extra-index-url`.
@@ -502,6 +611,9 @@ impl RequirementsTxt {
                 RequirementsTxtStatement::EditableRequirement(editable) => {
                     data.editables.push(editable);
                 }
+                RequirementsTxtStatement::EditableVcs(requirement_entry) => {
+                    data.editable_vcs.push(requirement_entry);
+                }
                 RequirementsTxtStatement::IndexUrl(url) => {
                     if data.index_url.is_some() {
                         let (line, column) = calculate_row_column(content, s.cursor());
@@ -539,6 +651,7 @@ impl RequirementsTxt {
             requirements,
             constraints,
             editables,
+            editable_vcs,
             index_url,
             extra_index_urls,
             find_links,
@@ -549,6 +662,7 @@ impl RequirementsTxt {
         self.requirements.extend(requirements);
         self.constraints.extend(constraints);
         self.editables.extend(editables);
+        self.editable_vcs.extend(editable_vcs);
         if self.index_url.is_none() {
             self.index_url = index_url;
         }
@@ -569,12 +683,25 @@ fn parse_entry(
     content: &str,
     working_dir: &Path,
 ) -> Result<Option<RequirementsTxtStatement>, RequirementsTxtParserError> {
-    // Eat all preceding whitespace, this may run us to the end of file
-    eat_wrappable_whitespace(s);
+    // Eat all preceding whitespace, this may run us to the end of file. Retain the comment lines
+    // along with their indentation, such that they can be attached to a requirement.
+    let mut comments = Vec::new();
+    let mut line_start = s.before().is_empty() || s.before().ends_with(['\n', '\r']);
+    let mut indent = eat_wrappable_whitespace(s);
     while s.at(['\n', '\r', '#']) {
-        // skip comments
+        if s.eat_if('#') {
+            let comment = s.eat_until(['\r', '\n']).trim();
+            // A comment at the end of the previous entry's line belongs to that entry.
+            if line_start {
+                comments.push((indent, comment));
+            }
+        } else {
+            // An empty line detaches any preceding comments from the next entry.
+            comments.clear();
+        }
         eat_trailing_line(content, s)?;
-        eat_wrappable_whitespace(s);
+        line_start = true;
+        indent = eat_wrappable_whitespace(s);
     }
 
     let start = s.cursor();
@@ -595,10 +722,29 @@ fn parse_entry(
             end,
         }
     } else if s.eat_if("-e") || s.eat_if("--editable") {
-        let path_or_url = parse_value(content, s, |c: char| !['\n', '\r', '#'].contains(&c))?;
-        let editable_requirement = EditableRequirement::parse(path_or_url, working_dir)
-            .map_err(|err| err.with_offset(start))?;
-        RequirementsTxtStatement::EditableRequirement(editable_requirement)
+        let mut path_or_url = parse_value(content, s, |c: char| !['\n', '\r', '#'].contains(&c))?;
+
+        // A `#` that directly follows a URL (as in `git+https://...#egg=example`) starts the URL
+        // fragment rather than a comment.
+        if s.at('#')
+            && !s.before().ends_with(char::is_whitespace)
+            && split_scheme(path_or_url).is_some_and(|(scheme, _)| scheme != "file")
+        {
+            let value_start = s.cursor() - path_or_url.len();
+            s.eat_until(|c: char| c.is_whitespace());
+            path_or_url = &content[value_start..s.cursor()];
+        }
+
+        if let Some(requirement_entry) =
+            RequirementEntry::from_editable_vcs(path_or_url, working_dir)
+                .map_err(|err| err.with_offset(start))?
+        {
+            RequirementsTxtStatement::EditableVcs(requirement_entry)
+        } else {
+            let editable_requirement = EditableRequirement::parse(path_or_url, working_dir)
+                .map_err(|err| err.with_offset(start))?;
+            RequirementsTxtStatement::EditableRequirement(editable_requirement)
+        }
     } else if s.eat_if("-i") || s.eat_if("--index-url") {
         let given = parse_value(content, s, |c: char| !['\n', '\r', '#'].contains(&c))?;
         let expanded = expand_env_vars(given);
@@ -659,10 +805,23 @@ fn parse_entry(
         })?;
         RequirementsTxtStatement::OnlyBinary(NoBuild::from_arg(specifier))
     } else if s.at(char::is_ascii_alphanumeric) || s.at(|char| matches!(char, '.' | '/' | '$')) {
-        let (requirement, hashes) = parse_requirement_and_hashes(s, content, working_dir)?;
+        // Only the comments at the same indentation as the requirement belong to it; an indented
+        // comment, such as a `# via` annotation, belongs to the previous requirement.
+        let attached = comments
+            .iter()
+            .rposition(|(comment_indent, _)| *comment_indent != indent)
+            .map_or(0, |index| index + 1);
+        let leading = comments[attached..]
+            .iter()
+            .map(|(_, comment)| (*comment).to_string())
+            .collect();
+        let (requirement, hashes, prerelease, trailing) =
+            parse_requirement_and_hashes(s, content, working_dir)?;
         RequirementsTxtStatement::RequirementEntry(RequirementEntry {
             requirement,
             hashes,
+            comments: RequirementComments { leading, trailing },
+            prerelease,
         })
     } else if let Some(char) = s.peek() {
         let (line, column) = calculate_row_column(content, s.cursor());
@@ -717,16 +876,25 @@ fn eat_trailing_line(content: &str, s: &mut Scanner) -> Result<(), RequirementsT
     Ok(())
 }
 
-/// Parse a PEP 508 requirement with optional trailing hashes
+/// Parse a PEP 508 requirement with optional trailing hashes, `--pre` flag and comment
 fn parse_requirement_and_hashes(
     s: &mut Scanner,
     content: &str,
     working_dir: &Path,
-) -> Result<(RequirementsTxtRequirement, Vec<String>), RequirementsTxtParserError> {
+) -> Result<
+    (
+        RequirementsTxtRequirement,
+        Vec<String>,
+        bool,
+        Option<String>,
+    ),
+    RequirementsTxtParserError,
+> {
     // PEP 508 requirement
     let start = s.cursor();
+    let mut comment = None;
     // Termination: s.eat() eventually becomes None
-    let (end, has_hashes) = loop {
+    let (end, has_options) = loop {
         let end = s.cursor();
 
         //  We look for the end of the line ...
@@ -737,12 +905,12 @@ fn parse_requirement_and_hashes(
             s.eat_if('\n'); // Support `\r\n` but also accept stray `\r`
             break (end, false);
         }
-        // ... or `--hash`, an escaped newline or a comment separated by whitespace ...
+        // ... or `--hash` or `--pre`, an escaped newline or a comment separated by whitespace ...
         if !eat_wrappable_whitespace(s).is_empty() {
             if s.after().starts_with("--") {
                 break (end, true);
             } else if s.eat_if('#') {
-                s.eat_until(['\r', '\n']);
+                comment = Some(s.eat_until(['\r', '\n']).trim().to_string());
                 if s.at('\r') {
                     s.eat_if('\n'); // `\r\n`, but just `\r` is also accepted
                 }
@@ -798,39 +966,50 @@ fn parse_requirement_and_hashes(
             }
         })?;
 
-    let hashes = if has_hashes {
-        parse_hashes(content, s)?
+    let (hashes, prerelease) = if has_options {
+        let options = parse_options(content, s)?;
+        // A comment may follow the options; the end of the line is consumed with the next entry.
+        if s.eat_if('#') {
+            comment = Some(s.eat_until(['\r', '\n']).trim().to_string());
+        }
+        options
     } else {
-        Vec::new()
+        (Vec::new(), false)
     };
-    Ok((requirement, hashes))
+    Ok((requirement, hashes, prerelease, comment))
 }
 
-/// Parse `--hash=... --hash ...` after a requirement
-fn parse_hashes(content: &str, s: &mut Scanner) -> Result<Vec<String>, RequirementsTxtParserError> {
+/// Parse `--hash=... --hash ... --pre` after a requirement
+fn parse_options(
+    content: &str,
+    s: &mut Scanner,
+) -> Result<(Vec<String>, bool), RequirementsTxtParserError> {
     let mut hashes = Vec::new();
-    if s.eat_while("--hash").is_empty() {
-        let (line, column) = calculate_row_column(content, s.cursor());
-        return Err(RequirementsTxtParserError::Parser {
-            message: format!(
-                "Expected '--hash', found '{:?}'",
-                s.eat_while(|c: char| !c.is_whitespace())
-            ),
-            line,
-            column,
-        });
-    }
-    let hash = parse_value(content, s, |c: char| !c.is_whitespace())?;
-    hashes.push(hash.to_string());
+    let mut prerelease = false;
     loop {
+        let (line, column) = calculate_row_column(content, s.cursor());
+        match s.eat_while(|c: char| !c.is_whitespace() && c != '=') {
+            "--hash" => {
+                let hash = parse_value(content, s, |c: char| !c.is_whitespace())?;
+                hashes.push(hash.to_string());
+            }
+            "--pre" => {
+                prerelease = true;
+            }
+            option => {
+                return Err(RequirementsTxtParserError::Parser {
+                    message: format!("Expected '--hash' or '--pre', found '{option:?}'"),
+                    line,
+                    column,
+                });
+            }
+        }
         eat_wrappable_whitespace(s);
-        if !s.eat_if("--hash") {
+        if !s.after().starts_with("--") {
             break;
         }
-        let hash = parse_value(content, s, |c: char| !c.is_whitespace())?;
-        hashes.push(hash.to_string());
     }
-    Ok(hashes)
+    Ok((hashes, prerelease))
 }
 
 /// In `-<key>=<value>` or `-<key> value`, this parses the part after the key
@@ -1283,7 +1462,7 @@ mod test {
     use uv_client::BaseClientBuilder;
     use uv_fs::Simplified;
 
-    use crate::{calculate_row_column, EditableRequirement, RequirementsTxt};
+    use crate::{calculate_row_column, EditableRequirement, RequirementEntry, RequirementsTxt};
 
     fn workspace_test_data_dir() -> PathBuf {
         PathBuf::from("./test-data").canonicalize().unwrap()
@@ -1551,6 +1730,88 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn editable_vcs() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {"
+            -e git+https://github.com/pallets/flask.git@3.0.0#egg=flask
+            -e git+https://github.com/org/repo.git#egg=example[dev]&subdirectory=pkg # comment
+            --editable git+https://github.com/org/unnamed.git
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await?;
+
+        assert!(requirements.editables.is_empty());
+        assert_eq!(
+            requirements
+                .editable_vcs
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            vec![
+                "flask @ git+https://github.com/pallets/flask.git@3.0.0",
+                "example[dev] @ git+https://github.com/org/repo.git#subdirectory=pkg",
+                "git+https://github.com/org/unnamed.git",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn round_trip() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {r"
+            flask
+            flask==3.0.0
+            Flask[async,dotenv] >= 2.0, < 4.0
+            flask>=2.0 ; python_version >= '3.8' and sys_platform != 'win32'
+            black --pre
+            iniconfig==2.0.0 --hash=sha256:1234 \
+                --hash sha256:5678
+            anyio @ https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl
+            anyio[trio] @ git+https://github.com/agronholm/anyio@4.3.0 ; python_version >= '3.8'
+            git+https://github.com/pallets/flask.git@3.0.0#subdirectory=src
+            https://files.pythonhosted.org/packages/14/fd/2f20c40b45e4fb4324834aea24bd4afdf1143390242c0b33774da0e2e34f/anyio-4.3.0-py3-none-any.whl[trio] --hash=sha256:048e05d0f6caeed70d731f3db756d35dcc1f35ad8d2f2def2294c5a4c2fe8b7c
+            ./scripts/packages/black_editable[dev] ; python_version >= '3.8'
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await?;
+        assert_eq!(requirements.requirements.len(), 11);
+
+        // Serialize each requirement, and parse the result from a different directory.
+        let serialized = temp_dir.child("nested").child("requirements.txt");
+        serialized.write_str(
+            &requirements
+                .requirements
+                .iter()
+                .map(RequirementEntry::to_requirements_txt_line)
+                .join("\n"),
+        )?;
+
+        let round_trip = RequirementsTxt::parse(
+            serialized.path(),
+            temp_dir.child("nested").path(),
+            &BaseClientBuilder::new(),
+        )
+        .await?;
+        assert_eq!(requirements.requirements, round_trip.requirements);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn invalid_editable_extra() -> Result<()> {
         let temp_dir = assert_fs::TempDir::new()?;
@@ -1733,10 +1994,94 @@ mod test {
                         },
                     ),
                     hashes: [],
+                    comments: RequirementComments {
+                        leading: [],
+                        trailing: None,
+                    },
+                    prerelease: false,
                 },
             ],
             constraints: [],
             editables: [],
+            editable_vcs: [],
+            index_url: None,
+            extra_index_urls: [],
+            find_links: [],
+            no_index: false,
+            no_binary: None,
+            only_binary: None,
+        }
+        "###);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn prerelease() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {r"
+            black --pre
+            flask \
+                --hash=sha256:1234 \
+                --pre  # comment
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap();
+        insta::assert_debug_snapshot!(requirements, @r###"
+        RequirementsTxt {
+            requirements: [
+                RequirementEntry {
+                    requirement: Pep508(
+                        Requirement {
+                            name: PackageName(
+                                "black",
+                            ),
+                            extras: [],
+                            version_or_url: None,
+                            marker: None,
+                        },
+                    ),
+                    hashes: [],
+                    comments: RequirementComments {
+                        leading: [],
+                        trailing: None,
+                    },
+                    prerelease: true,
+                },
+                RequirementEntry {
+                    requirement: Pep508(
+                        Requirement {
+                            name: PackageName(
+                                "flask",
+                            ),
+                            extras: [],
+                            version_or_url: None,
+                            marker: None,
+                        },
+                    ),
+                    hashes: [
+                        "sha256:1234",
+                    ],
+                    comments: RequirementComments {
+                        leading: [],
+                        trailing: Some(
+                            "comment",
+                        ),
+                    },
+                    prerelease: true,
+                },
+            ],
+            constraints: [],
+            editables: [],
+            editable_vcs: [],
             index_url: None,
             extra_index_urls: [],
             find_links: [],
@@ -1787,10 +2132,16 @@ mod test {
                         },
                     ),
                     hashes: [],
+                    comments: RequirementComments {
+                        leading: [],
+                        trailing: None,
+                    },
+                    prerelease: false,
                 },
             ],
             constraints: [],
             editables: [],
+            editable_vcs: [],
             index_url: None,
             extra_index_urls: [],
             find_links: [],
@@ -1864,6 +2215,7 @@ mod test {
                     path: "/foo/bar",
                 },
             ],
+            editable_vcs: [],
             index_url: None,
             extra_index_urls: [],
             find_links: [],
@@ -1969,6 +2321,13 @@ mod test {
                         },
                     ),
                     hashes: [],
+                    comments: RequirementComments {
+                        leading: [],
+                        trailing: Some(
+                            "comment",
+                        ),
+                    },
+                    prerelease: false,
                 },
                 RequirementEntry {
                     requirement: Pep508(
@@ -1995,6 +2354,13 @@ mod test {
                     hashes: [
                         "sha256:1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef",
                     ],
+                    comments: RequirementComments {
+                        leading: [],
+                        trailing: Some(
+                            "comment",
+                        ),
+                    },
+                    prerelease: false,
                 },
                 RequirementEntry {
                     requirement: Pep508(
@@ -2021,6 +2387,13 @@ mod test {
                     hashes: [
                         "sha256:fedcba0987654321fedcba0987654321fedcba0987654321fedcba0987654321",
                     ],
+                    comments: RequirementComments {
+                        leading: [],
+                        trailing: Some(
+                            "comment",
+                        ),
+                    },
+                    prerelease: false,
                 },
                 RequirementEntry {
                     requirement: Pep508(
@@ -2045,6 +2418,13 @@ mod test {
                         },
                     ),
                     hashes: [],
+                    comments: RequirementComments {
+                        leading: [],
+                        trailing: Some(
+                            "comment",
+                        ),
+                    },
+                    prerelease: false,
                 },
                 RequirementEntry {
                     requirement: Pep508(
@@ -2069,10 +2449,18 @@ mod test {
                         },
                     ),
                     hashes: [],
+                    comments: RequirementComments {
+                        leading: [],
+                        trailing: Some(
+                            "comment",
+                        ),
+                    },
+                    prerelease: false,
                 },
             ],
             constraints: [],
             editables: [],
+            editable_vcs: [],
             index_url: Some(
                 VerbatimUrl {
                     url: Url {
@@ -2106,6 +2494,49 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn leading_comments() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+
+        let requirements_txt = temp_dir.child("requirements.txt");
+        requirements_txt.write_str(indoc! {r"
+            --index-url https://test.pypi.org/simple/  # index
+            # first
+            # second
+            flask==3.0.0
+                # via annotation
+            # third
+            requests==2.26.0
+
+            # detached
+             
+            black==21.12b0
+        "})?;
+
+        let requirements = RequirementsTxt::parse(
+            requirements_txt.path(),
+            temp_dir.path(),
+            &BaseClientBuilder::new(),
+        )
+        .await
+        .unwrap();
+        let leading = requirements
+            .requirements
+            .iter()
+            .map(|entry| entry.comments.leading.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            leading,
+            vec![
+                vec!["first".to_string(), "second".to_string()],
+                vec!["third".to_string()],
+                vec![],
+            ]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn editable_extra() {
         assert_eq!(
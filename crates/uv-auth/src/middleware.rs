@@ -3,10 +3,12 @@ use std::sync::Arc;
 use http::Extensions;
 
 use netrc::Netrc;
-use reqwest::{Request, Response};
+use reqwest::{Request, Response, StatusCode};
 use reqwest_middleware::{Middleware, Next};
 use tracing::{debug, trace};
 
+use url::Url;
+
 use crate::{
     cache::CheckResponse, credentials::Credentials, CredentialsCache, KeyringProvider,
     CREDENTIALS_CACHE,
@@ -14,6 +16,11 @@ use crate::{
 
 /// A middleware that adds basic authentication to requests based on the netrc file and the keyring.
 ///
+/// Credentials are taken from (1) the request URL, then (2) the netrc file, then (3) the keyring,
+/// where each source is only consulted if the previous sources yield nothing. Credentials are
+/// cached per realm, and if cached credentials are rejected with a `401 Unauthorized`, the request
+/// is retried once with fresh credentials from the netrc file or keyring.
+///
 /// Netrc support Based on: <https://github.com/gribouille/netrc>.
 pub struct AuthMiddleware {
     netrc: Option<Netrc>,
@@ -59,6 +66,38 @@ impl AuthMiddleware {
     fn cache(&self) -> &CredentialsCache {
         self.cache.as_ref().unwrap_or(&CREDENTIALS_CACHE)
     }
+
+    /// Look for complete credentials for a URL in the netrc file and then the keyring.
+    ///
+    /// If a username is provided, only credentials for that username are returned.
+    fn fetch(&self, url: &Url, username: Option<&str>, display_url: &str) -> Option<Credentials> {
+        if let Some(credentials) = self.netrc.as_ref().and_then(|netrc| {
+            trace!("Checking netrc for credentials for {display_url}");
+            Credentials::from_netrc(netrc, url, username)
+        }) {
+            debug!("Found credentials in netrc file for {display_url}");
+            return Some(credentials);
+        }
+
+        // N.B. The keyring provider performs lookups for the exact URL then
+        //      falls back to the host, but we cache the result per host so if a keyring
+        //      implementation returns different credentials for different URLs in the
+        //      same realm we will use the wrong credentials.
+        if let Some(credentials) = self.keyring.as_ref().and_then(|keyring| {
+            if let Some(username) = username {
+                debug!("Checking keyring for credentials for {display_url}");
+                keyring.fetch(url, username)
+            } else {
+                trace!("Skipping keyring lookup for {display_url} with no username");
+                None
+            }
+        }) {
+            debug!("Found credentials in keyring for {display_url}");
+            return Some(credentials);
+        }
+
+        None
+    }
 }
 
 impl Default for AuthMiddleware {
@@ -100,55 +139,43 @@ impl Middleware for AuthMiddleware {
         };
         trace!("Handling request for {url}");
 
+        // Retain the username from the request itself, in case we need to look up credentials
+        // again after a failed request
+        let username = credentials
+            .as_ref()
+            .and_then(|credentials| credentials.username())
+            .map(str::to_string);
+
         // Then check for credentials in (2) the cache
         let credentials = self.cache().check(request.url(), credentials);
 
         // Track credentials that we might want to insert into the cache
         let mut new_credentials = None;
 
+        // Track credentials from the cache, which we might want to replace if they're rejected
+        let mut cached_credentials = None;
+
         // If already authenticated (including a password), don't query other services
         if credentials.is_authenticated() {
             match credentials {
                 // If we get credentials from the cache, update the request
-                CheckResponse::Cached(credentials) => request = credentials.authenticate(request),
+                CheckResponse::Cached(credentials) => {
+                    request = credentials.authenticate(request);
+                    cached_credentials = Some(credentials);
+                }
                 // If we get credentials from the request, we should update the cache
                 // but don't need to update the request
                 CheckResponse::Uncached(credentials) => new_credentials = Some(credentials.clone()),
                 CheckResponse::None => unreachable!("No credentials cannot be authenticated"),
             }
-        // Otherwise, look for complete credentials in:
-        // (3) The netrc file
-        } else if let Some(credentials) = self.netrc.as_ref().and_then(|netrc| {
-            trace!("Checking netrc for credentials for {url}");
-            Credentials::from_netrc(
-                netrc,
-                request.url(),
-                credentials
-                    .get()
-                    .and_then(|credentials| credentials.username()),
-            )
-        }) {
-            debug!("Found credentials in netrc file for {url}");
-            request = credentials.authenticate(request);
-            new_credentials = Some(Arc::new(credentials));
-        // (4) The keyring
-        // N.B. The keyring provider performs lookups for the exact URL then
-        //      falls back to the host, but we cache the result per host so if a keyring
-        //      implementation returns different credentials for different URLs in the
-        //      same realm we will use the wrong credentials.
-        } else if let Some(credentials) = self.keyring.as_ref().and_then(|keyring| {
-            if let Some(username) = credentials
+        // Otherwise, look for complete credentials in (3) the netrc file and (4) the keyring
+        } else if let Some(credentials) = self.fetch(
+            request.url(),
+            credentials
                 .get()
-                .and_then(|credentials| credentials.username())
-            {
-                debug!("Checking keyring for credentials for {url}");
-                keyring.fetch(request.url(), username)
-            } else {
-                trace!("Skipping keyring lookup for {url} with no username");
-                None
-            }
-        }) {
-            debug!("Found credentials in keyring for {url}");
+                .and_then(|credentials| credentials.username()),
+            &url,
+        ) {
             request = credentials.authenticate(request);
             new_credentials = Some(Arc::new(credentials));
         // No additional credentials were found
@@ -180,6 +207,40 @@ impl Middleware for AuthMiddleware {
                 self.cache().insert(&url, credentials)
             };
             result
+        } else if let Some(cached) = cached_credentials {
+            // Cached credentials may have been stored for another URL in the same realm, so if
+            // they're rejected, retry once with credentials from the netrc file or keyring.
+            let Some(retry) = request.try_clone() else {
+                return next.run(request, extensions).await;
+            };
+
+            let result = next.clone().run(request, extensions).await;
+            if !result
+                .as_ref()
+                .is_ok_and(|response| response.status() == StatusCode::UNAUTHORIZED)
+            {
+                return result;
+            }
+
+            let Some(credentials) = self
+                .fetch(retry.url(), username.as_deref(), &url)
+                .filter(|credentials| credentials != cached.as_ref())
+            else {
+                return result;
+            };
+
+            debug!("Cached credentials were rejected for {url}, retrying with new credentials");
+            let retry_url = retry.url().clone();
+            let retry = credentials.authenticate(retry);
+            let result = next.run(retry, extensions).await;
+            if result
+                .as_ref()
+                .is_ok_and(|response| response.error_for_status_ref().is_ok())
+            {
+                trace!("Updating cached credentials for {retry_url}");
+                self.cache().insert(&retry_url, Arc::new(credentials));
+            };
+            result
         } else {
             next.run(request, extensions).await
         }
@@ -352,6 +413,59 @@ mod tests {
         Ok(())
     }
 
+    #[test(tokio::test)]
+    async fn test_credentials_in_url_precedence_over_netrc() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        // Valid credentials in the URL are used, even if the netrc file has invalid ones.
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(netrc_file, "default login {username} password invalid")?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok()),
+            )
+            .build();
+
+        let mut url = base_url.clone();
+        url.set_username(username).unwrap();
+        url.set_password(Some(password)).unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            200,
+            "Credentials in the URL should take precedence over the netrc file"
+        );
+
+        // Invalid credentials in the URL are used, even if the netrc file has valid ones.
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(netrc_file, "default login {username} password {password}")?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok()),
+            )
+            .build();
+
+        let mut url = base_url.clone();
+        url.set_username(username).unwrap();
+        url.set_password(Some("invalid")).unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            401,
+            "Credentials in the URL should take precedence over the netrc file and fail"
+        );
+
+        Ok(())
+    }
+
     #[test(tokio::test)]
     async fn test_netrc_file_default_host() -> Result<(), Error> {
         let username = "user";
@@ -583,4 +697,126 @@ mod tests {
 
         Ok(())
     }
+
+    #[test(tokio::test)]
+    async fn test_netrc_file_precedence_over_keyring() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(netrc_file, "default login {username} password {password}")?;
+
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                    .with_keyring(Some(KeyringProvider::dummy([(
+                        (base_url.host_str().unwrap(), username),
+                        "invalid",
+                    )]))),
+            )
+            .build();
+
+        let mut url = base_url.clone();
+        url.set_username(username).unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            200,
+            "Credentials from the netrc file should take precedence over the keyring"
+        );
+
+        Ok(())
+    }
+
+    #[test(tokio::test)]
+    async fn test_keyring_fallback_for_netrc_mismatched_username() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(netrc_file, "default login other password {password}")?;
+
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                    .with_keyring(Some(KeyringProvider::dummy([(
+                        (base_url.host_str().unwrap(), username),
+                        password,
+                    )]))),
+            )
+            .build();
+
+        let mut url = base_url.clone();
+        url.set_username(username).unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            200,
+            "The keyring should be used if the netrc file has no credentials for the username"
+        );
+
+        Ok(())
+    }
+
+    #[test(tokio::test)]
+    async fn test_netrc_file_retry_after_rejected_cached_credentials() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+
+        let mut netrc_file = NamedTempFile::new()?;
+        writeln!(netrc_file, "default login {username} password {password}")?;
+
+        let server = start_test_server(username, password).await;
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(Netrc::from_file(netrc_file.path()).ok()),
+            )
+            .build();
+
+        // Populate the cache with invalid credentials for the realm.
+        let mut url = Url::parse(&server.uri())?;
+        url.set_username(username).unwrap();
+        url.set_password(Some("invalid")).unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            401,
+            "Credentials in the URL should take precedence and fail"
+        );
+
+        assert_eq!(
+            client
+                .get(format!("{}/foo", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Rejected cached credentials should be retried with the netrc credentials"
+        );
+
+        assert_eq!(
+            client
+                .get(format!("{}/bar", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Subsequent requests should use the netrc credentials from the cache"
+        );
+
+        // One rejected request with the URL credentials, one rejected request with the cached
+        // credentials and its retry, and one request with the updated cached credentials.
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+
+        Ok(())
+    }
 }
@@ -2,25 +2,29 @@
 //!
 //! <https://packaging.python.org/en/latest/specifications/source-distribution-format/>
 
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fmt::{Display, Formatter};
-use std::io;
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::{ExitStatus, Output};
+use std::process::{ExitStatus, Output, Stdio};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::{env, iter};
 
+use data_encoding::{BASE64URL_NOPAD, HEXLOWER};
 use fs_err as fs;
 use indoc::formatdoc;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::de::{value, SeqAccess, Visitor};
 use serde::{de, Deserialize, Deserializer};
+use sha2::{Digest, Sha256};
 use tempfile::{tempdir_in, TempDir};
 use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::process::Command;
 use tokio::sync::Mutex;
 use tracing::{debug, info_span, instrument, Instrument};
@@ -28,10 +32,12 @@ use tracing::{debug, info_span, instrument, Instrument};
 use distribution_types::Resolution;
 use pep440_rs::Version;
 use pep508_rs::{PackageName, Requirement};
-use uv_configuration::{BuildKind, ConfigSettings, SetupPyStrategy};
-use uv_fs::{PythonExt, Simplified};
+use uv_cache::{Cache, CacheBucket};
+use uv_configuration::{BuildKind, ConfigSettings, EditableMode, SetupPyStrategy};
+use uv_fs::{LockedFile, PythonExt, Simplified};
 use uv_interpreter::{Interpreter, PythonEnvironment};
 use uv_types::{BuildContext, BuildIsolation, SourceBuildTrait};
+use uv_warnings::warn_user_once;
 
 /// e.g. `pygraphviz/graphviz_wrap.c:3020:10: fatal error: graphviz/cgraph.h: No such file or directory`
 static MISSING_HEADER_RE: Lazy<Regex> = Lazy::new(|| {
@@ -50,6 +56,10 @@ static LD_NOT_FOUND_RE: Lazy<Regex> = Lazy::new(|| {
 static WHEEL_NOT_FOUND_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"error: invalid command 'bdist_wheel'").unwrap());
 
+/// e.g. `ModuleNotFoundError: No module named 'torch'`
+static MODULE_NOT_FOUND_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"ModuleNotFoundError: No module named '([^']+)'").unwrap());
+
 /// The default backend to use when PEP 517 is used without a `build-system` section.
 static DEFAULT_BACKEND: Lazy<Pep517Backend> = Lazy::new(|| Pep517Backend {
     backend: "setuptools.build_meta:__legacy__".to_string(),
@@ -57,6 +67,14 @@ static DEFAULT_BACKEND: Lazy<Pep517Backend> = Lazy::new(|| Pep517Backend {
     requirements: vec![Requirement::from_str("setuptools >= 40.8.0").unwrap()],
 });
 
+/// Directories that are excluded when syncing a source tree into a persistent build directory.
+const IGNORED_SOURCE_DIRECTORIES: &[&str] = &[".venv", "__pycache__"];
+
+/// Version control directories, which are synced into a persistent build directory (e.g., for
+/// `setuptools-scm`), but compared by size and modification time rather than by content, as they
+/// can be large.
+const VCS_DIRECTORIES: &[&str] = &[".git", ".hg", ".svn"];
+
 /// The requirements for `--legacy-setup-py` builds.
 static SETUP_PY_REQUIREMENTS: Lazy<[Requirement; 2]> = Lazy::new(|| {
     [
@@ -75,6 +93,10 @@ pub enum Error {
     InvalidPyprojectToml(#[from] toml::de::Error),
     #[error("Editable installs with setup.py legacy builds are unsupported, please specify a build backend in pyproject.toml")]
     EditableSetupPy,
+    #[error("Build backend `{0}` does not support PEP 660 editable installs (`build_editable`); use `--editable-mode legacy` to install it as a legacy editable instead")]
+    EditableUnsupported(String),
+    #[error("Failed to convert `{0}` into a legacy editable wheel")]
+    LegacyEditable(String, #[source] zip::result::ZipError),
     #[error("Failed to install requirements from {0}")]
     RequirementsInstall(&'static str, #[source] anyhow::Error),
     #[error("Failed to create temporary virtualenv")]
@@ -100,6 +122,20 @@ pub enum Error {
     },
     #[error("Failed to build PATH for build script")]
     BuildScriptPath(#[source] env::JoinPathsError),
+    /// A build hook failed; the build environment is retained for inspection.
+    #[error(
+        "Failed to run `{invocation}` with `{}`{environment}; the build environment was kept at: `{}`",
+        .python.user_display(),
+        .build_dir.user_display()
+    )]
+    HookFailed {
+        invocation: String,
+        python: PathBuf,
+        environment: String,
+        build_dir: PathBuf,
+        #[source]
+        err: Box<Error>,
+    },
 }
 
 #[derive(Debug)]
@@ -107,6 +143,8 @@ pub enum MissingLibrary {
     Header(String),
     Linker(String),
     PythonPackage(String),
+    /// A module that's missing from the shared environment of a build without isolation.
+    SharedModule(String),
 }
 
 #[derive(Debug, Error)]
@@ -140,11 +178,88 @@ impl Display for MissingHeaderCause {
                     package = package, version_id = self.version_id
                 )
             }
+            MissingLibrary::SharedModule(module) => {
+                write!(
+                    f,
+                    "This error likely indicates that `{module}` is missing from the environment used to build {version_id}. \
+                    Build isolation is disabled for this package (via `--no-build-isolation` or `--no-build-isolation-package`), \
+                    so its build dependencies must be installed into the environment first",
+                    module = module, version_id = self.version_id
+                )
+            }
         }
     }
 }
 
 impl Error {
+    /// Attach the failed invocation and the retained build directory to a build hook failure.
+    fn hook_failed(
+        err: Self,
+        invocation: impl Into<String>,
+        venv: &PythonEnvironment,
+        environment_variables: &FxHashMap<OsString, OsString>,
+        build_dir: PathBuf,
+    ) -> Self {
+        let environment = if environment_variables.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " (environment: {})",
+                environment_variables
+                    .iter()
+                    .map(|(key, value)| format!("`{}`", redact_variable(key, value)))
+                    .sorted()
+                    .join(", ")
+            )
+        };
+        Self::HookFailed {
+            invocation: invocation.into(),
+            python: venv.python_executable().to_path_buf(),
+            environment,
+            build_dir,
+            err: Box::new(err),
+        }
+    }
+
+    /// For a build without isolation, attach a hint if the build backend failed because a module
+    /// is missing from the shared environment.
+    fn missing_shared_module(self, version_id: &str) -> Self {
+        match self {
+            Self::BuildBackend {
+                message,
+                exit_code,
+                stdout,
+                stderr,
+            } => {
+                let module = stderr.lines().rev().take(10).find_map(|line| {
+                    MODULE_NOT_FOUND_RE
+                        .captures(line.trim())
+                        .map(|captures| captures[1].to_string())
+                });
+                if let Some(module) = module {
+                    Self::MissingHeader {
+                        message,
+                        exit_code,
+                        stdout,
+                        stderr,
+                        missing_header_cause: MissingHeaderCause {
+                            missing_library: MissingLibrary::SharedModule(module),
+                            version_id: version_id.to_string(),
+                        },
+                    }
+                } else {
+                    Self::BuildBackend {
+                        message,
+                        exit_code,
+                        stdout,
+                        stderr,
+                    }
+                }
+            }
+            err => err,
+        }
+    }
+
     fn from_command_output(
         message: String,
         output: &Output,
@@ -338,17 +453,59 @@ pub struct SourceBuildContext {
     setup_py_resolution: Arc<Mutex<Option<Resolution>>>,
 }
 
+/// A reporter for the output of a build backend, e.g., to stream it to the user while building.
+pub trait Reporter: Send + Sync {
+    /// Callback to invoke for each line of output from a build hook (e.g., `build_wheel`), and
+    /// for each variable in the build environment (as `environment`).
+    fn on_build_output(&self, source: &str, line: &str);
+}
+
+/// The directory in which a source distribution is built.
+enum BuildDirectory {
+    /// A temporary directory, removed once the build completes (unless a build hook fails).
+    Temporary(TempDir),
+    /// A persistent directory in the cache, reused across builds of the same source tree (with
+    /// `--no-clean`), and locked for the duration of the build.
+    Persistent(PathBuf, LockedFile),
+}
+
+impl BuildDirectory {
+    fn path(&self) -> &Path {
+        match self {
+            Self::Temporary(temp_dir) => temp_dir.path(),
+            Self::Persistent(path, _) => path,
+        }
+    }
+
+    fn into_path(self) -> PathBuf {
+        match self {
+            Self::Temporary(temp_dir) => temp_dir.into_path(),
+            Self::Persistent(path, _) => path,
+        }
+    }
+}
+
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single setup.py
 /// invocation.
 ///
 /// This keeps both the temp dir and the result of a potential `prepare_metadata_for_build_wheel`
 /// call which changes how we call `build_wheel`.
 pub struct SourceBuild {
-    temp_dir: TempDir,
+    /// The build directory; only taken when retaining it on drop.
+    temp_dir: Option<BuildDirectory>,
+    /// Whether a build hook failed, in which case the build directory is retained on drop.
+    failed: AtomicBool,
+    /// The reporter to stream the build backend output to, if any.
+    reporter: Option<Arc<dyn Reporter>>,
+    /// Whether the source distribution is built in an isolated environment.
+    isolated: bool,
     source_tree: PathBuf,
     config_settings: ConfigSettings,
     /// If performing a PEP 517 build, the backend to use.
     pep517_backend: Option<Pep517Backend>,
+    /// Whether the source tree lacks a `[build-system]` table, such that we fell back to the
+    /// legacy setuptools build.
+    legacy_fallback: bool,
     /// The PEP 621 project metadata, if any.
     project: Option<Project>,
     /// The virtual environment in which to build the source distribution.
@@ -367,6 +524,9 @@ pub struct SourceBuild {
     version_id: String,
     /// Whether we do a regular PEP 517 build or an PEP 660 editable build
     build_kind: BuildKind,
+    /// For editable builds, whether to use the PEP 660 `build_editable` hook or a legacy editable
+    /// wheel.
+    editable_mode: EditableMode,
     /// Modified PATH that contains the `venv_bin`, `user_path` and `system_path` variables in that order
     modified_path: OsString,
     /// Environment variables to be passed in during metadata or wheel building
@@ -377,7 +537,8 @@ impl SourceBuild {
     /// Create a virtual environment in which to build a source distribution, extracting the
     /// contents from an archive if necessary.
     ///
-    /// `source_dist` is for error reporting only.
+    /// `source_dist` is for error reporting only. `package_name` is used to determine whether
+    /// build isolation applies, if the name isn't declared in the `pyproject.toml`.
     #[allow(clippy::too_many_arguments)]
     pub async fn setup(
         source: &Path,
@@ -386,14 +547,16 @@ impl SourceBuild {
         build_context: &impl BuildContext,
         source_build_context: SourceBuildContext,
         version_id: String,
+        package_name: Option<&PackageName>,
         setup_py: SetupPyStrategy,
         config_settings: ConfigSettings,
         build_isolation: BuildIsolation<'_>,
         build_kind: BuildKind,
+        editable_mode: EditableMode,
         mut environment_variables: FxHashMap<OsString, OsString>,
+        reporter: Option<Arc<dyn Reporter>>,
+        reuse_build_dir: bool,
     ) -> Result<Self, Error> {
-        let temp_dir = tempdir_in(build_context.cache().root())?;
-
         let source_tree = if let Some(subdir) = subdirectory {
             source.join(subdir)
         } else {
@@ -403,25 +566,64 @@ impl SourceBuild {
         let default_backend: Pep517Backend = DEFAULT_BACKEND.clone();
 
         // Check if we have a PEP 517 build backend.
-        let (pep517_backend, project) =
+        let (pep517_backend, project, legacy_fallback) =
             Self::extract_pep517_backend(&source_tree, setup_py, &default_backend)
                 .map_err(|err| *err)?;
+        if legacy_fallback {
+            debug!(
+                "No `[build-system]` table found for {version_id}; falling back to the legacy setuptools build"
+            );
+        }
+
+        // Determine whether build isolation is disabled for this package.
+        let package_name = project
+            .as_ref()
+            .map(|project| &project.name)
+            .or(package_name);
+        let isolated = build_isolation.is_isolated(package_name);
+
+        // If requested, build in a persistent copy of the source tree, such that the build
+        // backend can reuse the artifacts of a previous build of the same sources. Editable builds
+        // always run in the source tree itself.
+        let (temp_dir, source_tree) =
+            match package_name.filter(|_| reuse_build_dir && build_kind == BuildKind::Wheel) {
+                Some(package_name) => {
+                    let (build_dir, lock) = persistent_build_dir(
+                        build_context.cache(),
+                        package_name,
+                        source,
+                        interpreter,
+                        &config_settings,
+                    )?;
+                    let source_tree = if let Some(subdir) = subdirectory {
+                        build_dir.join("source").join(subdir)
+                    } else {
+                        build_dir.join("source")
+                    };
+                    (BuildDirectory::Persistent(build_dir, lock), source_tree)
+                }
+                None => (
+                    BuildDirectory::Temporary(tempdir_in(build_context.cache().root())?),
+                    source_tree,
+                ),
+            };
 
         // Create a virtual environment, or install into the shared environment if requested.
-        let venv = match build_isolation {
-            BuildIsolation::Isolated => uv_virtualenv::create_venv(
+        let venv = if let Some(venv) = build_isolation.shared_environment(package_name) {
+            venv.clone()
+        } else {
+            uv_virtualenv::create_venv(
                 &temp_dir.path().join(".venv"),
                 interpreter.clone(),
                 uv_virtualenv::Prompt::None,
                 false,
                 Vec::new(),
-            )?,
-            BuildIsolation::Shared(venv) => venv.clone(),
+            )?
         };
 
         // Setup the build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        if build_isolation.is_isolated() {
+        if isolated {
             let resolved_requirements = Self::get_resolved_requirements(
                 build_context,
                 source_build_context,
@@ -438,6 +640,15 @@ impl SourceBuild {
                 })?;
         }
 
+        if let Some(reporter) = &reporter {
+            for (key, value) in environment_variables
+                .iter()
+                .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            {
+                reporter.on_build_output("environment", &redact_variable(key, value));
+            }
+        }
+
         // Figure out what the modified path should be
         // Remove the PATH variable from the environment variables if it's there
         let user_path = environment_variables.remove(&OsString::from("PATH"));
@@ -468,33 +679,67 @@ impl SourceBuild {
             OsString::from(venv.scripts())
         };
 
+        // Legacy editable installs are built as regular wheels, so they require the dependencies of
+        // a regular build.
+        let requires_kind = if editable_mode == EditableMode::Legacy {
+            BuildKind::Wheel
+        } else {
+            build_kind
+        };
+
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        if build_isolation.is_isolated() {
+        if isolated {
             if let Some(pep517_backend) = &pep517_backend {
-                create_pep517_build_environment(
+                let result = create_pep517_build_environment(
                     &source_tree,
                     &venv,
                     pep517_backend,
                     build_context,
                     &version_id,
-                    build_kind,
+                    requires_kind,
                     &config_settings,
                     &environment_variables,
                     &modified_path,
-                    &temp_dir,
+                    temp_dir.path(),
+                    reporter.as_deref(),
                 )
-                .await?;
+                .await;
+                if let Err(err) = result {
+                    if legacy_fallback {
+                        warn_legacy_fallback(&version_id, Some(pep517_backend));
+                    }
+                    return Err(match err {
+                        err @ (Error::BuildBackend { .. } | Error::MissingHeader { .. }) => {
+                            Error::hook_failed(
+                                err,
+                                format!(
+                                    "{}.get_requires_for_build_{requires_kind}()",
+                                    pep517_backend.backend
+                                ),
+                                &venv,
+                                &environment_variables,
+                                temp_dir.into_path(),
+                            )
+                        }
+                        err => err,
+                    });
+                }
             }
         }
 
         Ok(Self {
-            temp_dir,
+            temp_dir: Some(temp_dir),
+            failed: AtomicBool::new(false),
+            reporter,
+            isolated,
             source_tree,
             pep517_backend,
+            legacy_fallback,
             project,
             venv,
             build_kind,
+            editable_mode,
             config_settings,
             metadata_directory: None,
             version_id,
@@ -549,15 +794,19 @@ impl SourceBuild {
     }
 
     /// Extract the PEP 517 backend from the `pyproject.toml` or `setup.py` file.
+    ///
+    /// Also returns whether the source tree lacks a `[build-system]` table, in which case we fall
+    /// back to the legacy setuptools build.
     fn extract_pep517_backend(
         source_tree: &Path,
         setup_py: SetupPyStrategy,
         default_backend: &Pep517Backend,
-    ) -> Result<(Option<Pep517Backend>, Option<Project>), Box<Error>> {
+    ) -> Result<(Option<Pep517Backend>, Option<Project>, bool), Box<Error>> {
         match fs::read_to_string(source_tree.join("pyproject.toml")) {
             Ok(toml) => {
                 let pyproject_toml: PyProjectToml =
                     toml::from_str(&toml).map_err(Error::InvalidPyprojectToml)?;
+                let legacy_fallback = pyproject_toml.build_system.is_none();
                 let backend = if let Some(build_system) = pyproject_toml.build_system {
                     Pep517Backend {
                         // If `build-backend` is missing, inject the legacy setuptools backend, but
@@ -578,7 +827,7 @@ impl SourceBuild {
                     // a PEP 517 build using the default backend, to match `pip` and `build`.
                     default_backend.clone()
                 };
-                Ok((Some(backend), pyproject_toml.project))
+                Ok((Some(backend), pyproject_toml.project, legacy_fallback))
             }
             Err(err) if err.kind() == io::ErrorKind::NotFound => {
                 // We require either a `pyproject.toml` or a `setup.py` file at the top level.
@@ -595,8 +844,8 @@ impl SourceBuild {
                 // 517 builds the default in the future.
                 // See: https://github.com/pypa/pip/issues/9175.
                 match setup_py {
-                    SetupPyStrategy::Pep517 => Ok((Some(default_backend.clone()), None)),
-                    SetupPyStrategy::Setuptools => Ok((None, None)),
+                    SetupPyStrategy::Pep517 => Ok((Some(default_backend.clone()), None, true)),
+                    SetupPyStrategy::Setuptools => Ok((None, None, true)),
                 }
             }
             Err(err) => Err(Box::new(err.into())),
@@ -645,14 +894,15 @@ impl SourceBuild {
             }
         }
 
-        let metadata_directory = self.temp_dir.path().join("metadata_directory");
+        let metadata_directory = self.temp_dir().join("metadata_directory");
+        if metadata_directory.is_dir() {
+            // Remove the metadata from a previous build in a persistent build directory.
+            fs::remove_dir_all(&metadata_directory)?;
+        }
         fs::create_dir(&metadata_directory)?;
 
         // Write the hook output to a file so that we can read it back reliably.
-        let outfile = self
-            .temp_dir
-            .path()
-            .join("prepare_metadata_for_build_wheel.txt");
+        let outfile = self.temp_dir().join("prepare_metadata_for_build_wheel.txt");
 
         debug!(
             "Calling `{}.prepare_metadata_for_build_wheel()`",
@@ -688,14 +938,22 @@ impl SourceBuild {
             &self.source_tree,
             &self.environment_variables,
             &self.modified_path,
+            "prepare_metadata_for_build_wheel",
+            self.reporter.as_deref(),
         )
         .instrument(span)
         .await?;
         if !output.status.success() {
-            return Err(Error::from_command_output(
-                "Build backend failed to determine metadata through `prepare_metadata_for_build_wheel`".to_string(),
-                &output,
-                &self.version_id,
+            return Err(self.hook_failed(
+                format!(
+                    "{}.prepare_metadata_for_build_wheel()",
+                    pep517_backend.backend
+                ),
+                Error::from_command_output(
+                    "Build backend failed to determine metadata through `prepare_metadata_for_build_wheel`".to_string(),
+                    &output,
+                    &self.version_id,
+                ),
             ));
         }
 
@@ -722,7 +980,16 @@ impl SourceBuild {
         if let Some(pep517_backend) = &self.pep517_backend {
             // Prevent clashes from two uv processes building wheels in parallel.
             let tmp_dir = tempdir_in(&wheel_dir)?;
-            let filename = self.pep517_build(tmp_dir.path(), pep517_backend).await?;
+            let filename = match self.build_kind {
+                BuildKind::Wheel => self
+                    .pep517_build(tmp_dir.path(), pep517_backend, BuildKind::Wheel)
+                    .await?
+                    .expect("`build_wheel` is a mandatory hook"),
+                BuildKind::Editable => {
+                    self.pep517_build_editable(tmp_dir.path(), pep517_backend)
+                        .await?
+                }
+            };
 
             let from = tmp_dir.path().join(&filename);
             let to = wheel_dir.join(&filename);
@@ -732,6 +999,14 @@ impl SourceBuild {
             if self.build_kind != BuildKind::Wheel {
                 return Err(Error::EditableSetupPy);
             }
+            // Remove the wheel from a previous build in a persistent build directory.
+            if matches!(self.temp_dir, Some(BuildDirectory::Persistent(..))) {
+                match fs::remove_dir_all(self.source_tree.join("dist")) {
+                    Ok(()) => {}
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                    Err(err) => return Err(err.into()),
+                }
+            }
             // We checked earlier that setup.py exists.
             let python_interpreter = self.venv.python_executable();
             let span = info_span!(
@@ -739,18 +1014,23 @@ impl SourceBuild {
                 script="setup.py bdist_wheel",
                 python_version = %self.venv.interpreter().python_version()
             );
-            let output = Command::new(python_interpreter)
+            let mut command = Command::new(python_interpreter);
+            command
                 .args(["setup.py", "bdist_wheel"])
-                .current_dir(self.source_tree.simplified())
-                .output()
+                .current_dir(self.source_tree.simplified());
+            propagate_color_choice(&mut command);
+            let output = run_command(command, "setup.py bdist_wheel", self.reporter.as_deref())
                 .instrument(span)
                 .await
                 .map_err(|err| Error::CommandFailed(python_interpreter.to_path_buf(), err))?;
             if !output.status.success() {
-                return Err(Error::from_command_output(
-                    "Failed building wheel through setup.py".to_string(),
-                    &output,
-                    &self.version_id,
+                return Err(self.hook_failed(
+                    "setup.py bdist_wheel",
+                    Error::from_command_output(
+                        "Failed building wheel through setup.py".to_string(),
+                        &output,
+                        &self.version_id,
+                    ),
                 ));
             }
             let dist = fs::read_dir(self.source_tree.join("dist"))?;
@@ -773,11 +1053,47 @@ impl SourceBuild {
         }
     }
 
-    async fn pep517_build(
+    /// Build an editable wheel, preferring the PEP 660 `build_editable` hook, and falling back to a
+    /// legacy editable wheel (per the [`EditableMode`]).
+    async fn pep517_build_editable(
         &self,
         wheel_dir: &Path,
         pep517_backend: &Pep517Backend,
     ) -> Result<String, Error> {
+        if self.editable_mode != EditableMode::Legacy {
+            if let Some(filename) = self
+                .pep517_build(wheel_dir, pep517_backend, BuildKind::Editable)
+                .await?
+            {
+                return Ok(filename);
+            }
+            if self.editable_mode == EditableMode::Pep660 {
+                return Err(Error::EditableUnsupported(pep517_backend.backend.clone()));
+            }
+            debug!(
+                "Build backend `{}` does not support PEP 660 editable installs; falling back to a legacy editable install of {}",
+                pep517_backend.backend, self.version_id
+            );
+        }
+
+        let filename = self
+            .pep517_build(wheel_dir, pep517_backend, BuildKind::Wheel)
+            .await?
+            .expect("`build_wheel` is a mandatory hook");
+        legacy_editable(&wheel_dir.join(&filename), &self.source_tree)
+            .map_err(|err| Error::LegacyEditable(filename.clone(), err))?;
+        Ok(filename)
+    }
+
+    /// Call the `build_wheel` or `build_editable` hook, returning the filename of the built wheel.
+    ///
+    /// Returns `None` if the backend doesn't define the (optional) `build_editable` hook.
+    async fn pep517_build(
+        &self,
+        wheel_dir: &Path,
+        pep517_backend: &Pep517Backend,
+        build_kind: BuildKind,
+    ) -> Result<Option<String>, Error> {
         let metadata_directory = self
             .metadata_directory
             .as_deref()
@@ -786,25 +1102,26 @@ impl SourceBuild {
             });
 
         // Write the hook output to a file so that we can read it back reliably.
-        let outfile = self
-            .temp_dir
-            .path()
-            .join(format!("build_{}.txt", self.build_kind));
+        let outfile = self.temp_dir().join(format!("build_{}.txt", build_kind));
 
         debug!(
             "Calling `{}.build_{}(metadata_directory={})`",
-            pep517_backend.backend, self.build_kind, metadata_directory
+            pep517_backend.backend, build_kind, metadata_directory
         );
         let script = formatdoc! {
             r#"
             {}
 
-            wheel_filename = backend.build_{}("{}", metadata_directory={}, config_settings={})
+            build = getattr(backend, "build_{}", None)
+            if build is None:
+                wheel_filename = ""
+            else:
+                wheel_filename = build("{}", metadata_directory={}, config_settings={})
             with open("{}", "w") as fp:
                 fp.write(wheel_filename)
             "#,
             pep517_backend.backend_import(),
-            self.build_kind,
+            build_kind,
             wheel_dir.escape_for_python(),
             metadata_directory,
             self.config_settings.escape_for_python(),
@@ -812,7 +1129,7 @@ impl SourceBuild {
         };
         let span = info_span!(
             "run_python_script",
-            script=format!("build_{}", self.build_kind),
+            script=format!("build_{}", build_kind),
             python_version = %self.venv.interpreter().python_version()
         );
         let output = run_python_script(
@@ -821,32 +1138,81 @@ impl SourceBuild {
             &self.source_tree,
             &self.environment_variables,
             &self.modified_path,
+            &format!("build_{}", build_kind),
+            self.reporter.as_deref(),
         )
         .instrument(span)
         .await?;
         if !output.status.success() {
-            return Err(Error::from_command_output(
-                format!(
-                    "Build backend failed to build wheel through `build_{}()`",
-                    self.build_kind
+            return Err(self.hook_failed(
+                format!("{}.build_{}()", pep517_backend.backend, build_kind),
+                Error::from_command_output(
+                    format!(
+                        "Build backend failed to build wheel through `build_{}()`",
+                        build_kind
+                    ),
+                    &output,
+                    &self.version_id,
                 ),
-                &output,
-                &self.version_id,
             ));
         }
 
         let distribution_filename = fs::read_to_string(&outfile)?;
+        if distribution_filename.is_empty() && build_kind == BuildKind::Editable {
+            return Ok(None);
+        }
         if !wheel_dir.join(&distribution_filename).is_file() {
             return Err(Error::from_command_output(
                 format!(
                     "Build backend failed to produce wheel through `build_{}()`: `{distribution_filename}` not found",
-                    self.build_kind
+                    build_kind
                 ),
                 &output,
                 &self.version_id,
             ));
         }
-        Ok(distribution_filename)
+        Ok(Some(distribution_filename))
+    }
+
+    /// Return the path to the build directory.
+    fn temp_dir(&self) -> &Path {
+        self.temp_dir
+            .as_ref()
+            .expect("the build directory is only taken on drop")
+            .path()
+    }
+
+    /// Mark the build as failed, such that the build directory is retained for inspection, and
+    /// attach the failed invocation to the error.
+    fn hook_failed(&self, invocation: impl Into<String>, err: Error) -> Error {
+        self.failed.store(true, Ordering::Relaxed);
+        if self.legacy_fallback {
+            warn_legacy_fallback(&self.version_id, self.pep517_backend.as_ref());
+        }
+        let err = if self.isolated {
+            err
+        } else {
+            err.missing_shared_module(&self.version_id)
+        };
+        Error::hook_failed(
+            err,
+            invocation,
+            &self.venv,
+            &self.environment_variables,
+            self.temp_dir().to_path_buf(),
+        )
+    }
+}
+
+impl Drop for SourceBuild {
+    fn drop(&mut self) {
+        // If a build hook failed, retain the build directory rather than removing it.
+        if *self.failed.get_mut() {
+            if let Some(temp_dir) = self.temp_dir.take() {
+                let path = temp_dir.into_path();
+                debug!("Retaining build directory: {}", path.display());
+            }
+        }
     }
 }
 
@@ -860,12 +1226,266 @@ impl SourceBuildTrait for SourceBuild {
     }
 }
 
+/// Warn that a failed build fell back to the legacy setuptools build, since the source tree lacks
+/// a `[build-system]` table.
+fn warn_legacy_fallback(version_id: &str, pep517_backend: Option<&Pep517Backend>) {
+    warn_user_once!("{}", legacy_fallback_message(version_id, pep517_backend));
+}
+
+fn legacy_fallback_message(version_id: &str, pep517_backend: Option<&Pep517Backend>) -> String {
+    let fallback = if let Some(pep517_backend) = pep517_backend {
+        format!(
+            "building it with the legacy setuptools backend (`{}`)",
+            pep517_backend.backend
+        )
+    } else {
+        "invoking `setup.py` directly".to_string()
+    };
+    format!(
+        "`{version_id}` does not declare a `[build-system]` table in `pyproject.toml`, so uv fell back to {fallback}. \
+        Consider adding a `[build-system]` table that declares the build backend and its requirements \
+        (e.g., `requires = [\"setuptools>=61\"]` and `build-backend = \"setuptools.build_meta\"`)"
+    )
+}
+
+/// Return the persistent build directory for a source tree, syncing the source tree into it (as
+/// `source`), along with a lock that's held for the duration of the build.
+///
+/// The build directory is keyed by the package name, the config settings and the interpreter, and
+/// the path to the source tree (as `{package}/{environment digest}/{source digest}`), such that
+/// repeated builds of the same source tree reuse the same directory, while a change to the config
+/// settings or the interpreter results in a fresh build.
+fn persistent_build_dir(
+    cache: &Cache,
+    package_name: &PackageName,
+    source: &Path,
+    interpreter: &Interpreter,
+    config_settings: &ConfigSettings,
+) -> Result<(PathBuf, LockedFile), Error> {
+    let mut hasher = Sha256::new();
+    hasher.update(config_settings.escape_for_python().as_bytes());
+    hasher.update([0]);
+    hasher.update(interpreter.sys_executable().to_string_lossy().as_bytes());
+    hasher.update([0]);
+    hasher.update(interpreter.python_version().to_string().as_bytes());
+    let environment = HEXLOWER.encode(&hasher.finalize()[..8]);
+
+    let source_path = fs::canonicalize(source)?;
+    let source_digest =
+        HEXLOWER.encode(&Sha256::digest(source_path.to_string_lossy().as_bytes())[..8]);
+
+    let build_dir = cache
+        .bucket(CacheBucket::Builds)
+        .join(package_name.to_string())
+        .join(environment)
+        .join(source_digest);
+    fs::create_dir_all(&build_dir)?;
+
+    // Lock the build directory, such that concurrent builds of the same source tree (and `uv cache
+    // prune`) don't clobber each other.
+    let lock = LockedFile::acquire(build_dir.join(".lock"), build_dir.user_display())?;
+
+    if build_dir.join("source").is_dir() {
+        debug!("Reusing build directory: {}", build_dir.display());
+    } else {
+        debug!("Creating build directory: {}", build_dir.display());
+    }
+    sync_source_tree(source, &build_dir)?;
+
+    // Mark the build directory as recently used, such that `uv cache prune` retains it.
+    filetime::set_file_mtime(&build_dir, filetime::FileTime::now())?;
+
+    Ok((build_dir, lock))
+}
+
+/// Sync a source tree into the `source` directory of a persistent build directory.
+///
+/// Only files whose contents changed since the previous sync are copied, preserving their
+/// modification times, such that build backends that perform incremental builds only consider
+/// those files to be out-of-date. Files are compared by a content hash, recorded in a `manifest`
+/// alongside `source`, except for files in [`VCS_DIRECTORIES`], which are compared by size and
+/// modification time. Files that were never synced from the source tree (e.g., build artifacts)
+/// are left untouched.
+fn sync_source_tree(source: &Path, build_dir: &Path) -> io::Result<()> {
+    let target = build_dir.join("source");
+    let manifest_path = build_dir.join("manifest");
+
+    // Read the manifest of the previous sync. If it's missing, the previous sync never completed,
+    // so start from scratch.
+    let previous = match fs::read_to_string(&manifest_path) {
+        Ok(manifest) => manifest
+            .lines()
+            .filter_map(|line| line.split_once(' '))
+            .map(|(digest, relative)| (relative.to_string(), digest.to_string()))
+            .collect::<FxHashMap<_, _>>(),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            if target.exists() {
+                fs::remove_dir_all(&target)?;
+            }
+            FxHashMap::default()
+        }
+        Err(err) => return Err(err),
+    };
+
+    let mut manifest = String::new();
+    let mut synced = FxHashSet::default();
+    for entry in source_tree_entries(source) {
+        let entry = entry?;
+        let relative = entry
+            .path()
+            .strip_prefix(source)
+            .expect("walkdir starts with root");
+        let path = target.join(relative);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&path)?;
+            continue;
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let metadata = entry.metadata()?;
+        let mtime = filetime::FileTime::from_last_modification_time(&metadata);
+        let digest = if relative.components().any(|component| {
+            VCS_DIRECTORIES
+                .iter()
+                .any(|vcs| component.as_os_str() == *vcs)
+        }) {
+            format!(
+                "{}:{}.{}",
+                metadata.len(),
+                mtime.unix_seconds(),
+                mtime.nanoseconds()
+            )
+        } else {
+            let mut hasher = Sha256::new();
+            io::copy(&mut fs::File::open(entry.path())?, &mut hasher)?;
+            HEXLOWER.encode(&hasher.finalize())
+        };
+
+        let key = relative.to_string_lossy().replace('\\', "/");
+        if previous.get(&key) != Some(&digest) || !path.is_file() {
+            debug!("Syncing file into build directory: {}", relative.display());
+            fs::copy(entry.path(), &path)?;
+            filetime::set_file_mtime(&path, mtime)?;
+        }
+        manifest.push_str(&format!("{digest} {key}\n"));
+        synced.insert(key);
+    }
+
+    // Remove any files that were synced previously, but have since been removed from the source
+    // tree.
+    for key in previous.keys() {
+        if synced.contains(key) {
+            continue;
+        }
+        match fs::remove_file(target.join(key)) {
+            Ok(()) => debug!("Removed file from build directory: {key}"),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    uv_fs::write_atomic_sync(&manifest_path, manifest)
+}
+
+/// Iterate over the entries of a source tree in a stable order, skipping any
+/// [`IGNORED_SOURCE_DIRECTORIES`].
+///
+/// Symlinks are followed, but any symlink that points to one of its ancestors is skipped, rather
+/// than recursing forever.
+fn source_tree_entries(source: &Path) -> impl Iterator<Item = walkdir::Result<walkdir::DirEntry>> {
+    walkdir::WalkDir::new(source)
+        .follow_links(true)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            !(entry.file_type().is_dir()
+                && IGNORED_SOURCE_DIRECTORIES
+                    .iter()
+                    .any(|ignored| entry.file_name() == *ignored))
+        })
+        .filter(|entry| match entry {
+            Err(err) if err.loop_ancestor().is_some() => {
+                debug!("Skipping symlink loop in source tree: {err}");
+                false
+            }
+            _ => true,
+        })
+}
+
 fn escape_path_for_python(path: &Path) -> String {
     path.to_string_lossy()
         .replace('\\', "\\\\")
         .replace('"', "\\\"")
 }
 
+/// Convert a wheel into a legacy editable wheel, in-place.
+///
+/// Only the `.dist-info` directory of the wheel is retained, such that the metadata and entry
+/// points are installed as usual, alongside a `.pth` file that adds the source tree (or its `src`
+/// directory, if present) to `sys.path`, akin to `setup.py develop`.
+fn legacy_editable(wheel: &Path, source_tree: &Path) -> Result<(), zip::result::ZipError> {
+    let mut archive = zip::ZipArchive::new(fs::File::open(wheel)?)?;
+
+    // Identify the `.dist-info` directory (e.g., `foo-1.0.0.dist-info`).
+    let dist_info = archive
+        .file_names()
+        .filter_map(|name| name.split_once('/').map(|(directory, _)| directory))
+        .find(|directory| directory.ends_with(".dist-info"))
+        .map(ToString::to_string)
+        .ok_or(zip::result::ZipError::FileNotFound)?;
+    let record = format!("{dist_info}/RECORD");
+
+    let root = fs::canonicalize(source_tree)?;
+    let root = if root.join("src").is_dir() {
+        root.join("src")
+    } else {
+        root
+    };
+    let mut files = vec![(
+        format!(
+            "__editable__.{}.pth",
+            dist_info.trim_end_matches(".dist-info")
+        ),
+        format!("{}\n", root.simplified_display()).into_bytes(),
+    )];
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index)?;
+        if file.is_dir() || file.name() == record || !file.name().starts_with(&dist_info) {
+            continue;
+        }
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)?;
+        files.push((file.name().to_string(), contents));
+    }
+
+    // Write the editable wheel alongside the original, then replace it.
+    let temp_file = tempfile::NamedTempFile::new_in(wheel.parent().unwrap_or(Path::new(".")))?;
+    {
+        let mut writer = zip::ZipWriter::new(temp_file.as_file());
+        let options =
+            zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        let mut entries = String::new();
+        for (path, contents) in &files {
+            writer.start_file(path.as_str(), options)?;
+            writer.write_all(contents)?;
+            entries.push_str(&format!(
+                "{path},sha256={},{}\n",
+                BASE64URL_NOPAD.encode(&Sha256::digest(contents)),
+                contents.len()
+            ));
+        }
+        entries.push_str(&format!("{record},,\n"));
+        writer.start_file(record.as_str(), options)?;
+        writer.write_all(entries.as_bytes())?;
+        writer.finish()?;
+    }
+    temp_file.persist(wheel).map_err(|err| err.error)?;
+
+    Ok(())
+}
+
 /// Not a method because we call it before the builder is completely initialized
 #[allow(clippy::too_many_arguments)]
 async fn create_pep517_build_environment(
@@ -878,12 +1498,11 @@ async fn create_pep517_build_environment(
     config_settings: &ConfigSettings,
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
-    temp_dir: &TempDir,
+    temp_dir: &Path,
+    reporter: Option<&dyn Reporter>,
 ) -> Result<(), Error> {
     // Write the hook output to a file so that we can read it back reliably.
-    let outfile = temp_dir
-        .path()
-        .join(format!("get_requires_for_build_{build_kind}.txt"));
+    let outfile = temp_dir.join(format!("get_requires_for_build_{build_kind}.txt"));
 
     debug!(
         "Calling `{}.get_requires_for_build_{}()`",
@@ -920,12 +1539,14 @@ async fn create_pep517_build_environment(
         source_tree,
         environment_variables,
         modified_path,
+        &format!("get_requires_for_build_{build_kind}"),
+        reporter,
     )
     .instrument(span)
     .await?;
     if !output.status.success() {
         return Err(Error::from_command_output(
-            format!("Build backend failed to determine extra requires with `build_{build_kind}()`"),
+            format!("Build backend failed to determine extra requires with `get_requires_for_build_{build_kind}()`"),
             &output,
             version_id,
         ));
@@ -989,8 +1610,11 @@ async fn run_python_script(
     source_tree: &Path,
     environment_variables: &FxHashMap<OsString, OsString>,
     modified_path: &OsString,
+    hook: &str,
+    reporter: Option<&dyn Reporter>,
 ) -> Result<Output, Error> {
-    Command::new(venv.python_executable())
+    let mut command = Command::new(venv.python_executable());
+    command
         .args(["-c", script])
         .current_dir(source_tree.simplified())
         // Pass in remaining environment variables
@@ -998,20 +1622,131 @@ async fn run_python_script(
         // Set the modified PATH
         .env("PATH", modified_path)
         // Activate the venv
-        .env("VIRTUAL_ENV", venv.root())
-        .env("CLICOLOR_FORCE", "1")
-        .output()
+        .env("VIRTUAL_ENV", venv.root());
+    propagate_color_choice(&mut command);
+    run_command(command, hook, reporter)
         .await
         .map_err(|err| Error::CommandFailed(venv.python_executable().to_path_buf(), err))
 }
 
+/// Format an environment variable for display, hiding the value if the name suggests that it
+/// holds a credential.
+fn redact_variable(key: &OsStr, value: &OsStr) -> String {
+    let key = key.to_string_lossy();
+    let upper = key.to_uppercase();
+    if [
+        "TOKEN",
+        "SECRET",
+        "PASSWORD",
+        "PASSWD",
+        "KEY",
+        "CREDENTIAL",
+        "AUTH",
+    ]
+    .iter()
+    .any(|needle| upper.contains(needle))
+    {
+        format!("{key}=****")
+    } else {
+        format!("{key}={}", value.to_string_lossy())
+    }
+}
+
+/// Run a build command to completion, capturing its output.
+///
+/// If a [`Reporter`] is provided, the output is also streamed to it line-by-line as it's produced.
+///
+/// If colors are disabled, ANSI escape codes are stripped from the captured output, as not every
+/// build backend respects `NO_COLOR`.
+async fn run_command(
+    mut command: Command,
+    hook: &str,
+    reporter: Option<&dyn Reporter>,
+) -> io::Result<Output> {
+    let Some(reporter) = reporter else {
+        let mut output = command.output().await?;
+        if !color_enabled() {
+            output.stdout = strip_ansi(&output.stdout);
+            output.stderr = strip_ansi(&output.stderr);
+        }
+        return Ok(output);
+    };
+
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let stderr = child.stderr.take().expect("stderr is piped");
+    let (stdout, stderr, status) = tokio::try_join!(
+        stream_output(stdout, hook, reporter),
+        stream_output(stderr, hook, reporter),
+        child.wait()
+    )?;
+    Ok(Output {
+        status,
+        stdout,
+        stderr,
+    })
+}
+
+/// Forward each line of the output to the [`Reporter`], returning the full output.
+async fn stream_output(
+    reader: impl AsyncRead + Unpin,
+    hook: &str,
+    reporter: &dyn Reporter,
+) -> io::Result<Vec<u8>> {
+    let mut reader = BufReader::new(reader);
+    let mut output = Vec::new();
+    let mut line = Vec::new();
+    let color = color_enabled();
+    while reader.read_until(b'\n', &mut line).await? > 0 {
+        if !color {
+            line = strip_ansi(&line);
+        }
+        reporter.on_build_output(hook, String::from_utf8_lossy(&line).trim_end());
+        output.append(&mut line);
+    }
+    Ok(output)
+}
+
+/// Propagate uv's color choice to a build subprocess, which otherwise makes its own decision
+/// based on the environment.
+///
+/// The output is captured, so colors are only forced if they'll be shown when it's replayed.
+fn propagate_color_choice(command: &mut Command) {
+    if anstream::ColorChoice::global() == anstream::ColorChoice::Never {
+        command
+            .env_remove("FORCE_COLOR")
+            .env_remove("CLICOLOR_FORCE")
+            .env("NO_COLOR", "1");
+    } else if color_enabled() {
+        command
+            .env_remove("NO_COLOR")
+            .env("FORCE_COLOR", "1")
+            .env("CLICOLOR_FORCE", "1");
+    }
+}
+
+/// Whether colored output is enabled for uv's own stderr, taking `--color` into account.
+fn color_enabled() -> bool {
+    anstream::AutoStream::choice(&io::stderr()) != anstream::ColorChoice::Never
+}
+
+/// Remove any ANSI escape codes from the given output.
+fn strip_ansi(output: &[u8]) -> Vec<u8> {
+    anstream::adapter::strip_bytes(output).into_vec()
+}
+
 #[cfg(test)]
 mod test {
     use std::process::{ExitStatus, Output};
 
     use indoc::indoc;
+    use uv_configuration::SetupPyStrategy;
 
-    use crate::Error;
+    use crate::{legacy_fallback_message, Error, SourceBuild, DEFAULT_BACKEND};
 
     #[test]
     fn missing_header() {
@@ -1162,4 +1897,89 @@ mod test {
             @"This error likely indicates that you need to `uv pip install wheel` into the build environment for pygraphviz-1.11"
         );
     }
+
+    #[test]
+    fn missing_shared_module() {
+        let output = Output {
+            status: ExitStatus::default(), // This is wrong but `from_raw` is platform-gated.
+            stdout: Vec::new(),
+            stderr: indoc!(
+                r#"
+                Traceback (most recent call last):
+                  File "<string>", line 8, in <module>
+                ModuleNotFoundError: No module named 'torch'
+                "#
+            )
+            .as_bytes()
+            .to_vec(),
+        };
+
+        let err = Error::from_command_output(
+            "Build backend failed to build wheel through `build_wheel()`".to_string(),
+            &output,
+            "flash-attn-2.5.8",
+        )
+        .missing_shared_module("flash-attn-2.5.8");
+        assert!(matches!(err, Error::MissingHeader { .. }));
+        insta::assert_snapshot!(
+            std::error::Error::source(&err).unwrap(),
+            @"This error likely indicates that `torch` is missing from the environment used to build flash-attn-2.5.8. Build isolation is disabled for this package (via `--no-build-isolation` or `--no-build-isolation-package`), so its build dependencies must be installed into the environment first"
+        );
+    }
+
+    #[test]
+    fn legacy_fallback() -> Result<(), Box<dyn std::error::Error>> {
+        // A source tree with only a `setup.py` falls back to the legacy setuptools backend.
+        let source_tree = tempfile::tempdir()?;
+        fs_err::write(
+            source_tree.path().join("setup.py"),
+            "from setuptools import setup\nsetup(name='legacy')\n",
+        )?;
+        let (backend, project, legacy_fallback) = SourceBuild::extract_pep517_backend(
+            source_tree.path(),
+            SetupPyStrategy::Pep517,
+            &DEFAULT_BACKEND,
+        )
+        .map_err(|err| *err)?;
+        assert_eq!(backend.as_ref(), Some(&*DEFAULT_BACKEND));
+        assert!(project.is_none());
+        assert!(legacy_fallback);
+        insta::assert_snapshot!(
+            legacy_fallback_message("legacy-1.0.0", backend.as_ref()),
+            @r###"`legacy-1.0.0` does not declare a `[build-system]` table in `pyproject.toml`, so uv fell back to building it with the legacy setuptools backend (`setuptools.build_meta:__legacy__`). Consider adding a `[build-system]` table that declares the build backend and its requirements (e.g., `requires = ["setuptools>=61"]` and `build-backend = "setuptools.build_meta"`)"###
+        );
+
+        // With `--legacy-setup-py`, the same source tree invokes `setup.py` directly.
+        let (backend, _, legacy_fallback) = SourceBuild::extract_pep517_backend(
+            source_tree.path(),
+            SetupPyStrategy::Setuptools,
+            &DEFAULT_BACKEND,
+        )
+        .map_err(|err| *err)?;
+        assert!(backend.is_none());
+        assert!(legacy_fallback);
+        insta::assert_snapshot!(
+            legacy_fallback_message("legacy-1.0.0", backend.as_ref()),
+            @r###"`legacy-1.0.0` does not declare a `[build-system]` table in `pyproject.toml`, so uv fell back to invoking `setup.py` directly. Consider adding a `[build-system]` table that declares the build backend and its requirements (e.g., `requires = ["setuptools>=61"]` and `build-backend = "setuptools.build_meta"`)"###
+        );
+
+        // Declaring a `[build-system]` table avoids the fallback.
+        fs_err::write(
+            source_tree.path().join("pyproject.toml"),
+            "[build-system]\nrequires = [\"setuptools>=61\"]\nbuild-backend = \"setuptools.build_meta\"\n",
+        )?;
+        let (backend, _, legacy_fallback) = SourceBuild::extract_pep517_backend(
+            source_tree.path(),
+            SetupPyStrategy::Pep517,
+            &DEFAULT_BACKEND,
+        )
+        .map_err(|err| *err)?;
+        assert_eq!(
+            backend.map(|backend| backend.backend).as_deref(),
+            Some("setuptools.build_meta")
+        );
+        assert!(!legacy_fallback);
+
+        Ok(())
+    }
 }
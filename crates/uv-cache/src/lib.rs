@@ -13,7 +13,7 @@ use tracing::debug;
 
 use distribution_types::InstalledDist;
 use pypi_types::Metadata23;
-use uv_fs::directories;
+use uv_fs::{directories, LockedFile};
 use uv_normalize::PackageName;
 
 pub use crate::by_timestamp::CachedByTimestamp;
@@ -340,7 +340,36 @@ impl Cache {
             }
         }
 
-        // Second, remove any unused archives (by searching for archives that are not symlinked).
+        // Second, remove any stale build directories (from `--no-clean`), retaining only the most
+        // recently used build directory for each package, config settings, and interpreter.
+        let builds = self.bucket(CacheBucket::Builds);
+        if builds.is_dir() {
+            for package in fs::read_dir(&builds)? {
+                let package = package?;
+                if !package.file_type()?.is_dir() {
+                    continue;
+                }
+                for environment in fs::read_dir(package.path())? {
+                    let environment = environment?;
+                    if !environment.file_type()?.is_dir() {
+                        continue;
+                    }
+                    let mut build_dirs = fs::read_dir(environment.path())?
+                        .map(|entry| {
+                            let entry = entry?;
+                            Ok((entry.metadata()?.modified()?, entry.path()))
+                        })
+                        .collect::<Result<Vec<_>, io::Error>>()?;
+                    build_dirs.sort_unstable();
+                    build_dirs.pop();
+                    for (_, path) in build_dirs {
+                        summary += remove_build_dir(&path)?;
+                    }
+                }
+            }
+        }
+
+        // Third, remove any unused archives (by searching for archives that are not symlinked).
         // TODO(charlie): Remove any unused source distributions. This requires introspecting the
         // cache contents, e.g., reading and deserializing the manifests.
         let mut references = FxHashSet::default();
@@ -370,6 +399,30 @@ impl Cache {
     }
 }
 
+/// Remove a stale persistent build directory, unless it's in use by a running build.
+///
+/// The build directory's contents are removed while holding its lock, such that a concurrent
+/// build can't observe a partially removed source tree.
+fn remove_build_dir(path: &Path) -> Result<Removal, io::Error> {
+    let Some(lock) = LockedFile::try_acquire(path.join(".lock"))? else {
+        debug!("Retaining build directory in use: {}", path.display());
+        return Ok(Removal::default());
+    };
+
+    debug!("Removing stale build directory: {}", path.display());
+    let mut summary = Removal::default();
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_name() != ".lock" {
+            summary += rm_rf(entry.path())?;
+        }
+    }
+    drop(lock);
+
+    summary += rm_rf(path)?;
+    Ok(summary)
+}
+
 /// The different kinds of data in the cache are stored in different bucket, which in our case
 /// are subdirectories of the cache root.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -602,6 +655,22 @@ pub enum CacheBucket {
     /// that cache entries can be atomically replaced and removed, as storing directories in the
     /// other buckets directly would make atomic operations impossible.
     Archive,
+    /// Persistent build directories for source distributions, retained across builds when
+    /// `--no-clean` is enabled, such that incremental builds can reuse the previous build tree.
+    ///
+    /// Each build directory is keyed by the package name, the config settings and the interpreter,
+    /// and the path to the source tree, and contains a copy of the source tree (synced by content
+    /// on each build) alongside the build environment. `uv cache prune` retains only the most
+    /// recently used build directory for each package, config settings, and interpreter.
+    ///
+    /// Cache structure: `builds-v1/<package_name>/<digest(config_settings, interpreter)>/<digest(source path)>/`
+    Builds,
+    /// Archives (wheels and source distributions) downloaded as-is by `pip download`, alongside
+    /// their HTTP cache policy, such that they can be saved again without a network request (e.g.,
+    /// with `--offline`).
+    ///
+    /// Cache structure: `downloads-v0/<package_name>/<digest(url)>/{<filename>, <filename>.http}`
+    Downloads,
 }
 
 impl CacheBucket {
@@ -614,6 +683,8 @@ impl CacheBucket {
             Self::Simple => "simple-v7",
             Self::Wheels => "wheels-v1",
             Self::Archive => "archive-v0",
+            Self::Builds => "builds-v1",
+            Self::Downloads => "downloads-v0",
         }
     }
 
@@ -724,6 +795,11 @@ impl CacheBucket {
             Self::Archive => {
                 // Nothing to do.
             }
+            Self::Builds | Self::Downloads => {
+                // We expect a directory per package (indexed by name).
+                let root = cache.bucket(self);
+                summary += rm_rf(root.join(name.to_string()))?;
+            }
         }
         Ok(summary)
     }
@@ -738,6 +814,8 @@ impl CacheBucket {
             CacheBucket::Interpreter,
             CacheBucket::Simple,
             CacheBucket::Archive,
+            CacheBucket::Builds,
+            CacheBucket::Downloads,
         ]
         .iter()
         .copied()
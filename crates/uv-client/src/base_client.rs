@@ -1,3 +1,4 @@
+use netrc::Netrc;
 use pep508_rs::MarkerEnvironment;
 use platform_tags::Platform;
 use reqwest::{Client, ClientBuilder};
@@ -29,6 +30,8 @@ pub struct BaseClientBuilder<'a> {
     client: Option<Client>,
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
+    default_timeout: Option<u64>,
+    netrc_file: Option<&'a Path>,
 }
 
 impl Default for BaseClientBuilder<'_> {
@@ -47,6 +50,8 @@ impl BaseClientBuilder<'_> {
             client: None,
             markers: None,
             platform: None,
+            default_timeout: None,
+            netrc_file: None,
         }
     }
 }
@@ -94,6 +99,21 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the request timeout (in seconds) to use when none of the timeout environment variables
+    /// (e.g., `UV_HTTP_TIMEOUT`) are set.
+    #[must_use]
+    pub fn default_timeout(mut self, default_timeout: Option<u64>) -> Self {
+        self.default_timeout = default_timeout;
+        self
+    }
+
+    /// Read `.netrc` credentials from the given file, rather than from `NETRC` or `~/.netrc`.
+    #[must_use]
+    pub fn netrc_file(mut self, netrc_file: Option<&'a Path>) -> Self {
+        self.netrc_file = netrc_file;
+        self
+    }
+
     pub fn is_offline(&self) -> bool {
         matches!(self.connectivity, Connectivity::Offline)
     }
@@ -112,7 +132,7 @@ impl<'a> BaseClientBuilder<'a> {
 
         // Timeout options, matching https://doc.rust-lang.org/nightly/cargo/reference/config.html#httptimeout
         // `UV_REQUEST_TIMEOUT` is provided for backwards compatibility with v0.1.6
-        let default_timeout = 5 * 60;
+        let default_timeout = self.default_timeout.unwrap_or(5 * 60);
         let timeout = env::var("UV_HTTP_TIMEOUT")
             .or_else(|_| env::var("UV_REQUEST_TIMEOUT"))
             .or_else(|_| env::var("HTTP_TIMEOUT"))
@@ -170,8 +190,21 @@ impl<'a> BaseClientBuilder<'a> {
                 let client = client.with(retry_strategy);
 
                 // Initialize the authentication middleware to set headers.
-                let client =
-                    client.with(AuthMiddleware::new().with_keyring(self.keyring.to_provider()));
+                let mut auth_middleware =
+                    AuthMiddleware::new().with_keyring(self.keyring.to_provider());
+                if let Some(netrc_file) = self.netrc_file {
+                    auth_middleware = auth_middleware.with_netrc(
+                        Netrc::from_file(netrc_file)
+                            .map_err(|err| {
+                                debug!(
+                                    "Failed to read `.netrc` file at {}: {err}",
+                                    netrc_file.user_display()
+                                );
+                            })
+                            .ok(),
+                    );
+                }
+                let client = client.with(auth_middleware);
 
                 client.build()
             }
@@ -1,4 +1,6 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use futures::{FutureExt, StreamExt};
 use reqwest::Response;
@@ -8,7 +10,9 @@ use url::Url;
 use distribution_filename::DistFilename;
 use distribution_types::{File, FileLocation, FlatIndexLocation, IndexUrl};
 use pep508_rs::VerbatimUrl;
+use pypi_types::{HashAlgorithm, HashDigest};
 use uv_cache::{Cache, CacheBucket};
+use uv_warnings::warn_user;
 
 use crate::cached_client::{CacheControl, CachedClientError};
 use crate::html::SimpleHtml;
@@ -81,15 +85,19 @@ impl<'a> FlatIndexClient<'a> {
     }
 
     /// Read the directories and flat remote indexes from `--find-links`.
+    ///
+    /// If `recursive` is set, directories are scanned recursively, such that distributions in
+    /// nested subdirectories are included.
     #[allow(clippy::result_large_err)]
     pub async fn fetch(
         &self,
         indexes: impl Iterator<Item = &FlatIndexLocation>,
+        recursive: bool,
     ) -> Result<FlatIndexEntries, FlatIndexError> {
         let mut fetches = futures::stream::iter(indexes)
             .map(|index| async move {
                 let entries = match index {
-                    FlatIndexLocation::Path(path) => Self::read_from_directory(path)
+                    FlatIndexLocation::Path(path) => Self::read_from_directory(path, recursive)
                         .map_err(|err| FlatIndexError::FindLinksDirectory(path.clone(), err))?,
                     FlatIndexLocation::Url(url) => self
                         .read_from_url(url)
@@ -202,15 +210,54 @@ impl<'a> FlatIndexClient<'a> {
     }
 
     /// Read a flat remote index from a `--find-links` directory.
-    fn read_from_directory(path: &PathBuf) -> Result<FlatIndexEntries, std::io::Error> {
+    fn read_from_directory(
+        path: &PathBuf,
+        recursive: bool,
+    ) -> Result<FlatIndexEntries, std::io::Error> {
         // Absolute paths are required for the URL conversion.
         let path = fs_err::canonicalize(path)?;
         let index_url = IndexUrl::Path(VerbatimUrl::from_path(&path));
 
         let mut dists = Vec::new();
+        let mut visited = FxHashSet::default();
+        Self::scan_directory(&path, &index_url, recursive, &mut visited, &mut dists)?;
+        Ok(FlatIndexEntries::from_entries(dists))
+    }
+
+    /// Collect the distributions in a `--find-links` directory into `dists`, recursing into
+    /// subdirectories if `recursive` is set.
+    ///
+    /// Directories are tracked by their canonical path in `visited`, such that symlink loops (and
+    /// directories reachable through multiple symlinks) are only scanned once.
+    ///
+    /// If the directory contains a [`HASH_MANIFEST`], the hashes it lists are attached to the
+    /// corresponding distributions, such that they're verified on install, and any distributions
+    /// that it doesn't list are skipped.
+    fn scan_directory(
+        path: &Path,
+        index_url: &IndexUrl,
+        recursive: bool,
+        visited: &mut FxHashSet<PathBuf>,
+        dists: &mut Vec<(DistFilename, File, IndexUrl)>,
+    ) -> Result<(), std::io::Error> {
+        if !visited.insert(fs_err::canonicalize(path)?) {
+            debug!(
+                "Skipping already-visited `--find-links` directory: {}",
+                path.display()
+            );
+            return Ok(());
+        }
+
+        let manifest = read_hash_manifest(path)?;
+
         for entry in fs_err::read_dir(path)? {
             let entry = entry?;
             let metadata = entry.metadata()?;
+            if recursive && (metadata.is_dir() || (metadata.is_symlink() && entry.path().is_dir()))
+            {
+                Self::scan_directory(&entry.path(), index_url, recursive, visited, dists)?;
+                continue;
+            }
             if !metadata.is_file() {
                 continue;
             }
@@ -223,26 +270,126 @@ impl<'a> FlatIndexClient<'a> {
                 continue;
             };
 
+            let Some(dist_filename) = DistFilename::try_from_normalized_filename(&filename) else {
+                debug!(
+                    "Ignoring `--find-links` entry (expected a wheel or source distribution filename): {}",
+                    entry.path().display()
+                );
+                continue;
+            };
+
+            // If the directory has a manifest, any distribution that it doesn't list can't be
+            // verified, and so is rejected.
+            let hashes = match &manifest {
+                Some(manifest) => {
+                    let Some(hash) = manifest.get(&filename) else {
+                        warn_user!(
+                            "Ignoring `--find-links` entry that isn't listed in its `{HASH_MANIFEST}` manifest: {}",
+                            entry.path().display()
+                        );
+                        continue;
+                    };
+                    vec![hash.clone()]
+                }
+                None => Vec::new(),
+            };
+
             let file = File {
                 dist_info_metadata: false,
-                filename: filename.to_string(),
-                hashes: Vec::new(),
+                filename,
+                hashes,
                 requires_python: None,
                 size: None,
                 upload_time_utc_ms: None,
                 url: FileLocation::Path(entry.path().clone()),
                 yanked: None,
             };
+            dists.push((dist_filename, file, index_url.clone()));
+        }
+        Ok(())
+    }
+}
 
-            let Some(filename) = DistFilename::try_from_normalized_filename(&filename) else {
-                debug!(
-                    "Ignoring `--find-links` entry (expected a wheel or source distribution filename): {}",
-                    entry.path().display()
-                );
-                continue;
-            };
-            dists.push((filename, file, index_url.clone()));
+/// The name of the hash manifest in a `--find-links` directory, in the format produced by
+/// `sha256sum` (i.e., one `<digest>  <filename>` entry per line).
+const HASH_MANIFEST: &str = "SHA256SUMS";
+
+/// Read the [`HASH_MANIFEST`] in a `--find-links` directory, if any, mapping from filename to
+/// SHA-256 digest.
+fn read_hash_manifest(
+    directory: &Path,
+) -> Result<Option<FxHashMap<String, HashDigest>>, std::io::Error> {
+    let path = directory.join(HASH_MANIFEST);
+    let content = match fs_err::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    debug!("Reading hash manifest: {}", path.display());
+    parse_hash_manifest(&content).map(Some).map_err(|line| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "Invalid entry in `{}` (expected `<sha256>  <filename>`): `{line}`",
+                path.display()
+            ),
+        )
+    })
+}
+
+/// Parse the contents of a [`HASH_MANIFEST`], returning the first invalid line on failure.
+///
+/// Blank lines and `#` comments are ignored. As in `sha256sum`, a filename may be prefixed with
+/// `*` to indicate binary mode.
+fn parse_hash_manifest(content: &str) -> Result<FxHashMap<String, HashDigest>, &str> {
+    let mut hashes = FxHashMap::default();
+    for line in content.lines() {
+        let entry = line.trim();
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
         }
-        Ok(FlatIndexEntries::from_entries(dists))
+        let Some((digest, filename)) = entry.split_once(char::is_whitespace) else {
+            return Err(line);
+        };
+        let filename = filename.trim_start();
+        let filename = filename.strip_prefix('*').unwrap_or(filename);
+        if digest.len() != 64 || !digest.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(line);
+        }
+        hashes.insert(
+            filename.to_string(),
+            HashDigest {
+                algorithm: HashAlgorithm::Sha256,
+                digest: digest.to_ascii_lowercase().into_boxed_str(),
+            },
+        );
+    }
+    Ok(hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_manifest() {
+        let digest = "0f7b1d2c3e4a5b6c7d8e9f00112233445566778899aabbccddeeff0011223344";
+        let manifest = parse_hash_manifest(&format!(
+            "# Generated by sha256sum\n{digest}  tqdm-1000.0.0-py3-none-any.whl\n\n{}  *tqdm-999.0.0.tar.gz\n",
+            digest.to_ascii_uppercase()
+        ))
+        .unwrap();
+        assert_eq!(manifest.len(), 2);
+        assert_eq!(
+            manifest["tqdm-1000.0.0-py3-none-any.whl"].digest.as_ref(),
+            digest
+        );
+        assert_eq!(manifest["tqdm-999.0.0.tar.gz"].digest.as_ref(), digest);
+
+        assert_eq!(
+            parse_hash_manifest("abc  tqdm-1000.0.0-py3-none-any.whl"),
+            Err("abc  tqdm-1000.0.0-py3-none-any.whl")
+        );
+        assert_eq!(parse_hash_manifest(digest), Err(digest));
     }
 }
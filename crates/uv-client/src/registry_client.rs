@@ -45,6 +45,8 @@ pub struct RegistryClientBuilder<'a> {
     client: Option<Client>,
     markers: Option<&'a MarkerEnvironment>,
     platform: Option<&'a Platform>,
+    default_timeout: Option<u64>,
+    netrc_file: Option<&'a Path>,
 }
 
 impl RegistryClientBuilder<'_> {
@@ -60,6 +62,8 @@ impl RegistryClientBuilder<'_> {
             client: None,
             markers: None,
             platform: None,
+            default_timeout: None,
+            netrc_file: None,
         }
     }
 }
@@ -125,6 +129,18 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn default_timeout(mut self, default_timeout: Option<u64>) -> Self {
+        self.default_timeout = default_timeout;
+        self
+    }
+
+    #[must_use]
+    pub fn netrc_file(mut self, netrc_file: Option<&'a Path>) -> Self {
+        self.netrc_file = netrc_file;
+        self
+    }
+
     pub fn build(self) -> RegistryClient {
         // Build a base client
         let mut builder = BaseClientBuilder::new();
@@ -146,6 +162,8 @@ impl<'a> RegistryClientBuilder<'a> {
             .connectivity(self.connectivity)
             .native_tls(self.native_tls)
             .keyring(self.keyring)
+            .default_timeout(self.default_timeout)
+            .netrc_file(self.netrc_file)
             .build();
 
         let timeout = client.timeout();
@@ -209,6 +227,20 @@ impl RegistryClient {
         Self { index_urls, ..self }
     }
 
+    /// Return the URL that the given artifact URL is served from, after following any redirects
+    /// (e.g., to a mirror).
+    pub async fn resolve_redirects(&self, url: &Url) -> Result<Url, Error> {
+        let response = self
+            .uncached_client()
+            .head(url.clone())
+            .send()
+            .await
+            .map_err(ErrorKind::from)?
+            .error_for_status()
+            .map_err(ErrorKind::from)?;
+        Ok(response.url().clone())
+    }
+
     /// Fetch a package from the `PyPI` simple API.
     ///
     /// "simple" here refers to [PEP 503 – Simple Repository API](https://peps.python.org/pep-0503/)
@@ -0,0 +1,67 @@
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::LOCATION;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+
+use uv_cache::Cache;
+use uv_client::RegistryClientBuilder;
+
+#[tokio::test]
+async fn test_resolve_redirects() -> Result<()> {
+    // Set up the TCP listener on a random available port
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    // Spawn the server loop in a background task, redirecting any artifact to a mirror
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let socket = TokioIo::new(socket);
+            let svc = service_fn(move |req: Request<hyper::body::Incoming>| {
+                let response = if let Some(path) = req.uri().path().strip_prefix("/files/") {
+                    Response::builder()
+                        .status(StatusCode::FOUND)
+                        .header(LOCATION, format!("/mirror/{path}"))
+                        .body(Full::new(Bytes::new()))
+                } else {
+                    Response::builder().body(Full::new(Bytes::new()))
+                };
+                future::ok::<_, hyper::Error>(response.unwrap())
+            });
+            tokio::task::spawn(async move {
+                http1::Builder::new()
+                    .serve_connection(socket, svc)
+                    .await
+                    .expect("Server Started");
+            });
+        }
+    });
+
+    // Initialize uv-client
+    let cache = Cache::temp()?;
+    let client = RegistryClientBuilder::new(cache).build();
+
+    // The artifact is reported at the URL it was redirected to
+    let url = Url::parse(&format!("http://{addr}/files/anyio-4.3.0-py3-none-any.whl"))?;
+    let origin = client.resolve_redirects(&url).await?;
+    assert_eq!(
+        origin.as_str(),
+        format!("http://{addr}/mirror/anyio-4.3.0-py3-none-any.whl")
+    );
+
+    // An artifact that isn't redirected is reported at its own URL
+    let url = Url::parse(&format!(
+        "http://{addr}/mirror/anyio-4.3.0-py3-none-any.whl"
+    ))?;
+    let origin = client.resolve_redirects(&url).await?;
+    assert_eq!(origin, url);
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+
+/// An environment variable to set when building a specific package, as provided via
+/// `--build-env PACKAGE:KEY=VALUE`.
+#[derive(Debug, Clone)]
+pub struct BuildEnvEntry {
+    /// The package whose build should receive the variable.
+    package: PackageName,
+    /// The name of the variable. For example, given `foo:key=value`, this would be `key`.
+    key: String,
+    /// The value of the variable. For example, given `foo:key=value`, this would be `value`.
+    value: String,
+}
+
+impl FromStr for BuildEnvEntry {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, variable)) = s.split_once(':') else {
+            return Err(anyhow::anyhow!(
+                "Invalid build environment variable: {s} (expected `PACKAGE:KEY=VALUE`)"
+            ));
+        };
+        let Some((key, value)) = variable.split_once('=') else {
+            return Err(anyhow::anyhow!(
+                "Invalid build environment variable: {s} (expected `PACKAGE:KEY=VALUE`)"
+            ));
+        };
+        Ok(Self {
+            package: PackageName::from_str(package.trim())?,
+            key: key.trim().to_string(),
+            value: value.to_string(),
+        })
+    }
+}
+
+/// Environment variables to set when building specific packages, structured as a map from
+/// package name to variable name to value.
+///
+/// Values may reference variables from the surrounding environment as `${VAR}`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BuildEnv(BTreeMap<PackageName, BTreeMap<String, String>>);
+
+impl FromIterator<BuildEnvEntry> for BuildEnv {
+    fn from_iter<T: IntoIterator<Item = BuildEnvEntry>>(iter: T) -> Self {
+        let mut build_env: BTreeMap<PackageName, BTreeMap<String, String>> = BTreeMap::default();
+        for entry in iter {
+            build_env
+                .entry(entry.package)
+                .or_default()
+                .insert(entry.key, entry.value);
+        }
+        Self(build_env)
+    }
+}
+
+impl BuildEnv {
+    /// Return the environment variables to set when building the given package, with any
+    /// `${VAR}` references expanded against the current environment.
+    pub fn get(&self, package: &PackageName) -> Vec<(String, String)> {
+        self.0
+            .get(package)
+            .map(|variables| {
+                variables
+                    .iter()
+                    .map(|(key, value)| {
+                        (key.clone(), expand(value, |name| std::env::var(name).ok()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Expand `${VAR}` references in a value. Undefined variables expand to an empty string.
+fn expand(value: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start + 2..].find('}') else {
+            break;
+        };
+        expanded.push_str(&rest[..start]);
+        expanded.push_str(&lookup(&rest[start + 2..start + 2 + end]).unwrap_or_default());
+        rest = &rest[start + 2 + end + 1..];
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_build_env() -> Result<(), anyhow::Error> {
+        let build_env: BuildEnv = vec![
+            BuildEnvEntry::from_str("pycurl:PYCURL_SSL_LIBRARY=openssl")?,
+            BuildEnvEntry::from_str("mysqlclient:MYSQLCLIENT_CFLAGS=-I/usr/include")?,
+            BuildEnvEntry::from_str("pycurl:PYCURL_SSL_LIBRARY=gnutls")?,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            build_env.get(&PackageName::from_str("pycurl")?),
+            vec![("PYCURL_SSL_LIBRARY".to_string(), "gnutls".to_string())]
+        );
+        assert_eq!(
+            build_env.get(&PackageName::from_str("mysqlclient")?),
+            vec![(
+                "MYSQLCLIENT_CFLAGS".to_string(),
+                "-I/usr/include".to_string()
+            )]
+        );
+        assert!(build_env.get(&PackageName::from_str("flask")?).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_build_env_entry() {
+        assert!(BuildEnvEntry::from_str("PYCURL_SSL_LIBRARY=openssl").is_err());
+        assert!(BuildEnvEntry::from_str("pycurl:PYCURL_SSL_LIBRARY").is_err());
+    }
+
+    #[test]
+    fn expand_variables() {
+        let lookup = |name: &str| (name == "PREFIX").then(|| "/opt".to_string());
+        assert_eq!(expand("${PREFIX}/include", lookup), "/opt/include");
+        assert_eq!(expand("-I${PREFIX} -I${MISSING}", lookup), "-I/opt -I");
+        assert_eq!(expand("${PREFIX", lookup), "${PREFIX");
+        assert_eq!(expand("plain", lookup), "plain");
+    }
+}
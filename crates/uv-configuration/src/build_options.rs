@@ -32,6 +32,22 @@ impl Display for BuildKind {
     }
 }
 
+/// The strategy to use when building editable installations.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum EditableMode {
+    /// Build a PEP 660 editable wheel if the build backend supports it (i.e., defines
+    /// `build_editable`), falling back to a legacy editable installation otherwise.
+    #[default]
+    Auto,
+    /// Always build a PEP 660 editable wheel, failing if the build backend doesn't support it.
+    #[cfg_attr(feature = "clap", value(name = "pep660"))]
+    Pep660,
+    /// Build a regular wheel, and install it with a `.pth` file that adds the source tree to
+    /// `sys.path` in lieu of the package contents, akin to `setup.py develop`.
+    Legacy,
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub enum NoBinary {
     /// Allow installation of any wheel.
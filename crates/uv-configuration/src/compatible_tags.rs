@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use uv_normalize::PackageName;
+
+/// An additional platform tag to accept for a specific package, as provided via
+/// `--allow-platform-tag PACKAGE:TAG`.
+#[derive(Debug, Clone)]
+pub struct CompatibleTagEntry {
+    /// The package whose wheels may use the tag.
+    package: PackageName,
+    /// The platform tag. For example, given `foo:linux_x86_64`, this would be `linux_x86_64`.
+    tag: String,
+}
+
+impl FromStr for CompatibleTagEntry {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, tag)) = s.split_once(':') else {
+            return Err(anyhow::anyhow!(
+                "Invalid platform tag: {s} (expected `PACKAGE:TAG`)"
+            ));
+        };
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid platform tag: {s} (expected `PACKAGE:TAG`)"
+            ));
+        }
+        Ok(Self {
+            package: PackageName::from_str(package.trim())?,
+            tag: tag.to_string(),
+        })
+    }
+}
+
+/// Additional platform tags to accept for specific packages, structured as a map from package
+/// name to platform tags.
+///
+/// Wheels for the package that are tagged with one of the given platform tags are considered
+/// compatible with the current platform, as long as their Python and ABI tags are compatible.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CompatibleTags(BTreeMap<PackageName, Vec<String>>);
+
+impl FromIterator<CompatibleTagEntry> for CompatibleTags {
+    fn from_iter<T: IntoIterator<Item = CompatibleTagEntry>>(iter: T) -> Self {
+        let mut compatible_tags: BTreeMap<PackageName, Vec<String>> = BTreeMap::default();
+        for entry in iter {
+            let tags = compatible_tags.entry(entry.package).or_default();
+            if !tags.contains(&entry.tag) {
+                tags.push(entry.tag);
+            }
+        }
+        Self(compatible_tags)
+    }
+}
+
+impl CompatibleTags {
+    /// Iterate over the packages and the additional platform tags allowed for each.
+    pub fn iter(&self) -> impl Iterator<Item = (&PackageName, &[String])> {
+        self.0
+            .iter()
+            .map(|(package, tags)| (package, tags.as_slice()))
+    }
+
+    /// Returns `true` if no additional platform tags are allowed.
+    pub fn is_empty(&self) -> bool {
+        self.0.values().all(Vec::is_empty)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_compatible_tags() -> Result<(), anyhow::Error> {
+        let compatible_tags: CompatibleTags = vec![
+            CompatibleTagEntry::from_str("foo:linux_x86_64")?,
+            CompatibleTagEntry::from_str("bar:linux_aarch64")?,
+            CompatibleTagEntry::from_str("foo:linux_i686")?,
+            CompatibleTagEntry::from_str("foo:linux_x86_64")?,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            compatible_tags
+                .iter()
+                .map(|(package, tags)| (package.to_string(), tags.to_vec()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("bar".to_string(), vec!["linux_aarch64".to_string()]),
+                (
+                    "foo".to_string(),
+                    vec!["linux_x86_64".to_string(), "linux_i686".to_string()]
+                ),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_compatible_tag_entry() {
+        assert!(CompatibleTagEntry::from_str("linux_x86_64").is_err());
+        assert!(CompatibleTagEntry::from_str("foo:").is_err());
+    }
+}
@@ -17,11 +17,17 @@ impl FromStr for ConfigSettingEntry {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let Some((key, value)) = s.split_once('=') else {
             return Err(anyhow::anyhow!(
-                "Invalid config setting: {s} (expected `KEY=VALUE`)"
+                "Invalid config setting: `{s}` is missing a `=` (expected `KEY=VALUE`)"
             ));
         };
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid config setting: `{s}` is missing a key (expected `KEY=VALUE`)"
+            ));
+        }
         Ok(Self {
-            key: key.trim().to_string(),
+            key: key.to_string(),
             value: value.trim().to_string(),
         })
     }
@@ -110,6 +116,22 @@ impl FromIterator<ConfigSettingEntry> for ConfigSettings {
     }
 }
 
+impl ConfigSettings {
+    /// Iterate over the settings as `(key, value)` pairs, with one pair per value for keys that
+    /// were provided multiple times.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().flat_map(|(key, value)| {
+            let values = match value {
+                ConfigSettingValue::String(value) => std::slice::from_ref(value),
+                ConfigSettingValue::List(values) => values.as_slice(),
+            };
+            values
+                .iter()
+                .map(move |value| (key.as_str(), value.as_str()))
+        })
+    }
+}
+
 #[cfg(feature = "serde")]
 impl ConfigSettings {
     /// Convert the settings to a string that can be passed directly to a PEP 517 build backend.
@@ -201,6 +223,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_config_setting_entry() -> Result<(), anyhow::Error> {
+        let entry = ConfigSettingEntry::from_str(" key = value=with=equals ")?;
+        assert_eq!(entry.key, "key");
+        assert_eq!(entry.value, "value=with=equals");
+
+        let entry = ConfigSettingEntry::from_str("key=")?;
+        assert_eq!(entry.key, "key");
+        assert_eq!(entry.value, "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_config_setting_entry() {
+        assert_eq!(
+            ConfigSettingEntry::from_str("editable_mode")
+                .unwrap_err()
+                .to_string(),
+            "Invalid config setting: `editable_mode` is missing a `=` (expected `KEY=VALUE`)"
+        );
+        assert_eq!(
+            ConfigSettingEntry::from_str("=compat")
+                .unwrap_err()
+                .to_string(),
+            "Invalid config setting: `=compat` is missing a key (expected `KEY=VALUE`)"
+        );
+    }
+
+    #[test]
+    fn repeated_config_setting_key() -> Result<(), anyhow::Error> {
+        let settings: ConfigSettings = ["--build-option=--a", "other=value", "--build-option=--b"]
+            .into_iter()
+            .map(ConfigSettingEntry::from_str)
+            .collect::<Result<_, _>>()?;
+        assert_eq!(
+            settings.iter().collect::<Vec<_>>(),
+            vec![
+                ("--build-option", "--a"),
+                ("--build-option", "--b"),
+                ("other", "value")
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn iter_config_settings() {
+        let mut settings = ConfigSettings::default();
+        settings.0.insert(
+            "key".to_string(),
+            ConfigSettingValue::String("value".to_string()),
+        );
+        settings.0.insert(
+            "list".to_string(),
+            ConfigSettingValue::List(vec!["value1".to_string(), "value2".to_string()]),
+        );
+        assert_eq!(
+            settings.iter().collect::<Vec<_>>(),
+            vec![("key", "value"), ("list", "value1"), ("list", "value2")]
+        );
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn escape_for_python() {
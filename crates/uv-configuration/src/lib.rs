@@ -1,15 +1,23 @@
 pub use authentication::*;
+pub use build_env::*;
 pub use build_options::*;
+pub use compatible_tags::*;
 pub use config_settings::*;
 pub use constraints::*;
 pub use name_specifiers::*;
 pub use overrides::*;
 pub use package_options::*;
+pub use python_implementation::*;
+pub use python_preference::*;
 
 mod authentication;
+mod build_env;
 mod build_options;
+mod compatible_tags;
 mod config_settings;
 mod constraints;
 mod name_specifiers;
 mod overrides;
 mod package_options;
+mod python_implementation;
+mod python_preference;
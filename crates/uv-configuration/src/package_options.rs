@@ -1,6 +1,6 @@
-use pep508_rs::PackageName;
+use pep508_rs::{PackageName, Requirement};
 
-use rustc_hash::FxHashSet;
+use rustc_hash::FxHashMap;
 
 /// Whether to reinstall packages.
 #[derive(Debug, Clone)]
@@ -47,17 +47,25 @@ pub enum Upgrade {
     /// Allow package upgrades for all packages, ignoring the existing lockfile.
     All,
 
-    /// Allow package upgrades, but only for the specified packages.
-    Packages(FxHashSet<PackageName>),
+    /// Allow package upgrades, but only for the specified packages, subject to any version
+    /// specifiers provided alongside them (e.g., `requests<2.32`).
+    Packages(FxHashMap<PackageName, Vec<Requirement>>),
 }
 
 impl Upgrade {
     /// Determine the upgrade strategy from the command-line arguments.
-    pub fn from_args(upgrade: bool, upgrade_package: Vec<PackageName>) -> Self {
+    pub fn from_args(upgrade: bool, upgrade_package: Vec<Requirement>) -> Self {
         if upgrade {
             Self::All
         } else if !upgrade_package.is_empty() {
-            Self::Packages(upgrade_package.into_iter().collect())
+            let mut packages: FxHashMap<PackageName, Vec<Requirement>> = FxHashMap::default();
+            for requirement in upgrade_package {
+                packages
+                    .entry(requirement.name.clone())
+                    .or_default()
+                    .push(requirement);
+            }
+            Self::Packages(packages)
         } else {
             Self::None
         }
@@ -72,4 +80,29 @@ impl Upgrade {
     pub fn is_all(&self) -> bool {
         matches!(self, Self::All)
     }
+
+    /// Returns `true` if the given package should be upgraded.
+    pub fn contains(&self, package_name: &PackageName) -> bool {
+        match self {
+            Self::None => false,
+            Self::All => true,
+            Self::Packages(packages) => packages.contains_key(package_name),
+        }
+    }
+
+    /// Returns the version specifiers that constrain the upgraded packages (e.g., the
+    /// `requests<2.32` in `--upgrade-package requests<2.32`).
+    ///
+    /// Packages that were provided by name alone don't contribute any constraints.
+    pub fn constraints(&self) -> impl Iterator<Item = &Requirement> {
+        let packages = match self {
+            Self::None | Self::All => None,
+            Self::Packages(packages) => Some(packages),
+        };
+        packages
+            .into_iter()
+            .flat_map(FxHashMap::values)
+            .flatten()
+            .filter(|requirement| requirement.version_or_url.is_some())
+    }
 }
@@ -0,0 +1,45 @@
+use std::fmt::{Display, Formatter};
+
+/// A Python implementation, used to constrain interpreter discovery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(deny_unknown_fields, rename_all = "lowercase")
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PythonImplementation {
+    /// The reference implementation of Python.
+    #[cfg_attr(feature = "clap", value(name = "cpython"))]
+    CPython,
+    /// A Python implementation with a just-in-time compiler.
+    #[cfg_attr(feature = "clap", value(name = "pypy"))]
+    PyPy,
+}
+
+impl PythonImplementation {
+    /// Returns the value of `sys.implementation.name` (and the `implementation_name` marker) for
+    /// the implementation.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CPython => "cpython",
+            Self::PyPy => "pypy",
+        }
+    }
+
+    /// Returns the prefix of the executable names under which the implementation is typically
+    /// installed (e.g., `pypy` for `pypy3.10`).
+    pub fn executable_prefix(self) -> &'static str {
+        match self {
+            Self::CPython => "python",
+            Self::PyPy => "pypy",
+        }
+    }
+}
+
+impl Display for PythonImplementation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
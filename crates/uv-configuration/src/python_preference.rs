@@ -0,0 +1,39 @@
+/// The preferred source of Python interpreters, used when discovering an interpreter that wasn't
+/// requested by path.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(deny_unknown_fields, rename_all = "kebab-case")
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PythonPreference {
+    /// Only use managed Python installations (i.e., those installed by `uv`).
+    OnlyManaged,
+    /// Prefer managed Python installations over those found on the system.
+    PreferManaged,
+    /// Prefer Python installations found on the system over managed installations.
+    PreferSystem,
+    /// Only use Python installations found on the system (e.g., in `PATH`).
+    #[default]
+    OnlySystem,
+}
+
+impl PythonPreference {
+    /// Returns `true` if managed Python installations should be considered.
+    pub fn allows_managed(self) -> bool {
+        !matches!(self, Self::OnlySystem)
+    }
+
+    /// Returns `true` if Python installations found on the system should be considered.
+    pub fn allows_system(self) -> bool {
+        !matches!(self, Self::OnlyManaged)
+    }
+
+    /// Returns `true` if managed Python installations should be considered before those found on
+    /// the system.
+    pub fn prefers_managed(self) -> bool {
+        matches!(self, Self::OnlyManaged | Self::PreferManaged)
+    }
+}
@@ -10,7 +10,9 @@ use rustc_hash::FxHashMap;
 use uv_build::{SourceBuild, SourceBuildContext};
 use uv_cache::{Cache, CacheArgs};
 use uv_client::RegistryClientBuilder;
-use uv_configuration::{BuildKind, ConfigSettings, NoBinary, NoBuild, SetupPyStrategy};
+use uv_configuration::{
+    BuildKind, ConfigSettings, EditableMode, NoBinary, NoBuild, SetupPyStrategy,
+};
 use uv_dispatch::BuildDispatch;
 use uv_interpreter::PythonEnvironment;
 use uv_resolver::{FlatIndex, InMemoryIndex};
@@ -85,11 +87,15 @@ pub(crate) async fn build(args: BuildArgs) -> Result<PathBuf> {
         &build_dispatch,
         SourceBuildContext::default(),
         args.sdist.display().to_string(),
+        None,
         setup_py,
         config_settings.clone(),
         BuildIsolation::Isolated,
         build_kind,
+        EditableMode::default(),
         FxHashMap::default(),
+        None,
+        false,
     )
     .await?;
     Ok(wheel_dir.join(builder.build_wheel(&wheel_dir).await?))
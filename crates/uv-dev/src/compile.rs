@@ -24,12 +24,17 @@ pub(crate) async fn compile(args: CompileArgs) -> anyhow::Result<()> {
         venv.python_executable().to_path_buf()
     };
 
-    let files = uv_installer::compile_tree(
+    let summary = uv_installer::compile_tree(
         &fs_err::canonicalize(args.root)?,
         &interpreter,
+        0,
         cache.root(),
     )
     .await?;
-    info!("Compiled {files} files");
+    info!(
+        "Compiled {} files ({} failed)",
+        summary.files,
+        summary.failures.len()
+    );
     Ok(())
 }
@@ -69,7 +69,12 @@ pub(crate) async fn resolve_cli(args: ResolveCliArgs) -> Result<()> {
         .build();
     let flat_index = {
         let client = FlatIndexClient::new(&client, &cache);
-        let entries = client.fetch(index_locations.flat_index()).await?;
+        let entries = client
+            .fetch(
+                index_locations.flat_index(),
+                index_locations.recursive_flat_index(),
+            )
+            .await?;
         FlatIndex::from_entries(
             entries,
             venv.interpreter().tags()?,
@@ -5,6 +5,7 @@
 use std::ffi::OsStr;
 use std::ffi::OsString;
 use std::path::Path;
+use std::sync::Arc;
 
 use anyhow::{bail, Context, Result};
 use futures::FutureExt;
@@ -17,10 +18,13 @@ use pep508_rs::Requirement;
 use uv_build::{SourceBuild, SourceBuildContext};
 use uv_cache::Cache;
 use uv_client::RegistryClient;
-use uv_configuration::{BuildKind, ConfigSettings, NoBinary, NoBuild, Reinstall, SetupPyStrategy};
+use uv_configuration::{
+    BuildEnv, BuildKind, ConfigSettings, Constraints, EditableMode, NoBinary, NoBuild, Overrides,
+    Reinstall, SetupPyStrategy,
+};
 use uv_installer::{Downloader, Installer, Plan, Planner, SitePackages};
 use uv_interpreter::{Interpreter, PythonEnvironment};
-use uv_resolver::{FlatIndex, InMemoryIndex, Manifest, Options, Resolver};
+use uv_resolver::{Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Resolver};
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 
 /// The main implementation of [`BuildContext`], used by the CLI, see [`BuildContext`]
@@ -42,6 +46,11 @@ pub struct BuildDispatch<'a> {
     source_build_context: SourceBuildContext,
     options: Options,
     build_extra_env_vars: FxHashMap<OsString, OsString>,
+    build_env: BuildEnv,
+    build_constraints: Constraints,
+    build_reporter: Option<Arc<dyn uv_build::Reporter>>,
+    editable_mode: EditableMode,
+    reuse_build_dir: bool,
 }
 
 impl<'a> BuildDispatch<'a> {
@@ -78,6 +87,11 @@ impl<'a> BuildDispatch<'a> {
             source_build_context: SourceBuildContext::default(),
             options: Options::default(),
             build_extra_env_vars: FxHashMap::default(),
+            build_env: BuildEnv::default(),
+            build_constraints: Constraints::default(),
+            build_reporter: None,
+            editable_mode: EditableMode::default(),
+            reuse_build_dir: false,
         }
     }
 
@@ -101,6 +115,44 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Set the per-package environment variables to be used when building a source distribution.
+    #[must_use]
+    pub fn with_build_env(mut self, build_env: BuildEnv) -> Self {
+        self.build_env = build_env;
+        self
+    }
+
+    /// Set the constraints to apply when resolving the build requirements of a source
+    /// distribution.
+    #[must_use]
+    pub fn with_build_constraints(mut self, build_constraints: Constraints) -> Self {
+        self.build_constraints = build_constraints;
+        self
+    }
+
+    /// Set the reporter to stream the output of the build backend to when building a source
+    /// distribution, if any.
+    #[must_use]
+    pub fn with_build_reporter(mut self, reporter: Option<Arc<dyn uv_build::Reporter>>) -> Self {
+        self.build_reporter = reporter;
+        self
+    }
+
+    /// Set the strategy to use when building editable installations.
+    #[must_use]
+    pub fn with_editable_mode(mut self, editable_mode: EditableMode) -> Self {
+        self.editable_mode = editable_mode;
+        self
+    }
+
+    /// Reuse a persistent build directory across builds of unchanged source distributions, rather
+    /// than building in a fresh temporary directory.
+    #[must_use]
+    pub fn with_reuse_build_dir(mut self, reuse_build_dir: bool) -> Self {
+        self.reuse_build_dir = reuse_build_dir;
+        self
+    }
 }
 
 impl<'a> BuildContext for BuildDispatch<'a> {
@@ -138,8 +190,17 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         let markers = self.interpreter.markers();
         let tags = self.interpreter.tags()?;
         let resolver = Resolver::new(
-            Manifest::simple(requirements.to_vec()),
-            self.options,
+            Manifest::new(
+                requirements.to_vec(),
+                self.build_constraints.clone(),
+                Overrides::default(),
+                Vec::new(),
+                None,
+                Vec::new(),
+                Exclusions::default(),
+                Vec::new(),
+            ),
+            self.options.clone(),
             markers,
             self.interpreter,
             tags,
@@ -242,7 +303,7 @@ impl<'a> BuildContext for BuildDispatch<'a> {
         // Remove any unnecessary packages.
         if !reinstalls.is_empty() {
             for dist_info in &reinstalls {
-                let summary = uv_installer::uninstall(dist_info)
+                let summary = uv_installer::uninstall(dist_info, true)
                     .await
                     .context("Failed to uninstall build dependencies")?;
                 debug!(
@@ -302,6 +363,17 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             }
         }
 
+        // Layer any package-specific variables over the shared build environment.
+        let mut environment_variables = self.build_extra_env_vars.clone();
+        if let Some(dist) = dist {
+            environment_variables.extend(
+                self.build_env
+                    .get(dist.name())
+                    .into_iter()
+                    .map(|(key, value)| (OsString::from(key), OsString::from(value))),
+            );
+        }
+
         let builder = SourceBuild::setup(
             source,
             subdirectory,
@@ -309,11 +381,15 @@ impl<'a> BuildContext for BuildDispatch<'a> {
             self,
             self.source_build_context.clone(),
             version_id.to_string(),
+            dist.map(Name::name),
             self.setup_py,
             self.config_settings.clone(),
             self.build_isolation,
             build_kind,
-            self.build_extra_env_vars.clone(),
+            self.editable_mode,
+            environment_variables,
+            self.build_reporter.clone(),
+            self.reuse_build_dir,
         )
         .boxed()
         .await?;
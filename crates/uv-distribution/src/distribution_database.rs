@@ -1,21 +1,22 @@
 use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use futures::{FutureExt, TryStreamExt};
-use tempfile::TempDir;
-use tokio::io::AsyncSeekExt;
+use tempfile::{NamedTempFile, TempDir};
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 use tokio_util::compat::FuturesAsyncReadCompatExt;
 use tracing::{info_span, instrument, warn, Instrument};
 use url::Url;
 
+use cache_key::{digest, CanonicalUrl};
 use distribution_filename::WheelFilename;
 use distribution_types::{
     BuildableSource, BuiltDist, Dist, FileLocation, HashPolicy, Hashed, IndexLocations,
     LocalEditable, Name, SourceDist,
 };
 use platform_tags::Tags;
-use pypi_types::{HashDigest, Metadata23};
+use pypi_types::{HashAlgorithm, HashDigest, Metadata23};
 use uv_cache::{ArchiveId, ArchiveTimestamp, CacheBucket, CacheEntry, Timestamp, WheelCache};
 use uv_client::{
     CacheControl, CachedClientError, Connectivity, DataWithCachePolicy, RegistryClient,
@@ -23,6 +24,7 @@ use uv_client::{
 use uv_configuration::{NoBinary, NoBuild};
 use uv_extract::hash::Hasher;
 use uv_fs::write_atomic;
+use uv_normalize::PackageName;
 use uv_types::BuildContext;
 
 use crate::archive::Archive;
@@ -409,6 +411,207 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
         Ok(metadata)
     }
 
+    /// Download the archive for a distribution (a wheel or a source distribution) into the given
+    /// directory, as-is.
+    ///
+    /// Returns the path to the archive, along with its computed hashes. The hashes always include
+    /// a SHA-256 hash, in addition to any hashes required by the hash policy, which is enforced
+    /// before the archive is written.
+    pub async fn download_archive(
+        &self,
+        dist: &Dist,
+        hashes: HashPolicy<'_>,
+        directory: &Path,
+    ) -> Result<(PathBuf, Vec<HashDigest>), Error> {
+        let (filename, location) = match dist {
+            Dist::Built(BuiltDist::Registry(wheel)) => {
+                (wheel.filename.to_string(), wheel.file.url.clone())
+            }
+            Dist::Built(BuiltDist::DirectUrl(wheel)) => (
+                wheel.filename.to_string(),
+                FileLocation::AbsoluteUrl(wheel.url.raw().to_string()),
+            ),
+            Dist::Built(BuiltDist::Path(wheel)) => (
+                wheel.filename.to_string(),
+                FileLocation::Path(wheel.path.clone()),
+            ),
+            Dist::Source(SourceDist::Registry(sdist)) => {
+                (sdist.file.filename.clone(), sdist.file.url.clone())
+            }
+            Dist::Source(SourceDist::DirectUrl(sdist)) => {
+                let Some(filename) = sdist
+                    .url
+                    .raw()
+                    .path_segments()
+                    .and_then(Iterator::last)
+                    .filter(|segment| !segment.is_empty())
+                else {
+                    return Err(Error::ArchiveUnavailable(dist.to_string()));
+                };
+                (
+                    filename.to_string(),
+                    FileLocation::AbsoluteUrl(sdist.url.raw().to_string()),
+                )
+            }
+            Dist::Source(SourceDist::Path(sdist)) if sdist.path.is_file() => {
+                let Some(filename) = sdist.path.file_name().and_then(|name| name.to_str()) else {
+                    return Err(Error::ArchiveUnavailable(dist.to_string()));
+                };
+                (filename.to_string(), FileLocation::Path(sdist.path.clone()))
+            }
+            Dist::Source(SourceDist::Path(_) | SourceDist::Git(_)) => {
+                return Err(Error::ArchiveUnavailable(dist.to_string()));
+            }
+        };
+
+        // Write the archive to a temporary file in the directory.
+        let target = directory.join(filename);
+        let (temp_file, digests) = match location {
+            FileLocation::RelativeUrl(base, url) => {
+                let url = pypi_types::base_url_join_relative(&base, &url)?;
+                self.fetch_archive(url, dist.name(), hashes, &target)
+                    .await?
+            }
+            FileLocation::AbsoluteUrl(url) => {
+                let url = Url::parse(&url).map_err(|err| Error::Url(url.clone(), err))?;
+                self.fetch_archive(url, dist.name(), hashes, &target)
+                    .await?
+            }
+            FileLocation::Path(path) => {
+                let file = fs_err::tokio::File::open(&path)
+                    .await
+                    .map_err(Error::CacheRead)?;
+                write_archive(file, hashes, &target).await?
+            }
+        };
+
+        // Enforce the hash policy before persisting the archive.
+        if let HashPolicy::Validate(expected) = hashes {
+            if !digests.iter().any(|digest| expected.contains(digest)) {
+                return Err(Error::hash_mismatch(dist.to_string(), expected, &digests));
+            }
+        }
+
+        temp_file
+            .persist(&target)
+            .map_err(|err| Error::ArchiveWrite(target.clone(), err.error))?;
+
+        Ok((target, digests))
+    }
+
+    /// Build a source distribution into a wheel (or fetch the built wheel from the cache), and
+    /// copy the wheel into the given directory.
+    ///
+    /// Returns the path to the wheel, along with its computed hashes. The hash policy is enforced
+    /// against the source distribution, rather than the built wheel.
+    pub async fn build_archive(
+        &self,
+        dist: &SourceDist,
+        tags: &Tags,
+        hashes: HashPolicy<'_>,
+        directory: &Path,
+    ) -> Result<(PathBuf, Vec<HashDigest>), Error> {
+        let lock = self.locks.acquire(&Dist::Source(dist.clone())).await;
+        let _guard = lock.lock().await;
+
+        let built_wheel = self
+            .builder
+            .download_and_build(&BuildableSource::Dist(dist), tags, hashes)
+            .boxed()
+            .await?;
+
+        let target = directory.join(built_wheel.filename.to_string());
+        let file = fs_err::tokio::File::open(&built_wheel.path)
+            .await
+            .map_err(Error::CacheRead)?;
+        let (temp_file, digests) = write_archive(file, HashPolicy::Generate, &target).await?;
+
+        temp_file
+            .persist(&target)
+            .map_err(|err| Error::ArchiveWrite(target.clone(), err.error))?;
+
+        Ok((target, digests))
+    }
+
+    /// Download an archive from a URL to a temporary file alongside the given target.
+    ///
+    /// The archive is stored in the cache alongside its HTTP cache policy, such that it's reused
+    /// while fresh (or, when offline, regardless of its freshness).
+    async fn fetch_archive(
+        &self,
+        url: Url,
+        name: &PackageName,
+        hashes: HashPolicy<'_>,
+        target: &Path,
+    ) -> Result<(NamedTempFile, Vec<HashDigest>), Error> {
+        let filename = target
+            .file_name()
+            .expect("archive target has a filename")
+            .to_string_lossy();
+        let cache_entry = self.build_context.cache().entry(
+            CacheBucket::Downloads,
+            Path::new(name.as_ref()).join(digest(&CanonicalUrl::new(&url))),
+            filename.as_ref(),
+        );
+        let http_entry = cache_entry.with_file(format!("{filename}.http"));
+        let cache_control = match self.client.connectivity() {
+            Connectivity::Online => CacheControl::from(
+                self.build_context
+                    .cache()
+                    .freshness(&http_entry, Some(name))
+                    .map_err(Error::CacheRead)?,
+            ),
+            Connectivity::Offline => CacheControl::AllowStale,
+        };
+
+        let download = |response: reqwest::Response| {
+            async {
+                let reader = response
+                    .bytes_stream()
+                    .map_err(|err| self.handle_response_errors(err))
+                    .into_async_read()
+                    .compat();
+                fs_err::tokio::create_dir_all(cache_entry.dir())
+                    .await
+                    .map_err(Error::CacheWrite)?;
+                let (temp_file, _) =
+                    write_archive(reader, HashPolicy::None, cache_entry.path()).await?;
+                temp_file.persist(cache_entry.path()).map_err(|err| {
+                    Error::ArchiveWrite(cache_entry.path().to_path_buf(), err.error)
+                })?;
+                Ok(())
+            }
+            .instrument(info_span!("download", archive = %filename))
+        };
+
+        let req = self.request(url.clone())?;
+        self.client
+            .cached_client()
+            .get_serde(req, &http_entry, cache_control, download)
+            .await
+            .map_err(|err| match err {
+                CachedClientError::Callback(err) => err,
+                CachedClientError::Client(err) => Error::Client(err),
+            })?;
+
+        // If the archive itself is missing from the cache, download it again.
+        if !cache_entry.path().is_file() {
+            self.client
+                .cached_client()
+                .skip_cache(self.request(url)?, &http_entry, download)
+                .await
+                .map_err(|err| match err {
+                    CachedClientError::Callback(err) => err,
+                    CachedClientError::Client(err) => Error::Client(err),
+                })?;
+        }
+
+        let file = fs_err::tokio::File::open(cache_entry.path())
+            .await
+            .map_err(Error::CacheRead)?;
+        write_archive(file, hashes, target).await
+    }
+
     /// Stream a wheel from a URL, unzipping it into the cache as it's downloaded.
     async fn stream_wheel(
         &self,
@@ -750,6 +953,45 @@ impl<'a, Context: BuildContext + Send + Sync> DistributionDatabase<'a, Context>
     }
 }
 
+/// Write an archive to a temporary file alongside the given target, computing its hashes.
+///
+/// The hashes always include a SHA-256 hash, in addition to any hashes required by the hash policy.
+async fn write_archive(
+    reader: impl tokio::io::AsyncRead + Unpin,
+    hashes: HashPolicy<'_>,
+    target: &Path,
+) -> Result<(NamedTempFile, Vec<HashDigest>), Error> {
+    let mut algorithms = hashes.algorithms();
+    if !algorithms.contains(&HashAlgorithm::Sha256) {
+        algorithms.push(HashAlgorithm::Sha256);
+    }
+    let mut hashers = algorithms.into_iter().map(Hasher::from).collect::<Vec<_>>();
+
+    let directory = target
+        .parent()
+        .expect("archive target has a parent directory");
+    let temp_file = NamedTempFile::new_in(directory)
+        .map_err(|err| Error::ArchiveWrite(target.to_path_buf(), err))?;
+    let file = temp_file
+        .as_file()
+        .try_clone()
+        .map_err(|err| Error::ArchiveWrite(target.to_path_buf(), err))?;
+
+    let mut writer = tokio::io::BufWriter::new(tokio::fs::File::from_std(file));
+    let mut reader = uv_extract::hash::HashReader::new(reader, &mut hashers);
+    tokio::io::copy(&mut reader, &mut writer)
+        .await
+        .map_err(|err| Error::ArchiveWrite(target.to_path_buf(), err))?;
+    writer
+        .flush()
+        .await
+        .map_err(|err| Error::ArchiveWrite(target.to_path_buf(), err))?;
+    drop(reader);
+
+    let digests = hashers.into_iter().map(HashDigest::from).collect();
+    Ok((temp_file, digests))
+}
+
 /// A pointer to an archive in the cache, fetched from an HTTP archive.
 ///
 /// Encoded with `MsgPack`, and represented on disk by a `.http` file.
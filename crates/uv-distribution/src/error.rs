@@ -116,6 +116,12 @@ pub enum Error {
 
     #[error("Hash-checking is not supported for Git repositories: {0}")]
     HashesNotSupportedGit(String),
+
+    // Archive errors
+    #[error("Only wheels and source archives can be downloaded, but found: {0}")]
+    ArchiveUnavailable(String),
+    #[error("Failed to write archive to: {0}")]
+    ArchiveWrite(PathBuf, #[source] std::io::Error),
 }
 
 impl From<reqwest::Error> for Error {
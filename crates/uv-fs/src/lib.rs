@@ -304,6 +304,20 @@ impl LockedFile {
             }
         }
     }
+
+    /// Acquire the lock on the given file if it's free, returning `None` if it's held by another
+    /// process, rather than waiting for it to be released.
+    pub fn try_acquire(path: impl AsRef<Path>) -> Result<Option<Self>, std::io::Error> {
+        let file = fs_err::File::create(path.as_ref())?;
+        match file.file().try_lock_exclusive() {
+            Ok(()) => Ok(Some(Self(file))),
+            Err(err) if err.raw_os_error() == fs2::lock_contended_error().raw_os_error() => {
+                debug!("Lock is held: {}", path.as_ref().user_display());
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
+    }
 }
 
 impl Drop for LockedFile {
@@ -50,10 +50,33 @@ pub enum CompileError {
     Timeout(Duration),
 }
 
+/// The result of bytecode compiling a directory.
+#[derive(Debug, Default)]
+pub struct CompileSummary {
+    /// The number of Python source files that were found.
+    pub files: usize,
+    /// The source files that failed to compile, e.g., due to a syntax error in vendored Python 2
+    /// code.
+    pub failures: Vec<CompileFailure>,
+}
+
+/// A Python source file that failed to compile to bytecode.
+#[derive(Debug)]
+pub struct CompileFailure {
+    /// The absolute path to the source file.
+    pub path: PathBuf,
+    /// The reason reported by Python, e.g., `SyntaxError: invalid syntax`.
+    pub reason: String,
+}
+
 /// Bytecode compile all file in `dir` using a pool of work-stealing Python interpreters running a
 /// Python script that calls `compileall.compile_file`.
 ///
-/// All compilation errors are muted (like pip). There is a 60s timeout for each file to handle
+/// The `optimization_level` is passed to `compileall.compile_file` as `optimize`; as with
+/// `python -O` and `python -OO`, levels `1` and `2` produce `.opt-1.pyc` and `.opt-2.pyc` files.
+///
+/// Like pip, compilation errors don't abort the compilation; instead, the failed files are
+/// collected into the returned [`CompileSummary`]. There is a 60s timeout for each file to handle
 /// a broken `python`.
 ///
 /// We only compile all files, but we don't update the RECORD, relying on PEP 491:
@@ -64,8 +87,9 @@ pub enum CompileError {
 pub async fn compile_tree(
     dir: &Path,
     python_executable: &Path,
+    optimization_level: u8,
     cache: &Path,
-) -> Result<usize, CompileError> {
+) -> Result<CompileSummary, CompileError> {
     debug_assert!(
         dir.is_absolute(),
         "compileall doesn't work with relative paths"
@@ -88,6 +112,7 @@ pub async fn compile_tree(
         worker_handles.push(tokio::task::spawn(worker(
             dir.to_path_buf(),
             python_executable.to_path_buf(),
+            optimization_level,
             pip_compileall_py.clone(),
             receiver.clone(),
         )));
@@ -123,15 +148,17 @@ pub async fn compile_tree(
     drop(sender);
 
     // Make sure all workers exit regularly, avoid hiding errors.
+    let mut failures = Vec::new();
     for result in futures::future::join_all(worker_handles).await {
         match result {
             // There spawning earlier errored due to a panic in a task.
             Err(join_err) => return Err(CompileError::Join(join_err)),
             // The worker reports an error.
             Ok(Err(compile_error)) => return Err(compile_error),
-            Ok(Ok(())) => {}
+            Ok(Ok(worker_failures)) => failures.extend(worker_failures),
         }
     }
+    failures.sort_unstable_by(|a, b| a.path.cmp(&b.path));
 
     if let Some(send_error) = send_error {
         // This is suspicious: Why did the channel stop working, but all workers exited
@@ -139,15 +166,19 @@ pub async fn compile_tree(
         return Err(CompileError::WorkerDisappeared(send_error));
     }
 
-    Ok(source_files)
+    Ok(CompileSummary {
+        files: source_files,
+        failures,
+    })
 }
 
 async fn worker(
     dir: PathBuf,
     interpreter: PathBuf,
+    optimization_level: u8,
     pip_compileall_py: PathBuf,
     receiver: Receiver<PathBuf>,
-) -> Result<(), CompileError> {
+) -> Result<Vec<CompileFailure>, CompileError> {
     fs_err::tokio::write(&pip_compileall_py, COMPILEALL_SCRIPT)
         .await
         .map_err(CompileError::TempFile)?;
@@ -160,7 +191,8 @@ async fn worker(
         loop {
             // If the interpreter started successful, return it, else retry.
             if let Some(child) =
-                launch_bytecode_compiler(&dir, &interpreter, &pip_compileall_py).await?
+                launch_bytecode_compiler(&dir, &interpreter, optimization_level, &pip_compileall_py)
+                    .await?
             {
                 break Ok::<_, CompileError>(child);
             }
@@ -198,13 +230,13 @@ async fn worker(
     } else {
         let stderr = String::from_utf8_lossy(&child_stderr_collected);
         match result {
-            Ok(()) => {
+            Ok(failures) => {
                 debug!(
                     "Bytecode compilation `python` at {} stderr:\n{}\n---",
                     interpreter.user_display(),
                     stderr
                 );
-                Ok(())
+                Ok(failures)
             }
             Err(err) => Err(CompileError::ErrorWithStderr {
                 stderr: stderr.trim().to_string(),
@@ -222,6 +254,7 @@ async fn worker(
 async fn launch_bytecode_compiler(
     dir: &Path,
     interpreter: &Path,
+    optimization_level: u8,
     pip_compileall_py: &Path,
 ) -> Result<
     Option<(
@@ -235,6 +268,7 @@ async fn launch_bytecode_compiler(
     // We input the paths through stdin and get the successful paths returned through stdout.
     let mut bytecode_compiler = Command::new(interpreter)
         .arg(pip_compileall_py)
+        .arg(optimization_level.to_string())
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -293,11 +327,14 @@ async fn launch_bytecode_compiler(
 /// We use stdin/stdout as a sort of bounded channel. We write one path to stdin, then wait until
 /// we get the same path back from stdout. This way we ensure one worker is only working on one
 /// piece of work at the same time.
+///
+/// If a file fails to compile, the path is followed by a tab and the reason for the failure.
 async fn worker_main_loop(
     receiver: Receiver<PathBuf>,
     mut child_stdin: ChildStdin,
     child_stdout: &mut BufReader<ChildStdout>,
-) -> Result<(), CompileError> {
+) -> Result<Vec<CompileFailure>, CompileError> {
+    let mut failures = Vec::new();
     let mut out_line = String::new();
     while let Ok(source_file) = receiver.recv().await {
         let source_file = source_file.display().to_string();
@@ -336,9 +373,17 @@ async fn worker_main_loop(
         // This is a sanity check, if we don't get the path back something has gone wrong, e.g.
         // we're not actually running a python interpreter.
         let actual = out_line.trim_end_matches(['\n', '\r']);
-        if actual != source_file {
+        let Some(remainder) = actual.strip_prefix(source_file.as_str()) else {
+            return Err(CompileError::WrongPath(source_file, actual.to_string()));
+        };
+        if let Some(reason) = remainder.strip_prefix('\t') {
+            failures.push(CompileFailure {
+                path: PathBuf::from(source_file),
+                reason: reason.to_string(),
+            });
+        } else if !remainder.is_empty() {
             return Err(CompileError::WrongPath(source_file, actual.to_string()));
         }
     }
-    Ok(())
+    Ok(failures)
 }
@@ -8,8 +8,8 @@ use tracing::instrument;
 use url::Url;
 
 use distribution_types::{
-    BuildableSource, CachedDist, Dist, Hashed, Identifier, LocalEditable, LocalEditables,
-    RemoteSource,
+    BuildableSource, CachedDist, Dist, FileLocation, HashPolicy, Hashed, Identifier, LocalEditable,
+    LocalEditables, RemoteSource,
 };
 use platform_tags::Tags;
 use uv_cache::Cache;
@@ -32,8 +32,9 @@ pub enum Error {
     Editable(#[from] uv_distribution::Error),
     #[error("Failed to write to the client cache")]
     CacheWrite(#[source] std::io::Error),
-    #[error("Unzip failed in another thread: {0}")]
-    Thread(String),
+    /// A failure shared with any tasks waiting on the same distribution.
+    #[error(transparent)]
+    Shared(Arc<dyn std::error::Error + Send + Sync>),
 }
 
 /// Download, build, and unzip a set of distributions.
@@ -170,7 +171,16 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
     pub async fn get_wheel(&self, dist: Dist, in_flight: &InFlight) -> Result<CachedDist, Error> {
         let id = dist.distribution_id();
         if in_flight.downloads.register(id.clone()) {
-            let policy = self.hashes.get(&dist);
+            let policy = match self.hashes.get(&dist) {
+                // Absent any other hash-checking, validate distributions from a `--find-links`
+                // directory against the hashes in its manifest, if any.
+                policy @ (HashPolicy::None | HashPolicy::Generate) => dist
+                    .file()
+                    .filter(|file| matches!(file.url, FileLocation::Path(_)))
+                    .filter(|file| !file.hashes.is_empty())
+                    .map_or(policy, |file| HashPolicy::Validate(&file.hashes)),
+                policy @ HashPolicy::Validate(_) => policy,
+            };
             let result = self
                 .database
                 .get_or_build_wheel(&dist, self.tags, policy)
@@ -198,8 +208,11 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
                     Ok(cached)
                 }
                 Err(err) => {
-                    in_flight.downloads.done(id, Err(err.to_string()));
-                    Err(err)
+                    // Share the error itself, such that any tasks waiting on the same
+                    // distribution can report why it failed (e.g., a hash mismatch).
+                    let err: Arc<dyn std::error::Error + Send + Sync> = Arc::new(err);
+                    in_flight.downloads.done(id, Err(err.clone()));
+                    Err(Error::Shared(err))
                 }
             }
         } else {
@@ -211,7 +224,7 @@ impl<'a, Context: BuildContext + Send + Sync> Downloader<'a, Context> {
 
             match result.as_ref() {
                 Ok(cached) => Ok(cached.clone()),
-                Err(err) => Err(Error::Thread(err.to_string())),
+                Err(err) => Err(Error::Shared(err.clone())),
             }
         }
     }
@@ -1,15 +1,23 @@
+use std::collections::BTreeMap;
+
 use anyhow::{Context, Error, Result};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use tracing::instrument;
 
-use distribution_types::CachedDist;
+use distribution_types::{git_reference, CachedDist, DirectUrl, Hashed};
+use uv_distribution::to_precise;
 use uv_interpreter::PythonEnvironment;
+use uv_normalize::PackageName;
+
+use crate::Transaction;
 
 pub struct Installer<'a> {
     venv: &'a PythonEnvironment,
     link_mode: install_wheel_rs::linker::LinkMode,
+    link_mode_package: BTreeMap<PackageName, install_wheel_rs::linker::LinkMode>,
     reporter: Option<Box<dyn Reporter>>,
     installer_name: Option<String>,
+    transaction: Option<&'a Transaction>,
 }
 
 impl<'a> Installer<'a> {
@@ -18,8 +26,10 @@ impl<'a> Installer<'a> {
         Self {
             venv,
             link_mode: install_wheel_rs::linker::LinkMode::default(),
+            link_mode_package: BTreeMap::default(),
             reporter: None,
             installer_name: Some("uv".to_string()),
+            transaction: None,
         }
     }
 
@@ -29,6 +39,19 @@ impl<'a> Installer<'a> {
         Self { link_mode, ..self }
     }
 
+    /// Set the [`LinkMode`][`install_wheel_rs::linker::LinkMode`] to use for specific packages,
+    /// overriding the link mode of this installer.
+    #[must_use]
+    pub fn with_link_mode_package(
+        self,
+        link_mode_package: BTreeMap<PackageName, install_wheel_rs::linker::LinkMode>,
+    ) -> Self {
+        Self {
+            link_mode_package,
+            ..self
+        }
+    }
+
     /// Set the [`Reporter`] to use for this installer.
     #[must_use]
     pub fn with_reporter(self, reporter: impl Reporter + 'static) -> Self {
@@ -47,25 +70,43 @@ impl<'a> Installer<'a> {
         }
     }
 
+    /// Install each wheel as part of the given [`Transaction`], such that a failure partway
+    /// through can be rolled back.
+    #[must_use]
+    pub fn with_transaction(self, transaction: &'a Transaction) -> Self {
+        Self {
+            transaction: Some(transaction),
+            ..self
+        }
+    }
+
     /// Install a set of wheels into a Python virtual environment.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub fn install(self, wheels: &[CachedDist]) -> Result<()> {
         let layout = self.venv.interpreter().layout();
         tokio::task::block_in_place(|| {
             wheels.par_iter().try_for_each(|wheel| {
-                install_wheel_rs::linker::install_wheel(
-                    &layout,
-                    wheel.path(),
-                    wheel.filename(),
-                    wheel
-                        .direct_url()?
-                        .as_ref()
-                        .map(pypi_types::DirectUrl::try_from)
-                        .transpose()?
-                        .as_ref(),
-                    self.installer_name.as_deref(),
-                    self.link_mode,
-                )
+                let link_mode = self
+                    .link_mode_package
+                    .get(&wheel.filename().name)
+                    .copied()
+                    .unwrap_or(self.link_mode);
+                let install = |layout: &install_wheel_rs::Layout| -> Result<()> {
+                    install_wheel_rs::linker::install_wheel(
+                        layout,
+                        wheel.path(),
+                        wheel.filename(),
+                        direct_url(wheel)?.as_ref(),
+                        self.installer_name.as_deref(),
+                        link_mode,
+                    )?;
+                    Ok(())
+                };
+                if let Some(transaction) = self.transaction {
+                    transaction.install(&layout, install)
+                } else {
+                    install(&layout)
+                }
                 .with_context(|| format!("Failed to install: {} ({wheel})", wheel.filename()))?;
 
                 if let Some(reporter) = self.reporter.as_ref() {
@@ -78,6 +119,29 @@ impl<'a> Installer<'a> {
     }
 }
 
+/// Return the PEP 610 `direct_url.json` to record for the given wheel, if any.
+///
+/// Git dependencies are recorded with the commit to which they were resolved (even if the
+/// requested URL referenced a branch or tag), and archives are recorded alongside their hashes.
+fn direct_url(wheel: &CachedDist) -> Result<Option<pypi_types::DirectUrl>> {
+    let Some(mut direct_url) = wheel.direct_url()? else {
+        return Ok(None);
+    };
+    if let (DirectUrl::Git(git), CachedDist::Url(dist)) = (&mut direct_url, wheel) {
+        if git.url.precise().is_none() {
+            if let Some(precise) = to_precise(dist.url.raw())
+                .and_then(|url| git_reference(&url).ok())
+                .flatten()
+            {
+                git.url = git.url.clone().with_precise(precise);
+            }
+        }
+    }
+    Ok(Some(
+        pypi_types::DirectUrl::try_from(&direct_url)?.with_hashes(wheel.hashes()),
+    ))
+}
+
 pub trait Reporter: Send + Sync {
     /// Callback to invoke when a dependency is resolved.
     fn on_install_progress(&self, wheel: &CachedDist);
@@ -1,10 +1,11 @@
-pub use compile::{compile_tree, CompileError};
+pub use compile::{compile_tree, CompileError, CompileFailure, CompileSummary};
 pub use downloader::{Downloader, Reporter as DownloadReporter};
 pub use editable::{is_dynamic, BuiltEditable, ResolvedEditable};
 pub use installer::{Installer, Reporter as InstallReporter};
-pub use plan::{Plan, Planner};
+pub use plan::{is_seed_package, Plan, Planner};
 pub use site_packages::{Diagnostic, SitePackages};
-pub use uninstall::{uninstall, UninstallError};
+pub use transaction::Transaction;
+pub use uninstall::{uninstall, uninstall_all, UninstallError};
 
 mod compile;
 mod downloader;
@@ -12,4 +13,5 @@ mod editable;
 mod installer;
 mod plan;
 mod site_packages;
+mod transaction;
 mod uninstall;
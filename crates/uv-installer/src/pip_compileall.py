@@ -3,12 +3,15 @@ Based on
 https://github.com/pypa/pip/blob/3820b0e52c7fed2b2c43ba731b718f316e6816d1/src/pip/_internal/operations/install/wheel.py#L612-L623
 
 pip silently just swallows all pyc compilation errors, but `python -m compileall` does
-not have such a flag, so we adapt the pip code. This is relevant, e.g., for
+not have such a flag, so we adapt the pip code. Instead of swallowing the errors entirely, we
+report the reason for each failed file back to Rust, which summarizes them. This is relevant, e.g., for
 `debugpy-1.5.1-cp38-cp38-manylinux_2_5_x86_64.manylinux1_x86_64.manylinux_2_12_x86_64.manylinux2010_x86_64`,
 which contains some vendored Python 2 code which fails to compile.
 """
 
 import compileall
+import contextlib
+import io
 import os
 import py_compile
 import sys
@@ -42,6 +45,9 @@ with warnings.catch_warnings():
         except AttributeError:
             invalidation_mode = None  # guard against implementation details
 
+    # The optimization level (as with `python -O` or `python -OO`) is passed as the first argument.
+    optimize = int(sys.argv[1]) if len(sys.argv) > 1 else -1
+
     # Unlike pip, we will usually set force=False. It's unclear why pip sets force=True, but it
     # doesn't matter much for them, as pip only compiles newly installed files.
     force = False
@@ -57,11 +63,24 @@ with warnings.catch_warnings():
         path = path.strip()
         if not path:
             continue
-        # Unlike pip, we set quiet=2, so we don't have to capture stdout.
-        # We'd like to show those errors, but given that pip thinks that's totally fine,
-        # we can't really change that.
-        success = compileall.compile_file(
-            path, invalidation_mode=invalidation_mode, force=force, quiet=2
-        )
-        # We're ready for the next file.
-        print(path)
+        # Like pip, we don't fail on compilation errors, but we capture the error message (which
+        # `compileall` writes to stdout) so that Rust can report a summary of the failed files.
+        output = io.StringIO()
+        with contextlib.redirect_stdout(output):
+            success = compileall.compile_file(
+                path,
+                invalidation_mode=invalidation_mode,
+                force=force,
+                quiet=1,
+                optimize=optimize,
+            )
+        if success:
+            # We're ready for the next file.
+            print(path)
+        else:
+            # The last line of the error message contains the exception, e.g.,
+            # `SyntaxError: invalid syntax`.
+            lines = [line for line in output.getvalue().splitlines() if line.strip()]
+            reason = lines[-1].strip() if lines else "unknown error"
+            # We're ready for the next file, separating the reason with a tab.
+            print(f"{path}\t{reason}")
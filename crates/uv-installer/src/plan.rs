@@ -19,6 +19,7 @@ use uv_distribution::{
 };
 use uv_fs::Simplified;
 use uv_interpreter::PythonEnvironment;
+use uv_normalize::PackageName;
 use uv_types::HashStrategy;
 
 use crate::{ResolvedEditable, SitePackages};
@@ -361,12 +362,7 @@ impl<'a> Planner<'a> {
             // whether they're considered "seed" packages.
             let seed_packages = !venv.cfg().is_ok_and(|cfg| cfg.is_uv());
             for dist_info in site_packages {
-                if seed_packages
-                    && matches!(
-                        dist_info.name().as_ref(),
-                        "pip" | "setuptools" | "wheel" | "uv"
-                    )
-                {
+                if seed_packages && is_seed_package(dist_info.name()) {
                     debug!("Preserving seed package: {dist_info}");
                     continue;
                 }
@@ -386,6 +382,18 @@ impl<'a> Planner<'a> {
     }
 }
 
+/// Returns `true` if the package is a "seed" package that tools like `virtualenv` install into new
+/// environments (e.g., `pip`), or uv itself.
+///
+/// Seed packages are preserved when removing unnecessary packages, unless listed in the input, and
+/// omitted from `pip freeze` output, unless `--all` is provided.
+pub fn is_seed_package(name: &PackageName) -> bool {
+    matches!(
+        name.as_ref(),
+        "pip" | "setuptools" | "wheel" | "distribute" | "uv"
+    )
+}
+
 #[derive(Debug, PartialEq, Eq)]
 enum Specifier<'a> {
     /// An editable requirement, marked by the installed version of the package.
@@ -1,5 +1,5 @@
 use std::iter::Flatten;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{collections::BTreeSet, hash::BuildHasherDefault};
 
 use anyhow::{Context, Result};
@@ -10,10 +10,10 @@ use url::Url;
 use distribution_types::{InstalledDist, InstalledMetadata, InstalledVersion, Name};
 use pep440_rs::{Version, VersionSpecifiers};
 use pep508_rs::{Requirement, RequirementsTxtRequirement, VerbatimUrl};
-use requirements_txt::{EditableRequirement, RequirementEntry};
+use requirements_txt::{EditableRequirement, RequirementComments, RequirementEntry};
 use uv_cache::{ArchiveTarget, ArchiveTimestamp};
 use uv_interpreter::PythonEnvironment;
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
 use uv_types::InstalledPackagesProvider;
 
 use crate::is_dynamic;
@@ -39,11 +39,23 @@ pub struct SitePackages<'a> {
 impl<'a> SitePackages<'a> {
     /// Build an index of installed packages from the given Python executable.
     pub fn from_executable(venv: &'a PythonEnvironment) -> Result<SitePackages<'a>> {
+        Self::from_paths(venv, venv.site_packages())
+    }
+
+    /// Build an index of installed packages from the given `site-packages` directories, rather
+    /// than those of the Python executable (e.g., a `--target` directory).
+    ///
+    /// The Python executable is used to evaluate the markers of the installed packages.
+    pub fn from_paths(
+        venv: &'a PythonEnvironment,
+        paths: impl IntoIterator<Item = impl AsRef<Path>>,
+    ) -> Result<SitePackages<'a>> {
         let mut distributions: Vec<Option<InstalledDist>> = Vec::new();
         let mut by_name = FxHashMap::default();
         let mut by_url = FxHashMap::default();
 
-        for site_packages in venv.site_packages() {
+        for site_packages in paths {
+            let site_packages = site_packages.as_ref();
             // Read the site-packages directory.
             let site_packages = match fs::read_dir(site_packages) {
                 Ok(site_packages) => {
@@ -203,6 +215,10 @@ impl<'a> SitePackages<'a> {
     pub fn diagnostics(&self) -> Result<Vec<Diagnostic>> {
         let mut diagnostics = Vec::new();
 
+        // Determine the extras requested of each package, such that any dependencies that are
+        // gated behind an extra are verified too.
+        let extras = self.requested_extras();
+
         for (package, indexes) in &self.by_name {
             let mut distributions = indexes.iter().flat_map(|index| &self.distributions[*index]);
 
@@ -249,8 +265,9 @@ impl<'a> SitePackages<'a> {
                 }
 
                 // Verify that the dependencies are installed.
+                let requested = extras.get(package).map_or(&[][..], Vec::as_slice);
                 for dependency in &metadata.requires_dist {
-                    if !dependency.evaluate_markers(self.venv.interpreter().markers(), &[]) {
+                    if !dependency.evaluate_markers(self.venv.interpreter().markers(), requested) {
                         continue;
                     }
 
@@ -293,6 +310,48 @@ impl<'a> SitePackages<'a> {
         Ok(diagnostics)
     }
 
+    /// Determine the extras that are requested of each installed package by the dependencies of
+    /// the other installed packages.
+    ///
+    /// Extras are resolved transitively: if `a` requires `b[x]`, and `b[x]` requires `c[y]`, then
+    /// both `x` (for `b`) and `y` (for `c`) are requested. Dependencies are only considered if
+    /// their markers apply to the current environment.
+    fn requested_extras(&self) -> FxHashMap<PackageName, Vec<ExtraName>> {
+        let markers = self.venv.interpreter().markers();
+
+        // Read the dependencies of each installed package, skipping any with missing metadata
+        // (which are reported separately).
+        let requires_dist = self
+            .iter()
+            .filter_map(|dist| Some((dist.name().clone(), dist.metadata().ok()?.requires_dist)))
+            .collect::<FxHashMap<_, _>>();
+
+        let mut extras: FxHashMap<PackageName, Vec<ExtraName>> = FxHashMap::default();
+        let mut queue = requires_dist.keys().collect::<Vec<_>>();
+        while let Some(package) = queue.pop() {
+            let requested = extras.get(package).cloned().unwrap_or_default();
+            for dependency in &requires_dist[package] {
+                if !dependency.evaluate_markers(markers, &requested) {
+                    continue;
+                }
+                for extra in &dependency.extras {
+                    let entry = extras.entry(dependency.name.clone()).or_default();
+                    if entry.contains(extra) {
+                        continue;
+                    }
+                    entry.push(extra.clone());
+
+                    // Revisit the dependency, as the new extra may enable further dependencies.
+                    if let Some((name, _)) = requires_dist.get_key_value(&dependency.name) {
+                        queue.push(name);
+                    }
+                }
+            }
+        }
+
+        extras
+    }
+
     /// Returns `true` if the installed packages satisfy the given requirements.
     pub fn satisfies(
         &self,
@@ -352,6 +411,8 @@ impl<'a> SitePackages<'a> {
                             let dependency = RequirementEntry {
                                 requirement: RequirementsTxtRequirement::Pep508(dependency),
                                 hashes: vec![],
+                                comments: RequirementComments::default(),
+                                prerelease: false,
                             };
                             if seen.insert(dependency.clone()) {
                                 stack.push(dependency);
@@ -474,6 +535,8 @@ impl<'a> SitePackages<'a> {
                             let dependency = RequirementEntry {
                                 requirement: RequirementsTxtRequirement::Pep508(dependency),
                                 hashes: vec![],
+                                comments: RequirementComments::default(),
+                                prerelease: false,
                             };
                             if seen.insert(dependency.clone()) {
                                 stack.push(dependency);
@@ -0,0 +1,352 @@
+use std::collections::BTreeSet;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use fs_err as fs;
+use tempfile::TempDir;
+use tracing::debug;
+use walkdir::WalkDir;
+
+use distribution_types::InstalledDist;
+use install_wheel_rs::Layout;
+use pypi_types::Scheme;
+use uv_fs::Simplified;
+use uv_interpreter::PythonEnvironment;
+
+/// A set of changes to a Python environment that are applied as a unit.
+///
+/// Rather than deleting the files of a distribution that's being removed, the transaction parks
+/// them in a staging directory. Rather than installing a wheel in place, the transaction installs
+/// it into the staging directory and then promotes its files into the environment with renames.
+///
+/// If any step fails, [`Transaction::rollback`] removes the promoted files and restores the parked
+/// distributions. Otherwise, [`Transaction::commit`] discards the parked files.
+pub struct Transaction {
+    /// The common ancestor of the environment's installation directories.
+    root: PathBuf,
+    /// The `site-packages` directory of the environment.
+    site_packages: PathBuf,
+    /// The staging directory, which lives in `site-packages` such that files can be moved in and
+    /// out of the environment with renames.
+    staging: TempDir,
+    /// A counter used to allocate unique paths within the staging directory.
+    counter: AtomicUsize,
+    /// The changes that have been applied to the environment so far.
+    journal: Mutex<Journal>,
+}
+
+#[derive(Debug, Default)]
+struct Journal {
+    /// The distributions that were removed from the environment.
+    removed: Vec<InstalledDist>,
+    /// The files that were moved out of the environment, as `(original, parked)` pairs.
+    parked: Vec<(PathBuf, PathBuf)>,
+    /// The files that were moved into the environment.
+    promoted: Vec<PathBuf>,
+    /// The directories that were created in the environment.
+    created: Vec<PathBuf>,
+}
+
+impl Transaction {
+    /// Start a new transaction against the given environment.
+    pub fn new(venv: &PythonEnvironment) -> Result<Self> {
+        let interpreter = venv.interpreter();
+        let layout = interpreter.layout();
+        let root = common_ancestor(&[
+            &layout.scheme.purelib,
+            &layout.scheme.platlib,
+            &layout.scheme.scripts,
+            &layout.scheme.data,
+            &layout.scheme.include,
+        ]);
+        let site_packages = interpreter.purelib().to_path_buf();
+        fs::create_dir_all(&site_packages)?;
+        let staging = tempfile::Builder::new()
+            .prefix(".uv-transaction-")
+            .tempdir_in(&site_packages)
+            .context("Failed to create staging directory")?;
+        debug!(
+            "Staging changes to the environment in: {}",
+            staging.path().user_display()
+        );
+        Ok(Self {
+            root,
+            site_packages,
+            staging,
+            counter: AtomicUsize::new(0),
+            journal: Mutex::new(Journal::default()),
+        })
+    }
+
+    /// Remove a distribution from the environment, parking its files such that it can be restored
+    /// if the transaction is rolled back.
+    ///
+    /// Returns the number of files that were parked.
+    pub fn park(&self, dist: &InstalledDist) -> Result<usize, install_wheel_rs::Error> {
+        let paths = install_wheel_rs::installed_paths(dist.path())?;
+
+        let mut journal = self.journal.lock().unwrap();
+        let mut file_count = 0;
+        for path in paths {
+            match fs::symlink_metadata(&path) {
+                Ok(_) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err.into()),
+            }
+            let parked = self.allocate();
+            move_path(&path, &parked)?;
+            debug!("Parked file: {}", path.display());
+            journal.parked.push((path, parked));
+            file_count += 1;
+        }
+
+        // Park the `.dist-info` directory itself, in case the `RECORD` doesn't list its contents
+        // (e.g., if it was written by an older installer under a different name).
+        if fs::symlink_metadata(dist.path()).is_ok() {
+            let parked = self.allocate();
+            move_path(dist.path(), &parked)?;
+            debug!("Parked directory: {}", dist.path().display());
+            journal.parked.push((dist.path().to_path_buf(), parked));
+        }
+
+        journal.removed.push(dist.clone());
+
+        Ok(file_count)
+    }
+
+    /// Install a wheel into the environment by way of the staging directory.
+    ///
+    /// The wheel is installed (via `install`) into a copy of the environment's [`Layout`] that's
+    /// rooted in the staging directory, and its files are then promoted into the environment.
+    pub fn install(
+        &self,
+        layout: &Layout,
+        install: impl FnOnce(&Layout) -> Result<()>,
+    ) -> Result<()> {
+        let stage = self.allocate();
+        let staged = Layout {
+            scheme: Scheme {
+                purelib: self.rebase(&layout.scheme.purelib, &stage),
+                platlib: self.rebase(&layout.scheme.platlib, &stage),
+                scripts: self.rebase(&layout.scheme.scripts, &stage),
+                data: self.rebase(&layout.scheme.data, &stage),
+                include: self.rebase(&layout.scheme.include, &stage),
+            },
+            ..layout.clone()
+        };
+        install(&staged)?;
+        self.promote(&stage)
+    }
+
+    /// Abandon the transaction, removing any files that were added to the environment and
+    /// restoring any distributions that were removed.
+    ///
+    /// The rollback is best-effort: if a file can't be removed or restored, the remaining files are
+    /// still processed, and the first error is returned.
+    ///
+    /// Returns the distributions that were restored.
+    pub fn rollback(self) -> Result<Vec<InstalledDist>> {
+        let journal = self.journal.into_inner().unwrap();
+        let mut first_error = None;
+
+        for path in journal.promoted.iter().rev() {
+            match fs::remove_file(path) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => {
+                    debug!("Failed to remove promoted file: {err}");
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+        for path in journal.created.iter().rev() {
+            // Ignore failures, as the directory may contain files that we didn't create.
+            let _ = fs::remove_dir(path);
+        }
+        for (original, parked) in journal.parked.iter().rev() {
+            let restored = original
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|()| move_path(parked, original));
+            match restored {
+                Ok(()) => debug!("Restored file: {}", original.display()),
+                Err(err) => {
+                    debug!("Failed to restore parked file: {err}");
+                    first_error.get_or_insert(err);
+                }
+            }
+        }
+
+        // If any file couldn't be restored, retain the staging directory, such that the parked
+        // files can be recovered by hand.
+        if let Some(err) = first_error {
+            let staging = self.staging.into_path();
+            return Err(err).with_context(|| {
+                format!(
+                    "Failed to roll back the transaction; any files that couldn't be restored remain in: {}",
+                    staging.user_display()
+                )
+            });
+        }
+
+        Ok(journal.removed)
+    }
+
+    /// Complete the transaction, discarding the parked files of any removed distributions.
+    pub fn commit(self) -> Result<()> {
+        let journal = self.journal.into_inner().unwrap();
+        self.staging
+            .close()
+            .context("Failed to remove staging directory")?;
+
+        // Remove any directories that were left empty by the removed distributions.
+        let visited = journal
+            .parked
+            .iter()
+            .filter_map(|(original, _)| original.parent().map(Path::to_path_buf))
+            .collect::<BTreeSet<_>>();
+        install_wheel_rs::remove_empty_directories(&visited, &self.site_packages)?;
+
+        Ok(())
+    }
+
+    /// Move the files in the given staging directory into the environment.
+    fn promote(&self, stage: &Path) -> Result<()> {
+        for entry in WalkDir::new(stage).sort_by_file_name() {
+            let entry = entry?;
+            if entry.file_type().is_dir() {
+                continue;
+            }
+            let relative = entry
+                .path()
+                .strip_prefix(stage)
+                .expect("walkdir starts with root");
+            let target = self.root.join(relative);
+
+            let mut journal = self.journal.lock().unwrap();
+
+            // Create any missing parent directories, tracking them for rollback.
+            if let Some(parent) = target.parent() {
+                let missing = parent
+                    .ancestors()
+                    .take_while(|ancestor| fs::symlink_metadata(ancestor).is_err())
+                    .map(Path::to_path_buf)
+                    .collect::<Vec<_>>();
+                for directory in missing.into_iter().rev() {
+                    fs::create_dir(&directory)?;
+                    journal.created.push(directory);
+                }
+            }
+
+            // If a file already exists at the target, park it.
+            if fs::symlink_metadata(&target).is_ok_and(|metadata| !metadata.is_dir()) {
+                let parked = self.allocate();
+                move_path(&target, &parked)?;
+                journal.parked.push((target.clone(), parked));
+            }
+
+            move_path(entry.path(), &target)?;
+            journal.promoted.push(target);
+        }
+        Ok(())
+    }
+
+    /// Map a path in the environment to the corresponding path in the given staging directory.
+    fn rebase(&self, path: &Path, stage: &Path) -> PathBuf {
+        stage.join(
+            path.strip_prefix(&self.root)
+                .expect("installation directories are within the root"),
+        )
+    }
+
+    /// Allocate a unique path within the staging directory.
+    fn allocate(&self) -> PathBuf {
+        let index = self.counter.fetch_add(1, Ordering::Relaxed);
+        self.staging.path().join(index.to_string())
+    }
+}
+
+/// Move a file or directory, falling back to copying it and removing the original if the source
+/// and destination are on different filesystems (e.g., if the environment's `bin` directory is on
+/// a different mount than its `site-packages`).
+fn move_path(from: &Path, to: &Path) -> std::io::Result<()> {
+    match std::fs::rename(from, to) {
+        Ok(()) => Ok(()),
+        Err(err) if is_cross_device(&err) => {
+            debug!(
+                "Copying across filesystems: {} to {}",
+                from.display(),
+                to.display()
+            );
+            copy_path(from, to)?;
+            if fs::symlink_metadata(from)?.is_dir() {
+                fs::remove_dir_all(from)
+            } else {
+                fs::remove_file(from)
+            }
+        }
+        Err(err) => Err(std::io::Error::new(
+            err.kind(),
+            format!(
+                "failed to rename `{}` to `{}`: {err}",
+                from.user_display(),
+                to.user_display()
+            ),
+        )),
+    }
+}
+
+/// Returns `true` if the error indicates that a rename crossed a filesystem boundary.
+fn is_cross_device(err: &std::io::Error) -> bool {
+    // `EXDEV` on Unix, and `ERROR_NOT_SAME_DEVICE` on Windows.
+    if cfg!(windows) {
+        err.raw_os_error() == Some(17)
+    } else {
+        err.raw_os_error() == Some(18)
+    }
+}
+
+/// Copy a file, symlink, or directory tree, preserving symlinks.
+fn copy_path(from: &Path, to: &Path) -> std::io::Result<()> {
+    for entry in WalkDir::new(from) {
+        let entry = entry?;
+        let target = to.join(
+            entry
+                .path()
+                .strip_prefix(from)
+                .expect("walkdir starts with root"),
+        );
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else if entry.file_type().is_symlink() {
+            let link = fs::read_link(entry.path())?;
+            #[cfg(unix)]
+            fs_err::os::unix::fs::symlink(link, &target)?;
+            #[cfg(windows)]
+            if entry.path().is_dir() {
+                fs_err::os::windows::fs::symlink_dir(link, &target)?;
+            } else {
+                fs_err::os::windows::fs::symlink_file(link, &target)?;
+            }
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Return the deepest directory that contains all of the given paths.
+fn common_ancestor(paths: &[&Path]) -> PathBuf {
+    let mut ancestor = paths[0].components().collect::<Vec<Component>>();
+    for path in &paths[1..] {
+        let shared = ancestor
+            .iter()
+            .zip(path.components())
+            .take_while(|(a, b)| **a == *b)
+            .count();
+        ancestor.truncate(shared);
+    }
+    ancestor.into_iter().collect()
+}
@@ -1,20 +1,48 @@
+use std::path::PathBuf;
+
 use anyhow::Result;
 
 use distribution_types::InstalledDist;
 
 /// Uninstall a package from the specified Python environment.
+///
+/// Unless `force` is set, files that were modified after installation are preserved.
 pub async fn uninstall(
     dist: &InstalledDist,
+    force: bool,
 ) -> Result<install_wheel_rs::Uninstall, UninstallError> {
     let uninstall = tokio::task::spawn_blocking({
         let path = dist.path().to_owned();
-        move || install_wheel_rs::uninstall_wheel(&path)
+        move || install_wheel_rs::uninstall_wheel(&path, force)
     })
     .await??;
 
     Ok(uninstall)
 }
 
+/// Uninstall a set of packages from the specified Python environment, returning a summary for
+/// each, in order.
+///
+/// Unless `force` is set, files that were modified after installation are preserved.
+pub async fn uninstall_all(
+    dists: &[&InstalledDist],
+    force: bool,
+) -> Result<Vec<install_wheel_rs::Uninstall>, UninstallError> {
+    let uninstalls = tokio::task::spawn_blocking({
+        let paths = dists
+            .iter()
+            .map(|dist| dist.path().to_owned())
+            .collect::<Vec<_>>();
+        move || {
+            let paths = paths.iter().map(PathBuf::as_path).collect::<Vec<_>>();
+            install_wheel_rs::uninstall_wheels(&paths, force)
+        }
+    })
+    .await??;
+
+    Ok(uninstalls)
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum UninstallError {
     #[error(transparent)]
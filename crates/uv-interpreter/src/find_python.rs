@@ -1,11 +1,16 @@
 use std::borrow::Cow;
 use std::env;
 use std::ffi::{OsStr, OsString};
-use std::path::PathBuf;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
 
 use tracing::{debug, instrument};
 
 use uv_cache::Cache;
+use uv_configuration::{PythonImplementation, PythonPreference};
+use uv_fs::Simplified;
 use uv_toolchain::PythonVersion;
 
 use crate::interpreter::InterpreterInfoError;
@@ -15,8 +20,9 @@ use crate::{Error, Interpreter};
 /// Find a Python of a specific version, a binary with a name or a path to a binary.
 ///
 /// Supported formats:
-/// * `-p 3.10` searches for an installed Python 3.10 (`py --list-paths` on Windows, `python3.10` on
-///   Linux/Mac). Specifying a patch version is not supported.
+/// * `-p 3`, `-p 3.10`, or `-p 3.10.4` searches for an installed Python matching the version
+///   (`py --list-paths` on Windows, `python3.10` on Linux/Mac), as well as managed toolchains if
+///   permitted by the [`PythonPreference`].
 /// * `-p python3.10` or `-p python.exe` looks for a binary in `PATH`.
 /// * `-p /home/ferris/.local/bin/python3.10` uses this exact Python.
 ///
@@ -24,25 +30,25 @@ use crate::{Error, Interpreter};
 /// version (e.g. `python3.12` on unix) and error when the version mismatches, as a binary with the
 /// patch version (e.g. `python3.12.1`) is often not in `PATH` and we make the simplifying
 /// assumption that the user has only this one patch version installed.
+///
+/// The [`PythonPreference`] determines whether version requests are satisfied by managed
+/// toolchains, by installations found on the system, or both. Requests for a path or an
+/// executable name are unaffected.
+///
+/// If a [`PythonImplementation`] is provided, version requests only consider interpreters of that
+/// implementation (e.g., `pypy3.10` rather than `python3.10`), and an interpreter requested by
+/// path or executable name must be of that implementation.
 #[instrument(skip_all, fields(%request))]
-pub fn find_requested_python(request: &str, cache: &Cache) -> Result<Option<Interpreter>, Error> {
+pub fn find_requested_python(
+    request: &str,
+    implementation: Option<PythonImplementation>,
+    preference: PythonPreference,
+    cache: &Cache,
+) -> Result<Option<Interpreter>, Error> {
     debug!("Starting interpreter discovery for Python @ `{request}`");
-    let versions = request
-        .splitn(3, '.')
-        .map(str::parse::<u8>)
-        .collect::<Result<Vec<_>, _>>();
-    if let Ok(versions) = versions {
-        // `-p 3.10` or `-p 3.10.1`
-        let selector = match versions.as_slice() {
-            [requested_major] => PythonVersionSelector::Major(*requested_major),
-            [major, minor] => PythonVersionSelector::MajorMinor(*major, *minor),
-            [major, minor, requested_patch] => {
-                PythonVersionSelector::MajorMinorPatch(*major, *minor, *requested_patch)
-            }
-            // SAFETY: Guaranteed by the Ok(versions) guard
-            _ => unreachable!(),
-        };
-        find_python(selector, cache)
+    if let Some(selector) = parse_version_request(request) {
+        // `-p 3`, `-p 3.10`, or `-p 3.10.1`
+        find_python(selector, implementation, preference, cache)
     } else {
         match fs_err::metadata(request) {
             Ok(metadata) => {
@@ -61,28 +67,312 @@ pub fn find_requested_python(request: &str, cache: &Cache) -> Result<Option<Inte
                     // `-p /home/ferris/.local/bin/python3.10`
                     path
                 };
-                Interpreter::query(executable, cache).map(Some)
+                let interpreter = Interpreter::query(executable, cache)?;
+                check_implementation(&interpreter, implementation)?;
+                Ok(Some(interpreter))
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
                 // `-p python3.10`; Generally not used on windows because all Python are `python.exe`.
                 let Some(executable) = find_executable(request)? else {
                     return Ok(None);
                 };
-                Interpreter::query(executable, cache).map(Some)
+                let interpreter = Interpreter::query(executable, cache)?;
+                check_implementation(&interpreter, implementation)?;
+                Ok(Some(interpreter))
             }
             Err(err) => return Err(err.into()),
         }
     }
 }
 
+/// Find a Python interpreter for a user-provided request, as in [`find_requested_python`], but
+/// return an error if no interpreter matches the request.
+///
+/// If a version (e.g., `3.11`) was requested, the error lists the interpreters that were
+/// discovered instead, to help the user pick one that's available.
+pub fn find_required_python(
+    request: &str,
+    implementation: Option<PythonImplementation>,
+    preference: PythonPreference,
+    cache: &Cache,
+) -> Result<Interpreter, Error> {
+    if let Some(interpreter) = find_requested_python(request, implementation, preference, cache)? {
+        return Ok(interpreter);
+    }
+
+    if parse_version_request(request).is_none() {
+        return Err(Error::RequestedPythonNotFound(request.to_string()));
+    }
+
+    let discovered = discover_pythons(preference, cache);
+    let discovered = if discovered.is_empty() {
+        "No Python interpreters were discovered. Is Python installed?".to_string()
+    } else {
+        let mut message = "Discovered interpreters:".to_string();
+        for DiscoveredPython { interpreter, .. } in discovered {
+            message.push_str(&format!(
+                "\n  - Python {} at `{}`",
+                interpreter.python_version(),
+                interpreter.sys_executable().user_display()
+            ));
+        }
+        message
+    };
+    Err(Error::NoMatchingPython {
+        request: request.to_string(),
+        discovered,
+    })
+}
+
+/// Return an error if the interpreter isn't of the requested [`PythonImplementation`], if any.
+fn check_implementation(
+    interpreter: &Interpreter,
+    implementation: Option<PythonImplementation>,
+) -> Result<(), Error> {
+    match implementation {
+        Some(implementation) if !interpreter.is_implementation(implementation) => {
+            Err(Error::ImplementationMismatch {
+                interpreter: interpreter.sys_executable().to_path_buf(),
+                found: interpreter.implementation_name().to_string(),
+                requested: implementation,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns `true` if the request is resolved by interpreter discovery, rather than naming a path on
+/// disk: a Python version (e.g., `3.12`) or an executable to look up in the `PATH` (e.g.,
+/// `python3.12` or `pypy3`).
+pub fn is_discovery_request(request: &str) -> Result<bool, Error> {
+    if parse_version_request(request).is_some() {
+        return Ok(true);
+    }
+    if Path::new(request).components().count() > 1 {
+        return Ok(false);
+    }
+    Ok(is_python_executable_name(request) || find_executable(request)?.is_some())
+}
+
+/// Parse a request for a Python version, like `3`, `3.10`, or `3.10.1`.
+///
+/// Returns `None` if the request isn't a version (e.g., a path or an executable name).
+fn parse_version_request(request: &str) -> Option<PythonVersionSelector> {
+    let versions = request
+        .splitn(3, '.')
+        .map(str::parse::<u8>)
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    match versions.as_slice() {
+        [major] => Some(PythonVersionSelector::Major(*major)),
+        [major, minor] => Some(PythonVersionSelector::MajorMinor(*major, *minor)),
+        [major, minor, patch] => Some(PythonVersionSelector::MajorMinorPatch(
+            *major, *minor, *patch,
+        )),
+        _ => None,
+    }
+}
+
+/// Where a discovered Python interpreter was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum InterpreterSource {
+    /// The interpreter belongs to a virtual environment.
+    #[serde(rename = "venv")]
+    VirtualEnv,
+    /// The interpreter was found on the system, e.g., in `PATH`.
+    System,
+    /// The interpreter is part of a toolchain managed by `uv`.
+    Managed,
+}
+
+impl Display for InterpreterSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::VirtualEnv => write!(f, "venv"),
+            Self::System => write!(f, "system"),
+            Self::Managed => write!(f, "managed"),
+        }
+    }
+}
+
+/// A Python interpreter found during discovery, alongside its [`InterpreterSource`].
+#[derive(Debug, Clone)]
+pub struct DiscoveredPython {
+    pub source: InterpreterSource,
+    pub interpreter: Interpreter,
+}
+
+/// Find every Python interpreter that `uv` could use, in the order in which they'd be considered.
+///
+/// The active virtual environment (if any) comes first, followed by the interpreters permitted by
+/// the [`PythonPreference`].
+pub fn find_all_pythons(
+    preference: PythonPreference,
+    cache: &Cache,
+) -> Result<Vec<DiscoveredPython>, Error> {
+    let mut pythons = Vec::new();
+    if let Some(venv) = detect_virtual_env()? {
+        let executable = detect_python_executable(venv);
+        match Interpreter::query(&executable, cache) {
+            Ok(interpreter) => pythons.push(DiscoveredPython {
+                source: InterpreterSource::VirtualEnv,
+                interpreter,
+            }),
+            Err(err) => debug!("Skipping `{}`: {err}", executable.user_display()),
+        }
+    }
+    for python in discover_pythons(preference, cache) {
+        if pythons.iter().all(|existing| {
+            existing.interpreter.prefix() != python.interpreter.prefix()
+                || existing.interpreter.python_version() != python.interpreter.python_version()
+        }) {
+            pythons.push(python);
+        }
+    }
+    Ok(pythons)
+}
+
+/// Discover every Python interpreter permitted by the [`PythonPreference`].
+///
+/// Interpreters that can't be queried are skipped, and interpreters that are reachable under
+/// multiple names (e.g., `python3` and `python3.12`) are only reported once.
+fn discover_pythons(preference: PythonPreference, cache: &Cache) -> Vec<DiscoveredPython> {
+    let managed = if preference.allows_managed() {
+        match uv_toolchain::installed_toolchains() {
+            Ok(toolchains) => toolchains
+                .iter()
+                .map(|toolchain| (InterpreterSource::Managed, toolchain.executable()))
+                .collect(),
+            Err(err) => {
+                debug!("Failed to read managed toolchains: {err}");
+                Vec::new()
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    let system = if preference.allows_system() {
+        discover_system_executables()
+            .into_iter()
+            .map(|executable| (InterpreterSource::System, executable))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let executables = if preference.prefers_managed() {
+        managed.into_iter().chain(system).collect::<Vec<_>>()
+    } else {
+        system.into_iter().chain(managed).collect::<Vec<_>>()
+    };
+
+    let mut seen = Vec::new();
+    let mut pythons = Vec::new();
+    for (source, executable) in executables {
+        let interpreter = match Interpreter::query(&executable, cache) {
+            Ok(interpreter) => interpreter,
+            Err(err) => {
+                debug!("Skipping `{}`: {err}", executable.user_display());
+                continue;
+            }
+        };
+
+        // A virtual environment's executable often links to its base interpreter, so identify
+        // interpreters by their prefix and version rather than by their canonical path.
+        let key = (
+            interpreter.prefix().to_path_buf(),
+            interpreter.python_version().clone(),
+        );
+        if seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+
+        let source = if source == InterpreterSource::System && interpreter.is_virtualenv() {
+            InterpreterSource::VirtualEnv
+        } else {
+            source
+        };
+        pythons.push(DiscoveredPython {
+            source,
+            interpreter,
+        });
+    }
+    pythons
+}
+
+/// Find every executable in `PATH` that looks like a Python interpreter (e.g., `python`,
+/// `python3`, or `python3.12`), respecting `UV_TEST_PYTHON_PATH`.
+fn discover_system_executables() -> Vec<PathBuf> {
+    #[allow(non_snake_case)]
+    let UV_TEST_PYTHON_PATH = env::var_os("UV_TEST_PYTHON_PATH");
+
+    let use_override = UV_TEST_PYTHON_PATH.is_some();
+
+    #[allow(non_snake_case)]
+    let PATH = UV_TEST_PYTHON_PATH
+        .or(env::var_os("PATH"))
+        .unwrap_or_default();
+
+    let mut executables = Vec::new();
+    for path in env::split_paths(&PATH) {
+        let Ok(entries) = fs_err::read_dir(&path) else {
+            continue;
+        };
+        // Sort the entries, as `read_dir` is not stable across platforms.
+        let mut entries = entries
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.file_name()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(is_python_executable_name)
+            })
+            .collect::<Vec<_>>();
+        entries.sort();
+
+        #[cfg(windows)]
+        entries.retain(|path| !windows::is_windows_store_shim(path));
+
+        executables.extend(entries);
+    }
+
+    if cfg!(windows) && !use_override {
+        if let Ok(paths) = windows::py_list_paths() {
+            executables.extend(paths.into_iter().map(|entry| entry.executable_path));
+        }
+    }
+
+    executables
+}
+
+/// Returns `true` if the file name is that of a Python interpreter, like `python`, `python3`,
+/// `python3.12`, or `python.exe`.
+fn is_python_executable_name(name: &str) -> bool {
+    let name = name.strip_suffix(".exe").unwrap_or(name);
+    let Some(version) = name.strip_prefix("python") else {
+        return false;
+    };
+    version.is_empty()
+        || version
+            .split('.')
+            .all(|part| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit()))
+}
+
 /// Pick a sensible default for the Python a user wants when they didn't specify a version.
 ///
 /// We prefer the test overwrite `UV_TEST_PYTHON_PATH` if it is set, otherwise `python3`/`python` or
 /// `python.exe` respectively.
 #[instrument(skip_all)]
-pub fn find_default_python(cache: &Cache) -> Result<Interpreter, Error> {
+pub fn find_default_python(
+    implementation: Option<PythonImplementation>,
+    preference: PythonPreference,
+    cache: &Cache,
+) -> Result<Interpreter, Error> {
     debug!("Starting interpreter discovery for default Python");
-    try_find_default_python(cache)?.ok_or(if cfg!(windows) {
+    try_find_default_python(implementation, preference, cache)?.ok_or(if cfg!(windows) {
         Error::NoPythonInstalledWindows
     } else if cfg!(unix) {
         Error::NoPythonInstalledUnix
@@ -92,11 +382,75 @@ pub fn find_default_python(cache: &Cache) -> Result<Interpreter, Error> {
 }
 
 /// Same as [`find_default_python`] but returns `None` if no python is found instead of returning an `Err`.
-pub(crate) fn try_find_default_python(cache: &Cache) -> Result<Option<Interpreter>, Error> {
-    find_python(PythonVersionSelector::Default, cache)
+pub(crate) fn try_find_default_python(
+    implementation: Option<PythonImplementation>,
+    preference: PythonPreference,
+    cache: &Cache,
+) -> Result<Option<Interpreter>, Error> {
+    find_python(
+        PythonVersionSelector::Default,
+        implementation,
+        preference,
+        cache,
+    )
+}
+
+/// Find a Python version matching `selector` (and `implementation`, if provided), searching
+/// managed toolchains and the system in the order given by the [`PythonPreference`].
+fn find_python(
+    selector: PythonVersionSelector,
+    implementation: Option<PythonImplementation>,
+    preference: PythonPreference,
+    cache: &Cache,
+) -> Result<Option<Interpreter>, Error> {
+    if preference.prefers_managed() {
+        if let Some(interpreter) = find_managed_python(selector, implementation, cache)? {
+            return Ok(Some(interpreter));
+        }
+    }
+
+    if preference.allows_system() {
+        if let Some(interpreter) = find_system_python(selector, implementation, cache)? {
+            return Ok(Some(interpreter));
+        }
+    }
+
+    if preference.allows_managed() && !preference.prefers_managed() {
+        if let Some(interpreter) = find_managed_python(selector, implementation, cache)? {
+            return Ok(Some(interpreter));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Find a managed Python toolchain (i.e., one installed by `uv`) matching `selector`, preferring
+/// newer versions.
+fn find_managed_python(
+    selector: PythonVersionSelector,
+    implementation: Option<PythonImplementation>,
+    cache: &Cache,
+) -> Result<Option<Interpreter>, Error> {
+    for toolchain in uv_toolchain::installed_toolchains()? {
+        let interpreter = match Interpreter::query(toolchain.executable(), cache) {
+            Ok(interpreter) => interpreter,
+            Err(err) => {
+                debug!("Skipping managed toolchain: {err}");
+                continue;
+            }
+        };
+
+        if let Some(interpreter) =
+            PythonInstallation::Interpreter(interpreter).select(selector, implementation, cache)?
+        {
+            return Ok(Some(interpreter));
+        }
+    }
+
+    Ok(None)
 }
 
-/// Find a Python version matching `selector`.
+/// Find a Python version matching `selector` on the system.
 ///
 /// It searches for an existing installation in the following order:
 /// * Search for the python binary in `PATH` (or `UV_TEST_PYTHON_PATH` if set). Visits each path and for each path resolves the
@@ -109,15 +463,16 @@ pub(crate) fn try_find_default_python(cache: &Cache) -> Result<Option<Interprete
 /// * (windows): Discover installations using `py --list-paths` (PEP514). Continue if `py` is not installed.
 ///
 /// (Windows): Filter out the Windows store shim (Enabled in Settings/Apps/Advanced app settings/App execution aliases).
-fn find_python(
+fn find_system_python(
     selector: PythonVersionSelector,
+    implementation: Option<PythonImplementation>,
     cache: &Cache,
 ) -> Result<Option<Interpreter>, Error> {
     #[allow(non_snake_case)]
     let UV_TEST_PYTHON_PATH = env::var_os("UV_TEST_PYTHON_PATH");
 
     let use_override = UV_TEST_PYTHON_PATH.is_some();
-    let possible_names = selector.possible_names();
+    let possible_names = selector.possible_names(implementation);
 
     #[allow(non_snake_case)]
     let PATH = UV_TEST_PYTHON_PATH
@@ -156,7 +511,9 @@ fn find_python(
 
                     let installation = PythonInstallation::Interpreter(interpreter);
 
-                    if let Some(interpreter) = installation.select(selector, cache)? {
+                    if let Some(interpreter) =
+                        installation.select(selector, implementation, cache)?
+                    {
                         return Ok(Some(interpreter));
                     }
                 }
@@ -179,9 +536,11 @@ fn find_python(
                         }
                     };
 
-                    if let Some(interpreter) =
-                        PythonInstallation::Interpreter(interpreter).select(selector, cache)?
-                    {
+                    if let Some(interpreter) = PythonInstallation::Interpreter(interpreter).select(
+                        selector,
+                        implementation,
+                        cache,
+                    )? {
                         return Ok(Some(interpreter));
                     }
                 }
@@ -195,7 +554,9 @@ fn find_python(
             Ok(paths) => {
                 for entry in paths {
                     let installation = PythonInstallation::PyListPath(entry);
-                    if let Some(interpreter) = installation.select(selector, cache)? {
+                    if let Some(interpreter) =
+                        installation.select(selector, implementation, cache)?
+                    {
                         return Ok(Some(interpreter));
                     }
                 }
@@ -310,8 +671,32 @@ impl PythonInstallation {
         }
     }
 
-    /// Selects the interpreter if it matches the selector (version specification).
+    /// Selects the interpreter if it matches the selector (version specification) and the
+    /// requested implementation, if any.
     fn select(
+        self,
+        selector: PythonVersionSelector,
+        implementation: Option<PythonImplementation>,
+        cache: &Cache,
+    ) -> Result<Option<Interpreter>, Error> {
+        let Some(interpreter) = self.select_version(selector, cache)? else {
+            return Ok(None);
+        };
+        if implementation
+            .is_some_and(|implementation| !interpreter.is_implementation(implementation))
+        {
+            debug!(
+                "Skipping {} interpreter at {}",
+                interpreter.implementation_name(),
+                interpreter.sys_executable().user_display()
+            );
+            return Ok(None);
+        }
+        Ok(Some(interpreter))
+    }
+
+    /// Selects the interpreter if it matches the selector (version specification).
+    fn select_version(
         self,
         selector: PythonVersionSelector,
         cache: &Cache,
@@ -366,28 +751,45 @@ enum PythonVersionSelector {
 }
 
 impl PythonVersionSelector {
-    fn possible_names(self) -> [Option<Cow<'static, str>>; 4] {
-        let (python, python3, extension) = if cfg!(windows) {
-            (
-                Cow::Borrowed("python.exe"),
-                Cow::Borrowed("python3.exe"),
-                ".exe",
-            )
-        } else {
-            (Cow::Borrowed("python"), Cow::Borrowed("python3"), "")
+    fn possible_names(
+        self,
+        implementation: Option<PythonImplementation>,
+    ) -> [Option<Cow<'static, str>>; 4] {
+        let (python, python3, extension) = match implementation {
+            None | Some(PythonImplementation::CPython) => {
+                if cfg!(windows) {
+                    (
+                        Cow::Borrowed("python.exe"),
+                        Cow::Borrowed("python3.exe"),
+                        ".exe",
+                    )
+                } else {
+                    (Cow::Borrowed("python"), Cow::Borrowed("python3"), "")
+                }
+            }
+            Some(implementation) => {
+                let extension = if cfg!(windows) { ".exe" } else { "" };
+                let prefix = implementation.executable_prefix();
+                (
+                    Cow::Owned(format!("{prefix}{extension}")),
+                    Cow::Owned(format!("{prefix}3{extension}")),
+                    extension,
+                )
+            }
         };
+        let prefix = implementation.map_or("python", PythonImplementation::executable_prefix);
 
         match self {
             Self::Default => [Some(python3), Some(python), None, None],
             Self::Major(major) => [
-                Some(Cow::Owned(format!("python{major}{extension}"))),
+                Some(Cow::Owned(format!("{prefix}{major}{extension}"))),
                 Some(python),
                 None,
                 None,
             ],
             Self::MajorMinor(major, minor) => [
-                Some(Cow::Owned(format!("python{major}.{minor}{extension}"))),
-                Some(Cow::Owned(format!("python{major}{extension}"))),
+                Some(Cow::Owned(format!("{prefix}{major}.{minor}{extension}"))),
+                Some(Cow::Owned(format!("{prefix}{major}{extension}"))),
                 Some(python),
                 None,
             ],
@@ -395,8 +797,8 @@ impl PythonVersionSelector {
                 Some(Cow::Owned(format!(
                     "python{major}.{minor}.{patch}{extension}",
                 ))),
-                Some(Cow::Owned(format!("python{major}.{minor}{extension}"))),
-                Some(Cow::Owned(format!("python{major}{extension}"))),
+                Some(Cow::Owned(format!("{prefix}{major}.{minor}{extension}"))),
+                Some(Cow::Owned(format!("{prefix}{major}{extension}"))),
                 Some(python),
             ],
         }
@@ -425,7 +827,9 @@ impl PythonVersionSelector {
 #[instrument(skip_all, fields(?python_version))]
 pub fn find_best_python(
     python_version: Option<&PythonVersion>,
+    implementation: Option<PythonImplementation>,
     system: bool,
+    preference: PythonPreference,
     cache: &Cache,
 ) -> Result<Interpreter, Error> {
     if let Some(python_version) = python_version {
@@ -438,7 +842,9 @@ pub fn find_best_python(
     }
 
     // First, check for an exact match (or the first available version if no Python version was provided)
-    if let Some(interpreter) = find_version(python_version, system, cache)? {
+    if let Some(interpreter) =
+        find_version(python_version, implementation, system, preference, cache)?
+    {
         return Ok(interpreter);
     }
 
@@ -446,16 +852,20 @@ pub fn find_best_python(
         // If that fails, and a specific patch version was requested try again allowing a
         // different patch version
         if python_version.patch().is_some() {
-            if let Some(interpreter) =
-                find_version(Some(&python_version.without_patch()), system, cache)?
-            {
+            if let Some(interpreter) = find_version(
+                Some(&python_version.without_patch()),
+                implementation,
+                system,
+                preference,
+                cache,
+            )? {
                 return Ok(interpreter);
             }
         }
     }
 
     // If a Python version was requested but cannot be fulfilled, just take any version
-    if let Some(interpreter) = find_version(None, system, cache)? {
+    if let Some(interpreter) = find_version(None, implementation, system, preference, cache)? {
         return Ok(interpreter);
     }
 
@@ -480,10 +890,17 @@ pub fn find_best_python(
 /// we will return [`None`].
 fn find_version(
     python_version: Option<&PythonVersion>,
+    implementation: Option<PythonImplementation>,
     system: bool,
+    preference: PythonPreference,
     cache: &Cache,
 ) -> Result<Option<Interpreter>, Error> {
     let version_matches = |interpreter: &Interpreter| -> bool {
+        if implementation
+            .is_some_and(|implementation| !interpreter.is_implementation(implementation))
+        {
+            return false;
+        }
         if let Some(python_version) = python_version {
             // If a patch version was provided, check for an exact match
             interpreter.satisfies(python_version)
@@ -508,9 +925,9 @@ fn find_version(
     // Look for the requested version with by search for `python{major}.{minor}` in `PATH` on
     // Unix and `py --list-paths` on Windows.
     let interpreter = if let Some(python_version) = python_version {
-        find_requested_python(&python_version.string, cache)?
+        find_requested_python(&python_version.string, implementation, preference, cache)?
     } else {
-        try_find_default_python(cache)?
+        try_find_default_python(implementation, preference, cache)?
     };
 
     if let Some(interpreter) = interpreter {
@@ -725,6 +1142,7 @@ mod windows {
         use itertools::Itertools;
 
         use uv_cache::Cache;
+        use uv_configuration::PythonPreference;
 
         use crate::{find_requested_python, Error};
 
@@ -737,12 +1155,16 @@ mod windows {
         #[test]
         #[cfg_attr(not(windows), ignore)]
         fn no_such_python_path() {
-            let result =
-                find_requested_python(r"C:\does\not\exists\python3.12", &Cache::temp().unwrap())
-                    .unwrap()
-                    .ok_or(Error::RequestedPythonNotFound(
-                        r"C:\does\not\exists\python3.12".to_string(),
-                    ));
+            let result = find_requested_python(
+                r"C:\does\not\exists\python3.12",
+                None,
+                PythonPreference::default(),
+                &Cache::temp().unwrap(),
+            )
+            .unwrap()
+            .ok_or(Error::RequestedPythonNotFound(
+                r"C:\does\not\exists\python3.12".to_string(),
+            ));
             assert_snapshot!(
                 format_err(result),
                 @"Failed to locate Python interpreter at `C:\\does\\not\\exists\\python3.12`"
@@ -757,8 +1179,9 @@ mod tests {
     use itertools::Itertools;
 
     use uv_cache::Cache;
+    use uv_configuration::PythonPreference;
 
-    use crate::find_python::find_requested_python;
+    use crate::find_python::{find_requested_python, is_python_executable_name};
     use crate::Error;
 
     fn format_err<T: std::fmt::Debug>(err: Result<T, Error>) -> String {
@@ -771,9 +1194,14 @@ mod tests {
     #[cfg_attr(not(unix), ignore)]
     fn no_such_python_version() {
         let request = "3.1000";
-        let result = find_requested_python(request, &Cache::temp().unwrap())
-            .unwrap()
-            .ok_or(Error::NoSuchPython(request.to_string()));
+        let result = find_requested_python(
+            request,
+            None,
+            PythonPreference::default(),
+            &Cache::temp().unwrap(),
+        )
+        .unwrap()
+        .ok_or(Error::NoSuchPython(request.to_string()));
         assert_snapshot!(
             format_err(result),
             @"No Python 3.1000 in `PATH`. Is Python 3.1000 installed?"
@@ -784,9 +1212,14 @@ mod tests {
     #[cfg_attr(not(unix), ignore)]
     fn no_such_python_binary() {
         let request = "python3.1000";
-        let result = find_requested_python(request, &Cache::temp().unwrap())
-            .unwrap()
-            .ok_or(Error::NoSuchPython(request.to_string()));
+        let result = find_requested_python(
+            request,
+            None,
+            PythonPreference::default(),
+            &Cache::temp().unwrap(),
+        )
+        .unwrap()
+        .ok_or(Error::NoSuchPython(request.to_string()));
         assert_snapshot!(
             format_err(result),
             @"No Python python3.1000 in `PATH`. Is Python python3.1000 installed?"
@@ -796,12 +1229,39 @@ mod tests {
     #[test]
     #[cfg_attr(not(unix), ignore)]
     fn no_such_python_path() {
-        let result = find_requested_python("/does/not/exists/python3.12", &Cache::temp().unwrap())
-            .unwrap()
-            .ok_or(Error::RequestedPythonNotFound(
-                "/does/not/exists/python3.12".to_string(),
-            ));
+        let result = find_requested_python(
+            "/does/not/exists/python3.12",
+            None,
+            PythonPreference::default(),
+            &Cache::temp().unwrap(),
+        )
+        .unwrap()
+        .ok_or(Error::RequestedPythonNotFound(
+            "/does/not/exists/python3.12".to_string(),
+        ));
         assert_snapshot!(
             format_err(result), @"Failed to locate Python interpreter at `/does/not/exists/python3.12`");
     }
+
+    #[test]
+    fn python_executable_names() {
+        for name in [
+            "python",
+            "python3",
+            "python3.12",
+            "python.exe",
+            "python3.12.exe",
+        ] {
+            assert!(is_python_executable_name(name), "{name}");
+        }
+        for name in [
+            "python3-config",
+            "python3.12-config",
+            "pythonw",
+            "python3.",
+            "ipython",
+        ] {
+            assert!(!is_python_executable_name(name), "{name}");
+        }
+    }
 }
@@ -15,6 +15,7 @@ use platform_tags::Platform;
 use platform_tags::{Tags, TagsError};
 use pypi_types::Scheme;
 use uv_cache::{Cache, CacheBucket, CachedByTimestamp, Freshness, Timestamp};
+use uv_configuration::PythonImplementation;
 use uv_fs::{write_atomic_sync, PythonExt, Simplified};
 use uv_toolchain::PythonVersion;
 
@@ -152,28 +153,7 @@ impl Interpreter {
             return None;
         };
 
-        let mut ini = Ini::new_cs();
-        ini.set_multiline(true);
-
-        let Ok(mut sections) = ini.read(contents) else {
-            // If a file exists but is not a valid INI file, we assume the environment is
-            // externally managed.
-            return Some(ExternallyManaged::default());
-        };
-
-        let Some(section) = sections.get_mut("externally-managed") else {
-            // If the file exists but does not contain an "externally-managed" section, we assume
-            // the environment is externally managed.
-            return Some(ExternallyManaged::default());
-        };
-
-        let Some(error) = section.remove("Error") else {
-            // If the file exists but does not contain an "Error" key, we assume the environment is
-            // externally managed.
-            return Some(ExternallyManaged::default());
-        };
-
-        Some(ExternallyManaged { error })
+        Some(ExternallyManaged::parse(contents, locale().as_deref()))
     }
 
     /// Returns the Python version.
@@ -233,6 +213,11 @@ impl Interpreter {
         &self.markers.implementation_name
     }
 
+    /// Returns `true` if the interpreter is of the given [`PythonImplementation`].
+    pub fn is_implementation(&self, implementation: PythonImplementation) -> bool {
+        self.implementation_name() == implementation.as_str()
+    }
+
     /// Return the `sys.base_exec_prefix` path for this Python interpreter.
     pub fn base_exec_prefix(&self) -> &Path {
         &self.base_exec_prefix
@@ -351,12 +336,62 @@ pub struct ExternallyManaged {
 }
 
 impl ExternallyManaged {
+    /// Parse the contents of an `EXTERNALLY-MANAGED` file.
+    ///
+    /// Per the spec, an error message for the user's locale (e.g., `Error-en_GB` or `Error-en`)
+    /// takes precedence over the default `Error` message.
+    fn parse(contents: String, locale: Option<&str>) -> Self {
+        let mut ini = Ini::new_cs();
+        ini.set_multiline(true);
+
+        let Ok(mut sections) = ini.read(contents) else {
+            // If a file exists but is not a valid INI file, we assume the environment is
+            // externally managed.
+            return Self::default();
+        };
+
+        let Some(section) = sections.get_mut("externally-managed") else {
+            // If the file exists but does not contain an "externally-managed" section, we assume
+            // the environment is externally managed.
+            return Self::default();
+        };
+
+        let mut keys = Vec::new();
+        if let Some(locale) = locale {
+            keys.push(format!("Error-{locale}"));
+            if let Some((language, _)) = locale.split_once('_') {
+                keys.push(format!("Error-{language}"));
+            }
+        }
+        keys.push("Error".to_string());
+
+        // If the file exists but does not contain an "Error" key, we assume the environment is
+        // externally managed.
+        let error = keys.iter().find_map(|key| section.remove(key)).flatten();
+
+        Self { error }
+    }
+
     /// Return the `EXTERNALLY-MANAGED` error message, if any.
     pub fn into_error(self) -> Option<String> {
         self.error
     }
 }
 
+/// Return the user's locale for messages (e.g., `en_GB`), as given by the `LC_ALL`, `LC_MESSAGES`,
+/// or `LANG` environment variables, without any encoding or modifier.
+fn locale() -> Option<String> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())?;
+    let locale = locale.split(['.', '@']).next().unwrap_or_default();
+    if locale.is_empty() || locale == "C" || locale == "POSIX" {
+        return None;
+    }
+    Some(locale.to_string())
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "result", rename_all = "lowercase")]
 enum InterpreterInfoResult {
@@ -567,8 +602,40 @@ mod tests {
     use pep440_rs::Version;
     use uv_cache::Cache;
 
+    use crate::interpreter::ExternallyManaged;
     use crate::Interpreter;
 
+    #[test]
+    fn externally_managed_error() {
+        let contents = indoc! {r"
+            [externally-managed]
+            Error=To install Python packages system-wide, try apt install python3-xyz.
+            Error-de=Um Python-Pakete systemweit zu installieren, versuchen Sie apt install python3-xyz.
+        "};
+
+        let error = ExternallyManaged::parse(contents.to_string(), None).into_error();
+        assert_eq!(
+            error.as_deref(),
+            Some("To install Python packages system-wide, try apt install python3-xyz.")
+        );
+
+        let error = ExternallyManaged::parse(contents.to_string(), Some("de_DE")).into_error();
+        assert_eq!(
+            error.as_deref(),
+            Some("Um Python-Pakete systemweit zu installieren, versuchen Sie apt install python3-xyz.")
+        );
+
+        let error = ExternallyManaged::parse(contents.to_string(), Some("fr_FR")).into_error();
+        assert_eq!(
+            error.as_deref(),
+            Some("To install Python packages system-wide, try apt install python3-xyz.")
+        );
+
+        let error =
+            ExternallyManaged::parse("[externally-managed]\n".to_string(), None).into_error();
+        assert_eq!(error, None);
+    }
+
     #[test]
     fn test_cache_invalidation() {
         let mock_dir = tempdir().unwrap();
@@ -654,4 +721,108 @@ mod tests {
             Version::from_str("3.13").unwrap()
         );
     }
+
+    #[test]
+    fn externally_managed_interpreter() {
+        let mock_dir = tempdir().unwrap();
+        let stdlib = mock_dir.path().join("lib").join("python3.12");
+        fs::create_dir_all(&stdlib).unwrap();
+        fs::write(
+            stdlib.join("EXTERNALLY-MANAGED"),
+            indoc! {r"
+                [externally-managed]
+                Error=To install Python packages system-wide, try apt install python3-xyz.
+            "},
+        )
+        .unwrap();
+
+        let mocked_interpreter = mock_dir.path().join("python");
+        let json = |prefix: &str| {
+            formatdoc! {r##"
+                {{
+                    "result": "success",
+                    "platform": {{
+                        "os": {{
+                            "name": "manylinux",
+                            "major": 2,
+                            "minor": 38
+                        }},
+                        "arch": "x86_64"
+                    }},
+                    "markers": {{
+                        "implementation_name": "cpython",
+                        "implementation_version": "3.12.0",
+                        "os_name": "posix",
+                        "platform_machine": "x86_64",
+                        "platform_python_implementation": "CPython",
+                        "platform_release": "6.5.0-13-generic",
+                        "platform_system": "Linux",
+                        "platform_version": "#13-Ubuntu SMP PREEMPT_DYNAMIC Fri Nov  3 12:16:05 UTC 2023",
+                        "python_full_version": "3.12.0",
+                        "python_version": "3.12",
+                        "sys_platform": "linux"
+                    }},
+                    "base_exec_prefix": "{root}",
+                    "base_prefix": "{root}",
+                    "prefix": "{prefix}",
+                    "sys_executable": "{root}/python",
+                    "stdlib": "{stdlib}",
+                    "scheme": {{
+                        "data": "{root}",
+                        "include": "{root}/include",
+                        "platlib": "{stdlib}/site-packages",
+                        "purelib": "{stdlib}/site-packages",
+                        "scripts": "{root}/bin"
+                    }},
+                    "virtualenv": {{
+                        "data": "",
+                        "include": "include",
+                        "platlib": "lib/python3.12/site-packages",
+                        "purelib": "lib/python3.12/site-packages",
+                        "scripts": "bin"
+                    }},
+                    "gil_disabled": false
+                }}
+            "##,
+                root = mock_dir.path().display(),
+                stdlib = stdlib.display(),
+            }
+        };
+
+        let cache = Cache::temp().unwrap();
+
+        // A system interpreter with an `EXTERNALLY-MANAGED` marker is externally managed.
+        fs::write(
+            &mocked_interpreter,
+            formatdoc! {r##"
+            #!/bin/bash
+            echo '{}'
+            "##, json(&mock_dir.path().display().to_string())},
+        )
+        .unwrap();
+        fs::set_permissions(
+            &mocked_interpreter,
+            std::os::unix::fs::PermissionsExt::from_mode(0o770),
+        )
+        .unwrap();
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        let externally_managed = interpreter.is_externally_managed().unwrap();
+        assert_eq!(
+            externally_managed.into_error().as_deref(),
+            Some("To install Python packages system-wide, try apt install python3-xyz.")
+        );
+
+        // A virtual environment is never externally managed, even if its base interpreter is.
+        let cache = Cache::temp().unwrap();
+        fs::write(
+            &mocked_interpreter,
+            formatdoc! {r##"
+            #!/bin/bash
+            echo '{}'
+            "##, json(&mock_dir.path().join(".venv").display().to_string())},
+        )
+        .unwrap();
+        let interpreter = Interpreter::query(&mocked_interpreter, &cache).unwrap();
+        assert!(interpreter.is_externally_managed().is_none());
+    }
 }
@@ -6,6 +6,10 @@
 //! * The `sync`, `install`, `uninstall`, `freeze`, `list` and `show` subcommands use
 //!   [`find_default_python`] when `--python` is used, [`find_default_python`] when `--system` is used
 //!   and the current venv by default.
+//!
+//! When searching by version, a [`uv_configuration::PythonPreference`] determines whether managed
+//! toolchains, installations found on the system, or both are considered, and in which order. An
+//! optional [`uv_configuration::PythonImplementation`] restricts discovery to, e.g., PyPy.
 
 use std::ffi::OsString;
 use std::io;
@@ -14,8 +18,13 @@ use std::process::ExitStatus;
 
 use thiserror::Error;
 
+use uv_configuration::PythonImplementation;
+
 pub use crate::cfg::PyVenvConfiguration;
-pub use crate::find_python::{find_best_python, find_default_python, find_requested_python};
+pub use crate::find_python::{
+    find_all_pythons, find_best_python, find_default_python, find_requested_python,
+    find_required_python, is_discovery_request, DiscoveredPython, InterpreterSource,
+};
 pub use crate::interpreter::Interpreter;
 use crate::interpreter::InterpreterInfoError;
 pub use crate::python_environment::PythonEnvironment;
@@ -37,6 +46,16 @@ pub enum Error {
     VenvNotFound,
     #[error("Failed to locate Python interpreter at `{0}`")]
     RequestedPythonNotFound(String),
+    #[error("No Python interpreter found for version `{request}`. {discovered}")]
+    NoMatchingPython { request: String, discovered: String },
+    #[error(
+        "The Python interpreter at `{interpreter}` is `{found}`, but `{requested}` was requested"
+    )]
+    ImplementationMismatch {
+        interpreter: PathBuf,
+        found: String,
+        requested: PythonImplementation,
+    },
     #[error(transparent)]
     Io(#[from] io::Error),
     #[error("Failed to query Python interpreter at `{interpreter}`")]
@@ -74,6 +93,8 @@ pub enum Error {
     Cfg(#[from] cfg::Error),
     #[error("Error finding `{}` in PATH", _0.to_string_lossy())]
     WhichError(OsString, #[source] which::Error),
+    #[error(transparent)]
+    Toolchain(#[from] uv_toolchain::Error),
     #[error("Can't use Python at `{interpreter}`")]
     QueryScript {
         #[source]
@@ -5,10 +5,11 @@ use same_file::is_same_file;
 use tracing::{debug, info};
 
 use uv_cache::Cache;
+use uv_configuration::{PythonImplementation, PythonPreference};
 use uv_fs::{LockedFile, Simplified};
 
 use crate::cfg::PyVenvConfiguration;
-use crate::{find_default_python, find_requested_python, Error, Interpreter};
+use crate::{find_default_python, find_required_python, Error, Interpreter};
 
 /// A Python environment, consisting of a Python [`Interpreter`] and its associated paths.
 #[derive(Debug, Clone)]
@@ -41,10 +42,13 @@ impl PythonEnvironment {
     }
 
     /// Create a [`PythonEnvironment`] for a Python interpreter specifier (e.g., a path or a binary name).
-    pub fn from_requested_python(python: &str, cache: &Cache) -> Result<Self, Error> {
-        let Some(interpreter) = find_requested_python(python, cache)? else {
-            return Err(Error::RequestedPythonNotFound(python.to_string()));
-        };
+    pub fn from_requested_python(
+        python: &str,
+        implementation: Option<PythonImplementation>,
+        preference: PythonPreference,
+        cache: &Cache,
+    ) -> Result<Self, Error> {
+        let interpreter = find_required_python(python, implementation, preference, cache)?;
         Ok(Self {
             root: interpreter.prefix().to_path_buf(),
             interpreter,
@@ -52,8 +56,12 @@ impl PythonEnvironment {
     }
 
     /// Create a [`PythonEnvironment`] for the default Python interpreter.
-    pub fn from_default_python(cache: &Cache) -> Result<Self, Error> {
-        let interpreter = find_default_python(cache)?;
+    pub fn from_default_python(
+        implementation: Option<PythonImplementation>,
+        preference: PythonPreference,
+        cache: &Cache,
+    ) -> Result<Self, Error> {
+        let interpreter = find_default_python(implementation, preference, cache)?;
         Ok(Self {
             root: interpreter.prefix().to_path_buf(),
             interpreter,
@@ -90,6 +90,23 @@ fn is_normalized(name: impl AsRef<str>) -> Result<bool, InvalidNameError> {
     Ok(true)
 }
 
+/// Compute the Levenshtein distance between two names (e.g., to suggest the name the user likely
+/// meant to type).
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != *cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(previous + 1).min(row[j] + 1);
+        }
+    }
+    row[b.len()]
+}
+
 /// Invalid [`crate::PackageName`] or [`crate::ExtraName`].
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct InvalidNameError(String);
@@ -190,4 +207,11 @@ mod tests {
             assert!(is_normalized(input).is_err());
         }
     }
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("dev", "dev"), 0);
+        assert_eq!(edit_distance("dev", "devs"), 1);
+        assert_eq!(edit_distance("tset", "test"), 2);
+        assert_eq!(edit_distance("", "docs"), 4);
+    }
 }
@@ -1,4 +1,5 @@
 pub use crate::lookahead::*;
+pub use crate::provenance::*;
 pub use crate::source_tree::*;
 pub use crate::sources::*;
 pub use crate::specification::*;
@@ -6,7 +7,9 @@ pub use crate::unnamed::*;
 
 mod confirm;
 mod lookahead;
+mod provenance;
 mod pyproject;
+mod script;
 mod source_tree;
 mod sources;
 mod specification;
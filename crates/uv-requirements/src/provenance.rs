@@ -0,0 +1,218 @@
+use std::fmt::{Display, Formatter};
+
+use rustc_hash::FxHashMap;
+
+use pep440_rs::{Operator, Version};
+use pep508_rs::{MarkerEnvironment, Requirement, RequirementsTxtRequirement, VersionOrUrl};
+use requirements_txt::RequirementEntry;
+use uv_normalize::{ExtraName, PackageName};
+
+use crate::RequirementsSource;
+
+/// The origin of a requirement, for use in diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequirementOrigin {
+    /// The requirement was read from the given source.
+    Requirement(String),
+    /// The requirement was read from an optional dependency group (an "extra") of the given
+    /// source.
+    Extra(String, ExtraName),
+    /// The requirement was read as a constraint from the given source.
+    Constraint(String),
+    /// The requirement was read as an override from the given source.
+    Override(String),
+}
+
+impl Display for RequirementOrigin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Requirement(source) => write!(f, "{source}"),
+            Self::Extra(source, extra) => write!(f, "{source} (extra: {extra})"),
+            Self::Constraint(source) => write!(f, "{source} (constraint)"),
+            Self::Override(source) => write!(f, "{source} (override)"),
+        }
+    }
+}
+
+/// The origins of the requirements on each package, across a set of sources.
+#[derive(Debug, Default)]
+pub struct RequirementsProvenance(FxHashMap<PackageName, Vec<(RequirementOrigin, Requirement)>>);
+
+impl RequirementsProvenance {
+    /// Return the requirements that directly conflict with one another, i.e., where one
+    /// requirement pins a package to an exact version that another requirement on the same package
+    /// excludes.
+    ///
+    /// Requirements whose markers don't apply to the given environment are ignored. If a package
+    /// is overridden, only the overrides are considered, as they replace any other requirements on
+    /// the package.
+    pub fn conflicts(&self, markers: &MarkerEnvironment) -> Vec<RequirementConflict> {
+        let mut conflicts = self
+            .0
+            .iter()
+            .filter_map(|(name, origins)| {
+                let applicable = origins
+                    .iter()
+                    .filter(|(origin, requirement)| {
+                        let extras = match origin {
+                            RequirementOrigin::Extra(_, extra) => std::slice::from_ref(extra),
+                            _ => &[],
+                        };
+                        requirement.evaluate_markers(markers, extras)
+                    })
+                    .collect::<Vec<_>>();
+                let applicable = if applicable
+                    .iter()
+                    .any(|(origin, _)| matches!(origin, RequirementOrigin::Override(_)))
+                {
+                    applicable
+                        .into_iter()
+                        .filter(|(origin, _)| matches!(origin, RequirementOrigin::Override(_)))
+                        .collect()
+                } else {
+                    applicable
+                };
+
+                // Retain every requirement that's party to a conflict, in the order in which the
+                // requirements were read.
+                let mut conflicting = vec![false; applicable.len()];
+                for (i, (_, first)) in applicable.iter().enumerate() {
+                    for (j, (_, second)) in applicable.iter().enumerate().skip(i + 1) {
+                        if excludes_pin(first, second) || excludes_pin(second, first) {
+                            conflicting[i] = true;
+                            conflicting[j] = true;
+                        }
+                    }
+                }
+                if !conflicting.contains(&true) {
+                    return None;
+                }
+                Some(RequirementConflict {
+                    name: name.clone(),
+                    requirements: applicable
+                        .into_iter()
+                        .zip(conflicting)
+                        .filter_map(|(entry, conflicting)| conflicting.then(|| entry.clone()))
+                        .collect(),
+                })
+            })
+            .collect::<Vec<_>>();
+        conflicts.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        conflicts
+    }
+
+    /// Attribute the given requirements and constraints to the source from which they were read.
+    pub(crate) fn from_entries(
+        source: &RequirementsSource,
+        requirements: &[RequirementEntry],
+        constraints: &[Requirement],
+    ) -> Self {
+        let mut provenance = Self::default();
+        for entry in requirements {
+            provenance.insert(
+                RequirementOrigin::Requirement(source.to_string()),
+                &entry.requirement,
+            );
+        }
+        for constraint in constraints {
+            provenance.insert_named(
+                RequirementOrigin::Constraint(source.to_string()),
+                constraint.clone(),
+            );
+        }
+        provenance
+    }
+
+    /// Merge the origins recorded for another set of sources.
+    pub(crate) fn extend(&mut self, other: Self) {
+        for (name, origins) in other.0 {
+            self.0.entry(name).or_default().extend(origins);
+        }
+    }
+
+    /// Re-attribute each requirement using the given function, dropping any requirement for which
+    /// it returns `None` (e.g., to treat the requirements read from a constraints file as
+    /// constraints).
+    pub(crate) fn map_origins(
+        self,
+        f: impl Fn(RequirementOrigin) -> Option<RequirementOrigin>,
+    ) -> Self {
+        let mut provenance = Self::default();
+        for (origin, requirement) in self.0.into_values().flatten() {
+            if let Some(origin) = f(origin) {
+                provenance.insert_named(origin, requirement);
+            }
+        }
+        provenance
+    }
+
+    /// Return the origins of the requirements on the given package.
+    pub fn get(&self, name: &PackageName) -> &[(RequirementOrigin, Requirement)] {
+        self.0.get(name).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Record the origin of a requirement. Unnamed requirements are ignored, as they can't be
+    /// attributed to a package until they're resolved.
+    fn insert(&mut self, origin: RequirementOrigin, requirement: &RequirementsTxtRequirement) {
+        if let RequirementsTxtRequirement::Pep508(requirement) = requirement {
+            self.insert_named(origin, requirement.clone());
+        }
+    }
+
+    /// Record the origin of a named requirement.
+    pub(crate) fn insert_named(&mut self, origin: RequirementOrigin, requirement: Requirement) {
+        self.0
+            .entry(requirement.name.clone())
+            .or_default()
+            .push((origin, requirement));
+    }
+}
+
+/// A set of requirements on a package that can't be satisfied together, along with their origins.
+#[derive(Debug, Clone)]
+pub struct RequirementConflict {
+    /// The package on which the requirements conflict.
+    pub name: PackageName,
+    /// The conflicting requirements, along with their origins.
+    pub requirements: Vec<(RequirementOrigin, Requirement)>,
+}
+
+impl Display for RequirementConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Conflicting requirements on {}:", self.name)?;
+        for (origin, requirement) in &self.requirements {
+            write!(f, "\n  {requirement}")?;
+            // A requirement passed on the command line is its own source, so there's no need to
+            // repeat it.
+            if !matches!(origin, RequirementOrigin::Requirement(source) if *source == requirement.to_string())
+            {
+                write!(f, " (from {origin})")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if `pin` pins a package to an exact version that `other` excludes.
+fn excludes_pin(pin: &Requirement, other: &Requirement) -> bool {
+    let Some(version) = pinned_version(pin) else {
+        return false;
+    };
+    match &other.version_or_url {
+        Some(VersionOrUrl::VersionSpecifier(specifiers)) => !specifiers.contains(version),
+        Some(VersionOrUrl::Url(_)) | None => false,
+    }
+}
+
+/// Return the exact version to which a requirement pins a package (e.g., `3.0.0` in
+/// `anyio==3.0.0`), if any.
+fn pinned_version(requirement: &Requirement) -> Option<&Version> {
+    let Some(VersionOrUrl::VersionSpecifier(specifiers)) = &requirement.version_or_url else {
+        return None;
+    };
+    let [specifier] = specifiers.as_ref() else {
+        return None;
+    };
+    matches!(specifier.operator(), Operator::Equal | Operator::ExactEqual)
+        .then(|| specifier.version())
+}
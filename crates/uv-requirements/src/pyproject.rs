@@ -15,6 +15,43 @@ use crate::ExtrasSpecification;
 pub(crate) struct PyProjectToml {
     /// Project metadata
     pub(crate) project: Option<Project>,
+    /// Tool-specific metadata
+    pub(crate) tool: Option<Tool>,
+}
+
+impl PyProjectToml {
+    /// Return a pair of requested extras that the project declares as conflicting in
+    /// `tool.uv.conflicts`, if any.
+    pub(crate) fn conflicting_extras(
+        &self,
+        requested: impl Fn(&ExtraName) -> bool,
+    ) -> Option<(&ExtraName, &ExtraName)> {
+        let conflicts = self.tool.as_ref()?.uv.as_ref()?.conflicts.as_ref()?;
+        conflicts.iter().find_map(|conflict| {
+            let mut extras = conflict.iter().filter(|extra| requested(*extra));
+            let first = extras.next()?;
+            let second = extras.find(|extra| *extra != first)?;
+            Some((first, second))
+        })
+    }
+}
+
+/// A `[tool]` section.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Tool {
+    pub(crate) uv: Option<ToolUv>,
+}
+
+/// A `[tool.uv]` section.
+///
+/// This is a subset of the full settings, and only includes the fields that are relevant for
+/// collecting requirements.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct ToolUv {
+    /// Sets of extras that are mutually exclusive, e.g., because they pin conflicting versions
+    /// of the same package.
+    pub(crate) conflicts: Option<Vec<Vec<ExtraName>>>,
 }
 
 /// PEP 621 project metadata.
@@ -44,8 +81,13 @@ pub(crate) struct Pep621Metadata {
     pub(crate) name: PackageName,
     /// The requirements extracted from the project.
     pub(crate) requirements: Vec<Requirement>,
+    /// The requirements extracted from the requested extras, along with the extra that introduced
+    /// each of them.
+    pub(crate) optional_requirements: Vec<(ExtraName, Requirement)>,
     /// The extras used to collect requirements.
     pub(crate) used_extras: FxHashSet<ExtraName>,
+    /// The extras defined by the project.
+    pub(crate) defined_extras: FxHashSet<ExtraName>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -79,9 +121,15 @@ impl Pep621Metadata {
         }
 
         let name = project.name;
+        let defined_extras = project
+            .optional_dependencies
+            .iter()
+            .flat_map(IndexMap::keys)
+            .cloned()
+            .collect();
 
         // Parse out the project requirements.
-        let mut requirements = project
+        let requirements = project
             .dependencies
             .unwrap_or_default()
             .iter()
@@ -91,6 +139,7 @@ impl Pep621Metadata {
 
         // Include any optional dependencies specified in `extras`.
         let mut used_extras = FxHashSet::default();
+        let mut extra_requirements = Vec::new();
         if !extras.is_empty() {
             if let Some(optional_dependencies) = project.optional_dependencies {
                 // Parse out the optional dependencies.
@@ -110,11 +159,11 @@ impl Pep621Metadata {
                 for (extra, optional_requirements) in &optional_dependencies {
                     if extras.contains(extra) {
                         used_extras.insert(extra.clone());
-                        requirements.extend(flatten_extra(
-                            &name,
-                            optional_requirements,
-                            &optional_dependencies,
-                        ));
+                        extra_requirements.extend(
+                            flatten_extra(&name, optional_requirements, &optional_dependencies)
+                                .into_iter()
+                                .map(|requirement| (extra.clone(), requirement)),
+                        );
                     }
                 }
             }
@@ -123,7 +172,9 @@ impl Pep621Metadata {
         Ok(Some(Self {
             name,
             requirements,
+            optional_requirements: extra_requirements,
             used_extras,
+            defined_extras,
         }))
     }
 }
@@ -0,0 +1,139 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use pep440_rs::{VersionSpecifiers, VersionSpecifiersParseError};
+use pep508_rs::{Pep508Error, Requirement};
+use pypi_types::{LenientRequirement, LenientVersionSpecifiers};
+
+/// The opening line of a PEP 723 `script` metadata block.
+const OPENING: &str = "# /// script";
+
+/// The closing line of a PEP 723 metadata block.
+const CLOSING: &str = "# ///";
+
+/// The metadata embedded in a single-file script, as specified in PEP 723.
+///
+/// See: <https://peps.python.org/pep-0723/>
+#[derive(Debug)]
+pub(crate) struct Pep723Metadata {
+    /// The requirements declared by the script.
+    pub(crate) requirements: Vec<Requirement>,
+    /// The Python versions supported by the script.
+    pub(crate) requires_python: Option<VersionSpecifiers>,
+}
+
+/// The raw contents of a `script` metadata block.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+struct ScriptMetadata {
+    requires_python: Option<String>,
+    dependencies: Option<Vec<String>>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum Pep723Error {
+    #[error("line {0}: Found multiple `script` metadata blocks")]
+    Duplicate(usize),
+    #[error("line {0}: The `script` metadata block is never closed (expected a `{CLOSING}` line)")]
+    Unclosed(usize),
+    #[error("line {0}: Invalid `script` metadata: {1}")]
+    Toml(usize, String),
+    #[error("line {0}: Invalid `requires-python` value")]
+    RequiresPython(usize, #[source] VersionSpecifiersParseError),
+    #[error("line {0}: Invalid dependency")]
+    Dependency(usize, #[source] Pep508Error),
+}
+
+impl Pep723Metadata {
+    /// Read the [`Pep723Metadata`] from the contents of a Python script.
+    ///
+    /// Returns `None` if the script doesn't contain a `script` metadata block. Errors include the
+    /// (one-based) line number in the script at which the problem was found.
+    pub(crate) fn parse(contents: &str) -> Result<Option<Self>, Pep723Error> {
+        let lines = contents.lines().map(str::trim_end).collect::<Vec<_>>();
+
+        let mut block = None;
+        let mut index = 0;
+        while index < lines.len() {
+            if lines[index] != OPENING {
+                index += 1;
+                continue;
+            }
+            if block.is_some() {
+                return Err(Pep723Error::Duplicate(index + 1));
+            }
+
+            // The block extends over the following comment lines (either `#` alone, or `# `
+            // followed by content), and ends at the _last_ closing line among them.
+            let start = index + 1;
+            let end = lines[start..]
+                .iter()
+                .take_while(|line| **line == "#" || line.starts_with("# "))
+                .count()
+                + start;
+            let Some(close) = (start..end).rev().find(|&index| lines[index] == CLOSING) else {
+                return Err(Pep723Error::Unclosed(index + 1));
+            };
+
+            block = Some((start, &lines[start..close]));
+            index = close + 1;
+        }
+
+        let Some((start, block)) = block else {
+            return Ok(None);
+        };
+
+        // Strip the comment prefix from each line to recover the embedded TOML.
+        let content = block
+            .iter()
+            .map(|line| line.strip_prefix("# ").unwrap_or_default())
+            .collect::<Vec<_>>();
+        let toml = content.join("\n");
+
+        // Map a position within the embedded TOML back to a line in the script.
+        let line_of = |offset: usize| start + 1 + toml[..offset].matches('\n').count();
+
+        let metadata = toml::from_str::<ScriptMetadata>(&toml).map_err(|err| {
+            let line = err
+                .span()
+                .map_or(start, |span| line_of(span.start.min(toml.len())));
+            Pep723Error::Toml(line, err.message().to_string())
+        })?;
+
+        // Report any invalid value at the first line that mentions it.
+        let line_containing = |value: &str| {
+            content
+                .iter()
+                .position(|line| line.contains(value))
+                .map_or(start, |index| start + 1 + index)
+        };
+
+        let requires_python = metadata
+            .requires_python
+            .map(|requires_python| {
+                LenientVersionSpecifiers::from_str(&requires_python)
+                    .map(VersionSpecifiers::from)
+                    .map_err(|err| {
+                        Pep723Error::RequiresPython(line_containing(&requires_python), err)
+                    })
+            })
+            .transpose()?;
+
+        let requirements = metadata
+            .dependencies
+            .unwrap_or_default()
+            .iter()
+            .map(|dependency| {
+                LenientRequirement::from_str(dependency)
+                    .map(Requirement::from)
+                    .map_err(|err| Pep723Error::Dependency(line_containing(dependency), err))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(Self {
+            requirements,
+            requires_python,
+        }))
+    }
+}
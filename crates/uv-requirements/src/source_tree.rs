@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use futures::{StreamExt, TryStreamExt};
+use rustc_hash::FxHashSet;
 use url::Url;
 
 use distribution_types::{BuildableSource, HashPolicy, PathSourceUrl, SourceUrl, VersionId};
@@ -10,11 +11,21 @@ use pep508_rs::Requirement;
 use uv_client::RegistryClient;
 use uv_distribution::{DistributionDatabase, Reporter};
 use uv_fs::Simplified;
+use uv_normalize::ExtraName;
 use uv_resolver::{InMemoryIndex, MetadataResponse};
 use uv_types::{BuildContext, HashStrategy};
 
 use crate::ExtrasSpecification;
 
+/// The requirements and extras extracted from a set of source trees.
+#[derive(Debug, Default)]
+pub struct SourceTreeResolution {
+    /// The requirements of the source trees.
+    pub requirements: Vec<Requirement>,
+    /// The extras provided by the source trees.
+    pub extras: FxHashSet<ExtraName>,
+}
+
 /// A resolver for requirements specified via source trees.
 ///
 /// Used, e.g., to determine the input requirements when a user specifies a `pyproject.toml`
@@ -61,17 +72,23 @@ impl<'a, Context: BuildContext + Send + Sync> SourceTreeResolver<'a, Context> {
     }
 
     /// Resolve the requirements from the provided source trees.
-    pub async fn resolve(self) -> Result<Vec<Requirement>> {
-        let requirements: Vec<_> = futures::stream::iter(self.source_trees.iter())
+    pub async fn resolve(self) -> Result<SourceTreeResolution> {
+        let resolutions: Vec<_> = futures::stream::iter(self.source_trees.iter())
             .map(|source_tree| async { self.resolve_source_tree(source_tree).await })
             .buffered(50)
             .try_collect()
             .await?;
-        Ok(requirements.into_iter().flatten().collect())
+        Ok(resolutions
+            .into_iter()
+            .fold(SourceTreeResolution::default(), |mut acc, resolution| {
+                acc.requirements.extend(resolution.requirements);
+                acc.extras.extend(resolution.extras);
+                acc
+            }))
     }
 
     /// Infer the package name for a given "unnamed" requirement.
-    async fn resolve_source_tree(&self, source_tree: &Path) -> Result<Vec<Requirement>> {
+    async fn resolve_source_tree(&self, source_tree: &Path) -> Result<SourceTreeResolution> {
         // Convert to a buildable source.
         let path = fs_err::canonicalize(source_tree).with_context(|| {
             format!(
@@ -92,6 +109,13 @@ impl<'a, Context: BuildContext + Send + Sync> SourceTreeResolver<'a, Context> {
         let hashes = match self.hasher {
             HashStrategy::None => HashPolicy::None,
             HashStrategy::Generate => HashPolicy::Generate,
+            HashStrategy::Constrain { generate, .. } => {
+                if *generate {
+                    HashPolicy::Generate
+                } else {
+                    HashPolicy::None
+                }
+            }
             HashStrategy::Validate { .. } => {
                 return Err(anyhow::anyhow!(
                     "Hash-checking is not supported for local directories: {}",
@@ -133,9 +157,9 @@ impl<'a, Context: BuildContext + Send + Sync> SourceTreeResolver<'a, Context> {
         // Determine the appropriate requirements to return based on the extras. This involves
         // evaluating the `extras` expression in any markers, but preserving the remaining marker
         // conditions.
-        match self.extras {
-            ExtrasSpecification::None => Ok(metadata.requires_dist),
-            ExtrasSpecification::All => Ok(metadata
+        let requirements = match self.extras {
+            ExtrasSpecification::None => metadata.requires_dist,
+            ExtrasSpecification::All => metadata
                 .requires_dist
                 .into_iter()
                 .map(|requirement| Requirement {
@@ -144,8 +168,8 @@ impl<'a, Context: BuildContext + Send + Sync> SourceTreeResolver<'a, Context> {
                         .and_then(|marker| marker.simplify_extras(&metadata.provides_extras)),
                     ..requirement
                 })
-                .collect()),
-            ExtrasSpecification::Some(extras) => Ok(metadata
+                .collect(),
+            ExtrasSpecification::Some(extras) => metadata
                 .requires_dist
                 .into_iter()
                 .map(|requirement| Requirement {
@@ -154,7 +178,12 @@ impl<'a, Context: BuildContext + Send + Sync> SourceTreeResolver<'a, Context> {
                         .and_then(|marker| marker.simplify_extras(extras)),
                     ..requirement
                 })
-                .collect()),
-        }
+                .collect(),
+        };
+
+        Ok(SourceTreeResolution {
+            requirements,
+            extras: metadata.provides_extras.into_iter().collect(),
+        })
     }
 }
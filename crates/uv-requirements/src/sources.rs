@@ -22,6 +22,9 @@ pub enum RequirementsSource {
     SetupPy(PathBuf),
     /// Dependencies were provided via a `setup.cfg` file (e.g., `pip-compile setup.cfg`).
     SetupCfg(PathBuf),
+    /// Dependencies were provided via a single-file script with PEP 723 inline metadata (e.g.,
+    /// `pip-compile script.py`).
+    Pep723Script(PathBuf),
 }
 
 impl RequirementsSource {
@@ -34,6 +37,8 @@ impl RequirementsSource {
             Self::SetupPy(path)
         } else if path.ends_with("setup.cfg") {
             Self::SetupCfg(path)
+        } else if path.extension().is_some_and(|ext| ext == "py") {
+            Self::Pep723Script(path)
         } else {
             Self::RequirementsTxt(path)
         }
@@ -139,7 +144,8 @@ impl std::fmt::Display for RequirementsSource {
             Self::RequirementsTxt(path)
             | Self::PyprojectToml(path)
             | Self::SetupPy(path)
-            | Self::SetupCfg(path) => {
+            | Self::SetupCfg(path)
+            | Self::Pep723Script(path) => {
                 write!(f, "{}", path.simplified_display())
             }
         }
@@ -1,19 +1,28 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tracing::{instrument, Level};
 
 use cache_key::CanonicalUrl;
+use distribution_filename::SourceDistExtension;
 use distribution_types::{FlatIndexLocation, IndexUrl};
-use pep508_rs::{Requirement, RequirementsTxtRequirement};
-use requirements_txt::{EditableRequirement, FindLink, RequirementEntry, RequirementsTxt};
+use pep440_rs::VersionSpecifiers;
+use pep508_rs::{
+    split_extras, Pep508Error, Requirement, RequirementsTxtRequirement, UnnamedRequirement,
+    VerbatimUrl, VersionOrUrl,
+};
+use requirements_txt::{
+    EditableRequirement, FindLink, RequirementComments, RequirementEntry, RequirementsTxt,
+};
 use uv_client::BaseClientBuilder;
 use uv_configuration::{NoBinary, NoBuild};
 use uv_fs::Simplified;
 use uv_normalize::{ExtraName, PackageName};
 
+use crate::provenance::{RequirementOrigin, RequirementsProvenance};
 use crate::pyproject::{Pep621Metadata, PyProjectToml};
+use crate::script::Pep723Metadata;
 use crate::{ExtrasSpecification, RequirementsSource};
 
 #[derive(Debug, Default)]
@@ -24,14 +33,25 @@ pub struct RequirementsSpecification {
     pub requirements: Vec<RequirementEntry>,
     /// The constraints for the project.
     pub constraints: Vec<Requirement>,
+    /// The hash-pinned constraints for the project (e.g., from a compiled `requirements.txt`
+    /// provided via `-c`), whose hashes should be reused when resolving.
+    pub pinned_constraints: Vec<RequirementEntry>,
+    /// The `# via` annotations of each package in any compiled constraints files (e.g., a
+    /// `requirements.txt` written by `pip compile` and provided via `-c`).
+    pub constraint_annotations: FxHashMap<PackageName, Vec<String>>,
     /// The overrides for the project.
     pub overrides: Vec<RequirementEntry>,
     /// Package to install as editable installs
     pub editables: Vec<EditableRequirement>,
+    /// Editable requirements for VCS URLs (e.g., `-e git+https://...#egg=example`), which can't be
+    /// installed in editable mode, parsed as regular requirements.
+    pub editable_vcs: Vec<RequirementEntry>,
     /// The source trees from which to extract requirements.
     pub source_trees: Vec<PathBuf>,
     /// The extras used to collect requirements.
     pub extras: FxHashSet<ExtraName>,
+    /// The extras defined by the projects, to validate the requested extras against.
+    pub defined_extras: FxHashSet<ExtraName>,
     /// The index URL to use for fetching packages.
     pub index_url: Option<IndexUrl>,
     /// The extra index URLs to use for fetching packages.
@@ -44,6 +64,12 @@ pub struct RequirementsSpecification {
     pub no_binary: NoBinary,
     /// The `--no-build` flags to enforce when selecting distributions.
     pub no_build: NoBuild,
+    /// The Python versions required by the sources (e.g., via a script's `requires-python`),
+    /// along with the path to the source that declared them.
+    pub requires_python: Vec<(PathBuf, VersionSpecifiers)>,
+    /// The source (and extra) from which each named requirement, constraint, and override was
+    /// read, for use in diagnostics.
+    pub provenance: RequirementsProvenance,
 }
 
 impl RequirementsSpecification {
@@ -56,57 +82,93 @@ impl RequirementsSpecification {
     ) -> Result<Self> {
         Ok(match source {
             RequirementsSource::Package(name) => {
-                let requirement = RequirementsTxtRequirement::parse(name, std::env::current_dir()?)
+                let requirement = parse_package(name, &std::env::current_dir()?)
                     .with_context(|| format!("Failed to parse `{name}`"))?;
+                let requirements = vec![RequirementEntry {
+                    requirement,
+                    hashes: vec![],
+                    comments: RequirementComments::default(),
+                    prerelease: false,
+                }];
+                let provenance = RequirementsProvenance::from_entries(source, &requirements, &[]);
                 Self {
                     project: None,
-                    requirements: vec![RequirementEntry {
-                        requirement,
-                        hashes: vec![],
-                    }],
+                    requirements,
                     constraints: vec![],
+                    pinned_constraints: vec![],
+                    constraint_annotations: FxHashMap::default(),
                     overrides: vec![],
                     editables: vec![],
+                    editable_vcs: vec![],
                     source_trees: vec![],
                     extras: FxHashSet::default(),
+                    defined_extras: FxHashSet::default(),
                     index_url: None,
                     extra_index_urls: vec![],
                     no_index: false,
                     find_links: vec![],
                     no_binary: NoBinary::default(),
                     no_build: NoBuild::default(),
+                    requires_python: vec![],
+                    provenance,
                 }
             }
             RequirementsSource::Editable(name) => {
+                // Editable VCS URLs are parsed as regular requirements, to be rejected (or
+                // converted) by the caller.
+                if let Some(requirement) =
+                    RequirementEntry::from_editable_vcs(name, std::env::current_dir()?)
+                        .with_context(|| format!("Failed to parse `{name}`"))?
+                {
+                    return Ok(Self {
+                        editable_vcs: vec![requirement],
+                        ..Self::default()
+                    });
+                }
                 let requirement = EditableRequirement::parse(name, std::env::current_dir()?)
                     .with_context(|| format!("Failed to parse `{name}`"))?;
                 Self {
                     project: None,
                     requirements: vec![],
                     constraints: vec![],
+                    pinned_constraints: vec![],
+                    constraint_annotations: FxHashMap::default(),
                     overrides: vec![],
                     editables: vec![requirement],
+                    editable_vcs: vec![],
                     source_trees: vec![],
                     extras: FxHashSet::default(),
+                    defined_extras: FxHashSet::default(),
                     index_url: None,
                     extra_index_urls: vec![],
                     no_index: false,
                     find_links: vec![],
                     no_binary: NoBinary::default(),
                     no_build: NoBuild::default(),
+                    requires_python: vec![],
+                    provenance: RequirementsProvenance::default(),
                 }
             }
             RequirementsSource::RequirementsTxt(path) => {
                 let requirements_txt =
                     RequirementsTxt::parse(path, std::env::current_dir()?, client_builder).await?;
+                let provenance = RequirementsProvenance::from_entries(
+                    source,
+                    &requirements_txt.requirements,
+                    &requirements_txt.constraints,
+                );
                 Self {
                     project: None,
                     requirements: requirements_txt.requirements,
                     constraints: requirements_txt.constraints,
+                    pinned_constraints: vec![],
+                    constraint_annotations: FxHashMap::default(),
                     overrides: vec![],
                     editables: requirements_txt.editables,
+                    editable_vcs: requirements_txt.editable_vcs,
                     source_trees: vec![],
                     extras: FxHashSet::default(),
+                    defined_extras: FxHashSet::default(),
                     index_url: requirements_txt.index_url.map(IndexUrl::from),
                     extra_index_urls: requirements_txt
                         .extra_index_urls
@@ -124,6 +186,8 @@ impl RequirementsSpecification {
                         .collect(),
                     no_binary: requirements_txt.no_binary,
                     no_build: requirements_txt.only_binary,
+                    requires_python: vec![],
+                    provenance,
                 }
             }
             RequirementsSource::PyprojectToml(path) => {
@@ -131,6 +195,20 @@ impl RequirementsSpecification {
                 let pyproject = toml::from_str::<PyProjectToml>(&contents)
                     .with_context(|| format!("Failed to parse `{}`", path.user_display()))?;
 
+                // Reject any requested extras that the project declares as conflicting, before
+                // resolving (or building) anything.
+                let defined_extras = pyproject
+                    .project
+                    .as_ref()
+                    .and_then(|project| project.optional_dependencies.as_ref());
+                validate_conflicts(&pyproject, path, |extra| match extras {
+                    ExtrasSpecification::None => false,
+                    ExtrasSpecification::All => {
+                        defined_extras.map_or(true, |defined| defined.contains_key(extra))
+                    }
+                    ExtrasSpecification::Some(extras) => extras.contains(extra),
+                })?;
+
                 // Attempt to read metadata from the `pyproject.toml` directly.
                 //
                 // If we fail to extract the PEP 621 metadata, fall back to treating it as a source
@@ -145,27 +223,58 @@ impl RequirementsSpecification {
                     .project
                     .and_then(|project| Pep621Metadata::try_from(project, extras).ok().flatten())
                 {
+                    // Attribute any requirements that are only enabled by an extra to that extra.
+                    let mut provenance = RequirementsProvenance::default();
+                    for requirement in &project.requirements {
+                        provenance.insert_named(
+                            RequirementOrigin::Requirement(source.to_string()),
+                            requirement.clone(),
+                        );
+                    }
+                    for (extra, requirement) in &project.optional_requirements {
+                        if !project.requirements.contains(requirement) {
+                            provenance.insert_named(
+                                RequirementOrigin::Extra(source.to_string(), extra.clone()),
+                                requirement.clone(),
+                            );
+                        }
+                    }
+
                     Self {
                         project: Some(project.name),
                         requirements: project
                             .requirements
                             .into_iter()
+                            .chain(
+                                project
+                                    .optional_requirements
+                                    .into_iter()
+                                    .map(|(_, requirement)| requirement),
+                            )
                             .map(|requirement| RequirementEntry {
                                 requirement: RequirementsTxtRequirement::Pep508(requirement),
                                 hashes: vec![],
+                                comments: RequirementComments::default(),
+                                prerelease: false,
                             })
                             .collect(),
                         constraints: vec![],
+                        pinned_constraints: vec![],
+                        constraint_annotations: FxHashMap::default(),
                         overrides: vec![],
                         editables: vec![],
+                        editable_vcs: vec![],
                         source_trees: vec![],
                         extras: project.used_extras,
+                        defined_extras: project.defined_extras,
                         index_url: None,
                         extra_index_urls: vec![],
                         no_index: false,
                         find_links: vec![],
                         no_binary: NoBinary::default(),
                         no_build: NoBuild::default(),
+                        requires_python: vec![],
+                        provenance,
                     }
                 } else {
                     let path = fs_err::canonicalize(path)?;
@@ -179,16 +288,22 @@ impl RequirementsSpecification {
                         project: None,
                         requirements: vec![],
                         constraints: vec![],
+                        pinned_constraints: vec![],
+                        constraint_annotations: FxHashMap::default(),
                         overrides: vec![],
                         editables: vec![],
+                        editable_vcs: vec![],
                         source_trees: vec![source_tree.to_path_buf()],
                         extras: FxHashSet::default(),
+                        defined_extras: FxHashSet::default(),
                         index_url: None,
                         extra_index_urls: vec![],
                         no_index: false,
                         find_links: vec![],
                         no_binary: NoBinary::default(),
                         no_build: NoBuild::default(),
+                        requires_python: vec![],
+                        provenance: RequirementsProvenance::default(),
                     }
                 }
             }
@@ -204,16 +319,69 @@ impl RequirementsSpecification {
                     project: None,
                     requirements: vec![],
                     constraints: vec![],
+                    pinned_constraints: vec![],
+                    constraint_annotations: FxHashMap::default(),
                     overrides: vec![],
                     editables: vec![],
+                    editable_vcs: vec![],
                     source_trees: vec![source_tree.to_path_buf()],
                     extras: FxHashSet::default(),
+                    defined_extras: FxHashSet::default(),
+                    index_url: None,
+                    extra_index_urls: vec![],
+                    no_index: false,
+                    find_links: vec![],
+                    no_binary: NoBinary::default(),
+                    no_build: NoBuild::default(),
+                    requires_python: vec![],
+                    provenance: RequirementsProvenance::default(),
+                }
+            }
+            RequirementsSource::Pep723Script(path) => {
+                let contents = uv_fs::read_to_string(path).await?;
+                let metadata = Pep723Metadata::parse(&contents)
+                    .with_context(|| format!("Failed to parse `{}`", path.user_display()))?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "The script `{}` does not contain a PEP 723 `script` metadata block",
+                            path.user_display()
+                        )
+                    })?;
+                let requirements = metadata
+                    .requirements
+                    .into_iter()
+                    .map(|requirement| RequirementEntry {
+                        requirement: RequirementsTxtRequirement::Pep508(requirement),
+                        hashes: vec![],
+                        comments: RequirementComments::default(),
+                        prerelease: false,
+                    })
+                    .collect::<Vec<_>>();
+                let provenance = RequirementsProvenance::from_entries(source, &requirements, &[]);
+                Self {
+                    project: None,
+                    requirements,
+                    constraints: vec![],
+                    pinned_constraints: vec![],
+                    constraint_annotations: FxHashMap::default(),
+                    overrides: vec![],
+                    editables: vec![],
+                    editable_vcs: vec![],
+                    source_trees: vec![],
+                    extras: FxHashSet::default(),
+                    defined_extras: FxHashSet::default(),
                     index_url: None,
                     extra_index_urls: vec![],
                     no_index: false,
                     find_links: vec![],
                     no_binary: NoBinary::default(),
                     no_build: NoBuild::default(),
+                    requires_python: metadata
+                        .requires_python
+                        .map(|requires_python| (path.clone(), requires_python))
+                        .into_iter()
+                        .collect(),
+                    provenance,
                 }
             }
         })
@@ -238,8 +406,12 @@ impl RequirementsSpecification {
             spec.constraints.extend(source.constraints);
             spec.overrides.extend(source.overrides);
             spec.extras.extend(source.extras);
+            spec.defined_extras.extend(source.defined_extras);
             spec.editables.extend(source.editables);
+            spec.editable_vcs.extend(source.editable_vcs);
             spec.source_trees.extend(source.source_trees);
+            spec.requires_python.extend(source.requires_python);
+            spec.provenance.extend(source.provenance);
 
             // Use the first project name discovered.
             if spec.project.is_none() {
@@ -263,13 +435,67 @@ impl RequirementsSpecification {
             spec.no_build.extend(source.no_build);
         }
 
+        // Reject any local projects that are requested with extras they declare as conflicting.
+        for entry in &spec.requirements {
+            let (url, extras) = match &entry.requirement {
+                RequirementsTxtRequirement::Pep508(Requirement {
+                    version_or_url: Some(VersionOrUrl::Url(url)),
+                    extras,
+                    ..
+                }) => (url, extras),
+                RequirementsTxtRequirement::Unnamed(UnnamedRequirement { url, extras, .. }) => {
+                    (url, extras)
+                }
+                RequirementsTxtRequirement::Pep508(_) => continue,
+            };
+            if let Ok(path) = url.to_file_path() {
+                validate_local_conflicts(&path, extras).await?;
+            }
+        }
+        for editable in &spec.editables {
+            validate_local_conflicts(&editable.path, &editable.extras).await?;
+        }
+
         // Read all constraints, treating both requirements _and_ constraints as constraints.
-        // Overrides are ignored, as are the hashes, as they are not relevant for constraints.
+        // Overrides are ignored. Hashes are retained for pinned constraints, such that a
+        // resolution layered on top of a compiled file reuses the same hashes.
         for source in constraints {
+            // Retain the `# via` annotations of any compiled constraints files, such that they
+            // can be carried into the output of a layered resolution.
+            if let RequirementsSource::RequirementsTxt(path) = source {
+                if let Ok(contents) = uv_fs::read_to_string(path).await {
+                    for (name, via) in read_via_annotations(&contents) {
+                        spec.constraint_annotations
+                            .entry(name)
+                            .or_default()
+                            .extend(via);
+                    }
+                }
+            }
+
             let source = Self::from_source(source, extras, client_builder).await?;
+            spec.provenance
+                .extend(source.provenance.map_origins(|origin| match origin {
+                    RequirementOrigin::Requirement(source)
+                    | RequirementOrigin::Extra(source, _)
+                    | RequirementOrigin::Constraint(source) => {
+                        Some(RequirementOrigin::Constraint(source))
+                    }
+                    RequirementOrigin::Override(_) => None,
+                }));
             for entry in source.requirements {
                 match entry.requirement {
                     RequirementsTxtRequirement::Pep508(requirement) => {
+                        if !entry.hashes.is_empty() {
+                            spec.pinned_constraints.push(RequirementEntry {
+                                requirement: RequirementsTxtRequirement::Pep508(
+                                    requirement.clone(),
+                                ),
+                                hashes: entry.hashes,
+                                comments: entry.comments,
+                                prerelease: entry.prerelease,
+                            });
+                        }
                         spec.constraints.push(requirement);
                     }
                     RequirementsTxtRequirement::Unnamed(requirement) => {
@@ -302,6 +528,15 @@ impl RequirementsSpecification {
         // Constraints are ignored.
         for source in overrides {
             let source = Self::from_source(source, extras, client_builder).await?;
+            spec.provenance
+                .extend(source.provenance.map_origins(|origin| match origin {
+                    RequirementOrigin::Requirement(source)
+                    | RequirementOrigin::Extra(source, _)
+                    | RequirementOrigin::Override(source) => {
+                        Some(RequirementOrigin::Override(source))
+                    }
+                    RequirementOrigin::Constraint(_) => None,
+                }));
             spec.overrides.extend(source.requirements);
             spec.overrides.extend(source.overrides);
 
@@ -325,6 +560,23 @@ impl RequirementsSpecification {
         Ok(spec)
     }
 
+    /// Read the constraints from a set of sources, e.g., to constrain the build requirements of
+    /// source distributions.
+    pub async fn from_constraint_sources(
+        constraints: &[RequirementsSource],
+        client_builder: &BaseClientBuilder<'_>,
+    ) -> Result<Vec<Requirement>> {
+        Ok(Self::from_sources(
+            &[],
+            constraints,
+            &[],
+            &ExtrasSpecification::None,
+            client_builder,
+        )
+        .await?
+        .constraints)
+    }
+
     /// Read the requirements from a set of sources.
     pub async fn from_simple_sources(
         requirements: &[RequirementsSource],
@@ -340,3 +592,146 @@ impl RequirementsSpecification {
         .await
     }
 }
+
+/// Parse a package provided on the command line (e.g., `flask`, `.[dev]`, or
+/// `dist/example-0.1.0-py3-none-any.whl`).
+///
+/// Local paths, optionally followed by a list of extras, are parsed as unnamed requirements, even
+/// if they'd otherwise be valid PEP 508 names (as in `example-0.1.0.tar.gz`). If the path exists
+/// as given, any trailing brackets are considered part of the path rather than a list of extras,
+/// as with a directory named `project[v2]`.
+fn parse_package(
+    name: &str,
+    working_dir: &Path,
+) -> Result<RequirementsTxtRequirement, Pep508Error> {
+    if !looks_like_path(name) {
+        return RequirementsTxtRequirement::parse(name, working_dir);
+    }
+
+    if working_dir.join(name).exists() {
+        return Ok(RequirementsTxtRequirement::Unnamed(UnnamedRequirement {
+            url: VerbatimUrl::parse_path(name, working_dir).with_given(name.to_string()),
+            extras: vec![],
+            marker: None,
+        }));
+    }
+
+    Ok(RequirementsTxtRequirement::Unnamed(
+        UnnamedRequirement::parse(name, working_dir)?,
+    ))
+}
+
+/// Returns `true` if a package provided on the command line refers to a local path, ignoring
+/// any trailing list of extras.
+fn looks_like_path(name: &str) -> bool {
+    let path = split_extras(name).map_or(name, |(path, _)| path);
+
+    // Ex) `/home/ferris/project` or `C:\Users\ferris\project`
+    if Path::new(path).is_absolute() {
+        return true;
+    }
+
+    // Ex) `flask @ https://...` or `flask; python_version >= "3.8"`
+    if path.contains(['@', ';']) || path.contains("://") {
+        return false;
+    }
+
+    // Ex) `.`, `./project`, or `..\project`
+    if path.starts_with('.') || path.contains('/') || path.contains(std::path::MAIN_SEPARATOR) {
+        return true;
+    }
+
+    // Ex) `example-0.1.0-py3-none-any.whl` or `example-0.1.0.tar.gz`
+    path.ends_with(".whl") || SourceDistExtension::from_filename(path).is_some()
+}
+
+/// Validate that the requested extras don't include any pair that the `pyproject.toml` declares
+/// as conflicting via `tool.uv.conflicts`.
+fn validate_conflicts(
+    pyproject: &PyProjectToml,
+    path: &Path,
+    requested: impl Fn(&ExtraName) -> bool,
+) -> Result<()> {
+    if let Some((first, second)) = pyproject.conflicting_extras(requested) {
+        return Err(anyhow::anyhow!(
+            "Extras `{first}` and `{second}` are declared as conflicting in `{}`, and cannot be requested together",
+            path.user_display()
+        ));
+    }
+    Ok(())
+}
+
+/// Validate that the extras requested of a local project don't include any pair that the
+/// project's `pyproject.toml` declares as conflicting.
+async fn validate_local_conflicts(source_tree: &Path, extras: &[ExtraName]) -> Result<()> {
+    if extras.len() < 2 {
+        return Ok(());
+    }
+    let path = source_tree.join("pyproject.toml");
+    let Ok(contents) = uv_fs::read_to_string(&path).await else {
+        return Ok(());
+    };
+    let Ok(pyproject) = toml::from_str::<PyProjectToml>(&contents) else {
+        return Ok(());
+    };
+    validate_conflicts(&pyproject, &path, |extra| extras.contains(extra))
+}
+
+/// Read the `# via` annotations from a compiled `requirements.txt` (as written by `pip compile`),
+/// keyed by package.
+///
+/// Both the line style (e.g., `flask==3.0.0  # via -r requirements.in`) and the split style (i.e.,
+/// an indented `# via` comment, or `# via` block, following the requirement) are supported.
+fn read_via_annotations(contents: &str) -> FxHashMap<PackageName, Vec<String>> {
+    let mut annotations = FxHashMap::<PackageName, Vec<String>>::default();
+    let mut current = None;
+    let mut in_block = false;
+    for line in contents.lines() {
+        let trimmed = line.trim_start();
+
+        // Ex) `flask==3.0.0`, or `flask==3.0.0  # via -r requirements.in`
+        if trimmed.len() == line.len() {
+            in_block = false;
+            current = None;
+            if trimmed.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+                let end = trimmed
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+                    .unwrap_or(trimmed.len());
+                current = PackageName::new(trimmed[..end].to_string()).ok();
+                if let (Some(name), Some((_, via))) = (&current, trimmed.split_once("# via ")) {
+                    annotations
+                        .entry(name.clone())
+                        .or_default()
+                        .extend(via.split(',').map(|via| via.trim().to_string()));
+                }
+            }
+            continue;
+        }
+
+        let Some(name) = &current else {
+            continue;
+        };
+        let Some(comment) = trimmed.strip_prefix('#').map(str::trim) else {
+            in_block = false;
+            continue;
+        };
+
+        if comment == "via" {
+            // Ex) `    # via`, followed by `    #   flask`
+            in_block = true;
+        } else if let Some(via) = comment.strip_prefix("via ") {
+            // Ex) `    # via flask, jinja2`
+            in_block = false;
+            annotations
+                .entry(name.clone())
+                .or_default()
+                .extend(via.split(',').map(|via| via.trim().to_string()));
+        } else if in_block && !comment.is_empty() {
+            annotations
+                .entry(name.clone())
+                .or_default()
+                .push(comment.to_string());
+        }
+    }
+    annotations
+}
@@ -42,7 +42,7 @@ pub async fn read_lockfile(
         // Ignore pinned versions for the specified packages.
         Upgrade::Packages(packages) => preferences
             .into_iter()
-            .filter(|preference| !packages.contains(preference.name()))
+            .filter(|preference| !packages.contains_key(preference.name()))
             .collect(),
     })
 }
@@ -1,4 +1,5 @@
 use pubgrub::range::Range;
+use rustc_hash::FxHashSet;
 
 use distribution_types::{CompatibleDist, IncompatibleDist, IncompatibleSource};
 use distribution_types::{DistributionMetadata, IncompatibleWheel, Name, PrioritizedDist};
@@ -18,18 +19,20 @@ use crate::{Exclusions, Manifest, Options};
 pub(crate) struct CandidateSelector {
     resolution_strategy: ResolutionStrategy,
     prerelease_strategy: PreReleaseStrategy,
+    prerelease_packages: FxHashSet<PackageName>,
 }
 
 impl CandidateSelector {
     /// Return a [`CandidateSelector`] for the given [`Manifest`].
     pub(crate) fn for_resolution(
-        options: Options,
+        options: &Options,
         manifest: &Manifest,
         markers: &MarkerEnvironment,
     ) -> Self {
         Self {
             resolution_strategy: ResolutionStrategy::from_mode(
                 options.resolution_mode,
+                &options.resolution_exclude,
                 manifest,
                 markers,
             ),
@@ -38,6 +41,7 @@ impl CandidateSelector {
                 manifest,
                 markers,
             ),
+            prerelease_packages: options.prerelease_packages.iter().cloned().collect(),
         }
     }
 
@@ -52,6 +56,13 @@ impl CandidateSelector {
     pub(crate) fn prerelease_strategy(&self) -> &PreReleaseStrategy {
         &self.prerelease_strategy
     }
+
+    /// Returns `true` if a [`PackageName`] is allowed to have pre-release versions, either via the
+    /// [`PreReleaseStrategy`] or an explicit per-package override.
+    pub(crate) fn allows_prerelease(&self, package_name: &PackageName) -> bool {
+        self.prerelease_packages.contains(package_name)
+            || self.prerelease_strategy.allows(package_name)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -170,6 +181,11 @@ impl CandidateSelector {
 
     /// Determine the appropriate prerelease strategy for the current package.
     fn allow_prereleases(&self, package_name: &PackageName) -> AllowPreRelease {
+        // Per-package overrides take precedence over the global strategy.
+        if self.prerelease_packages.contains(package_name) {
+            return AllowPreRelease::Yes;
+        }
+
         match &self.prerelease_strategy {
             PreReleaseStrategy::Disallow => AllowPreRelease::No,
             PreReleaseStrategy::Allow => AllowPreRelease::Yes,
@@ -229,7 +245,7 @@ impl CandidateSelector {
     pub(crate) fn use_highest_version(&self, package_name: &PackageName) -> bool {
         match &self.resolution_strategy {
             ResolutionStrategy::Highest => true,
-            ResolutionStrategy::Lowest => false,
+            ResolutionStrategy::Lowest(exclude) => exclude.contains(package_name),
             ResolutionStrategy::LowestDirect(direct_dependencies) => {
                 !direct_dependencies.contains(package_name)
             }
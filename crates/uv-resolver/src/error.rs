@@ -18,6 +18,7 @@ use uv_normalize::PackageName;
 
 use crate::candidate_selector::CandidateSelector;
 use crate::dependency_provider::UvDependencyProvider;
+use crate::explain::ExplainedRequirement;
 use crate::pubgrub::{PubGrubPackage, PubGrubPython, PubGrubReportFormatter};
 use crate::python_requirement::PythonRequirement;
 use crate::resolver::{IncompletePackage, UnavailablePackage, VersionsResponse};
@@ -182,6 +183,28 @@ impl std::fmt::Display for NoSolutionError {
 }
 
 impl NoSolutionError {
+    /// Render the full derivation tree that led to the resolution failure.
+    ///
+    /// Unlike the [`std::fmt::Display`] implementation, which summarizes the conflict, this
+    /// includes every incompatibility considered by the resolver.
+    pub fn derivation_tree_report(&self) -> String {
+        let formatter = PubGrubReportFormatter {
+            available_versions: &self.available_versions,
+            python_requirement: self.python_requirement.as_ref(),
+        };
+        formatter.format_derivation_tree(&self.derivation_tree)
+    }
+
+    /// Return the chain of requirements that constrained the given package, as recorded in the
+    /// derivation tree.
+    pub fn explain(&self, name: &PackageName) -> Vec<ExplainedRequirement> {
+        let formatter = PubGrubReportFormatter {
+            available_versions: &self.available_versions,
+            python_requirement: self.python_requirement.as_ref(),
+        };
+        formatter.explain(&self.derivation_tree, name)
+    }
+
     /// Update the available versions attached to the error using the given package version index.
     ///
     /// Only packages used in the error's derivation tree will be retrieved.
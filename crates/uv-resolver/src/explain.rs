@@ -0,0 +1,29 @@
+use uv_normalize::PackageName;
+
+/// A requirement that constrained a package during resolution, along with the chain of
+/// requirements that introduced it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplainedRequirement {
+    /// The name of the constrained package.
+    pub name: PackageName,
+    /// The requirement imposed on the package (e.g., `foo>=2.0`).
+    pub requirement: String,
+    /// The origin of the requirement.
+    pub required_by: RequiredBy,
+}
+
+/// The origin of an [`ExplainedRequirement`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequiredBy {
+    /// The requirement was provided directly by the user (e.g., via a requirements file).
+    User,
+    /// The requirement was declared by another package.
+    Package {
+        /// The name of the dependent package.
+        name: PackageName,
+        /// The versions of the dependent package that declared the requirement (e.g., `bar==1.0`).
+        dependent: String,
+        /// The requirements that, in turn, constrained the dependent package.
+        via: Vec<ExplainedRequirement>,
+    },
+}
@@ -11,7 +11,7 @@ use distribution_types::{
     WheelCompatibility,
 };
 use pep440_rs::Version;
-use platform_tags::{TagCompatibility, Tags};
+use platform_tags::{IncompatibleTag, TagCompatibility, Tags};
 use pypi_types::HashDigest;
 use uv_client::FlatIndexEntries;
 use uv_configuration::{NoBinary, NoBuild};
@@ -167,6 +167,14 @@ impl FlatIndex {
 
         // Determine a compatibility for the wheel based on tags.
         let priority = match filename.compatibility(tags) {
+            // If the package allows additional platform tags, list them as part of the error.
+            TagCompatibility::Incompatible(IncompatibleTag::Platform)
+                if !tags.package_platforms(&filename.name).is_empty() =>
+            {
+                return WheelCompatibility::Incompatible(IncompatibleWheel::AllowedPlatformTag(
+                    tags.package_platforms(&filename.name).to_vec(),
+                ));
+            }
             TagCompatibility::Incompatible(tag) => {
                 return WheelCompatibility::Incompatible(IncompatibleWheel::Tag(tag))
             }
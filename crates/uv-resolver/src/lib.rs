@@ -2,6 +2,7 @@ pub use dependency_mode::DependencyMode;
 pub use error::ResolveError;
 pub use exclude_newer::ExcludeNewer;
 pub use exclusions::Exclusions;
+pub use explain::{ExplainedRequirement, RequiredBy};
 pub use flat_index::FlatIndex;
 pub use manifest::Manifest;
 pub use options::{Options, OptionsBuilder};
@@ -27,6 +28,7 @@ mod editables;
 mod error;
 mod exclude_newer;
 mod exclusions;
+mod explain;
 mod flat_index;
 mod manifest;
 mod options;
@@ -1,10 +1,14 @@
+use uv_normalize::PackageName;
+
 use crate::{DependencyMode, ExcludeNewer, PreReleaseMode, ResolutionMode};
 
 /// Options for resolving a manifest.
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct Options {
     pub resolution_mode: ResolutionMode,
+    pub resolution_exclude: Vec<PackageName>,
     pub prerelease_mode: PreReleaseMode,
+    pub prerelease_packages: Vec<PackageName>,
     pub dependency_mode: DependencyMode,
     pub exclude_newer: Option<ExcludeNewer>,
 }
@@ -13,7 +17,9 @@ pub struct Options {
 #[derive(Debug, Default, Clone)]
 pub struct OptionsBuilder {
     resolution_mode: ResolutionMode,
+    resolution_exclude: Vec<PackageName>,
     prerelease_mode: PreReleaseMode,
+    prerelease_packages: Vec<PackageName>,
     dependency_mode: DependencyMode,
     exclude_newer: Option<ExcludeNewer>,
 }
@@ -31,6 +37,14 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the packages to exclude from the [`ResolutionMode`], which will instead resolve to
+    /// their highest compatible version.
+    #[must_use]
+    pub fn resolution_exclude(mut self, resolution_exclude: Vec<PackageName>) -> Self {
+        self.resolution_exclude = resolution_exclude;
+        self
+    }
+
     /// Sets the [`PreReleaseMode`].
     #[must_use]
     pub fn prerelease_mode(mut self, prerelease_mode: PreReleaseMode) -> Self {
@@ -38,6 +52,14 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the packages for which pre-release versions are allowed, regardless of the
+    /// [`PreReleaseMode`].
+    #[must_use]
+    pub fn prerelease_packages(mut self, prerelease_packages: Vec<PackageName>) -> Self {
+        self.prerelease_packages = prerelease_packages;
+        self
+    }
+
     /// Sets the dependency mode.
     #[must_use]
     pub fn dependency_mode(mut self, dependency_mode: DependencyMode) -> Self {
@@ -56,7 +78,9 @@ impl OptionsBuilder {
     pub fn build(self) -> Options {
         Options {
             resolution_mode: self.resolution_mode,
+            resolution_exclude: self.resolution_exclude,
             prerelease_mode: self.prerelease_mode,
+            prerelease_packages: self.prerelease_packages,
             dependency_mode: self.dependency_mode,
             exclude_newer: self.exclude_newer,
         }
@@ -1,5 +1,6 @@
 use itertools::Itertools;
 use pubgrub::range::Range;
+use rustc_hash::FxHashSet;
 use tracing::warn;
 
 use distribution_types::Verbatim;
@@ -31,17 +32,9 @@ impl PubGrubDependencies {
     ) -> Result<Self, ResolveError> {
         let mut dependencies = Vec::default();
 
-        // Iterate over all declared requirements.
-        for requirement in overrides.apply(requirements) {
-            // If the requirement isn't relevant for the current platform, skip it.
-            if let Some(extra) = source_extra {
-                if !requirement.evaluate_markers(env, std::slice::from_ref(extra)) {
-                    continue;
-                }
-            } else if !requirement.evaluate_markers(env, &[]) {
-                continue;
-            }
-
+        // Iterate over all declared requirements, flattening any self-referential extras.
+        let requirements = overrides.apply(requirements).collect::<Vec<_>>();
+        for requirement in flatten_requirements(&requirements, source_name, source_extra, env) {
             // Add the package, plus any extra variants.
             for result in std::iter::once(to_pubgrub(requirement, None, urls, locals)).chain(
                 requirement
@@ -117,6 +110,70 @@ impl PubGrubDependencies {
     }
 }
 
+/// Filter the requirements of a package down to those that are relevant for the current
+/// platform and the given extra, flattening any references from the package to its own extras.
+///
+/// For example, given a package `my-project` with the extras:
+/// ```toml
+/// [project.optional-dependencies]
+/// test = [
+///     "pep517",
+/// ]
+/// dev = [
+///     "my-project[test]",
+/// ]
+/// ```
+///
+/// The requirements of `my-project[dev]` include `pep517` directly, in addition to the dependency
+/// on `my-project[test]`. This mirrors the flattening applied to `pyproject.toml` extras.
+fn flatten_requirements<'a>(
+    requirements: &[&'a Requirement],
+    source_name: Option<&PackageName>,
+    source_extra: Option<&ExtraName>,
+    env: &MarkerEnvironment,
+) -> Vec<&'a Requirement> {
+    let Some(source_extra) = source_extra else {
+        return requirements
+            .iter()
+            .copied()
+            .filter(|requirement| requirement.evaluate_markers(env, &[]))
+            .collect();
+    };
+
+    let mut flattened = Vec::with_capacity(requirements.len());
+    let mut included = FxHashSet::default();
+    let mut seen = FxHashSet::from_iter([source_extra.clone()]);
+    let mut stack = vec![source_extra.clone()];
+    while let Some(extra) = stack.pop() {
+        for (index, requirement) in requirements.iter().enumerate() {
+            // If the requirement isn't relevant for the current platform, skip it.
+            if !requirement.evaluate_markers(env, std::slice::from_ref(&extra)) {
+                continue;
+            }
+
+            if source_name.is_some_and(|source_name| *source_name == requirement.name) {
+                // Flatten the referenced extras, avoiding infinite recursion on mutually recursive
+                // extras.
+                for other_extra in &requirement.extras {
+                    if seen.insert(other_extra.clone()) {
+                        stack.push(other_extra.clone());
+                    }
+                }
+
+                // Only retain the self-dependencies declared by the requested extra itself.
+                if extra != *source_extra {
+                    continue;
+                }
+            }
+
+            if included.insert(index) {
+                flattened.push(*requirement);
+            }
+        }
+    }
+    flattened
+}
+
 /// Convert a [`PubGrubDependencies`] to a [`DependencyConstraints`].
 impl From<PubGrubDependencies> for Vec<(PubGrubPackage, Range<Version>)> {
     fn from(dependencies: PubGrubDependencies) -> Self {
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
 use std::ops::Bound;
 
 use derivative::Derivative;
@@ -12,10 +13,11 @@ use pubgrub::range::Range;
 use pubgrub::report::{DerivationTree, Derived, External, ReportFormatter};
 use pubgrub::term::Term;
 use pubgrub::type_aliases::Map;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use uv_normalize::PackageName;
 
 use crate::candidate_selector::CandidateSelector;
+use crate::explain::{ExplainedRequirement, RequiredBy};
 use crate::python_requirement::PythonRequirement;
 use crate::resolver::{IncompletePackage, UnavailablePackage};
 
@@ -349,7 +351,7 @@ impl PubGrubReportFormatter<'_> {
             let PubGrubPackage::Package(package, ..) = package else {
                 return false;
             };
-            selector.prerelease_strategy().allows(package)
+            selector.allows_prerelease(package)
         }
 
         let mut hints = IndexSet::default();
@@ -494,6 +496,129 @@ impl PubGrubReportFormatter<'_> {
         }
         hints
     }
+
+    /// Render the full derivation tree, with each incompatibility on its own line and the causes
+    /// of each derived incompatibility indented beneath it.
+    pub(crate) fn format_derivation_tree(
+        &self,
+        derivation_tree: &DerivationTree<PubGrubPackage, Range<Version>>,
+    ) -> String {
+        fn write_tree(
+            formatter: &PubGrubReportFormatter<'_>,
+            derivation_tree: &DerivationTree<PubGrubPackage, Range<Version>>,
+            depth: usize,
+            output: &mut String,
+        ) {
+            let indent = "  ".repeat(depth);
+            match derivation_tree {
+                DerivationTree::External(external) => {
+                    let _ = writeln!(output, "{indent}{}", formatter.format_external(external));
+                }
+                DerivationTree::Derived(derived) => {
+                    let _ = writeln!(output, "{indent}{}", formatter.format_terms(&derived.terms));
+                    write_tree(formatter, &derived.cause1, depth + 1, output);
+                    write_tree(formatter, &derived.cause2, depth + 1, output);
+                }
+            }
+        }
+
+        let mut output = String::new();
+        write_tree(self, derivation_tree, 0, &mut output);
+        output
+    }
+
+    /// Collect the chain of requirements that constrained the given package, as recorded in the
+    /// derivation tree.
+    pub(crate) fn explain(
+        &self,
+        derivation_tree: &DerivationTree<PubGrubPackage, Range<Version>>,
+        name: &PackageName,
+    ) -> Vec<ExplainedRequirement> {
+        /// Collect every dependency incompatibility in the tree.
+        fn collect<'a>(
+            derivation_tree: &'a DerivationTree<PubGrubPackage, Range<Version>>,
+            dependencies: &mut Vec<&'a External<PubGrubPackage, Range<Version>>>,
+        ) {
+            match derivation_tree {
+                DerivationTree::External(external @ External::FromDependencyOf(..)) => {
+                    dependencies.push(external);
+                }
+                DerivationTree::External(_) => {}
+                DerivationTree::Derived(derived) => {
+                    collect(&derived.cause1, dependencies);
+                    collect(&derived.cause2, dependencies);
+                }
+            }
+        }
+
+        let mut dependencies = Vec::new();
+        collect(derivation_tree, &mut dependencies);
+        self.explain_dependencies(&dependencies, name, &mut FxHashSet::default())
+    }
+
+    /// Explain the requirements on the given package, following the dependents back to the root.
+    fn explain_dependencies(
+        &self,
+        dependencies: &[&External<PubGrubPackage, Range<Version>>],
+        name: &PackageName,
+        visited: &mut FxHashSet<PackageName>,
+    ) -> Vec<ExplainedRequirement> {
+        // Avoid looping on dependency cycles.
+        if !visited.insert(name.clone()) {
+            return Vec::new();
+        }
+
+        let mut explained = Vec::new();
+        for external in dependencies {
+            let External::FromDependencyOf(package, package_set, dependency, dependency_set) =
+                external
+            else {
+                continue;
+            };
+            let PubGrubPackage::Package(dependency_name, ..) = dependency else {
+                continue;
+            };
+            if dependency_name != name {
+                continue;
+            }
+
+            let required_by = match package {
+                PubGrubPackage::Root(_) => RequiredBy::User,
+                PubGrubPackage::Python(_) => continue,
+                PubGrubPackage::Package(package_name, ..) => {
+                    // Skip the dependency of an extra on its base package.
+                    if package_name == name {
+                        continue;
+                    }
+                    let package_set = self.simplify_set(package_set, package);
+                    RequiredBy::Package {
+                        name: package_name.clone(),
+                        dependent: PackageRange::compatibility(package, &package_set).to_string(),
+                        via: self.explain_dependencies(dependencies, package_name, visited),
+                    }
+                }
+            };
+
+            // The same incompatibility can appear in multiple branches of the tree.
+            let dependency_set = self.simplify_set(dependency_set, dependency);
+            let requirement = ExplainedRequirement {
+                name: name.clone(),
+                requirement: PackageRange::dependency(dependency, &dependency_set).to_string(),
+                required_by,
+            };
+            if !explained.contains(&requirement) {
+                explained.push(requirement);
+            }
+        }
+
+        visited.remove(name);
+        explained
+    }
+}
+
+/// Format a requirement on the given package (e.g., `foo>=1.0,<2.0`).
+pub(crate) fn format_requirement(package: &PubGrubPackage, range: &Range<Version>) -> String {
+    PackageRange::dependency(package, range).to_string()
 }
 
 #[derive(Derivative, Debug, Clone)]
@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::hash::BuildHasherDefault;
 
 use anyhow::Result;
+use chrono::DateTime;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use petgraph::visit::EdgeRef;
@@ -10,23 +11,26 @@ use pubgrub::range::Range;
 use pubgrub::solver::{Kind, State};
 use pubgrub::type_aliases::SelectedDependencies;
 use rustc_hash::{FxHashMap, FxHashSet};
+use url::Url;
 
 use distribution_types::{
-    Dist, DistributionMetadata, IndexUrl, LocalEditable, Name, ResolvedDist, Verbatim, VersionId,
-    VersionOrUrl,
+    Dist, DistributionMetadata, File, IndexUrl, LocalEditable, Name, ResolvedDist, Verbatim,
+    VersionId, VersionOrUrl,
 };
 use once_map::OnceMap;
 use pep440_rs::Version;
 use pep508_rs::MarkerEnvironment;
 use pypi_types::HashDigest;
+use requirements_txt::RequirementComments;
 use uv_distribution::to_precise;
 use uv_normalize::{ExtraName, PackageName};
 
 use crate::dependency_provider::UvDependencyProvider;
 use crate::editables::Editables;
+use crate::explain::{ExplainedRequirement, RequiredBy};
 use crate::pins::FilePins;
 use crate::preferences::Preferences;
-use crate::pubgrub::{PubGrubDistribution, PubGrubPackage};
+use crate::pubgrub::{format_requirement, PubGrubDistribution, PubGrubPackage};
 use crate::redirect::apply_redirect;
 use crate::resolver::{InMemoryIndex, MetadataResponse, VersionsResponse};
 use crate::{Manifest, ResolveError};
@@ -356,6 +360,74 @@ impl ResolutionGraph {
         &self.petgraph
     }
 
+    /// Return the chain of requirements that constrained the given package in this resolution.
+    ///
+    /// Requirements provided directly by the user are not tracked in the graph, so only the
+    /// requirements declared by other packages are included.
+    pub fn explain(&self, name: &PackageName) -> Vec<ExplainedRequirement> {
+        let mut visited = FxHashSet::default();
+        self.petgraph
+            .node_indices()
+            .filter(|index| self.petgraph[*index].name() == name)
+            .flat_map(|index| self.explain_node(index, &mut visited))
+            .collect()
+    }
+
+    /// Return the packages in this resolution whose requirements on the given package exclude the
+    /// given version (e.g., a version pinned by a previous resolution).
+    pub fn incompatible_dependents(
+        &self,
+        name: &PackageName,
+        version: &Version,
+    ) -> Vec<&PackageName> {
+        let mut dependents = self
+            .petgraph
+            .node_indices()
+            .filter(|index| self.petgraph[*index].name() == name)
+            .flat_map(|index| self.petgraph.edges_directed(index, Direction::Incoming))
+            .filter(|edge| !edge.weight().contains(version))
+            .map(|edge| self.petgraph[edge.source()].name())
+            .collect::<Vec<_>>();
+        dependents.sort_unstable();
+        dependents.dedup();
+        dependents
+    }
+
+    /// Explain the incoming edges of a node, following the dependents back to the roots.
+    fn explain_node(
+        &self,
+        index: petgraph::graph::NodeIndex,
+        visited: &mut FxHashSet<petgraph::graph::NodeIndex>,
+    ) -> Vec<ExplainedRequirement> {
+        // Avoid looping on dependency cycles.
+        if !visited.insert(index) {
+            return Vec::new();
+        }
+
+        let name = self.petgraph[index].name();
+        let package = PubGrubPackage::Package(name.clone(), None, None);
+        let mut explained = Vec::new();
+        for edge in self
+            .petgraph
+            .edges_directed(index, Direction::Incoming)
+            .collect::<Vec<_>>()
+        {
+            let dependent = &self.petgraph[edge.source()];
+            explained.push(ExplainedRequirement {
+                name: name.clone(),
+                requirement: format_requirement(&package, edge.weight()),
+                required_by: RequiredBy::Package {
+                    name: dependent.name().clone(),
+                    dependent: dependent.to_string(),
+                    via: self.explain_node(edge.source(), visited),
+                },
+            });
+        }
+
+        visited.remove(&index);
+        explained
+    }
+
     /// Return the marker tree specific to this resolution.
     ///
     /// This accepts a manifest, in-memory-index and marker environment. All
@@ -509,6 +581,9 @@ pub struct DisplayResolutionGraph<'a> {
     resolution: &'a ResolutionGraph,
     /// The packages to exclude from the output.
     no_emit_packages: &'a [PackageName],
+    /// The packages for which pre-releases were explicitly allowed, to annotate any pre-release
+    /// versions that were selected for them.
+    prerelease_packages: &'a [PackageName],
     /// Whether to include hashes in the output.
     show_hashes: bool,
     /// Whether to include extras in the output (e.g., `black[colorama]`).
@@ -518,9 +593,21 @@ pub struct DisplayResolutionGraph<'a> {
     include_annotations: bool,
     /// Whether to include indexes in the output, to indicate which index was used for each package.
     include_index_annotation: bool,
+    /// Whether to include the origin of each package in the output, i.e., the URL of the selected
+    /// artifact and its upload time.
+    include_origin: bool,
+    /// The URL that each artifact is served from after following any redirects, keyed by the URL
+    /// listed on the index.
+    redirects: FxHashMap<Url, Url>,
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
+    /// The comments attached to each package in the input requirements, to be re-emitted
+    /// alongside the pinned requirement.
+    comments: FxHashMap<PackageName, RequirementComments>,
+    /// The `# via` annotations of each package in any compiled constraints files, to be merged
+    /// into the annotations of the package in the output.
+    constraint_annotations: FxHashMap<PackageName, Vec<String>>,
 }
 
 impl<'a> From<&'a ResolutionGraph> for DisplayResolutionGraph<'a> {
@@ -528,11 +615,16 @@ impl<'a> From<&'a ResolutionGraph> for DisplayResolutionGraph<'a> {
         Self::new(
             resolution,
             &[],
+            &[],
             false,
             false,
             true,
             false,
+            false,
+            FxHashMap::default(),
             AnnotationStyle::default(),
+            FxHashMap::default(),
+            FxHashMap::default(),
         )
     }
 }
@@ -543,20 +635,30 @@ impl<'a> DisplayResolutionGraph<'a> {
     pub fn new(
         underlying: &'a ResolutionGraph,
         no_emit_packages: &'a [PackageName],
+        prerelease_packages: &'a [PackageName],
         show_hashes: bool,
         include_extras: bool,
         include_annotations: bool,
         include_index_annotation: bool,
+        include_origin: bool,
+        redirects: FxHashMap<Url, Url>,
         annotation_style: AnnotationStyle,
+        comments: FxHashMap<PackageName, RequirementComments>,
+        constraint_annotations: FxHashMap<PackageName, Vec<String>>,
     ) -> DisplayResolutionGraph<'a> {
         Self {
             resolution: underlying,
             no_emit_packages,
+            prerelease_packages,
             show_hashes,
             include_extras,
             include_annotations,
             include_index_annotation,
+            include_origin,
+            redirects,
             annotation_style,
+            comments,
+            constraint_annotations,
         }
     }
 }
@@ -601,6 +703,25 @@ impl<'a> Node<'a> {
             Node::Distribution(_, dist, _) => dist.index(),
         }
     }
+
+    /// Return the [`File`] of the selected distribution, if it's from a registry.
+    fn file(&self) -> Option<&File> {
+        match self {
+            Node::Editable(_, _) => None,
+            Node::Distribution(_, dist, _) => dist.file(),
+        }
+    }
+
+    /// Returns `true` if the node is pinned to a pre-release version.
+    fn is_prerelease(&self) -> bool {
+        match self {
+            Node::Editable(_, _) => false,
+            Node::Distribution(_, dist, _) => match dist.version_or_url() {
+                VersionOrUrl::Version(version) => version.any_prerelease(),
+                VersionOrUrl::Url(_) => false,
+            },
+        }
+    }
 }
 
 impl Verbatim for Node<'_> {
@@ -623,6 +744,15 @@ impl Verbatim for Node<'_> {
     }
 }
 
+impl DisplayResolutionGraph<'_> {
+    /// Return the URL that the selected artifact for the given node is served from, following any
+    /// redirects that were resolved ahead of time.
+    fn origin(&self, node: &Node) -> Option<Url> {
+        let url = node.file()?.url.to_url()?;
+        Some(self.redirects.get(&url).cloned().unwrap_or(url))
+    }
+}
+
 /// Write the graph in the `{name}=={version}` format of requirements.txt that pip uses.
 impl std::fmt::Display for DisplayResolutionGraph<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -682,30 +812,45 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 }
             }
 
+            // Re-emit any comments attached to the requirement in the input files.
+            if let Some(comments) = self.comments.get(node.name()) {
+                for comment in &comments.leading {
+                    writeln!(f, "{}", format!("# {comment}").trim_end())?;
+                }
+                if let Some(comment) = &comments.trailing {
+                    line.push_str(format!("  # {comment}").trim_end());
+                }
+            }
+
             // Determine the annotation comment and separator (between comment and requirement).
             let mut annotation = None;
 
             // If enabled, include annotations to indicate the dependencies that requested each
             // package (e.g., `# via mypy`).
             if self.include_annotations {
-                // Display all dependencies.
+                // Display all dependencies, along with the annotations from any compiled
+                // constraints files.
                 let mut edges = self
                     .resolution
                     .petgraph
                     .edges_directed(index, Direction::Incoming)
-                    .map(|edge| &self.resolution.petgraph[edge.source()])
+                    .map(|edge| self.resolution.petgraph[edge.source()].name().to_string())
+                    .chain(
+                        self.constraint_annotations
+                            .get(node.name())
+                            .into_iter()
+                            .flatten()
+                            .cloned(),
+                    )
                     .collect::<Vec<_>>();
-                edges.sort_unstable_by_key(|package| package.name());
+                edges.sort_unstable();
+                edges.dedup();
 
                 match self.annotation_style {
                     AnnotationStyle::Line => {
                         if !edges.is_empty() {
                             let separator = if has_hashes { "\n    " } else { "  " };
-                            let deps = edges
-                                .into_iter()
-                                .map(|dependency| dependency.name().to_string())
-                                .collect::<Vec<_>>()
-                                .join(", ");
+                            let deps = edges.join(", ");
                             let comment = format!("# via {deps}").green().to_string();
                             annotation = Some((separator, comment));
                         }
@@ -714,14 +859,14 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                         [] => {}
                         [edge] => {
                             let separator = "\n";
-                            let comment = format!("    # via {}", edge.name()).green().to_string();
+                            let comment = format!("    # via {edge}").green().to_string();
                             annotation = Some((separator, comment));
                         }
                         edges => {
                             let separator = "\n";
                             let deps = edges
                                 .iter()
-                                .map(|dependency| format!("    #   {}", dependency.name()))
+                                .map(|dependency| format!("    #   {dependency}"))
                                 .collect::<Vec<_>>()
                                 .join("\n");
                             let comment = format!("    # via\n{deps}").green().to_string();
@@ -742,14 +887,55 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 writeln!(f, "{line}")?;
             }
 
-            // If enabled, include indexes to indicate which index was used for each package (e.g.,
-            // `# from https://pypi.org/simple`).
+            // If enabled, include indexes to indicate which index was used for each package, along
+            // with the host that serves the selected artifact (e.g.,
+            // `# from https://pypi.org/simple (files.pythonhosted.org)`).
             if self.include_index_annotation {
                 if let Some(index) = node.index() {
                     let url = index.redacted();
-                    writeln!(f, "{}", format!("    # from {url}").green())?;
+                    let host = self
+                        .origin(&node)
+                        .and_then(|url| url.host_str().map(ToString::to_string));
+                    if let Some(host) = host {
+                        writeln!(f, "{}", format!("    # from {url} ({host})").green())?;
+                    } else {
+                        writeln!(f, "{}", format!("    # from {url}").green())?;
+                    }
+                }
+            }
+
+            // If enabled, include the URL of the selected artifact, along with its upload time, if
+            // known (e.g., `# origin https://files.pythonhosted.org/...`).
+            if self.include_origin {
+                if let Some(file) = node.file() {
+                    if let Some(mut url) = self.origin(&node) {
+                        let _ = url.set_username("");
+                        let _ = url.set_password(None);
+                        writeln!(f, "{}", format!("    # origin {url}").green())?;
+                    }
+                    if let Some(upload_time) = file
+                        .upload_time_utc_ms
+                        .and_then(|ms| DateTime::from_timestamp(ms.div_euclid(1000), 0))
+                    {
+                        let upload_time = upload_time.format("%Y-%m-%dT%H:%M:%SZ");
+                        writeln!(f, "{}", format!("    # uploaded {upload_time}").green())?;
+                    }
                 }
             }
+
+            // If annotations are enabled and a pre-release was selected for a package with an
+            // explicit pre-release override, flag it, such that the pin is easy to spot when
+            // reviewing the output.
+            if self.include_annotations
+                && node.is_prerelease()
+                && self.prerelease_packages.contains(node.name())
+            {
+                writeln!(
+                    f,
+                    "{}",
+                    "    # pre-release allowed by --prerelease-package".green()
+                )?;
+            }
         }
 
         Ok(())
@@ -30,8 +30,9 @@ pub enum ResolutionMode {
 pub(crate) enum ResolutionStrategy {
     /// Resolve the highest compatible version of each package.
     Highest,
-    /// Resolve the lowest compatible version of each package.
-    Lowest,
+    /// Resolve the lowest compatible version of each package, except for the given excluded
+    /// packages, which resolve to their highest compatible version.
+    Lowest(FxHashSet<PackageName>),
     /// Resolve the lowest compatible version of any direct dependencies, and the highest
     /// compatible version of any transitive dependencies.
     LowestDirect(FxHashSet<PackageName>),
@@ -40,15 +41,20 @@ pub(crate) enum ResolutionStrategy {
 impl ResolutionStrategy {
     pub(crate) fn from_mode(
         mode: ResolutionMode,
+        exclude: &[PackageName],
         manifest: &Manifest,
         markers: &MarkerEnvironment,
     ) -> Self {
         match mode {
             ResolutionMode::Highest => Self::Highest,
-            ResolutionMode::Lowest => Self::Lowest,
-            ResolutionMode::LowestDirect => {
-                Self::LowestDirect(manifest.direct_dependencies(markers).cloned().collect())
-            }
+            ResolutionMode::Lowest => Self::Lowest(exclude.iter().cloned().collect()),
+            ResolutionMode::LowestDirect => Self::LowestDirect(
+                manifest
+                    .direct_dependencies(markers)
+                    .filter(|name| !exclude.contains(name))
+                    .cloned()
+                    .collect(),
+            ),
         }
     }
 }
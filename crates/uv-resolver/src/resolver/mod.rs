@@ -208,7 +208,7 @@ impl<
             unavailable_packages: DashMap::default(),
             incomplete_packages: DashMap::default(),
             visited: DashSet::default(),
-            selector: CandidateSelector::for_resolution(options, &manifest, markers),
+            selector: CandidateSelector::for_resolution(&options, &manifest, markers),
             dependency_mode: options.dependency_mode,
             urls: Urls::from_manifest(&manifest, markers)?,
             locals: Locals::from_manifest(&manifest, markers),
@@ -261,8 +261,8 @@ impl<
             Err(err) => {
                 // Add version information to improve unsat error messages.
                 Err(if let ResolveError::NoSolution(err) = err {
-                    ResolveError::NoSolution(
-                        err.with_available_versions(
+                    let err = err
+                        .with_available_versions(
                             &self.python_requirement,
                             &self.visited,
                             &self.index.packages,
@@ -271,8 +271,12 @@ impl<
                         .with_python_requirement(&self.python_requirement)
                         .with_index_locations(self.provider.index_locations())
                         .with_unavailable_packages(&self.unavailable_packages)
-                        .with_incomplete_packages(&self.incomplete_packages),
-                    )
+                        .with_incomplete_packages(&self.incomplete_packages);
+                    debug!(
+                        "Resolution failed with the following derivation tree:\n{}",
+                        err.derivation_tree_report()
+                    );
+                    ResolveError::NoSolution(err)
                 } else {
                     err
                 })
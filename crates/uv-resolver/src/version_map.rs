@@ -11,7 +11,7 @@ use distribution_types::{
     SourceDistCompatibility, WheelCompatibility,
 };
 use pep440_rs::{Version, VersionSpecifiers};
-use platform_tags::{TagCompatibility, Tags};
+use platform_tags::{IncompatibleTag, TagCompatibility, Tags};
 use pypi_types::{HashDigest, Yanked};
 use uv_client::{OwnedArchive, SimpleMetadata, VersionFiles};
 use uv_configuration::{NoBinary, NoBuild};
@@ -530,6 +530,14 @@ impl VersionMapLazy {
 
         // Determine a compatibility for the wheel based on tags.
         let priority = match filename.compatibility(&self.tags) {
+            // If the package allows additional platform tags, list them as part of the error.
+            TagCompatibility::Incompatible(IncompatibleTag::Platform)
+                if !self.tags.package_platforms(&filename.name).is_empty() =>
+            {
+                return WheelCompatibility::Incompatible(IncompatibleWheel::AllowedPlatformTag(
+                    self.tags.package_platforms(&filename.name).to_vec(),
+                ));
+            }
             TagCompatibility::Incompatible(tag) => {
                 return WheelCompatibility::Incompatible(IncompatibleWheel::Tag(tag))
             }
@@ -15,7 +15,9 @@ use pep508_rs::{MarkerEnvironment, Requirement, StringVersion};
 use platform_tags::{Arch, Os, Platform, Tags};
 use uv_cache::Cache;
 use uv_client::RegistryClientBuilder;
-use uv_configuration::{BuildKind, Constraints, NoBinary, NoBuild, Overrides, SetupPyStrategy};
+use uv_configuration::{
+    BuildKind, Constraints, NoBinary, NoBuild, Overrides, PythonPreference, SetupPyStrategy,
+};
 use uv_interpreter::{find_default_python, Interpreter, PythonEnvironment};
 use uv_resolver::{
     DisplayResolutionGraph, ExcludeNewer, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options,
@@ -124,7 +126,8 @@ async fn resolve(
     let index = InMemoryIndex::default();
     // TODO(konstin): Should we also use the bootstrapped pythons here?
     let real_interpreter =
-        find_default_python(&Cache::temp().unwrap()).expect("Expected a python to be installed");
+        find_default_python(None, PythonPreference::default(), &Cache::temp().unwrap())
+            .expect("Expected a python to be installed");
     let interpreter = Interpreter::artificial(real_interpreter.platform().clone(), markers.clone());
     let build_context = DummyContext::new(Cache::temp()?, interpreter.clone());
     let hashes = HashStrategy::None;
@@ -28,6 +28,11 @@ pub struct Toolchain {
 }
 
 impl Toolchain {
+    /// The path to the top-level directory of the installed toolchain.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
     pub fn executable(&self) -> PathBuf {
         if cfg!(windows) {
             self.path.join("install").join("python.exe")
@@ -46,6 +51,26 @@ impl Toolchain {
 /// - The platform metadata cannot be read
 /// - A directory in the toolchain directory cannot be read
 pub fn toolchains_for_version(version: &PythonVersion) -> Result<Vec<Toolchain>, Error> {
+    let prefix = format!("cpython-{version}");
+    Ok(installed_toolchains()?
+        .into_iter()
+        .filter(|toolchain| {
+            toolchain
+                .path
+                .file_name()
+                .map(OsStr::to_string_lossy)
+                .is_some_and(|filename| filename.starts_with(&prefix))
+        })
+        .collect())
+}
+
+/// Return all installed toolchains for this platform, with newer versions of Python first.
+///
+/// ## Errors
+///
+/// - The platform metadata cannot be read
+/// - A directory in the toolchain directory cannot be read
+pub fn installed_toolchains() -> Result<Vec<Toolchain>, Error> {
     let platform_key = platform_key_from_env()?;
 
     // TODO(zanieb): Consider returning an iterator instead of a `Vec`
@@ -53,11 +78,12 @@ pub fn toolchains_for_version(version: &PythonVersion) -> Result<Vec<Toolchain>,
 
     let toolchain_dirs = match fs_err::read_dir(TOOLCHAIN_DIRECTORY.to_path_buf()) {
         Ok(toolchain_dirs) => {
-            // Collect sorted directory paths; `read_dir` is not stable across platforms
+            // Collect sorted directory paths; `read_dir` is not stable across platforms. Follow
+            // symlinks, such that a toolchain can be linked into the directory.
             let directories: BTreeSet<_> = toolchain_dirs
                 .filter_map(|read_dir| match read_dir {
-                    Ok(entry) => match entry.file_type() {
-                        Ok(file_type) => file_type.is_dir().then_some(Ok(entry.path())),
+                    Ok(entry) => match fs_err::metadata(entry.path()) {
+                        Ok(metadata) => metadata.is_dir().then_some(Ok(entry.path())),
                         Err(err) => Some(Err(err)),
                     },
                     Err(err) => Some(Err(err)),
@@ -89,8 +115,7 @@ pub fn toolchains_for_version(version: &PythonVersion) -> Result<Vec<Toolchain>,
                 .file_name()
                 .map(OsStr::to_string_lossy)
                 .is_some_and(|filename| {
-                    filename.starts_with(&format!("cpython-{version}"))
-                        && filename.ends_with(&platform_key)
+                    filename.starts_with("cpython-") && filename.ends_with(&platform_key)
                 })
             {
                 Some(Toolchain { path })
@@ -1,7 +1,9 @@
 pub use crate::downloads::{
     DownloadResult, Error, Platform, PythonDownload, PythonDownloadRequest,
 };
-pub use crate::find::{toolchains_for_version, Toolchain, TOOLCHAIN_DIRECTORY};
+pub use crate::find::{
+    installed_toolchains, toolchains_for_version, Toolchain, TOOLCHAIN_DIRECTORY,
+};
 pub use crate::python_version::PythonVersion;
 
 mod downloads;
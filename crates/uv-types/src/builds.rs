@@ -1,15 +1,38 @@
 use uv_interpreter::PythonEnvironment;
+use uv_normalize::PackageName;
 
 /// Whether to enforce build isolation when building source distributions.
 #[derive(Debug, Copy, Clone)]
 pub enum BuildIsolation<'a> {
     Isolated,
     Shared(&'a PythonEnvironment),
+    /// Build isolation is disabled for the given packages only, which are built in the shared
+    /// environment.
+    SharedPackage(&'a PythonEnvironment, &'a [PackageName]),
 }
 
 impl<'a> BuildIsolation<'a> {
-    /// Returns `true` if build isolation is enforced.
-    pub fn is_isolated(&self) -> bool {
-        matches!(self, Self::Isolated)
+    /// Returns `true` if build isolation is enforced for the given package.
+    ///
+    /// Packages without a known name are always built in isolation, unless isolation is disabled
+    /// globally.
+    pub fn is_isolated(&self, package: Option<&PackageName>) -> bool {
+        self.shared_environment(package).is_none()
+    }
+
+    /// Returns the shared environment in which the given package should be built, if build
+    /// isolation is disabled for it.
+    pub fn shared_environment(
+        &self,
+        package: Option<&PackageName>,
+    ) -> Option<&'a PythonEnvironment> {
+        match *self {
+            Self::Isolated => None,
+            Self::Shared(venv) => Some(venv),
+            Self::SharedPackage(venv, packages) => {
+                package.filter(|package| packages.contains(package))?;
+                Some(venv)
+            }
+        }
     }
 }
@@ -1,8 +1,11 @@
+use std::error::Error;
+use std::sync::Arc;
+
 use distribution_types::{CachedDist, DistributionId};
 use once_map::OnceMap;
 
 #[derive(Default)]
 pub struct InFlight {
     /// The in-flight distribution downloads.
-    pub downloads: OnceMap<DistributionId, Result<CachedDist, String>>,
+    pub downloads: OnceMap<DistributionId, Result<CachedDist, Arc<dyn Error + Send + Sync>>>,
 }
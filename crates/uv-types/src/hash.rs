@@ -17,6 +17,13 @@ pub enum HashStrategy {
     /// Hashes should be validated against a pre-defined list of hashes. If necessary, hashes should
     /// be generated so as to ensure that the archive is valid.
     Validate(FxHashMap<PackageId, Vec<HashDigest>>),
+    /// Hashes should be validated against a pre-defined list of hashes for the listed packages
+    /// (e.g., from hash-pinned constraints). For all other packages, hashes should be generated if
+    /// `generate` is set, and ignored otherwise.
+    Constrain {
+        hashes: FxHashMap<PackageId, Vec<HashDigest>>,
+        generate: bool,
+    },
 }
 
 impl HashStrategy {
@@ -31,6 +38,9 @@ impl HashStrategy {
                     .map(Vec::as_slice)
                     .unwrap_or_default(),
             ),
+            Self::Constrain { hashes, generate } => {
+                Self::constrain(hashes.get(&distribution.package_id()), *generate)
+            }
         }
     }
 
@@ -45,6 +55,10 @@ impl HashStrategy {
                     .map(Vec::as_slice)
                     .unwrap_or_default(),
             ),
+            Self::Constrain { hashes, generate } => Self::constrain(
+                hashes.get(&PackageId::from_registry(name.clone())),
+                *generate,
+            ),
         }
     }
 
@@ -59,6 +73,9 @@ impl HashStrategy {
                     .map(Vec::as_slice)
                     .unwrap_or_default(),
             ),
+            Self::Constrain { hashes, generate } => {
+                Self::constrain(hashes.get(&PackageId::from_url(url)), *generate)
+            }
         }
     }
 
@@ -68,6 +85,7 @@ impl HashStrategy {
             Self::None => true,
             Self::Generate => true,
             Self::Validate(hashes) => hashes.contains_key(&PackageId::from_registry(name.clone())),
+            Self::Constrain { .. } => true,
         }
     }
 
@@ -77,14 +95,32 @@ impl HashStrategy {
             Self::None => true,
             Self::Generate => true,
             Self::Validate(hashes) => hashes.contains_key(&PackageId::from_url(url)),
+            Self::Constrain { .. } => true,
+        }
+    }
+
+    /// Return the [`HashPolicy`] for a package under [`HashStrategy::Constrain`], given its
+    /// pinned hashes, if any.
+    fn constrain(hashes: Option<&Vec<HashDigest>>, generate: bool) -> HashPolicy {
+        match hashes {
+            Some(hashes) => HashPolicy::Validate(hashes),
+            None if generate => HashPolicy::Generate,
+            None => HashPolicy::None,
         }
     }
 
     /// Generate the required hashes from a set of [`RequirementsTxtRequirement`] entries.
+    ///
+    /// Any hashes on the given constraints (e.g., from a compiled `requirements.txt` provided via
+    /// `-c`) are used for requirements that lack hashes of their own, and for any packages that
+    /// aren't requested directly (e.g., transitive dependencies).
     pub fn from_requirements<'a>(
         requirements: impl Iterator<Item = (&'a RequirementsTxtRequirement, &'a [String])>,
+        constraints: impl Iterator<Item = (&'a RequirementsTxtRequirement, &'a [String])>,
         markers: &MarkerEnvironment,
     ) -> Result<Self, HashStrategyError> {
+        let pinned = Self::pinned_hashes(constraints, markers)?;
+
         let mut hashes = FxHashMap::<PackageId, Vec<HashDigest>>::default();
 
         // For each requirement, map from name to allowed hashes. We use the last entry for each
@@ -95,58 +131,106 @@ impl HashStrategy {
             }
 
             // Every requirement must be either a pinned version or a direct URL.
-            let id = match &requirement {
-                RequirementsTxtRequirement::Pep508(requirement) => {
-                    match requirement.version_or_url.as_ref() {
-                        Some(VersionOrUrl::Url(url)) => {
-                            // Direct URLs are always allowed.
-                            PackageId::from_url(url)
-                        }
-                        Some(VersionOrUrl::VersionSpecifier(specifiers)) => {
-                            // Must be a single specifier.
-                            let [specifier] = specifiers.as_ref() else {
-                                return Err(HashStrategyError::UnpinnedRequirement(
-                                    requirement.to_string(),
-                                ));
-                            };
-
-                            // Must be pinned to a specific version.
-                            if *specifier.operator() != pep440_rs::Operator::Equal {
-                                return Err(HashStrategyError::UnpinnedRequirement(
-                                    requirement.to_string(),
-                                ));
-                            }
-
-                            PackageId::from_registry(requirement.name.clone())
-                        }
-                        None => {
-                            return Err(HashStrategyError::UnpinnedRequirement(
-                                requirement.to_string(),
-                            ))
-                        }
-                    }
-                }
-                RequirementsTxtRequirement::Unnamed(requirement) => {
-                    // Direct URLs are always allowed.
-                    PackageId::from_url(&requirement.url)
-                }
+            let id = Self::package_id(requirement)?;
+
+            // Every requirement must include a hash, either directly or via a constraint.
+            let digests = if digests.is_empty() {
+                let Some(digests) = pinned.get(&id) else {
+                    return Err(HashStrategyError::MissingHashes(requirement.to_string()));
+                };
+                digests.clone()
+            } else {
+                digests
+                    .iter()
+                    .map(|digest| HashDigest::from_str(digest))
+                    .collect::<Result<Vec<_>, _>>()?
             };
 
-            // Every requirement must include a hash.
-            if digests.is_empty() {
-                return Err(HashStrategyError::MissingHashes(requirement.to_string()));
-            }
+            hashes.insert(id, digests);
+        }
+
+        for (id, digests) in pinned {
+            hashes.entry(id).or_insert(digests);
+        }
+
+        Ok(Self::Validate(hashes))
+    }
+
+    /// Enforce the hashes on a set of constraints (e.g., from a compiled `requirements.txt`
+    /// provided via `-c`), generating hashes for all other packages if `generate` is set.
+    pub fn from_constraints<'a>(
+        constraints: impl Iterator<Item = (&'a RequirementsTxtRequirement, &'a [String])>,
+        markers: &MarkerEnvironment,
+        generate: bool,
+    ) -> Result<Self, HashStrategyError> {
+        let hashes = Self::pinned_hashes(constraints, markers)?;
+        if hashes.is_empty() {
+            Ok(if generate { Self::Generate } else { Self::None })
+        } else {
+            Ok(Self::Constrain { hashes, generate })
+        }
+    }
 
-            // Parse the hashes.
+    /// Map from package to allowed hashes for the given constraints, skipping any constraints
+    /// without hashes. We use the last entry for each package.
+    fn pinned_hashes<'a>(
+        constraints: impl Iterator<Item = (&'a RequirementsTxtRequirement, &'a [String])>,
+        markers: &MarkerEnvironment,
+    ) -> Result<FxHashMap<PackageId, Vec<HashDigest>>, HashStrategyError> {
+        let mut hashes = FxHashMap::<PackageId, Vec<HashDigest>>::default();
+        for (constraint, digests) in constraints {
+            if digests.is_empty() || !constraint.evaluate_markers(markers, &[]) {
+                continue;
+            }
+            let id = Self::package_id(constraint)?;
             let digests = digests
                 .iter()
                 .map(|digest| HashDigest::from_str(digest))
                 .collect::<Result<Vec<_>, _>>()?;
-
             hashes.insert(id, digests);
         }
+        Ok(hashes)
+    }
 
-        Ok(Self::Validate(hashes))
+    /// Return the [`PackageId`] for a hashed requirement, which must be either a pinned version or
+    /// a direct URL.
+    fn package_id(
+        requirement: &RequirementsTxtRequirement,
+    ) -> Result<PackageId, HashStrategyError> {
+        match requirement {
+            RequirementsTxtRequirement::Pep508(requirement) => {
+                match requirement.version_or_url.as_ref() {
+                    Some(VersionOrUrl::Url(url)) => {
+                        // Direct URLs are always allowed.
+                        Ok(PackageId::from_url(url))
+                    }
+                    Some(VersionOrUrl::VersionSpecifier(specifiers)) => {
+                        // Must be a single specifier.
+                        let [specifier] = specifiers.as_ref() else {
+                            return Err(HashStrategyError::UnpinnedRequirement(
+                                requirement.to_string(),
+                            ));
+                        };
+
+                        // Must be pinned to a specific version.
+                        if *specifier.operator() != pep440_rs::Operator::Equal {
+                            return Err(HashStrategyError::UnpinnedRequirement(
+                                requirement.to_string(),
+                            ));
+                        }
+
+                        Ok(PackageId::from_registry(requirement.name.clone()))
+                    }
+                    None => Err(HashStrategyError::UnpinnedRequirement(
+                        requirement.to_string(),
+                    )),
+                }
+            }
+            RequirementsTxtRequirement::Unnamed(requirement) => {
+                // Direct URLs are always allowed.
+                Ok(PackageId::from_url(&requirement.url))
+            }
+        }
     }
 }
 
@@ -12,6 +12,7 @@ use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::{fmt, EnvFilter};
 
 use uv_cache::Cache;
+use uv_configuration::PythonPreference;
 use uv_interpreter::{find_default_python, find_requested_python};
 use uv_virtualenv::{create_bare_venv, Prompt};
 
@@ -35,11 +36,11 @@ fn run() -> Result<(), uv_virtualenv::Error> {
         Cache::from_path(".cache")?
     };
     let interpreter = if let Some(python_request) = &cli.python {
-        find_requested_python(python_request, &cache)?.ok_or(
+        find_requested_python(python_request, None, PythonPreference::default(), &cache)?.ok_or(
             uv_interpreter::Error::NoSuchPython(python_request.to_string()),
         )?
     } else {
-        find_default_python(&cache)?
+        find_default_python(None, PythonPreference::default(), &cache)?
     };
     create_bare_venv(
         &location,
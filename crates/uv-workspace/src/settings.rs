@@ -1,10 +1,14 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
 use distribution_types::{FlatIndexLocation, IndexUrl};
 use install_wheel_rs::linker::LinkMode;
-use uv_configuration::{ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier};
+use uv_configuration::{
+    BuildEnv, CompatibleTags, ConfigSettings, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, PythonImplementation, PythonPreference,
+};
 use uv_normalize::{ExtraName, PackageName};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
 use uv_toolchain::PythonVersion;
@@ -17,10 +21,13 @@ pub(crate) struct PyProjectToml {
 }
 
 /// A `[tool]` section.
+///
+/// The `[tool.uv]` section is retained as a raw table, such that any unknown keys can be reported
+/// before it's deserialized into [`Options`].
 #[allow(dead_code)]
 #[derive(Debug, Clone, Default, Deserialize)]
 pub(crate) struct Tools {
-    pub(crate) uv: Option<Options>,
+    pub(crate) uv: Option<toml::Table>,
 }
 
 /// A `[tool.uv]` section.
@@ -32,6 +39,7 @@ pub struct Options {
     pub native_tls: Option<bool>,
     pub no_cache: Option<bool>,
     pub cache_dir: Option<PathBuf>,
+    pub conflicts: Option<Vec<Vec<ExtraName>>>,
     pub pip: Option<PipOptions>,
 }
 
@@ -42,25 +50,36 @@ pub struct Options {
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PipOptions {
     pub python: Option<String>,
+    pub python_implementation: Option<PythonImplementation>,
+    pub python_preference: Option<PythonPreference>,
     pub system: Option<bool>,
     pub break_system_packages: Option<bool>,
+    pub require_virtualenv: Option<bool>,
     pub offline: Option<bool>,
     pub index_url: Option<IndexUrl>,
     pub extra_index_url: Option<Vec<IndexUrl>>,
     pub no_index: Option<bool>,
     pub find_links: Option<Vec<FlatIndexLocation>>,
+    pub find_links_recursive: Option<bool>,
     pub index_strategy: Option<IndexStrategy>,
     pub keyring_provider: Option<KeyringProviderType>,
     pub no_build: Option<bool>,
     pub no_binary: Option<Vec<PackageNameSpecifier>>,
     pub only_binary: Option<Vec<PackageNameSpecifier>>,
+    pub compatible_tags: Option<CompatibleTags>,
     pub no_build_isolation: Option<bool>,
+    pub no_build_isolation_package: Option<Vec<PackageName>>,
+    pub reuse_build_dir: Option<bool>,
     pub strict: Option<bool>,
     pub extra: Option<Vec<ExtraName>>,
     pub all_extras: Option<bool>,
     pub no_deps: Option<bool>,
+    pub no_remove: Option<bool>,
+    pub keep: Option<Vec<PackageName>>,
     pub resolution: Option<ResolutionMode>,
+    pub resolution_exclude: Option<Vec<PackageName>>,
     pub prerelease: Option<PreReleaseMode>,
+    pub prerelease_package: Option<Vec<PackageName>>,
     pub output_file: Option<PathBuf>,
     pub no_strip_extras: Option<bool>,
     pub no_annotate: Option<bool>,
@@ -69,15 +88,418 @@ pub struct PipOptions {
     pub generate_hashes: Option<bool>,
     pub legacy_setup_py: Option<bool>,
     pub config_settings: Option<ConfigSettings>,
+    pub build_env: Option<BuildEnv>,
     pub python_version: Option<PythonVersion>,
     pub exclude_newer: Option<ExcludeNewer>,
     pub no_emit_package: Option<Vec<PackageName>>,
     pub emit_index_url: Option<bool>,
     pub emit_find_links: Option<bool>,
+    pub emit_find_links_relative_to: Option<PathBuf>,
+    pub emit_find_links_absolute: Option<bool>,
     pub emit_marker_expression: Option<bool>,
     pub emit_index_annotation: Option<bool>,
+    pub emit_origin: Option<bool>,
+    pub emit_build_options: Option<bool>,
     pub annotation_style: Option<AnnotationStyle>,
     pub link_mode: Option<LinkMode>,
+    pub link_mode_package: Option<BTreeMap<PackageName, LinkMode>>,
     pub compile_bytecode: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_bytecode_opt_level")]
+    #[cfg_attr(feature = "schemars", schemars(range(max = 2)))]
+    pub bytecode_opt_level: Option<u8>,
+    pub overwrite_scripts: Option<bool>,
     pub require_hashes: Option<bool>,
+    // Per-command sections (e.g., `[tool.uv.pip.compile]`), layered over the shared options.
+    pub compile: Option<Box<PipOptions>>,
+    pub sync: Option<Box<PipOptions>>,
+    pub install: Option<Box<PipOptions>>,
+    pub uninstall: Option<Box<PipOptions>>,
+    pub freeze: Option<Box<PipOptions>>,
+    pub list: Option<Box<PipOptions>>,
+    pub show: Option<Box<PipOptions>>,
+    pub check: Option<Box<PipOptions>>,
+    pub download: Option<Box<PipOptions>>,
+    pub wheel: Option<Box<PipOptions>>,
+    pub verify: Option<Box<PipOptions>>,
+}
+
+/// Deserialize a bytecode optimization level, which, as with `python -O` and `python -OO`, must be
+/// `0`, `1`, or `2`.
+fn deserialize_bytecode_opt_level<'de, D>(deserializer: D) -> Result<Option<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let level = Option::<u8>::deserialize(deserializer)?;
+    if let Some(level) = level.filter(|level| *level > 2) {
+        return Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Unsigned(u64::from(level)),
+            &"an optimization level of 0, 1, or 2",
+        ));
+    }
+    Ok(level)
+}
+
+/// The `pip` subcommands that accept a per-command section (e.g., `[tool.uv.pip.compile]`).
+pub(crate) const PIP_COMMANDS: &[&str] = &[
+    "compile",
+    "sync",
+    "install",
+    "uninstall",
+    "freeze",
+    "list",
+    "show",
+    "check",
+    "download",
+    "wheel",
+    "verify",
+];
+
+impl Options {
+    /// Combine the options with a less specific set of options (e.g., the user configuration),
+    /// preferring values from `self`.
+    #[must_use]
+    pub fn combine(self, other: Options) -> Options {
+        Options {
+            native_tls: self.native_tls.or(other.native_tls),
+            no_cache: self.no_cache.or(other.no_cache),
+            cache_dir: self.cache_dir.or(other.cache_dir),
+            conflicts: self.conflicts.or(other.conflicts),
+            pip: match (self.pip, other.pip) {
+                (Some(pip), Some(other)) => Some(pip.combine(other)),
+                (pip, other) => pip.or(other),
+            },
+        }
+    }
+
+    /// Layer the section for the given `pip` subcommand (e.g., `[tool.uv.pip.compile]`) over the
+    /// shared `[tool.uv.pip]` options.
+    #[must_use]
+    pub fn for_pip_command(self, command: &str) -> Options {
+        Options {
+            pip: self.pip.map(|pip| pip.for_command(command)),
+            ..self
+        }
+    }
+
+    /// Returns `true` if the option with the given name (in `snake_case`, e.g., `native_tls` or
+    /// `index_url`) is set, either at the top level or in the `[pip]` section.
+    pub fn is_set(&self, name: &str) -> bool {
+        match name {
+            "native_tls" => self.native_tls.is_some(),
+            "no_cache" => self.no_cache.is_some(),
+            "cache_dir" => self.cache_dir.is_some(),
+            "conflicts" => self.conflicts.is_some(),
+            name => self.pip.as_ref().is_some_and(|pip| pip.is_set(name)),
+        }
+    }
+}
+
+impl PipOptions {
+    /// Combine the options with a less specific set of options (e.g., the user configuration),
+    /// preferring values from `self`.
+    #[must_use]
+    pub fn combine(self, other: PipOptions) -> PipOptions {
+        PipOptions {
+            python: self.python.or(other.python),
+            python_implementation: self.python_implementation.or(other.python_implementation),
+            python_preference: self.python_preference.or(other.python_preference),
+            system: self.system.or(other.system),
+            break_system_packages: self.break_system_packages.or(other.break_system_packages),
+            require_virtualenv: self.require_virtualenv.or(other.require_virtualenv),
+            offline: self.offline.or(other.offline),
+            index_url: self.index_url.or(other.index_url),
+            extra_index_url: self.extra_index_url.or(other.extra_index_url),
+            no_index: self.no_index.or(other.no_index),
+            find_links: self.find_links.or(other.find_links),
+            find_links_recursive: self.find_links_recursive.or(other.find_links_recursive),
+            index_strategy: self.index_strategy.or(other.index_strategy),
+            keyring_provider: self.keyring_provider.or(other.keyring_provider),
+            no_build: self.no_build.or(other.no_build),
+            no_binary: self.no_binary.or(other.no_binary),
+            only_binary: self.only_binary.or(other.only_binary),
+            compatible_tags: self.compatible_tags.or(other.compatible_tags),
+            no_build_isolation: self.no_build_isolation.or(other.no_build_isolation),
+            no_build_isolation_package: self
+                .no_build_isolation_package
+                .or(other.no_build_isolation_package),
+            reuse_build_dir: self.reuse_build_dir.or(other.reuse_build_dir),
+            strict: self.strict.or(other.strict),
+            extra: self.extra.or(other.extra),
+            all_extras: self.all_extras.or(other.all_extras),
+            no_deps: self.no_deps.or(other.no_deps),
+            no_remove: self.no_remove.or(other.no_remove),
+            keep: self.keep.or(other.keep),
+            resolution: self.resolution.or(other.resolution),
+            resolution_exclude: self.resolution_exclude.or(other.resolution_exclude),
+            prerelease: self.prerelease.or(other.prerelease),
+            prerelease_package: self.prerelease_package.or(other.prerelease_package),
+            output_file: self.output_file.or(other.output_file),
+            no_strip_extras: self.no_strip_extras.or(other.no_strip_extras),
+            no_annotate: self.no_annotate.or(other.no_annotate),
+            no_header: self.no_header.or(other.no_header),
+            custom_compile_command: self.custom_compile_command.or(other.custom_compile_command),
+            generate_hashes: self.generate_hashes.or(other.generate_hashes),
+            legacy_setup_py: self.legacy_setup_py.or(other.legacy_setup_py),
+            config_settings: self.config_settings.or(other.config_settings),
+            build_env: self.build_env.or(other.build_env),
+            python_version: self.python_version.or(other.python_version),
+            exclude_newer: self.exclude_newer.or(other.exclude_newer),
+            no_emit_package: self.no_emit_package.or(other.no_emit_package),
+            emit_index_url: self.emit_index_url.or(other.emit_index_url),
+            emit_find_links: self.emit_find_links.or(other.emit_find_links),
+            emit_find_links_relative_to: self
+                .emit_find_links_relative_to
+                .or(other.emit_find_links_relative_to),
+            emit_find_links_absolute: self
+                .emit_find_links_absolute
+                .or(other.emit_find_links_absolute),
+            emit_marker_expression: self.emit_marker_expression.or(other.emit_marker_expression),
+            emit_index_annotation: self.emit_index_annotation.or(other.emit_index_annotation),
+            emit_origin: self.emit_origin.or(other.emit_origin),
+            emit_build_options: self.emit_build_options.or(other.emit_build_options),
+            annotation_style: self.annotation_style.or(other.annotation_style),
+            link_mode: self.link_mode.or(other.link_mode),
+            link_mode_package: self.link_mode_package.or(other.link_mode_package),
+            compile_bytecode: self.compile_bytecode.or(other.compile_bytecode),
+            bytecode_opt_level: self.bytecode_opt_level.or(other.bytecode_opt_level),
+            overwrite_scripts: self.overwrite_scripts.or(other.overwrite_scripts),
+            require_hashes: self.require_hashes.or(other.require_hashes),
+            compile: combine_section(self.compile, other.compile),
+            sync: combine_section(self.sync, other.sync),
+            install: combine_section(self.install, other.install),
+            uninstall: combine_section(self.uninstall, other.uninstall),
+            freeze: combine_section(self.freeze, other.freeze),
+            list: combine_section(self.list, other.list),
+            show: combine_section(self.show, other.show),
+            check: combine_section(self.check, other.check),
+            download: combine_section(self.download, other.download),
+            wheel: combine_section(self.wheel, other.wheel),
+            verify: combine_section(self.verify, other.verify),
+        }
+    }
+
+    /// Returns `true` if the option with the given name (in `snake_case`, e.g., `index_url`) is
+    /// set.
+    pub fn is_set(&self, name: &str) -> bool {
+        self.set_fields().contains(&name)
+    }
+
+    /// Returns the names (in `snake_case`, e.g., `index_url`) of the options that are set,
+    /// including any per-command sections (e.g., `compile`).
+    pub fn set_fields(&self) -> Vec<&'static str> {
+        let PipOptions {
+            python,
+            python_implementation,
+            python_preference,
+            system,
+            break_system_packages,
+            require_virtualenv,
+            offline,
+            index_url,
+            extra_index_url,
+            no_index,
+            find_links,
+            find_links_recursive,
+            index_strategy,
+            keyring_provider,
+            no_build,
+            no_binary,
+            only_binary,
+            compatible_tags,
+            no_build_isolation,
+            no_build_isolation_package,
+            reuse_build_dir,
+            strict,
+            extra,
+            all_extras,
+            no_deps,
+            no_remove,
+            keep,
+            resolution,
+            resolution_exclude,
+            prerelease,
+            prerelease_package,
+            output_file,
+            no_strip_extras,
+            no_annotate,
+            no_header,
+            custom_compile_command,
+            generate_hashes,
+            legacy_setup_py,
+            config_settings,
+            build_env,
+            python_version,
+            exclude_newer,
+            no_emit_package,
+            emit_index_url,
+            emit_find_links,
+            emit_find_links_relative_to,
+            emit_find_links_absolute,
+            emit_marker_expression,
+            emit_index_annotation,
+            emit_origin,
+            emit_build_options,
+            annotation_style,
+            link_mode,
+            link_mode_package,
+            compile_bytecode,
+            bytecode_opt_level,
+            overwrite_scripts,
+            require_hashes,
+            compile,
+            sync,
+            install,
+            uninstall,
+            freeze,
+            list,
+            show,
+            check,
+            download,
+            wheel,
+            verify,
+        } = self;
+        [
+            ("python", python.is_some()),
+            ("python_implementation", python_implementation.is_some()),
+            ("python_preference", python_preference.is_some()),
+            ("system", system.is_some()),
+            ("break_system_packages", break_system_packages.is_some()),
+            ("require_virtualenv", require_virtualenv.is_some()),
+            ("offline", offline.is_some()),
+            ("index_url", index_url.is_some()),
+            ("extra_index_url", extra_index_url.is_some()),
+            ("no_index", no_index.is_some()),
+            ("find_links", find_links.is_some()),
+            ("find_links_recursive", find_links_recursive.is_some()),
+            ("index_strategy", index_strategy.is_some()),
+            ("keyring_provider", keyring_provider.is_some()),
+            ("no_build", no_build.is_some()),
+            ("no_binary", no_binary.is_some()),
+            ("only_binary", only_binary.is_some()),
+            ("compatible_tags", compatible_tags.is_some()),
+            ("no_build_isolation", no_build_isolation.is_some()),
+            (
+                "no_build_isolation_package",
+                no_build_isolation_package.is_some(),
+            ),
+            ("reuse_build_dir", reuse_build_dir.is_some()),
+            ("strict", strict.is_some()),
+            ("extra", extra.is_some()),
+            ("all_extras", all_extras.is_some()),
+            ("no_deps", no_deps.is_some()),
+            ("no_remove", no_remove.is_some()),
+            ("keep", keep.is_some()),
+            ("resolution", resolution.is_some()),
+            ("resolution_exclude", resolution_exclude.is_some()),
+            ("prerelease", prerelease.is_some()),
+            ("prerelease_package", prerelease_package.is_some()),
+            ("output_file", output_file.is_some()),
+            ("no_strip_extras", no_strip_extras.is_some()),
+            ("no_annotate", no_annotate.is_some()),
+            ("no_header", no_header.is_some()),
+            ("custom_compile_command", custom_compile_command.is_some()),
+            ("generate_hashes", generate_hashes.is_some()),
+            ("legacy_setup_py", legacy_setup_py.is_some()),
+            ("config_settings", config_settings.is_some()),
+            ("build_env", build_env.is_some()),
+            ("python_version", python_version.is_some()),
+            ("exclude_newer", exclude_newer.is_some()),
+            ("no_emit_package", no_emit_package.is_some()),
+            ("emit_index_url", emit_index_url.is_some()),
+            ("emit_find_links", emit_find_links.is_some()),
+            (
+                "emit_find_links_relative_to",
+                emit_find_links_relative_to.is_some(),
+            ),
+            (
+                "emit_find_links_absolute",
+                emit_find_links_absolute.is_some(),
+            ),
+            ("emit_marker_expression", emit_marker_expression.is_some()),
+            ("emit_index_annotation", emit_index_annotation.is_some()),
+            ("emit_origin", emit_origin.is_some()),
+            ("emit_build_options", emit_build_options.is_some()),
+            ("annotation_style", annotation_style.is_some()),
+            ("link_mode", link_mode.is_some()),
+            ("link_mode_package", link_mode_package.is_some()),
+            ("compile_bytecode", compile_bytecode.is_some()),
+            ("bytecode_opt_level", bytecode_opt_level.is_some()),
+            ("overwrite_scripts", overwrite_scripts.is_some()),
+            ("require_hashes", require_hashes.is_some()),
+            ("compile", compile.is_some()),
+            ("sync", sync.is_some()),
+            ("install", install.is_some()),
+            ("uninstall", uninstall.is_some()),
+            ("freeze", freeze.is_some()),
+            ("list", list.is_some()),
+            ("show", show.is_some()),
+            ("check", check.is_some()),
+            ("download", download.is_some()),
+            ("wheel", wheel.is_some()),
+            ("verify", verify.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, set)| set.then_some(name))
+        .collect()
+    }
+
+    /// Returns the section for the given `pip` subcommand (e.g., `compile` for
+    /// `[tool.uv.pip.compile]`), if any.
+    pub fn command(&self, command: &str) -> Option<&PipOptions> {
+        match command {
+            "compile" => self.compile.as_deref(),
+            "sync" => self.sync.as_deref(),
+            "install" => self.install.as_deref(),
+            "uninstall" => self.uninstall.as_deref(),
+            "freeze" => self.freeze.as_deref(),
+            "list" => self.list.as_deref(),
+            "show" => self.show.as_deref(),
+            "check" => self.check.as_deref(),
+            "download" => self.download.as_deref(),
+            "wheel" => self.wheel.as_deref(),
+            "verify" => self.verify.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Layer the section for the given `pip` subcommand (e.g., `[tool.uv.pip.compile]`) over the
+    /// shared options, discarding the sections for every subcommand.
+    #[must_use]
+    pub fn for_command(self, command: &str) -> PipOptions {
+        let section = self.command(command).cloned();
+        let shared = self.without_commands();
+        match section {
+            Some(section) => section.without_commands().combine(shared),
+            None => shared,
+        }
+    }
+
+    /// Discard the per-command sections.
+    fn without_commands(self) -> PipOptions {
+        PipOptions {
+            compile: None,
+            sync: None,
+            install: None,
+            uninstall: None,
+            freeze: None,
+            list: None,
+            show: None,
+            check: None,
+            download: None,
+            wheel: None,
+            verify: None,
+            ..self
+        }
+    }
+}
+
+/// Combine a per-command section with a less specific one, preferring values from `section`.
+fn combine_section(
+    section: Option<Box<PipOptions>>,
+    other: Option<Box<PipOptions>>,
+) -> Option<Box<PipOptions>> {
+    match (section, other) {
+        (Some(section), Some(other)) => Some(Box::new(section.combine(*other))),
+        (section, other) => section.or(other),
+    }
 }
@@ -1,10 +1,14 @@
+use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
 use tracing::debug;
 
 use uv_fs::Simplified;
+use uv_normalize::edit_distance;
 use uv_warnings::warn_user;
 
-use crate::{Options, PyProjectToml};
+use crate::{Options, PipOptions, PyProjectToml, PIP_COMMANDS};
 
 /// Represents a project workspace that contains a set of options and a root path.
 #[allow(dead_code)]
@@ -12,37 +16,57 @@ use crate::{Options, PyProjectToml};
 pub struct Workspace {
     pub options: Options,
     pub root: PathBuf,
+    /// The `pyproject.toml` or `uv.toml` file from which the options were read.
+    pub file: PathBuf,
 }
 
 impl Workspace {
     /// Load the user [`Workspace`].
-    pub fn user() -> Result<Option<Self>, WorkspaceError> {
+    ///
+    /// If `strict` is set, any unknown keys in the configuration are an error; otherwise, they're
+    /// reported as warnings and ignored. Likewise for [`Workspace::find`] and
+    /// [`Workspace::from_file`].
+    pub fn user(strict: bool) -> Result<Option<Self>, WorkspaceError> {
         let Some(dir) = config_dir() else {
             return Ok(None);
         };
         let root = dir.join("uv");
         let file = root.join("uv.toml");
+        if !file.is_file() {
+            return Ok(None);
+        }
         Ok(Some(Self {
-            options: find_in_directory(&file)?.unwrap_or_default(),
+            options: read_file(&file, strict)?,
             root,
+            file,
         }))
     }
 
     /// Find the [`Workspace`] for the given path.
     ///
     /// The search starts at the given path and goes up the directory tree until a workspace is
-    /// found.
-    pub fn find(path: impl AsRef<Path>) -> Result<Option<Self>, WorkspaceError> {
+    /// found, stopping at the root of the enclosing Git repository (i.e., the first directory
+    /// that contains a `.git` entry), if any.
+    pub fn find(path: impl AsRef<Path>, strict: bool) -> Result<Option<Self>, WorkspaceError> {
         for ancestor in path.as_ref().ancestors() {
-            match find_in_directory(ancestor) {
-                Ok(Some(options)) => {
+            match find_in_directory(ancestor, strict) {
+                Ok(Some((options, file))) => {
                     return Ok(Some(Self {
                         options,
                         root: ancestor.to_path_buf(),
+                        file,
                     }))
                 }
                 Ok(None) => {
-                    // Continue traversing the directory tree.
+                    // Continue traversing the directory tree, unless we've reached the root of
+                    // the repository.
+                    if ancestor.join(".git").exists() {
+                        debug!(
+                            "Stopping configuration discovery at repository root: `{}`",
+                            ancestor.display()
+                        );
+                        break;
+                    }
                 }
                 Err(err @ WorkspaceError::PyprojectToml(..)) => {
                     // If we see an invalid `pyproject.toml`, warn but continue.
@@ -57,11 +81,24 @@ impl Workspace {
         Ok(None)
     }
 
+    /// Layer the [`Workspace`] over a less specific [`Workspace`] (e.g., the user
+    /// configuration), such that any options that are unset in this workspace fall back to those
+    /// of the other.
+    #[must_use]
+    pub fn combine(self, other: Workspace) -> Self {
+        Self {
+            options: self.options.combine(other.options),
+            root: self.root,
+            file: self.file,
+        }
+    }
+
     /// Load a [`Workspace`] from a `pyproject.toml` or `uv.toml` file.
-    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, WorkspaceError> {
+    pub fn from_file(path: impl AsRef<Path>, strict: bool) -> Result<Self, WorkspaceError> {
         Ok(Self {
-            options: read_file(path.as_ref())?,
+            options: read_file(path.as_ref(), strict)?,
             root: path.as_ref().parent().unwrap().to_path_buf(),
+            file: path.as_ref().to_path_buf(),
         })
     }
 }
@@ -87,17 +124,22 @@ fn config_dir() -> Option<PathBuf> {
     }
 }
 
-/// Read a `uv.toml` or `pyproject.toml` file in the given directory.
-fn find_in_directory(dir: &Path) -> Result<Option<Options>, WorkspaceError> {
+/// Read a `uv.toml` or `pyproject.toml` file in the given directory, returning the options along
+/// with the path to the file from which they were read.
+fn find_in_directory(
+    dir: &Path,
+    strict: bool,
+) -> Result<Option<(Options, PathBuf)>, WorkspaceError> {
     // Read a `uv.toml` file in the current directory.
     let path = dir.join("uv.toml");
     match fs_err::read_to_string(&path) {
         Ok(content) => {
-            let options: Options = toml::from_str(&content)
+            let table: toml::Table = toml::from_str(&content)
                 .map_err(|err| WorkspaceError::UvToml(path.user_display().to_string(), err))?;
+            let options = parse_options(table, &path, strict)?;
 
             debug!("Found workspace configuration at `{}`", path.display());
-            return Ok(Some(options));
+            return Ok(Some((options, path)));
         }
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
         Err(err) => return Err(err.into()),
@@ -118,16 +160,17 @@ fn find_in_directory(dir: &Path) -> Result<Option<Options>, WorkspaceError> {
                 );
                 return Ok(None);
             };
-            let Some(options) = tool.uv else {
+            let Some(table) = tool.uv else {
                 debug!(
                     "Skipping `pyproject.toml` in `{}` (no `[tool.uv]` section)",
                     dir.display()
                 );
                 return Ok(None);
             };
+            let options = parse_options(table, &path, strict)?;
 
             debug!("Found workspace configuration at `{}`", path.display());
-            return Ok(Some(options));
+            return Ok(Some((options, path)));
         }
         Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
         Err(err) => return Err(err.into()),
@@ -136,17 +179,181 @@ fn find_in_directory(dir: &Path) -> Result<Option<Options>, WorkspaceError> {
     Ok(None)
 }
 
-/// Load [`Options`] from a `pyproject.toml` or `ruff.toml` file.
-fn read_file(path: &Path) -> Result<Options, WorkspaceError> {
+/// Load [`Options`] from a `pyproject.toml` or `uv.toml` file.
+fn read_file(path: &Path, strict: bool) -> Result<Options, WorkspaceError> {
     let content = fs_err::read_to_string(path)?;
     if path.ends_with("pyproject.toml") {
         let pyproject: PyProjectToml = toml::from_str(&content)
             .map_err(|err| WorkspaceError::PyprojectToml(path.user_display().to_string(), err))?;
-        Ok(pyproject.tool.and_then(|tool| tool.uv).unwrap_or_default())
+        match pyproject.tool.and_then(|tool| tool.uv) {
+            Some(table) => parse_options(table, path, strict),
+            None => Ok(Options::default()),
+        }
     } else {
-        let options: Options = toml::from_str(&content)
+        let table: toml::Table = toml::from_str(&content)
             .map_err(|err| WorkspaceError::UvToml(path.user_display().to_string(), err))?;
-        Ok(options)
+        parse_options(table, path, strict)
+    }
+}
+
+/// Parse the [`Options`] from the `[tool.uv]` section of a `pyproject.toml` file, or from a
+/// `uv.toml` file.
+///
+/// Unknown keys are collected upfront, rather than failing on the first, such that each can be
+/// reported with a suggestion. If `strict` is unset, they're reported as warnings and ignored.
+fn parse_options(
+    mut table: toml::Table,
+    path: &Path,
+    strict: bool,
+) -> Result<Options, WorkspaceError> {
+    let pyproject = path.ends_with("pyproject.toml");
+
+    let mut unknown = Vec::new();
+    remove_unknown_keys(
+        &mut table,
+        if pyproject { "tool.uv" } else { "" },
+        Section::Options,
+        &mut unknown,
+    );
+    if !unknown.is_empty() {
+        if strict {
+            return Err(WorkspaceError::UnknownKeys(
+                path.user_display().to_string(),
+                unknown,
+            ));
+        }
+        for key in &unknown {
+            warn_user!("Ignoring unknown key in `{}`: {key}", path.user_display());
+        }
+    }
+
+    toml::Value::Table(table).try_into().map_err(|err| {
+        if pyproject {
+            WorkspaceError::PyprojectToml(path.user_display().to_string(), err)
+        } else {
+            WorkspaceError::UvToml(path.user_display().to_string(), err)
+        }
+    })
+}
+
+/// A section of the configuration, against which keys are validated.
+#[derive(Debug, Copy, Clone)]
+enum Section {
+    /// The top-level `[tool.uv]` section.
+    Options,
+    /// The `[tool.uv.pip]` section, or a per-command section within it.
+    Pip,
+}
+
+impl Section {
+    /// Return the names of the keys accepted by the section.
+    fn fields(self) -> &'static [&'static str] {
+        match self {
+            Self::Options => struct_fields::<Options>(),
+            Self::Pip => struct_fields::<PipOptions>(),
+        }
+    }
+
+    /// Return the nested section for the given key, if any.
+    fn nested(self, key: &str) -> Option<Self> {
+        match self {
+            Self::Options => (key == "pip").then_some(Self::Pip),
+            Self::Pip => PIP_COMMANDS.contains(&key).then_some(Self::Pip),
+        }
+    }
+}
+
+/// Remove any keys in the `table` that aren't accepted by the `section`, recursing into any nested
+/// sections, and collect them into `unknown`.
+fn remove_unknown_keys(
+    table: &mut toml::Table,
+    prefix: &str,
+    section: Section,
+    unknown: &mut Vec<UnknownKey>,
+) {
+    let fields = section.fields();
+    table.retain(|key, value| {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        if !fields.contains(&key.as_str()) {
+            unknown.push(UnknownKey {
+                suggestion: closest_field(key, fields),
+                path,
+            });
+            return false;
+        }
+        if let (Some(nested), toml::Value::Table(table)) = (section.nested(key), value) {
+            remove_unknown_keys(table, &path, nested, unknown);
+        }
+        true
+    });
+}
+
+/// Return the field closest to `key` by edit distance, if any is close enough to be a likely
+/// typo.
+fn closest_field(key: &str, fields: &[&'static str]) -> Option<&'static str> {
+    let threshold = (key.chars().count() / 3).max(1);
+    fields
+        .iter()
+        .map(|field| (edit_distance(key, field), *field))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min()
+        .map(|(_, field)| field)
+}
+
+/// Return the names of the fields accepted by the [`Deserialize`] implementation of a struct
+/// (e.g., `index-url`), by intercepting the call to [`Deserializer::deserialize_struct`].
+fn struct_fields<T: for<'de> Deserialize<'de>>() -> &'static [&'static str] {
+    struct FieldsDeserializer<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> Deserializer<'de> for FieldsDeserializer<'_> {
+        type Error = de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+            Err(de::Error::custom("expected a struct"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            *self.0 = fields;
+            Err(de::Error::custom("fields collected"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+            option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+            ignored_any
+        }
+    }
+
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldsDeserializer(&mut fields));
+    fields
+}
+
+/// A key in a configuration file that isn't accepted by uv.
+#[derive(Debug)]
+pub struct UnknownKey {
+    /// The path to the key, e.g., `tool.uv.pip.index-ur`.
+    path: String,
+    /// The closest known key, if any, e.g., `index-url`.
+    suggestion: Option<&'static str>,
+}
+
+impl Display for UnknownKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}`", self.path)?;
+        if let Some(suggestion) = self.suggestion {
+            write!(f, " (did you mean `{suggestion}`?)")?;
+        }
+        Ok(())
     }
 }
 
@@ -160,4 +367,71 @@ pub enum WorkspaceError {
 
     #[error("Failed to parse `{0}`")]
     UvToml(String, #[source] toml::de::Error),
+
+    #[error(
+        "Unknown {} in `{0}` (use `--no-strict-config` to ignore unknown keys):{}",
+        if .1.len() == 1 { "key" } else { "keys" },
+        .1.iter().map(|key| format!("\n  {key}")).collect::<String>()
+    )]
+    UnknownKeys(String, Vec<UnknownKey>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_keys() -> Result<(), toml::de::Error> {
+        let mut table: toml::Table = toml::from_str(
+            r#"
+            native-tls = true
+            index-url = "https://test.pypi.org/simple"
+
+            [pip]
+            index-ur = "https://test.pypi.org/simple"
+            generate-hashes = true
+
+            [pip.compile]
+            resolutio = "lowest"
+
+            [pip.build-env.foo]
+            KEY = "value"
+            "#,
+        )?;
+        let mut unknown = Vec::new();
+        remove_unknown_keys(&mut table, "tool.uv", Section::Options, &mut unknown);
+        assert_eq!(
+            unknown.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "`tool.uv.index-url`",
+                "`tool.uv.pip.compile.resolutio` (did you mean `resolution`?)",
+                "`tool.uv.pip.index-ur` (did you mean `index-url`?)",
+            ]
+        );
+
+        // The remaining keys should deserialize.
+        let options: Options = toml::Value::Table(table).try_into()?;
+        assert_eq!(options.native_tls, Some(true));
+        assert!(options
+            .pip
+            .is_some_and(|pip| pip.generate_hashes == Some(true)));
+        Ok(())
+    }
+
+    #[test]
+    fn bytecode_opt_level() {
+        let options: Options = toml::from_str("[pip]\nbytecode-opt-level = 2").unwrap();
+        assert_eq!(options.pip.and_then(|pip| pip.bytecode_opt_level), Some(2));
+
+        let err = toml::from_str::<Options>("[pip]\nbytecode-opt-level = 3").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected an optimization level of 0, 1, or 2"));
+    }
+
+    #[test]
+    fn fields() {
+        assert!(struct_fields::<Options>().contains(&"native-tls"));
+        assert!(struct_fields::<PipOptions>().contains(&"index-url"));
+    }
 }
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use fs_err as fs;
 use std::{path::Path, process::Command};
 
@@ -12,6 +13,7 @@ fn main() {
         .to_path_buf();
 
     commit_info(&workspace_root);
+    build_date();
 
     #[allow(clippy::disallowed_methods)]
     let target = std::env::var("TARGET").unwrap();
@@ -65,7 +67,8 @@ fn commit_info(workspace_root: &Path) {
     let mut next = || parts.next().unwrap();
     println!("cargo:rustc-env=UV_COMMIT_HASH={}", next());
     println!("cargo:rustc-env=UV_COMMIT_SHORT_HASH={}", next());
-    println!("cargo:rustc-env=UV_COMMIT_DATE={}", next());
+    let commit_date = next();
+    println!("cargo:rustc-env=UV_COMMIT_DATE={commit_date}");
 
     // Describe can fail for some commits
     // https://git-scm.com/docs/pretty-formats#Documentation/pretty-formats.txt-emdescribeoptionsem
@@ -82,3 +85,23 @@ fn commit_info(workspace_root: &Path) {
         );
     }
 }
+
+fn build_date() {
+    // Respect `SOURCE_DATE_EPOCH`, so that builds are reproducible
+    // https://reproducible-builds.org/specs/source-date-epoch/
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    #[allow(clippy::disallowed_methods)]
+    let build_date = match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => {
+            let epoch = epoch
+                .parse::<i64>()
+                .expect("SOURCE_DATE_EPOCH should be a UNIX timestamp");
+            DateTime::from_timestamp(epoch, 0).expect("SOURCE_DATE_EPOCH should be in range")
+        }
+        Err(_) => Utc::now(),
+    };
+    println!(
+        "cargo:rustc-env=UV_BUILD_DATE={}",
+        build_date.format("%Y-%m-%d")
+    );
+}
@@ -6,15 +6,20 @@ use anyhow::Result;
 use clap::{Args, Parser, Subcommand};
 
 use distribution_types::{FlatIndexLocation, IndexUrl};
+use pep508_rs::Requirement;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    BuildEnvEntry, CompatibleTagEntry, ConfigSettingEntry, EditableMode, IndexStrategy,
+    KeyringProviderType, PackageNameSpecifier, PythonImplementation, PythonPreference,
 };
 use uv_normalize::{ExtraName, PackageName};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
 use uv_toolchain::PythonVersion;
 
-use crate::commands::{extra_name_with_clap_error, ListFormat, VersionFormat};
+use crate::commands::{
+    extra_name_with_clap_error, CheckFormat, FreezeConstraintMode, ListFormat, PythonListFormat,
+    ReusePins, SyncFormat, VerifyFormat, VersionFormat,
+};
 use crate::compat;
 
 #[derive(Parser)]
@@ -30,18 +35,18 @@ pub(crate) struct Cli {
 
     #[command(flatten)]
     pub(crate) cache_args: CacheArgs,
-
-    /// The path to a `pyproject.toml` or `uv.toml` file to use for configuration.
-    #[arg(long, short, env = "UV_CONFIG_FILE", hide = true)]
-    pub(crate) config_file: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
-pub(crate) struct GlobalArgs {
-    /// Do not print any output.
-    #[arg(global = true, long, short, conflicts_with = "verbose")]
-    pub(crate) quiet: bool,
+pub struct GlobalArgs {
+    /// Use quiet output.
+    ///
+    /// Repeating this option, e.g., `-qq`, will enable a silent mode in which uv will write no
+    /// output to stdout or stderr, other than errors. Otherwise, uv will continue to print warnings
+    /// and a summary of its changes (e.g., "Installed 12 packages").
+    #[arg(global = true, action = clap::ArgAction::Count, long, short, conflicts_with = "verbose")]
+    pub(crate) quiet: u8,
 
     /// Use verbose output.
     ///
@@ -78,10 +83,77 @@ pub(crate) struct GlobalArgs {
 
     #[arg(global = true, long, overrides_with("native_tls"), hide = true)]
     pub(crate) no_native_tls: bool,
+
+    /// The path to a `.netrc` file from which to read credentials for authenticated requests.
+    ///
+    /// By default, `uv` reads credentials from the file at `NETRC`, or `~/.netrc` (`~/_netrc` on
+    /// Windows). Credentials embedded in a URL take precedence over the `.netrc` file, which in
+    /// turn takes precedence over the keyring.
+    #[arg(global = true, long, value_name = "PATH")]
+    pub(crate) netrc_file: Option<PathBuf>,
+
+    /// The path to a `pyproject.toml` or `uv.toml` file to use for configuration.
+    ///
+    /// When provided, the given file is loaded in place of any discovered configuration.
+    #[arg(
+        global = true,
+        long,
+        env = "UV_CONFIG_FILE",
+        conflicts_with = "no_config"
+    )]
+    pub(crate) config_file: Option<PathBuf>,
+
+    /// Avoid discovering configuration files (`pyproject.toml`, `uv.toml`).
+    ///
+    /// By default, `uv` searches for a configuration file in the current directory and its
+    /// parents, stopping at the root of the enclosing Git repository, and falls back to the user
+    /// configuration file (e.g., `~/.config/uv/uv.toml`).
+    #[arg(global = true, long, env = "UV_NO_CONFIG", value_parser = clap::builder::BoolishValueParser::new())]
+    pub(crate) no_config: bool,
+
+    /// Warn about, rather than reject, unknown keys in the configuration files.
+    ///
+    /// By default, an unknown key in a `pyproject.toml` (under `[tool.uv]`) or `uv.toml` file is
+    /// an error, to avoid silently ignoring misspelled settings.
+    #[arg(global = true, long, env = "UV_NO_STRICT_CONFIG", value_parser = clap::builder::BoolishValueParser::new())]
+    pub(crate) no_strict_config: bool,
+
+    /// Ignore the `pip` environment variables (e.g., `PIP_INDEX_URL`).
+    ///
+    /// By default, for drop-in compatibility with `pip`, `uv` respects `PIP_INDEX_URL`,
+    /// `PIP_EXTRA_INDEX_URL`, `PIP_FIND_LINKS`, `PIP_NO_BINARY`, `PIP_ONLY_BINARY`, and
+    /// `PIP_TIMEOUT`, with lower precedence than the `UV_*` environment variables, the
+    /// configuration files, and the command-line.
+    #[arg(global = true, long, env = "UV_IGNORE_PIP_ENV", value_parser = clap::builder::BoolishValueParser::new())]
+    pub(crate) no_pip_env: bool,
+
+    /// Print the resolved settings, annotating each value with its source (`cli`, `env`,
+    /// `workspace`, `user`, `pip-env`, or `default`).
+    ///
+    /// By default, `uv` exits after printing the settings. Use `--show-settings=continue` to
+    /// proceed with the command.
+    #[arg(
+        global = true,
+        long,
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "exit"
+    )]
+    pub(crate) show_settings: Option<ShowSettings>,
 }
 
-#[derive(Debug, Clone, clap::ValueEnum)]
-pub(crate) enum ColorChoice {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ShowSettings {
+    /// Print the resolved settings, then exit.
+    Exit,
+
+    /// Print the resolved settings, then run the command.
+    Continue,
+}
+
+#[derive(Debug, Copy, Clone, clap::ValueEnum)]
+pub enum ColorChoice {
     /// Enables colored output only when the output is going to a terminal or TTY with support.
     Auto,
 
@@ -112,6 +184,8 @@ pub(crate) enum Commands {
     Venv(VenvArgs),
     /// Manage the cache.
     Cache(CacheNamespace),
+    /// Inspect the Python interpreters available to `uv`.
+    Python(PythonNamespace),
     /// Manage the `uv` executable.
     #[command(name = "self")]
     #[cfg(feature = "self-update")]
@@ -129,6 +203,17 @@ pub(crate) enum Commands {
     /// Generate shell completion
     #[command(alias = "--generate-shell-completion", hide = true)]
     GenerateShellCompletion { shell: clap_complete_command::Shell },
+    /// Complete the names of the packages installed in the current environment, for use by the
+    /// shell completion scripts.
+    #[command(name = "__complete", hide = true)]
+    Complete(CompleteArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct CompleteArgs {
+    /// The partially-typed package name to complete.
+    #[arg(default_value = "")]
+    pub(crate) prefix: String,
 }
 
 #[derive(Args)]
@@ -168,6 +253,31 @@ pub(crate) struct CleanArgs {
     pub(crate) package: Vec<PackageName>,
 }
 
+#[derive(Args)]
+pub(crate) struct PythonNamespace {
+    #[command(subcommand)]
+    pub(crate) command: PythonCommand,
+}
+
+#[derive(Subcommand)]
+pub(crate) enum PythonCommand {
+    /// List the Python interpreters that `uv` discovers, in the order in which they're considered.
+    List(PythonListArgs),
+}
+
+#[derive(Args)]
+pub(crate) struct PythonListArgs {
+    /// Whether to list Python installations managed by `uv`, those found on the system, or both.
+    ///
+    /// Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
+    /// Select the output format between: `text` (default) or `json`.
+    #[arg(long, value_enum, default_value_t = PythonListFormat::default())]
+    pub(crate) output_format: PythonListFormat,
+}
+
 #[derive(Args)]
 pub(crate) struct PipNamespace {
     #[command(subcommand)]
@@ -182,6 +292,10 @@ pub(crate) enum PipCommand {
     Sync(PipSyncArgs),
     /// Install packages into the current environment.
     Install(PipInstallArgs),
+    /// Download packages into a directory, without installing them.
+    Download(PipDownloadArgs),
+    /// Build wheels for packages into a directory, without installing them.
+    Wheel(PipWheelArgs),
     /// Uninstall packages from the current environment.
     Uninstall(PipUninstallArgs),
     /// Enumerate the installed packages in the current environment.
@@ -192,6 +306,9 @@ pub(crate) enum PipCommand {
     Show(PipShowArgs),
     /// Verify installed packages have compatible dependencies.
     Check(PipCheckArgs),
+    /// Verify that the files of the installed packages are intact, and, optionally, that the
+    /// installed packages exactly match a `requirements.txt` file.
+    Verify(PipVerifyArgs),
 }
 
 /// A re-implementation of `Option`, used to avoid Clap's automatic `Option` flattening in
@@ -225,7 +342,7 @@ fn parse_index_url(input: &str) -> Result<Maybe<IndexUrl>, String> {
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub(crate) struct PipCompileArgs {
+pub struct PipCompileArgs {
     /// Include all packages listed in the given `requirements.in` files.
     ///
     /// When the path is `-`, then requirements are read from stdin.
@@ -254,6 +371,49 @@ pub(crate) struct PipCompileArgs {
     #[arg(long)]
     pub(crate) r#override: Vec<PathBuf>,
 
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Build constraints are `requirements.txt`-like files that only apply to the resolution of
+    /// build requirements (e.g., `setuptools`, `cython`) in isolated build environments, and
+    /// don't affect the resolution of the requirements themselves.
+    #[arg(long, short = 'b')]
+    pub(crate) build_constraint: Vec<PathBuf>,
+
+    /// Install editable requirements for VCS URLs (e.g., `-e git+https://...#egg=example`) as
+    /// regular, non-editable requirements.
+    ///
+    /// Editable installs are only supported for local directories. Without this flag, editable VCS
+    /// requirements are rejected with an error.
+    #[arg(long)]
+    pub(crate) convert_editable_vcs: bool,
+
+    /// Constrain versions using the packages installed in the current environment, as listed by
+    /// `uv pip freeze`.
+    ///
+    /// With `prefer` (the default), the installed versions are preferred, but other versions are
+    /// selected if necessary. With `require`, the installed versions are treated as constraints.
+    /// Packages that were installed in editable mode or from a direct URL are skipped, as are any
+    /// packages passed to `--upgrade-package`.
+    #[arg(
+        long,
+        value_enum,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "prefer"
+    )]
+    pub(crate) constraint_from_freeze: Option<FreezeConstraintMode>,
+
+    /// Whether to reuse the versions pinned in the existing output file.
+    ///
+    /// With `auto` (the default), the existing pins are used as preferences. With `always`, the
+    /// existing pins are enforced, and only changed if the requirements or constraints are
+    /// incompatible with them. With `never`, the existing pins are ignored.
+    ///
+    /// Unless `auto` is selected, a summary of the pins that changed is displayed after resolution.
+    #[arg(long, value_enum, default_value_t = ReusePins::default())]
+    pub(crate) reuse_pins: ReusePins,
+
     /// Include optional dependencies in the given extra group name; may be provided more than once.
     #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
     pub(crate) extra: Option<Vec<ExtraName>>,
@@ -280,6 +440,14 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, value_enum, env = "UV_RESOLUTION")]
     pub(crate) resolution: Option<ResolutionMode>,
 
+    /// Exclude a package from the `--resolution` strategy, such that it resolves to its highest
+    /// compatible version.
+    ///
+    /// Useful with `--resolution=lowest` to skip packages whose oldest compatible releases can't
+    /// be installed (e.g., because they predate wheels for the target platform).
+    #[arg(long)]
+    pub(crate) resolution_exclude: Option<Vec<PackageName>>,
+
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, `uv` will accept pre-releases for packages that _only_ publish pre-releases,
@@ -291,6 +459,14 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, hide = true)]
     pub(crate) pre: bool,
 
+    /// Allow pre-release versions for a specific package, regardless of the `--prerelease`
+    /// strategy.
+    ///
+    /// Any pre-release versions selected for these packages are annotated in the output, so that
+    /// they stand out during review.
+    #[arg(long)]
+    pub(crate) prerelease_package: Option<Vec<PackageName>>,
+
     /// Write the compiled requirements to the given `requirements.txt` file.
     #[arg(long, short)]
     pub(crate) output_file: Option<PathBuf>,
@@ -330,6 +506,23 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, env = "UV_CUSTOM_COMPILE_COMMAND")]
     pub(crate) custom_compile_command: Option<String>,
 
+    /// Explain the chain of requirements that constrained the given package.
+    ///
+    /// The explanation is displayed whether or not resolution succeeds, and includes the input
+    /// file, `pyproject.toml` extra, constraint, or override that introduced each requirement.
+    ///
+    /// Use `--verbose` to display the full derivation tree on resolution failure.
+    #[arg(long)]
+    pub(crate) explain: Vec<PackageName>,
+
+    /// Write a summary of the pins that changed from the existing output file, along with the
+    /// reason for each change, to the given file.
+    ///
+    /// By default, the summary is displayed when upgrading packages with `--upgrade` or
+    /// `--upgrade-package`.
+    #[arg(long)]
+    pub(crate) changes: Option<PathBuf>,
+
     /// Run offline, i.e., without accessing the network.
     #[arg(
         global = true,
@@ -386,12 +579,27 @@ pub(crate) struct PipCompileArgs {
     /// Locations to search for candidate distributions, beyond those found in the indexes.
     ///
     /// If a path, the target must be a directory that contains package as wheel files (`.whl`) or
-    /// source distributions (`.tar.gz` or `.zip`) at the top level.
+    /// source distributions (`.tar.gz` or `.zip`) at the top level (or, with
+    /// `--find-links-recursive`, in any subdirectory).
+    ///
+    /// If a directory contains a `SHA256SUMS` manifest (as produced by `sha256sum`), the
+    /// distributions it lists are verified against their expected hashes on install, and any
+    /// distributions it doesn't list are ignored.
     ///
     /// If a URL, the page must contain a flat list of links to package files.
     #[arg(long, short)]
     pub(crate) find_links: Option<Vec<FlatIndexLocation>>,
 
+    /// Scan `--find-links` directories recursively, including distributions in nested
+    /// subdirectories.
+    ///
+    /// Symbolic links to directories are followed, but each directory is scanned at most once.
+    #[arg(long, overrides_with("no_find_links_recursive"))]
+    pub(crate) find_links_recursive: bool,
+
+    #[arg(long, overrides_with("find_links_recursive"), hide = true)]
+    pub(crate) no_find_links_recursive: bool,
+
     /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
     /// discovered via `--find-links`.
     #[arg(long)]
@@ -429,6 +637,22 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, verbatim_doc_comment, group = "discovery")]
     pub(crate) python: Option<String>,
 
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    ///
+    /// Only applies when the interpreter is discovered by version (e.g., `--python 3.12`) or by
+    /// default, rather than requested by path or executable name. Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
+    /// Restrict interpreter discovery to a specific Python implementation (e.g., `pypy`).
+    ///
+    /// When searching by version, only interpreters of the given implementation are considered
+    /// (e.g., `pypy3.10` rather than `python3.10`). An interpreter requested by path or executable
+    /// name must be of the given implementation.
+    #[arg(long, value_enum, env = "UV_PYTHON_IMPLEMENTATION")]
+    pub(crate) python_implementation: Option<PythonImplementation>,
+
     /// Install packages into the system Python.
     ///
     /// By default, `uv` uses the virtual environment in the current working directory or any parent
@@ -452,8 +676,16 @@ pub(crate) struct PipCompileArgs {
 
     /// Allow upgrades for a specific package, ignoring pinned versions in the existing output
     /// file.
+    ///
+    /// Accepts either a package name (e.g., `requests`), to allow any newer version, or a
+    /// requirement with a version specifier (e.g., `requests<2.32`), to limit the upgrade.
     #[arg(long, short = 'P')]
-    pub(crate) upgrade_package: Option<Vec<PackageName>>,
+    pub(crate) upgrade_package: Option<Vec<Requirement>>,
+
+    /// Hold a specific package at its pinned version in the existing output file, even when
+    /// upgrading all other packages with `--upgrade`.
+    #[arg(long)]
+    pub(crate) no_upgrade_package: Option<Vec<PackageName>>,
 
     /// Include distribution hashes in the output file.
     #[arg(long, overrides_with("no_generate_hashes"))]
@@ -479,6 +711,27 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, overrides_with("no_build_isolation"), hide = true)]
     pub(crate) build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
+    /// Other packages continue to be built in isolated environments.
+    #[arg(long, conflicts_with = "no_build_isolation")]
+    pub(crate) no_build_isolation_package: Option<Vec<PackageName>>,
+
+    /// Reuse the build directory of a source distribution across builds, rather than building in a
+    /// fresh temporary directory.
+    ///
+    /// The build directory is retained in the cache, keyed by the package and the path to the
+    /// source tree. On each build, only the source files whose contents changed are synced into it,
+    /// such that the build backend can reuse the artifacts of the previous build (e.g., compiled
+    /// extension modules). Changes to the config settings or the Python interpreter result in a
+    /// fresh build directory. Stale build directories are removed by `uv cache prune`.
+    #[arg(long, overrides_with("clean"))]
+    pub(crate) no_clean: bool,
+
+    #[arg(long, overrides_with("no_clean"), hide = true)]
+    pub(crate) clean: bool,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary code. The cached wheels of already-built
@@ -508,10 +761,37 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, conflicts_with = "no_build")]
     pub(crate) only_binary: Option<Vec<PackageNameSpecifier>>,
 
+    /// Additional platform tags to accept for a specific package, specified as `PACKAGE:TAG`
+    /// pairs (e.g., `foo:linux_x86_64`).
+    ///
+    /// Wheels for the package with the given platform tag are considered compatible with the
+    /// current platform, as long as their Python and ABI tags are compatible. Use with care: the
+    /// wheel may not work on the current platform.
+    #[arg(long)]
+    pub(crate) allow_platform_tag: Option<Vec<CompatibleTagEntry>>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
+    ///
+    /// The settings are passed to every build hook, including the `get_requires_for_build_*`
+    /// and `prepare_metadata_for_build_*` hooks, `build_wheel`, and the PEP 660 `build_editable`
+    /// hook used for editable installs (e.g., `-C editable_mode=compat` for setuptools).
     #[arg(long, short = 'C', alias = "config-settings")]
     pub(crate) config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    /// Environment variables to set when building a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    ///
+    /// The variables are only passed to the build backend for the given package, on top of the
+    /// inherited environment. Values may reference other variables as `${VAR}`.
+    #[arg(long)]
+    pub(crate) build_env: Option<Vec<BuildEnvEntry>>,
+
+    /// Stream the output of the build backend while building source distributions.
+    ///
+    /// Each line is prefixed with the name of the build hook that produced it.
+    #[arg(long)]
+    pub(crate) verbose_build: bool,
+
     /// The minimum Python version that should be supported by the compiled requirements (e.g.,
     /// `3.7` or `3.7.9`).
     ///
@@ -520,6 +800,14 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, short)]
     pub(crate) python_version: Option<PythonVersion>,
 
+    /// Resolve against the marker environment defined in the given JSON file, rather than that of
+    /// the current interpreter.
+    ///
+    /// The file must define every PEP 508 environment marker, e.g., `sys_platform`,
+    /// `platform_machine`, and `python_full_version`.
+    #[arg(long, conflicts_with = "python_version")]
+    pub(crate) marker_environment: Option<PathBuf>,
+
     /// Limit candidate packages to those that were uploaded prior to the given date.
     ///
     /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
@@ -546,6 +834,22 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, overrides_with("emit_find_links"), hide = true)]
     pub(crate) no_emit_find_links: bool,
 
+    /// Emit local `--find-links` directories relative to the given directory (e.g., that of the
+    /// output file), such that the generated output file is relocatable.
+    ///
+    /// Only applies with `--emit-find-links`. Takes precedence over `--emit-find-links-absolute`.
+    #[arg(long)]
+    pub(crate) emit_find_links_relative_to: Option<PathBuf>,
+
+    /// Emit local `--find-links` directories as absolute paths.
+    ///
+    /// Only applies with `--emit-find-links`. By default, directories are emitted as given.
+    #[arg(long, overrides_with("no_emit_find_links_absolute"))]
+    pub(crate) emit_find_links_absolute: bool,
+
+    #[arg(long, overrides_with("emit_find_links_absolute"), hide = true)]
+    pub(crate) no_emit_find_links_absolute: bool,
+
     /// Whether to emit a marker string indicating when it is known that the
     /// resulting set of pinned dependencies is valid.
     ///
@@ -558,25 +862,93 @@ pub(crate) struct PipCompileArgs {
     #[arg(long, overrides_with("emit_marker_expression"), hide = true)]
     pub(crate) no_emit_marker_expression: bool,
 
-    /// Include comment annotations indicating the index used to resolve each package (e.g.,
-    /// `# from https://pypi.org/simple`).
+    /// Include comment annotations indicating the index used to resolve each package, along with
+    /// the host that serves the selected artifact (e.g.,
+    /// `# from https://pypi.org/simple (files.pythonhosted.org)`).
+    ///
+    /// Redirects (e.g., to a mirror) are only followed to determine the host when `--emit-origin` is
+    /// also provided.
     #[arg(long, overrides_with("no_emit_index_annotation"))]
     pub(crate) emit_index_annotation: bool,
 
     #[arg(long, overrides_with("emit_index_annotation"), hide = true)]
     pub(crate) no_emit_index_annotation: bool,
 
+    /// Include comment annotations with the origin of each resolved artifact: the full URL from
+    /// which it's served, after following any redirects, and, when the index reports it, its
+    /// upload time.
+    ///
+    /// Following redirects requires a `HEAD` request per artifact; in offline mode, the URL listed
+    /// on the index is used instead.
+    #[arg(long, overrides_with("no_emit_origin"))]
+    pub(crate) emit_origin: bool,
+
+    #[arg(long, overrides_with("emit_origin"), hide = true)]
+    pub(crate) no_emit_origin: bool,
+
+    /// Include a header listing the build options used to compile the output file (e.g.,
+    /// `--no-build-isolation` or `--config-setting`).
+    ///
+    /// When recompiling an output file that includes such a header, a warning is shown if the
+    /// recorded build options differ from the current ones.
+    #[arg(long, overrides_with("no_emit_build_options"))]
+    pub(crate) emit_build_options: bool,
+
+    #[arg(long, overrides_with("emit_build_options"), hide = true)]
+    pub(crate) no_emit_build_options: bool,
+
     #[command(flatten)]
     pub(crate) compat_args: compat::PipCompileCompatArgs,
 }
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub(crate) struct PipSyncArgs {
+pub struct PipSyncArgs {
     /// Include all packages listed in the given `requirements.txt` files.
     #[arg(required(true))]
     pub(crate) src_file: Vec<PathBuf>,
 
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. Since the input files are expected to pin every package,
+    /// a pinned version that falls outside of a constraint is reported as an error, rather than
+    /// being replaced.
+    ///
+    /// Including a package in a constraints file will _not_ trigger the installation of that
+    /// package.
+    #[arg(long, short)]
+    pub(crate) constraint: Vec<PathBuf>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be installed. Any requirement in an overrides file replaces all entries for
+    /// the same package in the input files, so an override can be used to swap out a pinned
+    /// version without editing the lockfile itself.
+    ///
+    /// Packages that only appear in an overrides file are ignored; overrides never add packages
+    /// to the environment.
+    #[arg(long)]
+    pub(crate) r#override: Vec<PathBuf>,
+
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Build constraints are `requirements.txt`-like files that only apply to the resolution of
+    /// build requirements (e.g., `setuptools`, `cython`) in isolated build environments, and
+    /// don't affect the resolution of the requirements themselves.
+    #[arg(long, short = 'b')]
+    pub(crate) build_constraint: Vec<PathBuf>,
+
+    /// Install editable requirements for VCS URLs (e.g., `-e git+https://...#egg=example`) as
+    /// regular, non-editable requirements.
+    ///
+    /// Editable installs are only supported for local directories. Without this flag, editable VCS
+    /// requirements are rejected with an error.
+    #[arg(long)]
+    pub(crate) convert_editable_vcs: bool,
+
     /// Reinstall all packages, regardless of whether they're already installed.
     #[arg(long, alias = "force-reinstall")]
     pub(crate) reinstall: bool,
@@ -585,6 +957,57 @@ pub(crate) struct PipSyncArgs {
     #[arg(long)]
     pub(crate) reinstall_package: Vec<PackageName>,
 
+    /// Guarantee that the environment matches the input files exactly.
+    ///
+    /// Any package that isn't listed in the input files (including editable and URL installs) is
+    /// removed, and a package that can't be removed is treated as an error rather than a warning.
+    /// Seed packages (`pip`, `setuptools`, `wheel`, `distribute`, and `uv`) are exempt: they're
+    /// preserved unless they're listed in the input files, in which case they're synced like any
+    /// other package.
+    ///
+    /// Takes precedence over `no-remove` in the configuration file.
+    #[arg(long, overrides_with("inexact"))]
+    pub(crate) exact: bool,
+
+    /// Don't remove any installed packages that aren't listed in the input files, as with
+    /// `--no-remove`.
+    #[arg(long, overrides_with("exact"), conflicts_with = "remove")]
+    pub(crate) inexact: bool,
+
+    /// Perform a dry run, i.e., don't actually install or uninstall anything but resolve the
+    /// requirements and print the resulting plan, grouped by action.
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+
+    /// The format in which to print the plan when performing a dry run.
+    #[arg(long, value_enum, default_value_t = SyncFormat::default(), requires = "dry_run")]
+    pub(crate) format: SyncFormat,
+
+    /// Exit with a non-zero status if the dry run would make any changes to the environment.
+    #[arg(long, requires = "dry_run")]
+    pub(crate) fail_on_change: bool,
+
+    /// Download the distributions listed in the input files into the given directory, rather than
+    /// installing them.
+    ///
+    /// Each wheel or source distribution is saved as-is, after verifying any hashes, alongside an
+    /// `index.html` page that lists them. The directory can then be used as a package source with
+    /// `--find-links <DIR> --no-index --offline`.
+    #[arg(long, value_name = "DIR", conflicts_with = "dry_run")]
+    pub(crate) download_only: Option<PathBuf>,
+
+    /// With `--download-only`, build any source distributions into wheels, rather than saving the
+    /// source distributions as-is.
+    #[arg(long, requires = "download_only")]
+    pub(crate) build_wheels: bool,
+
+    /// After installing, verify that the files of each newly installed package match the sizes
+    /// and hashes recorded in its `RECORD`.
+    ///
+    /// Exits with a non-zero status if any files are missing or modified.
+    #[arg(long, conflicts_with_all = ["dry_run", "download_only"])]
+    pub(crate) verify: bool,
+
     #[arg(
         global = true,
         long,
@@ -605,6 +1028,33 @@ pub(crate) struct PipSyncArgs {
     #[arg(long)]
     pub(crate) refresh_package: Vec<PackageName>,
 
+    /// Ignore package dependencies, instead only installing those packages explicitly listed in
+    /// the given `requirements.txt` files.
+    ///
+    /// `uv pip sync` never installs packages that aren't listed in its inputs. With `--no-deps`,
+    /// `uv` will additionally warn about any dependencies of the installed packages that are
+    /// missing from the environment, as the input files are assumed to be complete.
+    #[arg(long)]
+    pub(crate) no_deps: bool,
+
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub(crate) deps: bool,
+
+    /// Don't remove any installed packages that aren't listed in the input files, instead only
+    /// installing or upgrading the packages that are listed.
+    #[arg(long, overrides_with("remove"), conflicts_with = "exact")]
+    pub(crate) no_remove: bool,
+
+    #[arg(long, overrides_with("no_remove"), hide = true)]
+    pub(crate) remove: bool,
+
+    /// Keep the given package installed, even if it isn't listed in the input files.
+    ///
+    /// If a kept package is listed in the input files at a version other than the one that's
+    /// installed, `uv` will error rather than replacing it.
+    #[arg(long, conflicts_with = "exact")]
+    pub(crate) keep: Option<Vec<PackageName>>,
+
     /// The method to use when installing packages from the global cache.
     ///
     /// Defaults to `clone` (also known as Copy-on-Write) on macOS, and `hardlink` on Linux and
@@ -638,12 +1088,27 @@ pub(crate) struct PipSyncArgs {
     /// Locations to search for candidate distributions, beyond those found in the indexes.
     ///
     /// If a path, the target must be a directory that contains package as wheel files (`.whl`) or
-    /// source distributions (`.tar.gz` or `.zip`) at the top level.
+    /// source distributions (`.tar.gz` or `.zip`) at the top level (or, with
+    /// `--find-links-recursive`, in any subdirectory).
+    ///
+    /// If a directory contains a `SHA256SUMS` manifest (as produced by `sha256sum`), the
+    /// distributions it lists are verified against their expected hashes on install, and any
+    /// distributions it doesn't list are ignored.
     ///
     /// If a URL, the page must contain a flat list of links to package files.
     #[arg(long, short)]
     pub(crate) find_links: Option<Vec<FlatIndexLocation>>,
 
+    /// Scan `--find-links` directories recursively, including distributions in nested
+    /// subdirectories.
+    ///
+    /// Symbolic links to directories are followed, but each directory is scanned at most once.
+    #[arg(long, overrides_with("no_find_links_recursive"))]
+    pub(crate) find_links_recursive: bool,
+
+    #[arg(long, overrides_with("find_links_recursive"), hide = true)]
+    pub(crate) no_find_links_recursive: bool,
+
     /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
     /// discovered via `--find-links`.
     #[arg(long)]
@@ -669,6 +1134,10 @@ pub(crate) struct PipSyncArgs {
     /// - Editable installs are not supported.
     /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
     ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    ///
+    /// As in pip, source distributions are verified against their hashes before they're built, but
+    /// the resulting wheels (and any build dependencies) are not. To avoid building from source
+    /// entirely, use `--only-binary :all:`.
     #[arg(long, overrides_with("no_require_hashes"))]
     pub(crate) require_hashes: bool,
 
@@ -699,6 +1168,31 @@ pub(crate) struct PipSyncArgs {
     #[arg(long, short, verbatim_doc_comment, group = "discovery")]
     pub(crate) python: Option<String>,
 
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    ///
+    /// Only applies when the interpreter is discovered by version (e.g., `--python 3.12`) or by
+    /// default, rather than requested by path or executable name. Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
+    /// Create the virtual environment that `--python` points to, if it doesn't exist.
+    ///
+    /// Applies when `--python` is a path to a virtual environment (e.g., `.venv-docs`) or to an
+    /// interpreter within one (e.g., `.venv-docs/bin/python`). The environment is created as with
+    /// `uv venv`, respecting `--python-preference` and `--python-implementation`. If the path
+    /// exists, but isn't a virtual environment, `uv` errors instead.
+    #[arg(long, requires = "python")]
+    pub(crate) allow_create: bool,
+
+    /// Restrict interpreter discovery to a specific Python implementation (e.g., `pypy`).
+    ///
+    /// When searching by version, only interpreters of the given implementation are considered
+    /// (e.g., `pypy3.10` rather than `python3.10`). An interpreter requested by path or executable
+    /// name must be of the given implementation.
+    #[arg(long, value_enum, env = "UV_PYTHON_IMPLEMENTATION")]
+    pub(crate) python_implementation: Option<PythonImplementation>,
+
     /// Install packages into the system Python.
     ///
     /// By default, `uv` installs into the virtual environment in the current working directory or
@@ -737,6 +1231,21 @@ pub(crate) struct PipSyncArgs {
     #[arg(long, overrides_with("break_system_packages"))]
     pub(crate) no_break_system_packages: bool,
 
+    /// Require that the target environment is a virtual environment.
+    ///
+    /// When enabled, `uv` will refuse to modify an interpreter that isn't part of a virtual
+    /// environment, unless `--system` is passed explicitly.
+    #[arg(
+        long,
+        env = "UV_REQUIRE_VIRTUALENV",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_virtualenv")
+    )]
+    pub(crate) require_virtualenv: bool,
+
+    #[arg(long, overrides_with("require_virtualenv"), hide = true)]
+    pub(crate) no_require_virtualenv: bool,
+
     /// Use legacy `setuptools` behavior when building source distributions without a
     /// `pyproject.toml`.
     #[arg(long, overrides_with("no_legacy_setup_py"))]
@@ -754,6 +1263,27 @@ pub(crate) struct PipSyncArgs {
     #[arg(long, overrides_with("no_build_isolation"), hide = true)]
     pub(crate) build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
+    /// Other packages continue to be built in isolated environments.
+    #[arg(long, conflicts_with = "no_build_isolation")]
+    pub(crate) no_build_isolation_package: Option<Vec<PackageName>>,
+
+    /// Reuse the build directory of a source distribution across builds, rather than building in a
+    /// fresh temporary directory.
+    ///
+    /// The build directory is retained in the cache, keyed by the package and the path to the
+    /// source tree. On each build, only the source files whose contents changed are synced into it,
+    /// such that the build backend can reuse the artifacts of the previous build (e.g., compiled
+    /// extension modules). Changes to the config settings or the Python interpreter result in a
+    /// fresh build directory. Stale build directories are removed by `uv cache prune`.
+    #[arg(long, overrides_with("clean"))]
+    pub(crate) no_clean: bool,
+
+    #[arg(long, overrides_with("no_clean"), hide = true)]
+    pub(crate) clean: bool,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary code. The cached wheels of already-built
@@ -819,10 +1349,65 @@ pub(crate) struct PipSyncArgs {
     )]
     pub(crate) no_compile_bytecode: bool,
 
+    /// The optimization level to use when compiling Python files to bytecode, as with `python -O`
+    /// (`1`) or `python -OO` (`2`).
+    ///
+    /// Optimized bytecode is written to `.opt-1.pyc` or `.opt-2.pyc` files, which are only used
+    /// when Python runs at the same optimization level. Only applies with `--compile-bytecode`.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=2))]
+    pub(crate) bytecode_opt_level: Option<u8>,
+
+    /// Allow an entrypoint script to overwrite a script of the same name that was installed by a
+    /// different package, emitting a warning for each such script (the default).
+    ///
+    /// Use `--no-overwrite-scripts` to abort the installation instead.
+    #[arg(long, overrides_with("no_overwrite_scripts"))]
+    pub(crate) overwrite_scripts: bool,
+
+    /// Abort the installation if an entrypoint script would overwrite a script of the same name
+    /// that was installed by a different package.
+    #[arg(long, overrides_with("overwrite_scripts"))]
+    pub(crate) no_overwrite_scripts: bool,
+
+    /// Additional platform tags to accept for a specific package, specified as `PACKAGE:TAG`
+    /// pairs (e.g., `foo:linux_x86_64`).
+    ///
+    /// Wheels for the package with the given platform tag are considered compatible with the
+    /// current platform, as long as their Python and ABI tags are compatible. Use with care: the
+    /// wheel may not work on the current platform.
+    #[arg(long)]
+    pub(crate) allow_platform_tag: Option<Vec<CompatibleTagEntry>>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
+    ///
+    /// The settings are passed to every build hook, including the `get_requires_for_build_*`
+    /// and `prepare_metadata_for_build_*` hooks, `build_wheel`, and the PEP 660 `build_editable`
+    /// hook used for editable installs (e.g., `-C editable_mode=compat` for setuptools).
     #[arg(long, short = 'C', alias = "config-settings")]
     pub(crate) config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    /// Environment variables to set when building a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    ///
+    /// The variables are only passed to the build backend for the given package, on top of the
+    /// inherited environment. Values may reference other variables as `${VAR}`.
+    #[arg(long)]
+    pub(crate) build_env: Option<Vec<BuildEnvEntry>>,
+
+    /// Stream the output of the build backend while building source distributions.
+    ///
+    /// Each line is prefixed with the name of the build hook that produced it.
+    #[arg(long)]
+    pub(crate) verbose_build: bool,
+
+    /// The strategy to use when building editable requirements.
+    ///
+    /// By default, `uv` builds a PEP 660 editable wheel if the build backend supports it (i.e.,
+    /// defines `build_editable`), and falls back to a legacy editable installation, which adds
+    /// the source tree to `sys.path` via a `.pth` file, otherwise (`auto`).
+    #[arg(long, value_enum, env = "UV_EDITABLE_MODE")]
+    pub(crate) editable_mode: Option<EditableMode>,
+
     /// Validate the virtual environment after completing the installation, to detect packages with
     /// missing dependencies or other issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -838,7 +1423,7 @@ pub(crate) struct PipSyncArgs {
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
-pub(crate) struct PipInstallArgs {
+pub struct PipInstallArgs {
     /// Install all listed packages.
     #[arg(group = "sources")]
     pub(crate) package: Vec<String>,
@@ -873,6 +1458,23 @@ pub(crate) struct PipInstallArgs {
     #[arg(long)]
     pub(crate) r#override: Vec<PathBuf>,
 
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    ///
+    /// Build constraints are `requirements.txt`-like files that only apply to the resolution of
+    /// build requirements (e.g., `setuptools`, `cython`) in isolated build environments, and
+    /// don't affect the resolution of the requirements themselves.
+    #[arg(long, short = 'b')]
+    pub(crate) build_constraint: Vec<PathBuf>,
+
+    /// Install editable requirements for VCS URLs (e.g., `-e git+https://...#egg=example`) as
+    /// regular, non-editable requirements.
+    ///
+    /// Editable installs are only supported for local directories. Without this flag, editable VCS
+    /// requirements are rejected with an error.
+    #[arg(long)]
+    pub(crate) convert_editable_vcs: bool,
+
     /// Include optional dependencies in the given extra group name; may be provided more than once.
     #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
     pub(crate) extra: Option<Vec<ExtraName>>,
@@ -889,8 +1491,11 @@ pub(crate) struct PipInstallArgs {
     pub(crate) upgrade: bool,
 
     /// Allow upgrade of a specific package.
+    ///
+    /// Accepts either a package name (e.g., `requests`), to allow any newer version, or a
+    /// requirement with a version specifier (e.g., `requests<2.32`), to limit the upgrade.
     #[arg(long, short = 'P')]
-    pub(crate) upgrade_package: Option<Vec<PackageName>>,
+    pub(crate) upgrade_package: Option<Vec<Requirement>>,
 
     /// Reinstall all packages, regardless of whether they're already installed.
     #[arg(long, alias = "force-reinstall")]
@@ -942,6 +1547,14 @@ pub(crate) struct PipInstallArgs {
     #[arg(long, value_enum, env = "UV_RESOLUTION")]
     pub(crate) resolution: Option<ResolutionMode>,
 
+    /// Exclude a package from the `--resolution` strategy, such that it resolves to its highest
+    /// compatible version.
+    ///
+    /// Useful with `--resolution=lowest` to skip packages whose oldest compatible releases can't
+    /// be installed (e.g., because they predate wheels for the target platform).
+    #[arg(long)]
+    pub(crate) resolution_exclude: Option<Vec<PackageName>>,
+
     /// The strategy to use when considering pre-release versions.
     ///
     /// By default, `uv` will accept pre-releases for packages that _only_ publish pre-releases,
@@ -953,6 +1566,14 @@ pub(crate) struct PipInstallArgs {
     #[arg(long, hide = true)]
     pub(crate) pre: bool,
 
+    /// Allow pre-release versions for a specific package, regardless of the `--prerelease`
+    /// strategy.
+    ///
+    /// Any pre-release versions selected for these packages are annotated in the output, so that
+    /// they stand out during review.
+    #[arg(long)]
+    pub(crate) prerelease_package: Option<Vec<PackageName>>,
+
     /// The URL of the Python package index (by default: <https://pypi.org/simple>).
     ///
     /// The index given by this flag is given lower priority than all other
@@ -979,12 +1600,27 @@ pub(crate) struct PipInstallArgs {
     /// Locations to search for candidate distributions, beyond those found in the indexes.
     ///
     /// If a path, the target must be a directory that contains package as wheel files (`.whl`) or
-    /// source distributions (`.tar.gz` or `.zip`) at the top level.
+    /// source distributions (`.tar.gz` or `.zip`) at the top level (or, with
+    /// `--find-links-recursive`, in any subdirectory).
+    ///
+    /// If a directory contains a `SHA256SUMS` manifest (as produced by `sha256sum`), the
+    /// distributions it lists are verified against their expected hashes on install, and any
+    /// distributions it doesn't list are ignored.
     ///
     /// If a URL, the page must contain a flat list of links to package files.
     #[arg(long, short)]
     pub(crate) find_links: Option<Vec<FlatIndexLocation>>,
 
+    /// Scan `--find-links` directories recursively, including distributions in nested
+    /// subdirectories.
+    ///
+    /// Symbolic links to directories are followed, but each directory is scanned at most once.
+    #[arg(long, overrides_with("no_find_links_recursive"))]
+    pub(crate) find_links_recursive: bool,
+
+    #[arg(long, overrides_with("find_links_recursive"), hide = true)]
+    pub(crate) no_find_links_recursive: bool,
+
     /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
     /// discovered via `--find-links`.
     #[arg(long)]
@@ -1010,6 +1646,10 @@ pub(crate) struct PipInstallArgs {
     /// - Editable installs are not supported.
     /// - Local dependencies are not supported, unless they point to a specific wheel (`.whl`) or
     ///   source archive (`.zip`, `.tar.gz`), as opposed to a directory.
+    ///
+    /// As in pip, source distributions are verified against their hashes before they're built, but
+    /// the resulting wheels (and any build dependencies) are not. To avoid building from source
+    /// entirely, use `--only-binary :all:`.
     #[arg(long, overrides_with("no_require_hashes"))]
     pub(crate) require_hashes: bool,
 
@@ -1040,6 +1680,31 @@ pub(crate) struct PipInstallArgs {
     #[arg(long, short, verbatim_doc_comment, group = "discovery")]
     pub(crate) python: Option<String>,
 
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    ///
+    /// Only applies when the interpreter is discovered by version (e.g., `--python 3.12`) or by
+    /// default, rather than requested by path or executable name. Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
+    /// Create the virtual environment that `--python` points to, if it doesn't exist.
+    ///
+    /// Applies when `--python` is a path to a virtual environment (e.g., `.venv-docs`) or to an
+    /// interpreter within one (e.g., `.venv-docs/bin/python`). The environment is created as with
+    /// `uv venv`, respecting `--python-preference` and `--python-implementation`. If the path
+    /// exists, but isn't a virtual environment, `uv` errors instead.
+    #[arg(long, requires = "python")]
+    pub(crate) allow_create: bool,
+
+    /// Restrict interpreter discovery to a specific Python implementation (e.g., `pypy`).
+    ///
+    /// When searching by version, only interpreters of the given implementation are considered
+    /// (e.g., `pypy3.10` rather than `python3.10`). An interpreter requested by path or executable
+    /// name must be of the given implementation.
+    #[arg(long, value_enum, env = "UV_PYTHON_IMPLEMENTATION")]
+    pub(crate) python_implementation: Option<PythonImplementation>,
+
     /// Install packages into the system Python.
     ///
     /// By default, `uv` installs into the virtual environment in the current working directory or
@@ -1078,6 +1743,21 @@ pub(crate) struct PipInstallArgs {
     #[arg(long, overrides_with("break_system_packages"))]
     pub(crate) no_break_system_packages: bool,
 
+    /// Require that the target environment is a virtual environment.
+    ///
+    /// When enabled, `uv` will refuse to modify an interpreter that isn't part of a virtual
+    /// environment, unless `--system` is passed explicitly.
+    #[arg(
+        long,
+        env = "UV_REQUIRE_VIRTUALENV",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_virtualenv")
+    )]
+    pub(crate) require_virtualenv: bool,
+
+    #[arg(long, overrides_with("require_virtualenv"), hide = true)]
+    pub(crate) no_require_virtualenv: bool,
+
     /// Use legacy `setuptools` behavior when building source distributions without a
     /// `pyproject.toml`.
     #[arg(long, overrides_with("no_legacy_setup_py"))]
@@ -1095,6 +1775,27 @@ pub(crate) struct PipInstallArgs {
     #[arg(long, overrides_with("no_build_isolation"), hide = true)]
     pub(crate) build_isolation: bool,
 
+    /// Disable isolation when building source distributions for a specific package.
+    ///
+    /// Assumes that the packages' build dependencies specified by PEP 518 are already installed.
+    /// Other packages continue to be built in isolated environments.
+    #[arg(long, conflicts_with = "no_build_isolation")]
+    pub(crate) no_build_isolation_package: Option<Vec<PackageName>>,
+
+    /// Reuse the build directory of a source distribution across builds, rather than building in a
+    /// fresh temporary directory.
+    ///
+    /// The build directory is retained in the cache, keyed by the package and the path to the
+    /// source tree. On each build, only the source files whose contents changed are synced into it,
+    /// such that the build backend can reuse the artifacts of the previous build (e.g., compiled
+    /// extension modules). Changes to the config settings or the Python interpreter result in a
+    /// fresh build directory. Stale build directories are removed by `uv cache prune`.
+    #[arg(long, overrides_with("clean"))]
+    pub(crate) no_clean: bool,
+
+    #[arg(long, overrides_with("no_clean"), hide = true)]
+    pub(crate) clean: bool,
+
     /// Don't build source distributions.
     ///
     /// When enabled, resolving will not run arbitrary code. The cached wheels of already-built
@@ -1160,10 +1861,65 @@ pub(crate) struct PipInstallArgs {
     )]
     pub(crate) no_compile_bytecode: bool,
 
+    /// The optimization level to use when compiling Python files to bytecode, as with `python -O`
+    /// (`1`) or `python -OO` (`2`).
+    ///
+    /// Optimized bytecode is written to `.opt-1.pyc` or `.opt-2.pyc` files, which are only used
+    /// when Python runs at the same optimization level. Only applies with `--compile-bytecode`.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=2))]
+    pub(crate) bytecode_opt_level: Option<u8>,
+
+    /// Allow an entrypoint script to overwrite a script of the same name that was installed by a
+    /// different package, emitting a warning for each such script (the default).
+    ///
+    /// Use `--no-overwrite-scripts` to abort the installation instead.
+    #[arg(long, overrides_with("no_overwrite_scripts"))]
+    pub(crate) overwrite_scripts: bool,
+
+    /// Abort the installation if an entrypoint script would overwrite a script of the same name
+    /// that was installed by a different package.
+    #[arg(long, overrides_with("overwrite_scripts"))]
+    pub(crate) no_overwrite_scripts: bool,
+
+    /// Additional platform tags to accept for a specific package, specified as `PACKAGE:TAG`
+    /// pairs (e.g., `foo:linux_x86_64`).
+    ///
+    /// Wheels for the package with the given platform tag are considered compatible with the
+    /// current platform, as long as their Python and ABI tags are compatible. Use with care: the
+    /// wheel may not work on the current platform.
+    #[arg(long)]
+    pub(crate) allow_platform_tag: Option<Vec<CompatibleTagEntry>>,
+
     /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
+    ///
+    /// The settings are passed to every build hook, including the `get_requires_for_build_*`
+    /// and `prepare_metadata_for_build_*` hooks, `build_wheel`, and the PEP 660 `build_editable`
+    /// hook used for editable installs (e.g., `-C editable_mode=compat` for setuptools).
     #[arg(long, short = 'C', alias = "config-settings")]
     pub(crate) config_setting: Option<Vec<ConfigSettingEntry>>,
 
+    /// Environment variables to set when building a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    ///
+    /// The variables are only passed to the build backend for the given package, on top of the
+    /// inherited environment. Values may reference other variables as `${VAR}`.
+    #[arg(long)]
+    pub(crate) build_env: Option<Vec<BuildEnvEntry>>,
+
+    /// Stream the output of the build backend while building source distributions.
+    ///
+    /// Each line is prefixed with the name of the build hook that produced it.
+    #[arg(long)]
+    pub(crate) verbose_build: bool,
+
+    /// The strategy to use when building editable requirements.
+    ///
+    /// By default, `uv` builds a PEP 660 editable wheel if the build backend supports it (i.e.,
+    /// defines `build_editable`), and falls back to a legacy editable installation, which adds
+    /// the source tree to `sys.path` via a `.pth` file, otherwise (`auto`).
+    #[arg(long, value_enum, env = "UV_EDITABLE_MODE")]
+    pub(crate) editable_mode: Option<EditableMode>,
+
     /// Validate the virtual environment after completing the installation, to detect packages with
     /// missing dependencies or other issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -1179,17 +1935,645 @@ pub(crate) struct PipInstallArgs {
     #[arg(long)]
     pub(crate) exclude_newer: Option<ExcludeNewer>,
 
+    /// Remove any installed packages that aren't required by the resolution, such that the
+    /// environment matches the requirements exactly, as with `uv pip sync --exact`.
+    ///
+    /// Seed packages (`pip`, `setuptools`, `wheel`, `distribute`, and `uv`) are preserved unless
+    /// they're required, and a package that can't be removed is treated as an error.
+    #[arg(long, overrides_with("inexact"))]
+    pub(crate) exact: bool,
+
+    #[arg(long, overrides_with("exact"), hide = true)]
+    pub(crate) inexact: bool,
+
     /// Perform a dry run, i.e., don't actually install anything but resolve the dependencies and
     /// print the resulting plan.
     #[arg(long)]
     pub(crate) dry_run: bool,
+
+    /// After installing, verify that the files of each newly installed package match the sizes
+    /// and hashes recorded in its `RECORD`.
+    ///
+    /// Exits with a non-zero status if any files are missing or modified.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub(crate) verify: bool,
 }
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
-pub(crate) struct PipUninstallArgs {
-    /// Uninstall all listed packages.
+pub struct PipDownloadArgs {
+    /// Download all listed packages.
+    #[arg(group = "sources")]
+    pub(crate) package: Vec<String>,
+
+    /// Download all packages listed in the given requirements files.
+    #[arg(long, short, group = "sources")]
+    pub(crate) requirement: Vec<PathBuf>,
+
+    /// The directory into which the distributions should be downloaded.
+    ///
+    /// Defaults to the current working directory.
+    #[arg(long, short = 'd', alias = "destination-directory")]
+    pub(crate) dest: Option<PathBuf>,
+
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's downloaded. However, including a package in a constraints file will
+    /// _not_ trigger the download of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(long, short)]
+    pub(crate) constraint: Vec<PathBuf>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be downloaded, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    #[arg(long)]
+    pub(crate) r#override: Vec<PathBuf>,
+
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    #[arg(long, short = 'b')]
+    pub(crate) build_constraint: Vec<PathBuf>,
+
+    /// Include optional dependencies in the given extra group name; may be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub(crate) extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra", overrides_with = "no_all_extras")]
+    pub(crate) all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub(crate) no_all_extras: bool,
+
+    #[arg(
+        global = true,
+        long,
+        conflicts_with = "refresh",
+        conflicts_with = "refresh_package",
+        overrides_with("no_offline")
+    )]
+    pub(crate) offline: bool,
+
+    #[arg(long, overrides_with("offline"), hide = true)]
+    pub(crate) no_offline: bool,
+
+    /// Refresh all cached data.
+    #[arg(long)]
+    pub(crate) refresh: bool,
+
+    /// Refresh cached data for a specific package.
+    #[arg(long)]
+    pub(crate) refresh_package: Option<Vec<PackageName>>,
+
+    /// Ignore package dependencies, instead only downloading those packages explicitly listed
+    /// on the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub(crate) no_deps: bool,
+
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub(crate) deps: bool,
+
+    /// The strategy to use when selecting between the different compatible versions for a given
+    /// package requirement.
+    ///
+    /// By default, `uv` will use the latest compatible version of each package (`highest`).
+    #[arg(long, value_enum, env = "UV_RESOLUTION")]
+    pub(crate) resolution: Option<ResolutionMode>,
+
+    /// The strategy to use when considering pre-release versions.
+    ///
+    /// By default, `uv` will accept pre-releases for packages that _only_ publish pre-releases,
+    /// along with first-party requirements that contain an explicit pre-release marker in the
+    /// declared specifiers (`if-necessary-or-explicit`).
+    #[arg(long, value_enum, env = "UV_PRERELEASE")]
+    pub(crate) prerelease: Option<PreReleaseMode>,
+
+    #[arg(long, hide = true)]
+    pub(crate) pre: bool,
+
+    /// Allow pre-release versions for a specific package, regardless of the `--prerelease`
+    /// strategy.
+    #[arg(long)]
+    pub(crate) prerelease_package: Option<Vec<PackageName>>,
+
+    /// The URL of the Python package index (by default: <https://pypi.org/simple>).
+    ///
+    /// The index given by this flag is given lower priority than all other
+    /// indexes specified via the `--extra-index-url` flag.
+    #[arg(long, short, env = "UV_INDEX_URL", value_parser = parse_index_url)]
+    pub(crate) index_url: Option<Maybe<IndexUrl>>,
+
+    /// Extra URLs of package indexes to use, in addition to `--index-url`.
+    ///
+    /// All indexes given via this flag take priority over the index
+    /// in `--index-url` (which defaults to PyPI). And when multiple
+    /// `--extra-index-url` flags are given, earlier values take priority.
+    #[arg(long, env = "UV_EXTRA_INDEX_URL", value_delimiter = ' ', value_parser = parse_index_url)]
+    pub(crate) extra_index_url: Option<Vec<Maybe<IndexUrl>>>,
+
+    /// Locations to search for candidate distributions, beyond those found in the indexes.
+    ///
+    /// If a path, the target must be a directory that contains package as wheel files (`.whl`) or
+    /// source distributions (`.tar.gz` or `.zip`) at the top level (or, with
+    /// `--find-links-recursive`, in any subdirectory).
+    ///
+    /// If a URL, the page must contain a flat list of links to package files.
+    #[arg(long, short)]
+    pub(crate) find_links: Option<Vec<FlatIndexLocation>>,
+
+    /// Scan `--find-links` directories recursively, including distributions in nested
+    /// subdirectories.
+    #[arg(long, overrides_with("no_find_links_recursive"))]
+    pub(crate) find_links_recursive: bool,
+
+    #[arg(long, overrides_with("find_links_recursive"), hide = true)]
+    pub(crate) no_find_links_recursive: bool,
+
+    /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
+    /// discovered via `--find-links`.
+    #[arg(long)]
+    pub(crate) no_index: bool,
+
+    /// The strategy to use when resolving against multiple index URLs.
+    ///
+    /// By default, `uv` will stop at the first index on which a given package is available, and
+    /// limit resolutions to those present on that first index (`first-match`).
+    #[arg(long, value_enum, env = "UV_INDEX_STRATEGY")]
+    pub(crate) index_strategy: Option<IndexStrategy>,
+
+    /// Require a matching hash for each requirement.
+    ///
+    /// Hash-checking mode is all or nothing. If enabled, _all_ requirements must be provided
+    /// with a corresponding hash or set of hashes, and each downloaded distribution is verified
+    /// against its hashes before it's written to the destination directory.
+    #[arg(long, overrides_with("no_require_hashes"))]
+    pub(crate) require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub(crate) no_require_hashes: bool,
+
+    /// Attempt to use `keyring` for authentication for index URLs.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = "UV_KEYRING_PROVIDER")]
+    pub(crate) keyring_provider: Option<KeyringProviderType>,
+
+    /// The Python interpreter to use when resolving, and for building source distributions.
+    ///
+    /// By default, `uv` uses the virtual environment in the current working directory or any
+    /// parent directory, falling back to the first Python found in the system `PATH`.
+    #[arg(long, short, verbatim_doc_comment)]
+    pub(crate) python: Option<String>,
+
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
+    /// Restrict interpreter discovery to a specific Python implementation (e.g., `pypy`).
+    #[arg(long, value_enum, env = "UV_PYTHON_IMPLEMENTATION")]
+    pub(crate) python_implementation: Option<PythonImplementation>,
+
+    /// Use the system Python, rather than a virtual environment, when resolving.
+    #[arg(
+        long,
+        env = "UV_SYSTEM_PYTHON",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub(crate) system: bool,
+
+    #[arg(long, overrides_with("system"))]
+    pub(crate) no_system: bool,
+
+    /// The Python version for which distributions should be downloaded (e.g., `3.7` or `3.7.9`).
+    ///
+    /// Defaults to the version of the Python interpreter used for resolution.
+    ///
+    /// Only the Python version can be targeted: wheels are always selected for the platform tags
+    /// of the current interpreter, so downloading for another operating system or architecture
+    /// (as with pip's `--platform`) isn't supported.
+    #[arg(long)]
+    pub(crate) python_version: Option<PythonVersion>,
+
+    /// Use legacy `setuptools` behavior when building source distributions without a
+    /// `pyproject.toml`.
+    #[arg(long, overrides_with("no_legacy_setup_py"))]
+    pub(crate) legacy_setup_py: bool,
+
+    #[arg(long, overrides_with("legacy_setup_py"), hide = true)]
+    pub(crate) no_legacy_setup_py: bool,
+
+    /// Disable isolation when building source distributions.
+    ///
+    /// Assumes that build dependencies specified by PEP 518 are already installed.
+    #[arg(long, overrides_with("build_isolation"))]
+    pub(crate) no_build_isolation: bool,
+
+    #[arg(long, overrides_with("no_build_isolation"), hide = true)]
+    pub(crate) build_isolation: bool,
+
+    /// Disable isolation when building source distributions for a specific package.
+    #[arg(long, conflicts_with = "no_build_isolation")]
+    pub(crate) no_build_isolation_package: Option<Vec<PackageName>>,
+
+    /// Reuse the build directory of a source distribution across builds, rather than building in a
+    /// fresh temporary directory.
+    ///
+    /// The build directory is retained in the cache, keyed by the package and the path to the
+    /// source tree. On each build, only the source files whose contents changed are synced into it,
+    /// such that the build backend can reuse the artifacts of the previous build (e.g., compiled
+    /// extension modules). Changes to the config settings or the Python interpreter result in a
+    /// fresh build directory. Stale build directories are removed by `uv cache prune`.
+    #[arg(long, overrides_with("clean"))]
+    pub(crate) no_clean: bool,
+
+    #[arg(long, overrides_with("no_clean"), hide = true)]
+    pub(crate) clean: bool,
+
+    /// Don't build or download source distributions.
+    ///
+    /// Alias for `--only-binary :all:`.
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
+    )]
+    pub(crate) no_build: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
+    )]
+    pub(crate) build: bool,
+
+    /// Don't download pre-built wheels.
+    ///
+    /// The source distributions of the given packages will be downloaded instead. The resolver
+    /// will still use pre-built wheels for metadata.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, conflicts_with = "no_build")]
+    pub(crate) no_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Only download pre-built wheels; don't download source distributions.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, conflicts_with = "no_build")]
+    pub(crate) only_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Additional platform tags to accept for a specific package, specified as `PACKAGE:TAG`
+    /// pairs (e.g., `foo:linux_x86_64`).
+    ///
+    /// Wheels for the package with the given platform tag are considered compatible with the
+    /// current platform, as long as their Python and ABI tags are compatible. Use with care: the
+    /// wheel may not work on the current platform.
+    #[arg(long)]
+    pub(crate) allow_platform_tag: Option<Vec<CompatibleTagEntry>>,
+
+    /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
+    #[arg(long, short = 'C', alias = "config-settings")]
+    pub(crate) config_setting: Option<Vec<ConfigSettingEntry>>,
+
+    /// Environment variables to set when building a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    #[arg(long)]
+    pub(crate) build_env: Option<Vec<BuildEnvEntry>>,
+
+    /// Stream the output of the build backend while building source distributions.
+    #[arg(long)]
+    pub(crate) verbose_build: bool,
+
+    /// Limit candidate packages to those that were uploaded prior to the given date.
+    ///
+    /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
+    /// format (e.g., `2006-12-02`).
+    #[arg(long)]
+    pub(crate) exclude_newer: Option<ExcludeNewer>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipWheelArgs {
+    /// Build wheels for all listed packages.
+    #[arg(group = "sources")]
+    pub(crate) package: Vec<String>,
+
+    /// Build wheels for all packages listed in the given requirements files.
+    #[arg(long, short, group = "sources")]
+    pub(crate) requirement: Vec<PathBuf>,
+
+    /// The directory into which the wheels should be written.
+    ///
+    /// Defaults to the current working directory.
+    #[arg(long, short = 'w')]
+    pub(crate) wheel_dir: Option<PathBuf>,
+
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's built. However, including a package in a constraints file will _not_
+    /// trigger a build of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(long, short)]
+    pub(crate) constraint: Vec<PathBuf>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be built, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    #[arg(long)]
+    pub(crate) r#override: Vec<PathBuf>,
+
+    /// Constrain build dependencies using the given requirements files when building source
+    /// distributions.
+    #[arg(long, short = 'b')]
+    pub(crate) build_constraint: Vec<PathBuf>,
+
+    /// Include optional dependencies in the given extra group name; may be provided more than once.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub(crate) extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    #[arg(long, conflicts_with = "extra", overrides_with = "no_all_extras")]
+    pub(crate) all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub(crate) no_all_extras: bool,
+
+    #[arg(
+        global = true,
+        long,
+        conflicts_with = "refresh",
+        conflicts_with = "refresh_package",
+        overrides_with("no_offline")
+    )]
+    pub(crate) offline: bool,
+
+    #[arg(long, overrides_with("offline"), hide = true)]
+    pub(crate) no_offline: bool,
+
+    /// Refresh all cached data.
+    #[arg(long)]
+    pub(crate) refresh: bool,
+
+    /// Refresh cached data for a specific package.
+    #[arg(long)]
+    pub(crate) refresh_package: Option<Vec<PackageName>>,
+
+    /// Ignore package dependencies, instead only building wheels for those packages explicitly
+    /// listed on the command line or in the requirements files.
+    #[arg(long, overrides_with("deps"))]
+    pub(crate) no_deps: bool,
+
+    #[arg(long, overrides_with("no_deps"), hide = true)]
+    pub(crate) deps: bool,
+
+    /// The strategy to use when selecting between the different compatible versions for a given
+    /// package requirement.
+    ///
+    /// By default, `uv` will use the latest compatible version of each package (`highest`).
+    #[arg(long, value_enum, env = "UV_RESOLUTION")]
+    pub(crate) resolution: Option<ResolutionMode>,
+
+    /// The strategy to use when considering pre-release versions.
+    ///
+    /// By default, `uv` will accept pre-releases for packages that _only_ publish pre-releases,
+    /// along with first-party requirements that contain an explicit pre-release marker in the
+    /// declared specifiers (`if-necessary-or-explicit`).
+    #[arg(long, value_enum, env = "UV_PRERELEASE")]
+    pub(crate) prerelease: Option<PreReleaseMode>,
+
+    #[arg(long, hide = true)]
+    pub(crate) pre: bool,
+
+    /// Allow pre-release versions for a specific package, regardless of the `--prerelease`
+    /// strategy.
+    #[arg(long)]
+    pub(crate) prerelease_package: Option<Vec<PackageName>>,
+
+    /// The URL of the Python package index (by default: <https://pypi.org/simple>).
+    ///
+    /// The index given by this flag is given lower priority than all other
+    /// indexes specified via the `--extra-index-url` flag.
+    #[arg(long, short, env = "UV_INDEX_URL", value_parser = parse_index_url)]
+    pub(crate) index_url: Option<Maybe<IndexUrl>>,
+
+    /// Extra URLs of package indexes to use, in addition to `--index-url`.
+    ///
+    /// All indexes given via this flag take priority over the index
+    /// in `--index-url` (which defaults to PyPI). And when multiple
+    /// `--extra-index-url` flags are given, earlier values take priority.
+    #[arg(long, env = "UV_EXTRA_INDEX_URL", value_delimiter = ' ', value_parser = parse_index_url)]
+    pub(crate) extra_index_url: Option<Vec<Maybe<IndexUrl>>>,
+
+    /// Locations to search for candidate distributions, beyond those found in the indexes.
+    ///
+    /// If a path, the target must be a directory that contains package as wheel files (`.whl`) or
+    /// source distributions (`.tar.gz` or `.zip`) at the top level (or, with
+    /// `--find-links-recursive`, in any subdirectory).
+    ///
+    /// If a URL, the page must contain a flat list of links to package files.
+    #[arg(long, short)]
+    pub(crate) find_links: Option<Vec<FlatIndexLocation>>,
+
+    /// Scan `--find-links` directories recursively, including distributions in nested
+    /// subdirectories.
+    #[arg(long, overrides_with("no_find_links_recursive"))]
+    pub(crate) find_links_recursive: bool,
+
+    #[arg(long, overrides_with("find_links_recursive"), hide = true)]
+    pub(crate) no_find_links_recursive: bool,
+
+    /// Ignore the registry index (e.g., PyPI), instead relying on direct URL dependencies and those
+    /// discovered via `--find-links`.
+    #[arg(long)]
+    pub(crate) no_index: bool,
+
+    /// The strategy to use when resolving against multiple index URLs.
+    ///
+    /// By default, `uv` will stop at the first index on which a given package is available, and
+    /// limit resolutions to those present on that first index (`first-match`).
+    #[arg(long, value_enum, env = "UV_INDEX_STRATEGY")]
+    pub(crate) index_strategy: Option<IndexStrategy>,
+
+    /// Require a matching hash for each requirement.
+    ///
+    /// Hash-checking mode is all or nothing. If enabled, _all_ requirements must be provided
+    /// with a corresponding hash or set of hashes, and each distribution is verified against its
+    /// hashes before it's built or written to the wheel directory.
+    #[arg(long, overrides_with("no_require_hashes"))]
+    pub(crate) require_hashes: bool,
+
+    #[arg(long, overrides_with("require_hashes"), hide = true)]
+    pub(crate) no_require_hashes: bool,
+
+    /// Attempt to use `keyring` for authentication for index URLs.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = "UV_KEYRING_PROVIDER")]
+    pub(crate) keyring_provider: Option<KeyringProviderType>,
+
+    /// The Python interpreter to use when resolving, and for building source distributions.
+    ///
+    /// By default, `uv` uses the virtual environment in the current working directory or any
+    /// parent directory, falling back to the first Python found in the system `PATH`.
+    #[arg(long, short, verbatim_doc_comment)]
+    pub(crate) python: Option<String>,
+
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
+    /// Restrict interpreter discovery to a specific Python implementation (e.g., `pypy`).
+    #[arg(long, value_enum, env = "UV_PYTHON_IMPLEMENTATION")]
+    pub(crate) python_implementation: Option<PythonImplementation>,
+
+    /// Use the system Python, rather than a virtual environment, when resolving.
+    #[arg(
+        long,
+        env = "UV_SYSTEM_PYTHON",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_system")
+    )]
+    pub(crate) system: bool,
+
+    #[arg(long, overrides_with("system"))]
+    pub(crate) no_system: bool,
+
+    /// The Python version for which wheels should be built (e.g., `3.7` or `3.7.9`).
+    ///
+    /// Defaults to the version of the Python interpreter used for resolution.
+    ///
+    /// Only the Python version can be targeted: wheels are always selected for the platform tags
+    /// of the current interpreter, so downloading for another operating system or architecture
+    /// (as with pip's `--platform`) isn't supported.
+    #[arg(long)]
+    pub(crate) python_version: Option<PythonVersion>,
+
+    /// Use legacy `setuptools` behavior when building source distributions without a
+    /// `pyproject.toml`.
+    #[arg(long, overrides_with("no_legacy_setup_py"))]
+    pub(crate) legacy_setup_py: bool,
+
+    #[arg(long, overrides_with("legacy_setup_py"), hide = true)]
+    pub(crate) no_legacy_setup_py: bool,
+
+    /// Disable isolation when building source distributions.
+    ///
+    /// Assumes that build dependencies specified by PEP 518 are already installed.
+    #[arg(long, overrides_with("build_isolation"))]
+    pub(crate) no_build_isolation: bool,
+
+    #[arg(long, overrides_with("no_build_isolation"), hide = true)]
+    pub(crate) build_isolation: bool,
+
+    /// Disable isolation when building source distributions for a specific package.
+    #[arg(long, conflicts_with = "no_build_isolation")]
+    pub(crate) no_build_isolation_package: Option<Vec<PackageName>>,
+
+    /// Reuse the build directory of a source distribution across builds, rather than building in a
+    /// fresh temporary directory.
+    ///
+    /// The build directory is retained in the cache, keyed by the package and the path to the
+    /// source tree. On each build, only the source files whose contents changed are synced into it,
+    /// such that the build backend can reuse the artifacts of the previous build (e.g., compiled
+    /// extension modules). Changes to the config settings or the Python interpreter result in a
+    /// fresh build directory. Stale build directories are removed by `uv cache prune`.
+    #[arg(long, overrides_with("clean"))]
+    pub(crate) no_clean: bool,
+
+    #[arg(long, overrides_with("no_clean"), hide = true)]
+    pub(crate) clean: bool,
+
+    /// Don't build or download source distributions.
+    ///
+    /// Alias for `--only-binary :all:`.
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("build")
+    )]
+    pub(crate) no_build: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "no_binary",
+        conflicts_with = "only_binary",
+        overrides_with("no_build"),
+        hide = true
+    )]
+    pub(crate) build: bool,
+
+    /// Don't use pre-built wheels.
+    ///
+    /// The given packages will be built from their source distributions instead. The resolver
+    /// will still use pre-built wheels for metadata.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, conflicts_with = "no_build")]
+    pub(crate) no_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Only use pre-built wheels; don't build source distributions.
+    ///
+    /// Multiple packages may be provided. Disable binaries for all packages with `:all:`.
+    /// Clear previously specified packages with `:none:`.
+    #[arg(long, conflicts_with = "no_build")]
+    pub(crate) only_binary: Option<Vec<PackageNameSpecifier>>,
+
+    /// Additional platform tags to accept for a specific package, specified as `PACKAGE:TAG`
+    /// pairs (e.g., `foo:linux_x86_64`).
+    ///
+    /// Wheels for the package with the given platform tag are considered compatible with the
+    /// current platform, as long as their Python and ABI tags are compatible. Use with care: the
+    /// wheel may not work on the current platform.
+    #[arg(long)]
+    pub(crate) allow_platform_tag: Option<Vec<CompatibleTagEntry>>,
+
+    /// Settings to pass to the PEP 517 build backend, specified as `KEY=VALUE` pairs.
+    #[arg(long, short = 'C', alias = "config-settings")]
+    pub(crate) config_setting: Option<Vec<ConfigSettingEntry>>,
+
+    /// Environment variables to set when building a specific package, specified as
+    /// `PACKAGE:KEY=VALUE` pairs.
+    #[arg(long)]
+    pub(crate) build_env: Option<Vec<BuildEnvEntry>>,
+
+    /// Stream the output of the build backend while building source distributions.
+    #[arg(long)]
+    pub(crate) verbose_build: bool,
+
+    /// Limit candidate packages to those that were uploaded prior to the given date.
+    ///
+    /// Accepts both RFC 3339 timestamps (e.g., `2006-12-02T02:07:43Z`) and UTC dates in the same
+    /// format (e.g., `2006-12-02`).
+    #[arg(long)]
+    pub(crate) exclude_newer: Option<ExcludeNewer>,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+#[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
+pub struct PipUninstallArgs {
+    /// Uninstall all listed packages.
     #[arg(group = "sources")]
     pub(crate) package: Vec<String>,
 
@@ -1197,6 +2581,21 @@ pub(crate) struct PipUninstallArgs {
     #[arg(long, short, group = "sources")]
     pub(crate) requirement: Vec<PathBuf>,
 
+    /// Uninstall all installed packages.
+    #[arg(long, group = "sources", conflicts_with_all = ["package", "requirement"])]
+    pub(crate) all: bool,
+
+    /// Preserve any editable packages when uninstalling all packages with `--all`.
+    #[arg(long, requires = "all")]
+    pub(crate) exclude_editable: bool,
+
+    /// Remove files that were modified after installation.
+    ///
+    /// By default, `uv` compares each file against the hash recorded in the package's `RECORD`,
+    /// and preserves any file that no longer matches, warning that it was left in place.
+    #[arg(long)]
+    pub(crate) force: bool,
+
     /// The Python interpreter from which packages should be uninstalled.
     ///
     /// By default, `uv` uninstalls from the virtual environment in the current working directory or
@@ -1212,6 +2611,14 @@ pub(crate) struct PipUninstallArgs {
     #[arg(long, short, verbatim_doc_comment, group = "discovery")]
     pub(crate) python: Option<String>,
 
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    ///
+    /// Only applies when the interpreter is discovered by version (e.g., `--python 3.12`) or by
+    /// default, rather than requested by path or executable name. Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
     /// Due to not having Python imports, only `--keyring-provider subprocess` argument is currently
@@ -1259,6 +2666,21 @@ pub(crate) struct PipUninstallArgs {
     #[arg(long, overrides_with("break_system_packages"))]
     pub(crate) no_break_system_packages: bool,
 
+    /// Require that the target environment is a virtual environment.
+    ///
+    /// When enabled, `uv` will refuse to modify an interpreter that isn't part of a virtual
+    /// environment, unless `--system` is passed explicitly.
+    #[arg(
+        long,
+        env = "UV_REQUIRE_VIRTUALENV",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        overrides_with("no_require_virtualenv")
+    )]
+    pub(crate) require_virtualenv: bool,
+
+    #[arg(long, overrides_with("require_virtualenv"), hide = true)]
+    pub(crate) no_require_virtualenv: bool,
+
     /// Run offline, i.e., without accessing the network.
     #[arg(long, overrides_with("no_offline"))]
     pub(crate) offline: bool,
@@ -1269,11 +2691,23 @@ pub(crate) struct PipUninstallArgs {
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub(crate) struct PipFreezeArgs {
+pub struct PipFreezeArgs {
     /// Exclude any editable packages from output.
     #[arg(long)]
     pub(crate) exclude_editable: bool,
 
+    /// Include the packages that are omitted from the output by default (i.e., `pip`,
+    /// `setuptools`, `wheel`, `distribute`, and `uv`).
+    #[arg(long)]
+    pub(crate) all: bool,
+
+    /// List packages from the given `site-packages` directory, rather than the site-packages of
+    /// the Python interpreter (e.g., a directory populated via `--target`).
+    ///
+    /// May be provided multiple times, in which case the packages of every directory are listed.
+    #[arg(long)]
+    pub(crate) path: Vec<PathBuf>,
+
     /// Validate the virtual environment, to detect packages with missing dependencies or other
     /// issues.
     #[arg(long, overrides_with("no_strict"))]
@@ -1296,6 +2730,14 @@ pub(crate) struct PipFreezeArgs {
     #[arg(long, short, verbatim_doc_comment, group = "discovery")]
     pub(crate) python: Option<String>,
 
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    ///
+    /// Only applies when the interpreter is discovered by version (e.g., `--python 3.12`) or by
+    /// default, rather than requested by path or executable name. Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
     /// List packages for the system Python.
     ///
     /// By default, `uv` lists packages in the currently activated virtual environment, or a virtual
@@ -1320,7 +2762,7 @@ pub(crate) struct PipFreezeArgs {
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub(crate) struct PipListArgs {
+pub struct PipListArgs {
     /// Only include editable projects.
     #[arg(short, long)]
     pub(crate) editable: bool,
@@ -1359,6 +2801,14 @@ pub(crate) struct PipListArgs {
     #[arg(long, short, verbatim_doc_comment, group = "discovery")]
     pub(crate) python: Option<String>,
 
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    ///
+    /// Only applies when the interpreter is discovered by version (e.g., `--python 3.12`) or by
+    /// default, rather than requested by path or executable name. Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
     /// List packages for the system Python.
     ///
     /// By default, `uv` lists packages in the currently activated virtual environment, or a virtual
@@ -1386,7 +2836,13 @@ pub(crate) struct PipListArgs {
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub(crate) struct PipCheckArgs {
+pub struct PipCheckArgs {
+    /// Select the output format between: `text` (default) or `json`.
+    ///
+    /// In either case, `uv` exits with a non-zero status if any incompatibilities are found.
+    #[arg(long, value_enum, default_value_t = CheckFormat::default())]
+    pub(crate) format: CheckFormat,
+
     /// The Python interpreter for which packages should be listed.
     ///
     /// By default, `uv` lists packages in the currently activated virtual environment, or a virtual
@@ -1401,6 +2857,14 @@ pub(crate) struct PipCheckArgs {
     #[arg(long, short, verbatim_doc_comment, group = "discovery")]
     pub(crate) python: Option<String>,
 
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    ///
+    /// Only applies when the interpreter is discovered by version (e.g., `--python 3.12`) or by
+    /// default, rather than requested by path or executable name. Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
     /// List packages for the system Python.
     ///
     /// By default, `uv` lists packages in the currently activated virtual environment, or a virtual
@@ -1425,7 +2889,86 @@ pub(crate) struct PipCheckArgs {
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub(crate) struct PipShowArgs {
+pub struct PipVerifyArgs {
+    /// Verify the environment against the packages listed in the given requirements files.
+    ///
+    /// If omitted, only the files of the installed packages are verified against their `RECORD`.
+    ///
+    /// Hashes in the requirements files can only be verified for packages that recorded their
+    /// hashes at install time (i.e., archives installed from a direct URL). Any other package that's
+    /// required to match a hash is reported as unverifiable.
+    #[arg(long, short)]
+    pub(crate) requirement: Vec<PathBuf>,
+
+    /// Select the output format between: `text` (default) or `json`.
+    ///
+    /// In either case, `uv` exits with a non-zero status if any discrepancies are found.
+    #[arg(long, value_enum, default_value_t = VerifyFormat::default())]
+    pub(crate) format: VerifyFormat,
+
+    /// The Python interpreter for which packages should be verified.
+    ///
+    /// By default, `uv` verifies packages in the currently activated virtual environment, or a
+    /// virtual environment (`.venv`) located in the current working directory or any parent
+    /// directory, falling back to the system Python if no virtual environment is found.
+    ///
+    /// Supported formats:
+    /// - `3.10` looks for an installed Python 3.10 using `py --list-paths` on Windows, or
+    ///   `python3.10` on Linux and macOS.
+    /// - `python3.10` or `python.exe` looks for a binary with the given name in `PATH`.
+    /// - `/home/ferris/.local/bin/python3.10` uses the exact Python at the given path.
+    #[arg(long, short, verbatim_doc_comment, group = "discovery")]
+    pub(crate) python: Option<String>,
+
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    ///
+    /// Only applies when the interpreter is discovered by version (e.g., `--python 3.12`) or by
+    /// default, rather than requested by path or executable name. Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
+    /// Attempt to use `keyring` for authentication for remote requirements files.
+    ///
+    /// Due to not having Python imports, only `--keyring-provider subprocess` argument is currently
+    /// implemented `uv` will try to use `keyring` via CLI when this flag is used.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = "UV_KEYRING_PROVIDER")]
+    pub(crate) keyring_provider: Option<KeyringProviderType>,
+
+    /// Verify packages for the system Python.
+    ///
+    /// By default, `uv` verifies packages in the currently activated virtual environment, or a
+    /// virtual environment (`.venv`) located in the current working directory or any parent
+    /// directory, falling back to the system Python if no virtual environment is found. The
+    /// `--system` option instructs `uv` to use the first Python found in the system `PATH`.
+    ///
+    /// WARNING: `--system` is intended for use in continuous integration (CI) environments and
+    /// should be used with caution.
+    #[arg(
+        long,
+        env = "UV_SYSTEM_PYTHON",
+        value_parser = clap::builder::BoolishValueParser::new(),
+        group = "discovery",
+        overrides_with("no_system")
+    )]
+    pub(crate) system: bool,
+
+    #[arg(long, overrides_with("system"))]
+    pub(crate) no_system: bool,
+
+    /// Run offline, i.e., without accessing the network.
+    #[arg(long, overrides_with("no_offline"))]
+    pub(crate) offline: bool,
+
+    #[arg(long, overrides_with("offline"), hide = true)]
+    pub(crate) no_offline: bool,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PipShowArgs {
     /// The package(s) to display.
     pub(crate) package: Vec<PackageName>,
 
@@ -1451,6 +2994,14 @@ pub(crate) struct PipShowArgs {
     #[arg(long, short, verbatim_doc_comment, group = "discovery")]
     pub(crate) python: Option<String>,
 
+    /// Whether to prefer Python installations managed by `uv` or those found on the system when
+    /// searching for an interpreter.
+    ///
+    /// Only applies when the interpreter is discovered by version (e.g., `--python 3.12`) or by
+    /// default, rather than requested by path or executable name. Defaults to `only-system`.
+    #[arg(long, value_enum, env = "UV_PYTHON_PREFERENCE")]
+    pub(crate) python_preference: Option<PythonPreference>,
+
     /// List packages for the system Python.
     ///
     /// By default, `uv` lists packages in the currently activated virtual environment, or a virtual
@@ -1475,7 +3026,7 @@ pub(crate) struct PipShowArgs {
 
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
-pub(crate) struct VenvArgs {
+pub struct VenvArgs {
     /// The Python interpreter to use for the virtual environment.
     ///
     /// Supported formats:
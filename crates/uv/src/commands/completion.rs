@@ -0,0 +1,247 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::io::stdout;
+
+use anyhow::Result;
+use clap::{Command, CommandFactory};
+use clap_complete_command::Shell;
+use itertools::Itertools;
+use tracing::debug;
+
+use distribution_types::Name;
+use uv_cache::Cache;
+use uv_configuration::PythonPreference;
+use uv_installer::SitePackages;
+use uv_interpreter::PythonEnvironment;
+use uv_normalize::PackageName;
+
+use crate::cli::Cli;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The IDs of the options that accept the name of an installed package.
+const PACKAGE_OPTIONS: &[&str] = &["upgrade_package", "refresh_package", "reinstall_package"];
+
+/// The `pip` subcommands whose positional arguments are the names of installed packages.
+const PACKAGE_COMMANDS: &[&str] = &["show", "uninstall"];
+
+/// Generate the completion script for the given shell.
+///
+/// Beyond the static completions generated by `clap`, the Bash, Zsh, and Fish scripts complete the
+/// names of the packages installed in the current environment (via `uv __complete`) for the
+/// options and arguments that accept them.
+pub(crate) fn generate_shell_completion(shell: Shell) -> Result<ExitStatus> {
+    let mut command = Cli::command();
+    let dynamic = dynamic_completion(shell, &mut command)?;
+    shell.generate(&mut command, &mut stdout());
+    std::io::Write::write_all(&mut stdout(), dynamic.as_bytes())?;
+    Ok(ExitStatus::Success)
+}
+
+/// Generate the functions that complete the names of installed packages for the given shell, to
+/// be appended to the static completion script.
+fn dynamic_completion(shell: Shell, command: &mut Command) -> Result<String, std::fmt::Error> {
+    // Propagate the global arguments, such that they're visible on each subcommand.
+    command.build();
+    let package_flags = package_flags(command);
+    let value_flags = value_flags(command);
+
+    let mut script = String::new();
+    match shell {
+        Shell::Bash => {
+            let package_flags = package_flags.iter().map(Flag::spellings).join("|");
+            let value_flags = value_flags.iter().map(Flag::spellings).join("|");
+            let package_commands = PACKAGE_COMMANDS.join("|");
+            write!(
+                script,
+                r#"
+_uv_complete_packages() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    local prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+    case "${{prev}}" in
+        {package_flags})
+            COMPREPLY=( $(compgen -W "$(uv __complete "${{cur}}" 2>/dev/null)" -- "${{cur}}") )
+            return 0
+            ;;
+    esac
+    if [[ "${{COMP_WORDS[1]}}" == "pip" && ${{COMP_CWORD}} -gt 2 && "${{cur}}" != -* ]]; then
+        case "${{COMP_WORDS[2]}}" in
+            {package_commands})
+                case "${{prev}}" in
+                    {value_flags})
+                        ;;
+                    *)
+                        COMPREPLY=( $(compgen -W "$(uv __complete "${{cur}}" 2>/dev/null)" -- "${{cur}}") )
+                        return 0
+                        ;;
+                esac
+                ;;
+        esac
+    fi
+    _uv "$@"
+}}
+
+complete -F _uv_complete_packages -o bashdefault -o default uv
+"#
+            )?;
+        }
+        Shell::Zsh => {
+            let package_flags = package_flags.iter().map(Flag::spellings).join("|");
+            let value_flags = value_flags.iter().map(Flag::spellings).join("|");
+            let package_commands = PACKAGE_COMMANDS.join("|");
+            write!(
+                script,
+                r#"
+_uv_complete_packages() {{
+    local cur="${{words[CURRENT]}}"
+    local prev="${{words[CURRENT-1]}}"
+    case "${{prev}}" in
+        {package_flags})
+            compadd -- ${{(f)"$(uv __complete "${{cur}}" 2>/dev/null)"}}
+            return
+            ;;
+    esac
+    if [[ "${{words[2]}}" == "pip" && ${{CURRENT}} -gt 3 && "${{cur}}" != -* ]]; then
+        case "${{words[3]}}" in
+            {package_commands})
+                case "${{prev}}" in
+                    {value_flags})
+                        ;;
+                    *)
+                        compadd -- ${{(f)"$(uv __complete "${{cur}}" 2>/dev/null)"}}
+                        return
+                        ;;
+                esac
+                ;;
+        esac
+    fi
+    _uv "$@"
+}}
+
+compdef _uv_complete_packages uv
+"#
+            )?;
+        }
+        Shell::Fish => {
+            writeln!(script)?;
+            for flag in &package_flags {
+                write!(script, "complete -c uv")?;
+                if let Some(long) = &flag.long {
+                    write!(script, " -l {long}")?;
+                }
+                if let Some(short) = flag.short {
+                    write!(script, " -s {short}")?;
+                }
+                writeln!(script, " -x -a \"(uv __complete (commandline -ct))\"")?;
+            }
+            writeln!(
+                script,
+                "complete -c uv -n \"__fish_seen_subcommand_from pip; and __fish_seen_subcommand_from {}\" -f -a \"(uv __complete (commandline -ct))\"",
+                PACKAGE_COMMANDS.join(" ")
+            )?;
+        }
+        // Dynamic completions aren't supported for the remaining shells.
+        _ => {}
+    }
+    Ok(script)
+}
+
+/// Complete the names of the packages installed in the current environment that start with the
+/// given prefix, one per line.
+///
+/// As this is invoked by the completion scripts, any failure to locate the environment results in
+/// no completions, rather than an error.
+pub(crate) fn complete_packages(
+    prefix: &str,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let venv = match PythonEnvironment::from_virtualenv(cache) {
+        Ok(venv) => venv,
+        Err(uv_interpreter::Error::VenvNotFound) => {
+            match PythonEnvironment::from_default_python(None, PythonPreference::default(), cache) {
+                Ok(venv) => venv,
+                Err(err) => {
+                    debug!("No environment to complete packages from: {err}");
+                    return Ok(ExitStatus::Success);
+                }
+            }
+        }
+        Err(err) => {
+            debug!("No environment to complete packages from: {err}");
+            return Ok(ExitStatus::Success);
+        }
+    };
+    let site_packages = match SitePackages::from_executable(&venv) {
+        Ok(site_packages) => site_packages,
+        Err(err) => {
+            debug!("Failed to read the installed packages: {err}");
+            return Ok(ExitStatus::Success);
+        }
+    };
+
+    // Match against the normalized prefix, such that, e.g., `Flask_` completes to `flask-cors`.
+    let prefix = prefix.to_ascii_lowercase().replace(['_', '.'], "-");
+    let names = site_packages
+        .iter()
+        .map(Name::name)
+        .filter(|name| name.as_ref().starts_with(&prefix))
+        .collect::<BTreeSet<&PackageName>>();
+    for name in names {
+        writeln!(printer.stdout(), "{name}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// The spellings of an option on the command line.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Flag {
+    long: Option<String>,
+    short: Option<char>,
+}
+
+impl Flag {
+    /// Return the spellings of the flag as a shell `case` pattern (e.g., `--upgrade-package|-P`).
+    fn spellings(&self) -> String {
+        self.long
+            .iter()
+            .map(|long| format!("--{long}"))
+            .chain(self.short.iter().map(|short| format!("-{short}")))
+            .join("|")
+    }
+}
+
+/// Return the flags, across all subcommands, that accept the name of an installed package.
+fn package_flags(command: &Command) -> BTreeSet<Flag> {
+    let mut flags = command
+        .get_arguments()
+        .filter(|arg| PACKAGE_OPTIONS.contains(&arg.get_id().as_str()))
+        .map(|arg| Flag {
+            long: arg.get_long().map(ToString::to_string),
+            short: arg.get_short(),
+        })
+        .collect::<BTreeSet<_>>();
+    for subcommand in command.get_subcommands() {
+        flags.extend(package_flags(subcommand));
+    }
+    flags
+}
+
+/// Return the flags of the [`PACKAGE_COMMANDS`] that take a value, such that the value isn't
+/// mistaken for a positional package name.
+fn value_flags(command: &Command) -> BTreeSet<Flag> {
+    let Some(pip) = command.find_subcommand("pip") else {
+        return BTreeSet::default();
+    };
+    PACKAGE_COMMANDS
+        .iter()
+        .filter_map(|name| pip.find_subcommand(name))
+        .flat_map(Command::get_arguments)
+        .filter(|arg| !arg.is_positional() && arg.get_action().takes_values())
+        .map(|arg| Flag {
+            long: arg.get_long().map(ToString::to_string),
+            short: arg.get_short(),
+        })
+        .collect()
+}
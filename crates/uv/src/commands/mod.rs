@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
@@ -7,15 +8,19 @@ use owo_colors::OwoColorize;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use completion::{complete_packages, generate_shell_completion};
 use distribution_types::InstalledMetadata;
 pub(crate) use pip_check::pip_check;
 pub(crate) use pip_compile::{extra_name_with_clap_error, pip_compile};
+pub(crate) use pip_download::pip_download;
 pub(crate) use pip_freeze::pip_freeze;
 pub(crate) use pip_install::pip_install;
 pub(crate) use pip_list::pip_list;
 pub(crate) use pip_show::pip_show;
 pub(crate) use pip_sync::pip_sync;
 pub(crate) use pip_uninstall::pip_uninstall;
+pub(crate) use pip_verify::pip_verify;
+pub(crate) use python_list::python_list;
 pub(crate) use run::run;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
@@ -32,14 +37,19 @@ use crate::printer::Printer;
 mod cache_clean;
 mod cache_dir;
 mod cache_prune;
+mod completion;
 mod pip_check;
 mod pip_compile;
+mod pip_download;
 mod pip_freeze;
 mod pip_install;
 mod pip_list;
+mod pip_operations;
 mod pip_show;
 mod pip_sync;
 mod pip_uninstall;
+mod pip_verify;
+mod python_list;
 mod reporters;
 mod run;
 #[cfg(feature = "self-update")]
@@ -116,7 +126,7 @@ pub(crate) enum VersionFormat {
 }
 
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
-pub(crate) enum ListFormat {
+pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
     #[default]
     Columns,
@@ -127,26 +137,99 @@ pub(crate) enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum SyncFormat {
+    /// Display the planned changes in a human-readable format, grouped by action.
+    #[default]
+    Text,
+    /// Display the planned changes in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum CheckFormat {
+    /// Display the incompatibilities in a human-readable format, one per line.
+    #[default]
+    Text,
+    /// Display the incompatibilities in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum VerifyFormat {
+    /// Display the discrepancies in a human-readable format, one per line.
+    #[default]
+    Text,
+    /// Display the discrepancies in a machine-readable JSON format.
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum FreezeConstraintMode {
+    /// Prefer the installed versions, but select other versions if necessary.
+    Prefer,
+    /// Require the installed versions, failing if they're incompatible with the requirements.
+    Require,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReusePins {
+    /// Prefer the versions pinned in the existing output file, where applicable.
+    #[default]
+    Auto,
+    /// Require the versions pinned in the existing output file, unless the requirements or
+    /// constraints are incompatible with them.
+    Always,
+    /// Ignore the versions pinned in the existing output file.
+    Never,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum PythonListFormat {
+    /// Display the interpreters in a human-readable table.
+    #[default]
+    Text,
+    /// Display the interpreters in a machine-readable JSON format.
+    Json,
+}
+
 /// Compile all Python source files in site-packages to bytecode, to speed up the
 /// initial run of any subsequent executions.
 ///
 /// See the `--compile` option on `pip sync` and `pip install`.
 pub(super) async fn compile_bytecode(
     venv: &PythonEnvironment,
+    optimization_level: u8,
     cache: &Cache,
     printer: Printer,
 ) -> anyhow::Result<()> {
     let start = std::time::Instant::now();
     let mut files = 0;
+    let mut failures = Vec::new();
     for site_packages in venv.site_packages() {
-        files += compile_tree(site_packages, venv.python_executable(), cache.root())
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to bytecode-compile Python file in: {}",
-                    site_packages.user_display()
-                )
-            })?;
+        let summary = compile_tree(
+            site_packages,
+            venv.python_executable(),
+            optimization_level,
+            cache.root(),
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to bytecode-compile Python file in: {}",
+                site_packages.user_display()
+            )
+        })?;
+        files += summary.files;
+        failures.extend(summary.failures.into_iter().map(|failure| {
+            // Display the failed files relative to `site-packages`.
+            let path = failure
+                .path
+                .strip_prefix(site_packages)
+                .map(Path::to_path_buf)
+                .unwrap_or(failure.path);
+            (path, failure.reason)
+        }));
     }
     let s = if files == 1 { "" } else { "s" };
     writeln!(
@@ -159,6 +242,27 @@ pub(super) async fn compile_bytecode(
         )
         .dimmed()
     )?;
+
+    // Like pip, don't fail on files that can't be compiled (e.g., vendored Python 2 code), but
+    // summarize them.
+    if !failures.is_empty() {
+        let s = if failures.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "{}{} Failed to bytecode-compile {} file{s}:",
+            "warning".yellow().bold(),
+            ":".bold(),
+            failures.len()
+        )?;
+        for (path, reason) in failures {
+            writeln!(
+                printer.stderr(),
+                "  - {}: {reason}",
+                path.simplified_display().bold()
+            )?;
+        }
+    }
+
     Ok(())
 }
 
@@ -3,20 +3,26 @@ use std::fmt::Write;
 use anyhow::Result;
 use distribution_types::InstalledDist;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 use std::time::Instant;
 use tracing::debug;
 
 use uv_cache::Cache;
+use uv_configuration::PythonPreference;
 use uv_fs::Simplified;
 use uv_installer::{Diagnostic, SitePackages};
 use uv_interpreter::PythonEnvironment;
 
-use crate::commands::{elapsed, ExitStatus};
+use crate::commands::{elapsed, CheckFormat, ExitStatus};
 use crate::printer::Printer;
 
 /// Check for incompatibilties in installed packages.
+///
+/// Exits with a non-zero status if any incompatibilities are found.
 pub(crate) fn pip_check(
+    format: CheckFormat,
     python: Option<&str>,
+    python_preference: PythonPreference,
     system: bool,
     cache: &Cache,
     printer: Printer,
@@ -25,14 +31,14 @@ pub(crate) fn pip_check(
 
     // Detect the current Python interpreter.
     let venv = if let Some(python) = python {
-        PythonEnvironment::from_requested_python(python, cache)?
+        PythonEnvironment::from_requested_python(python, None, python_preference, cache)?
     } else if system {
-        PythonEnvironment::from_default_python(cache)?
+        PythonEnvironment::from_default_python(None, python_preference, cache)?
     } else {
         match PythonEnvironment::from_virtualenv(cache) {
             Ok(venv) => venv,
             Err(uv_interpreter::Error::VenvNotFound) => {
-                PythonEnvironment::from_default_python(cache)?
+                PythonEnvironment::from_default_python(None, python_preference, cache)?
             }
             Err(err) => return Err(err.into()),
         }
@@ -62,6 +68,12 @@ pub(crate) fn pip_check(
 
     let diagnostics: Vec<Diagnostic> = site_packages.diagnostics()?.into_iter().collect();
 
+    if matches!(format, CheckFormat::Json) {
+        let entries = diagnostics.iter().map(Entry::from).collect::<Vec<_>>();
+        let output = serde_json::to_string(&entries)?;
+        writeln!(printer.stdout(), "{output}")?;
+    }
+
     if diagnostics.is_empty() {
         writeln!(
             printer.stderr(),
@@ -86,10 +98,78 @@ pub(crate) fn pip_check(
             .dimmed()
         )?;
 
-        for diagnostic in &diagnostics {
-            writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
+        if matches!(format, CheckFormat::Text) {
+            for diagnostic in &diagnostics {
+                writeln!(printer.stderr(), "{}", diagnostic.message().bold())?;
+            }
         }
 
         Ok(ExitStatus::Failure)
     }
 }
+
+/// An entry in a JSON list of incompatibilities.
+#[derive(Debug, Serialize)]
+struct Entry {
+    /// The kind of incompatibility (e.g., `incompatible-dependency`).
+    kind: &'static str,
+    /// The package with the incompatibility.
+    package: String,
+    /// The requirement that isn't satisfied, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requirement: Option<String>,
+    /// The installed version that doesn't satisfy the requirement, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    installed: Option<String>,
+    /// A user-facing description of the incompatibility.
+    message: String,
+}
+
+impl From<&Diagnostic> for Entry {
+    fn from(diagnostic: &Diagnostic) -> Self {
+        let (kind, package, requirement, installed) = match diagnostic {
+            Diagnostic::IncompletePackage { package, .. } => {
+                ("incomplete-package", package, None, None)
+            }
+            Diagnostic::IncompatiblePythonVersion {
+                package,
+                version,
+                requires_python,
+            } => (
+                "incompatible-python-version",
+                package,
+                Some(format!("python{requires_python}")),
+                Some(version.to_string()),
+            ),
+            Diagnostic::MissingDependency {
+                package,
+                requirement,
+            } => (
+                "missing-dependency",
+                package,
+                Some(requirement.to_string()),
+                None,
+            ),
+            Diagnostic::IncompatibleDependency {
+                package,
+                version,
+                requirement,
+            } => (
+                "incompatible-dependency",
+                package,
+                Some(requirement.to_string()),
+                Some(version.to_string()),
+            ),
+            Diagnostic::DuplicatePackage { package, .. } => {
+                ("duplicate-package", package, None, None)
+            }
+        };
+        Self {
+            kind,
+            package: package.to_string(),
+            requirement,
+            installed,
+            message: diagnostic.message(),
+        }
+    }
+}
@@ -3,47 +3,61 @@ use std::env;
 use std::fmt::Write;
 use std::io::stdout;
 use std::ops::Deref;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anstream::{eprint, AutoStream, StripStream};
 use anyhow::{anyhow, Context, Result};
+use futures::{future, StreamExt};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tempfile::tempdir_in;
 use tracing::debug;
+use url::Url;
 
-use distribution_types::{IndexLocations, LocalEditable, LocalEditables, Verbatim};
+use distribution_types::{
+    DistributionMetadata, FlatIndexLocation, IndexLocations, InstalledDist, LocalEditable,
+    LocalEditables, Name, Verbatim,
+};
 use install_wheel_rs::linker::LinkMode;
+use pep440_rs::{Operator, Version, VersionSpecifier, VersionSpecifiers};
+use pep508_rs::{MarkerEnvironment, Requirement, RequirementsTxtRequirement, VersionOrUrl};
 use platform_tags::Tags;
-use requirements_txt::EditableRequirement;
+use requirements_txt::{EditableRequirement, RequirementComments};
 use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_client::{
+    BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClient, RegistryClientBuilder,
+};
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{
-    ConfigSettings, Constraints, IndexStrategy, NoBinary, NoBuild, Overrides, SetupPyStrategy,
-    Upgrade,
+    BuildEnv, CompatibleTags, ConfigSettings, Constraints, IndexStrategy, NoBinary, NoBuild,
+    Overrides, PythonImplementation, PythonPreference, SetupPyStrategy, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
-use uv_installer::Downloader;
-use uv_interpreter::{find_best_python, find_requested_python, PythonEnvironment};
+use uv_installer::{Downloader, SitePackages};
+use uv_interpreter::{find_best_python, find_required_python, PythonEnvironment};
 use uv_normalize::{ExtraName, PackageName};
 use uv_requirements::{
     upgrade::read_lockfile, ExtrasSpecification, LookaheadResolver, NamedRequirementsResolver,
-    RequirementsSource, RequirementsSpecification, SourceTreeResolver,
+    RequirementsProvenance, RequirementsSource, RequirementsSpecification, SourceTreeResolver,
 };
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, Exclusions, FlatIndex,
-    InMemoryIndex, Manifest, OptionsBuilder, PreReleaseMode, PythonRequirement, ResolutionMode,
+    AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, Exclusions,
+    ExplainedRequirement, FlatIndex, InMemoryIndex, Manifest, OptionsBuilder, PreReleaseMode,
+    Preference, PreferenceError, PythonRequirement, RequiredBy, ResolutionGraph, ResolutionMode,
     Resolver,
 };
 use uv_toolchain::PythonVersion;
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
-use crate::commands::reporters::{DownloadReporter, ResolverReporter};
-use crate::commands::{elapsed, ExitStatus};
+use crate::commands::pip_operations::{
+    validate_extras, validate_no_index, with_compatible_tags, with_editable_vcs,
+};
+use crate::commands::reporters::{BuildOutputReporter, DownloadReporter, ResolverReporter};
+use crate::commands::{elapsed, ExitStatus, FreezeConstraintMode, ReusePins};
 use crate::printer::Printer;
 
 /// Resolve a set of requirements into a set of pinned versions.
@@ -52,37 +66,60 @@ pub(crate) async fn pip_compile(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    convert_editable_vcs: bool,
+    constraint_from_freeze: Option<FreezeConstraintMode>,
+    reuse_pins: ReusePins,
     extras: ExtrasSpecification<'_>,
     output_file: Option<&Path>,
     resolution_mode: ResolutionMode,
+    resolution_exclude: Vec<PackageName>,
     prerelease_mode: PreReleaseMode,
+    prerelease_package: Vec<PackageName>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
+    no_upgrade_package: Vec<PackageName>,
     generate_hashes: bool,
     no_emit_packages: Vec<PackageName>,
     include_extras: bool,
     include_annotations: bool,
     include_header: bool,
     custom_compile_command: Option<String>,
+    explain: &[PackageName],
+    changes: Option<&Path>,
     include_index_url: bool,
     include_find_links: bool,
+    find_links_relative_to: Option<&Path>,
+    find_links_absolute: bool,
     include_marker_expression: bool,
     include_index_annotation: bool,
+    include_origin: bool,
+    include_build_options: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
     setup_py: SetupPyStrategy,
     config_settings: ConfigSettings,
+    build_env: &BuildEnv,
     connectivity: Connectivity,
     no_build_isolation: bool,
+    no_build_isolation_package: &[PackageName],
+    reuse_build_dir: bool,
+    verbose_build: bool,
     no_build: NoBuild,
+    compatible_tags: &CompatibleTags,
     python_version: Option<PythonVersion>,
+    marker_environment: Option<&Path>,
     exclude_newer: Option<ExcludeNewer>,
     annotation_style: AnnotationStyle,
     link_mode: LinkMode,
     python: Option<String>,
+    python_implementation: Option<PythonImplementation>,
+    python_preference: PythonPreference,
     system: bool,
     native_tls: bool,
+    http_timeout: Option<u64>,
+    netrc_file: Option<&Path>,
     quiet: bool,
     cache: Cache,
     printer: Printer,
@@ -100,6 +137,8 @@ pub(crate) async fn pip_compile(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -107,16 +146,22 @@ pub(crate) async fn pip_compile(
         project,
         requirements,
         constraints,
+        pinned_constraints,
+        constraint_annotations,
         overrides,
         editables,
+        editable_vcs,
         source_trees,
-        extras: used_extras,
+        extras: _,
+        defined_extras,
         index_url,
         extra_index_urls,
         no_index,
         find_links,
         no_binary: _,
         no_build: specified_no_build,
+        requires_python,
+        provenance,
     } = RequirementsSpecification::from_sources(
         requirements,
         constraints,
@@ -126,32 +171,70 @@ pub(crate) async fn pip_compile(
     )
     .await?;
 
-    // If all the metadata could be statically resolved, validate that every extra was used. If we
-    // need to resolve metadata via PEP 517, we don't know which extras are used until much later.
-    if source_trees.is_empty() {
-        if let ExtrasSpecification::Some(extras) = extras {
-            let mut unused_extras = extras
+    // Editable installs are only supported for local directories, so editable VCS requirements are
+    // either rejected or treated as regular requirements.
+    let requirements = with_editable_vcs(requirements, editable_vcs, convert_editable_vcs)?;
+
+    // Read the build constraints, which apply only to the resolution of build requirements when
+    // building source distributions in isolated environments.
+    let build_constraints =
+        RequirementsSpecification::from_constraint_sources(build_constraints, &client_builder)
+            .await?;
+    if no_build_isolation && !build_constraints.is_empty() {
+        warn_user!(
+            "Build constraints have no effect when build isolation is disabled (`--no-build-isolation`)"
+        );
+    }
+    let build_constraints = Constraints::from_requirements(build_constraints);
+
+    // Collect the comments attached to each requirement, to re-emit them in the output.
+    let comments = requirements
+        .iter()
+        .filter_map(|entry| {
+            let RequirementsTxtRequirement::Pep508(requirement) = &entry.requirement else {
+                return None;
+            };
+            let comments = user_comments(&entry.comments);
+            (!comments.is_empty()).then(|| (requirement.name.clone(), comments))
+        })
+        .collect::<FxHashMap<_, _>>();
+
+    // Allow pre-releases for any requirements that opt in via `--pre` in the input files, in
+    // addition to those passed via `--prerelease-package`.
+    let prerelease_packages = prerelease_package
+        .iter()
+        .cloned()
+        .chain(
+            requirements
                 .iter()
-                .filter(|extra| !used_extras.contains(extra))
-                .collect::<Vec<_>>();
-            if !unused_extras.is_empty() {
-                unused_extras.sort_unstable();
-                unused_extras.dedup();
-                let s = if unused_extras.len() == 1 { "" } else { "s" };
-                return Err(anyhow!(
-                    "Requested extra{s} not found: {}",
-                    unused_extras.iter().join(", ")
-                ));
-            }
-        }
+                .filter(|entry| entry.prerelease)
+                .filter_map(|entry| match &entry.requirement {
+                    RequirementsTxtRequirement::Pep508(requirement) => {
+                        Some(requirement.name.clone())
+                    }
+                    RequirementsTxtRequirement::Unnamed(_) => None,
+                }),
+        )
+        .collect::<Vec<_>>();
+
+    // If all the metadata could be statically resolved, validate that every extra is defined. If
+    // we need to resolve metadata via PEP 517, we don't know which extras are defined until much
+    // later.
+    if source_trees.is_empty() {
+        validate_extras(&extras, &defined_extras, true)?;
     }
 
     // Find an interpreter to use for building distributions
     let interpreter = if let Some(python) = python.as_ref() {
-        find_requested_python(python, &cache)?
-            .ok_or_else(|| uv_interpreter::Error::RequestedPythonNotFound(python.to_string()))?
+        find_required_python(python, python_implementation, python_preference, &cache)?
     } else {
-        find_best_python(python_version.as_ref(), system, &cache)?
+        find_best_python(
+            python_version.as_ref(),
+            python_implementation,
+            system,
+            python_preference,
+            &cache,
+        )?
     };
     debug!(
         "Using Python {} interpreter at {} for builds",
@@ -179,13 +262,18 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Read the marker environment, if provided.
+    let marker_environment = marker_environment
+        .map(read_marker_environment)
+        .transpose()?;
+
     // Create a shared in-memory index.
     let source_index = InMemoryIndex::default();
 
     // If we're resolving against a different Python version, use a separate index. Source
     // distributions will be built against the installed version, and so the index may contain
     // different package priorities than in the top-level resolution.
-    let top_level_index = if python_version.is_some() {
+    let top_level_index = if python_version.is_some() || marker_environment.is_some() {
         InMemoryIndexRef::Owned(InMemoryIndex::default())
     } else {
         InMemoryIndexRef::Borrowed(&source_index)
@@ -203,18 +291,42 @@ pub(crate) async fn pip_compile(
     } else {
         Cow::Borrowed(interpreter.tags()?)
     };
-    let markers = python_version.map_or_else(
-        || Cow::Borrowed(interpreter.markers()),
-        |python_version| Cow::Owned(python_version.markers(interpreter.markers())),
-    );
 
-    // Generate, but don't enforce hashes for the requirements.
-    let hasher = if generate_hashes {
-        HashStrategy::Generate
+    // Accept any additional platform tags allowed for specific packages.
+    let tags = with_compatible_tags(&tags, compatible_tags);
+
+    let markers = if let Some(marker_environment) = marker_environment {
+        Cow::Owned(marker_environment)
     } else {
-        HashStrategy::None
+        python_version.map_or_else(
+            || Cow::Borrowed(interpreter.markers()),
+            |python_version| Cow::Owned(python_version.markers(interpreter.markers())),
+        )
     };
 
+    // Validate that the scripts are compatible with the target Python version.
+    let python_requirement = PythonRequirement::new(&interpreter, &markers);
+    for (path, python_requires) in &requires_python {
+        if !python_requires.contains(python_requirement.target()) {
+            return Err(anyhow!(
+                "The script `{}` requires Python {}, but resolution targets Python {}",
+                path.user_display(),
+                python_requires,
+                python_requirement.target()
+            ));
+        }
+    }
+
+    // Generate, but don't enforce hashes for the requirements. The hashes of any hash-pinned
+    // constraints are enforced, such that the output is consistent with the constraints.
+    let hasher = HashStrategy::from_constraints(
+        pinned_constraints
+            .iter()
+            .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+        &markers,
+        generate_hashes,
+    )?;
+
     // Incorporate any index locations from the provided sources.
     let index_locations =
         index_locations.combine(index_url, extra_index_urls, find_links, no_index);
@@ -222,6 +334,8 @@ pub(crate) async fn pip_compile(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -230,13 +344,130 @@ pub(crate) async fn pip_compile(
         .platform(interpreter.platform())
         .build();
 
-    // Read the lockfile, if present.
-    let preferences = read_lockfile(output_file, upgrade).await?;
+    // Treat any version specifiers provided via `--upgrade-package` as constraints.
+    let upgrade_constraints = upgrade.constraints().cloned().collect::<Vec<_>>();
+
+    // Hold any packages provided via `--no-upgrade-package` at their pinned versions in the
+    // existing output file, by treating those pins as constraints.
+    let held_constraints = if no_upgrade_package.is_empty() {
+        vec![]
+    } else {
+        let existing_pins = read_lockfile(output_file, Upgrade::None).await?;
+        let mut held_constraints = Vec::new();
+        for package in &no_upgrade_package {
+            let pins = existing_pins
+                .iter()
+                .filter(|preference| preference.name() == package)
+                .map(Preference::requirement)
+                .filter(|requirement| pinned_version(requirement).is_some())
+                .cloned()
+                .collect::<Vec<_>>();
+            if pins.is_empty() {
+                warn_user!(
+                    "`{package}` was provided to `--no-upgrade-package`, but isn't pinned in the existing output file"
+                );
+            }
+            held_constraints.extend(pins);
+        }
+        held_constraints
+    };
+
+    // If requested, snapshot the packages installed in the current environment, to either prefer
+    // or require their versions. Packages that are being upgraded are omitted.
+    let (installed_preferences, installed_constraints) = match constraint_from_freeze {
+        None => (vec![], vec![]),
+        Some(mode) => {
+            let installed = read_installed_requirements(
+                python.as_deref(),
+                python_implementation,
+                python_preference,
+                system,
+                &cache,
+                printer,
+            )?
+            .into_iter()
+            .filter(|requirement| !upgrade.contains(&requirement.name))
+            .collect::<Vec<_>>();
+            match mode {
+                FreezeConstraintMode::Prefer => (installed, vec![]),
+                FreezeConstraintMode::Require => (vec![], installed),
+            }
+        }
+    };
+
+    // Summarize the changes to the existing pins when upgrading, or when explicitly requested.
+    let report_changes = changes.is_some() || !upgrade.is_none();
+    if changes.is_some() && output_file.is_none() {
+        warn_user!("`--changes` has no effect without `--output-file`");
+    }
+
+    // Read the lockfile, if present, retaining the existing pins (including those of any upgraded
+    // packages) to summarize the changes after resolution.
+    let (lockfile_preferences, existing_pins) = match reuse_pins {
+        ReusePins::Auto => {
+            let lockfile_preferences = read_lockfile(output_file, upgrade.clone()).await?;
+            let existing_pins = if report_changes {
+                read_lockfile(output_file, Upgrade::None).await?
+            } else {
+                vec![]
+            };
+            (lockfile_preferences, existing_pins)
+        }
+        ReusePins::Always => {
+            let existing_pins = read_lockfile(output_file, Upgrade::None).await?;
+            let lockfile_preferences = existing_pins
+                .iter()
+                .filter(|preference| !upgrade.contains(preference.name()))
+                .cloned()
+                .collect();
+            (lockfile_preferences, existing_pins)
+        }
+        ReusePins::Never => (vec![], read_lockfile(output_file, Upgrade::None).await?),
+    };
+
+    // With `--reuse-pins=always`, the existing pins are enforced as constraints.
+    let pinned_constraints_from_lockfile = if reuse_pins == ReusePins::Always {
+        lockfile_preferences
+            .iter()
+            .map(Preference::requirement)
+            .filter(|requirement| {
+                matches!(
+                    requirement.version_or_url,
+                    Some(VersionOrUrl::VersionSpecifier(_))
+                )
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    } else {
+        vec![]
+    };
+
+    // As later preferences take precedence, the pins in the existing lockfile override those from
+    // the current environment.
+    let mut preferences = installed_preferences
+        .into_iter()
+        .map(Preference::from_requirement)
+        .collect::<Vec<_>>();
+    preferences.extend(lockfile_preferences);
+
+    // Prefer any hash-pinned constraints, such that their hashes are reused in the output. As
+    // later preferences take precedence, these override any hashes from the existing lockfile.
+    preferences.extend(
+        pinned_constraints
+            .into_iter()
+            .map(Preference::from_entry)
+            .collect::<Result<Vec<_>, PreferenceError>>()?,
+    );
 
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(&client, &cache);
-        let entries = client.fetch(index_locations.flat_index()).await?;
+        let entries = client
+            .fetch(
+                index_locations.flat_index(),
+                index_locations.recursive_flat_index(),
+            )
+            .await?;
         FlatIndex::from_entries(entries, &tags, &hasher, &no_build, &NoBinary::None)
     };
 
@@ -248,13 +479,48 @@ pub(crate) async fn pip_compile(
     let build_isolation = if no_build_isolation {
         venv = PythonEnvironment::from_interpreter(interpreter.clone());
         BuildIsolation::Shared(&venv)
-    } else {
+    } else if no_build_isolation_package.is_empty() {
         BuildIsolation::Isolated
+    } else {
+        venv = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::SharedPackage(&venv, no_build_isolation_package)
     };
 
     // Combine the `--no-build` flags.
     let no_build = no_build.combine(specified_no_build);
 
+    // Collect the options that affect how source distributions are built. If the existing output
+    // file was compiled with different options, it may not be reproducible with the current ones.
+    let build_options = build_options(
+        setup_py,
+        &config_settings,
+        no_build_isolation,
+        no_build_isolation_package,
+        &no_build,
+    );
+    if let Some(output_file) = output_file.filter(|_| include_build_options) {
+        if let Some(recorded) = read_build_options(output_file)? {
+            if recorded != build_options {
+                let format = |options: &[String]| {
+                    if options.is_empty() {
+                        "none".to_string()
+                    } else {
+                        options
+                            .iter()
+                            .map(|option| format!("`{option}`"))
+                            .join(", ")
+                    }
+                };
+                warn_user!(
+                    "The build options recorded in `{}` ({}) differ from the current build options ({})",
+                    output_file.user_display(),
+                    format(&recorded),
+                    format(&build_options)
+                );
+            }
+        }
+    }
+
     let build_dispatch = BuildDispatch::new(
         &client,
         &cache,
@@ -270,7 +536,14 @@ pub(crate) async fn pip_compile(
         &no_build,
         &NoBinary::None,
     )
-    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build());
+    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build())
+    .with_build_reporter(BuildOutputReporter::for_verbose_build(
+        verbose_build,
+        printer,
+    ))
+    .with_reuse_build_dir(reuse_build_dir)
+    .with_build_env(build_env.clone())
+    .with_build_constraints(build_constraints);
 
     // Resolve the requirements from the provided sources.
     let requirements = {
@@ -288,19 +561,24 @@ pub(crate) async fn pip_compile(
 
         // Resolve any source trees into requirements.
         if !source_trees.is_empty() {
-            requirements.extend(
-                SourceTreeResolver::new(
-                    source_trees,
-                    &extras,
-                    &hasher,
-                    &build_dispatch,
-                    &client,
-                    &top_level_index,
-                )
-                .with_reporter(ResolverReporter::from(printer))
-                .resolve()
-                .await?,
-            );
+            let resolution = SourceTreeResolver::new(
+                source_trees,
+                &extras,
+                &hasher,
+                &build_dispatch,
+                &client,
+                &top_level_index,
+            )
+            .with_reporter(ResolverReporter::from(printer))
+            .resolve()
+            .await?;
+
+            // Now that the metadata is available, validate that every extra is defined.
+            let mut defined_extras = defined_extras;
+            defined_extras.extend(resolution.extras);
+            validate_extras(&extras, &defined_extras, false)?;
+
+            requirements.extend(resolution.requirements);
         }
 
         requirements
@@ -319,9 +597,55 @@ pub(crate) async fn pip_compile(
     .await?;
 
     // Collect constraints and overrides.
-    let constraints = Constraints::from_requirements(constraints);
+    let constraints = Constraints::from_requirements(
+        constraints
+            .into_iter()
+            .chain(upgrade_constraints)
+            .chain(held_constraints)
+            .chain(installed_constraints)
+            .collect(),
+    );
     let overrides = Overrides::from_requirements(overrides);
 
+    // Determine which of the existing pins are incompatible with the user-provided constraints.
+    let conflicting_pins = existing_pins
+        .iter()
+        .filter(|preference| {
+            pinned_version(preference.requirement()).is_some_and(|version| {
+                constraints
+                    .get(preference.name())
+                    .into_iter()
+                    .flatten()
+                    .filter(|constraint| constraint.evaluate_markers(&markers, &[]))
+                    .any(|constraint| excludes(constraint, version))
+            })
+        })
+        .map(|preference| preference.name().clone())
+        .collect::<FxHashSet<_>>();
+
+    // Determine which of the existing pins are incompatible with the direct requirements, along
+    // with the set of directly-required packages, to explain any changes to the pins.
+    let direct_requirements = requirements
+        .iter()
+        .filter(|requirement| requirement.evaluate_markers(&markers, &[]))
+        .collect::<Vec<_>>();
+    let outdated_pins = existing_pins
+        .iter()
+        .filter(|preference| {
+            pinned_version(preference.requirement()).is_some_and(|version| {
+                direct_requirements
+                    .iter()
+                    .filter(|requirement| &requirement.name == preference.name())
+                    .any(|requirement| excludes(requirement, version))
+            })
+        })
+        .map(|preference| preference.name().clone())
+        .collect::<FxHashSet<_>>();
+    let direct_requirements = direct_requirements
+        .into_iter()
+        .map(|requirement| requirement.name.clone())
+        .collect::<FxHashSet<_>>();
+
     // Build the editables and add their requirements
     let editables = if editables.is_empty() {
         Vec::new()
@@ -375,6 +699,19 @@ pub(crate) async fn pip_compile(
         editables
     };
 
+    // Under `--no-index`, validate that the requirements can be satisfied from `--find-links`.
+    validate_no_index(
+        &requirements,
+        overrides.requirements(),
+        &editables
+            .iter()
+            .map(|(.., metadata)| metadata.name.clone())
+            .collect(),
+        &index_locations,
+        &flat_index,
+        &markers,
+    )?;
+
     // Determine any lookahead requirements.
     let lookaheads = LookaheadResolver::new(
         &requirements,
@@ -390,6 +727,37 @@ pub(crate) async fn pip_compile(
     .resolve(&markers)
     .await?;
 
+    // With `--reuse-pins=always`, enforce the existing pins alongside the user-provided
+    // constraints, except for those that are already known to be incompatible with the
+    // requirements or constraints.
+    let enforced_pins = pinned_constraints_from_lockfile
+        .into_iter()
+        .filter(|requirement| {
+            !conflicting_pins.contains(&requirement.name)
+                && !outdated_pins.contains(&requirement.name)
+        })
+        .collect::<Vec<_>>();
+    let pinned_manifest = if enforced_pins.is_empty() {
+        None
+    } else {
+        Some(Manifest::new(
+            requirements.clone(),
+            Constraints::from_requirements(
+                constraints
+                    .requirements()
+                    .cloned()
+                    .chain(enforced_pins.iter().cloned())
+                    .collect(),
+            ),
+            overrides.clone(),
+            preferences.clone(),
+            project.clone(),
+            editables.clone(),
+            Exclusions::All,
+            lookaheads.clone(),
+        ))
+    };
+
     // Create a manifest of the requirements.
     let manifest = Manifest::new(
         requirements,
@@ -405,36 +773,109 @@ pub(crate) async fn pip_compile(
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
+        .resolution_exclude(resolution_exclude)
         .prerelease_mode(prerelease_mode)
+        .prerelease_packages(prerelease_packages)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .build();
 
+    // If the existing pins are enforced, attempt to resolve with them first, falling back to
+    // treating them as preferences if they're incompatible with the requirements.
+    let enforced = pinned_manifest.is_some();
+    let pinned_resolution = if let Some(pinned_manifest) = pinned_manifest {
+        let resolver = Resolver::new(
+            pinned_manifest,
+            options.clone(),
+            &markers,
+            &interpreter,
+            &tags,
+            &client,
+            &flat_index,
+            &top_level_index,
+            &hasher,
+            &build_dispatch,
+            &EmptyInstalledPackages,
+        )?
+        .with_reporter(ResolverReporter::from(printer));
+
+        match resolver.resolve().await {
+            Err(uv_resolver::ResolveError::NoSolution(err)) => {
+                debug!("Unable to resolve with the existing pins enforced: {err}");
+                None
+            }
+            result => Some(result?),
+        }
+    } else {
+        None
+    };
+
     // Resolve the dependencies.
-    let resolver = Resolver::new(
-        manifest.clone(),
-        options,
-        &markers,
-        &interpreter,
-        &tags,
-        &client,
-        &flat_index,
-        &top_level_index,
-        &hasher,
-        &build_dispatch,
-        &EmptyInstalledPackages,
-    )?
-    .with_reporter(ResolverReporter::from(printer));
+    let resolution = if let Some(resolution) = pinned_resolution {
+        resolution
+    } else {
+        let resolver = Resolver::new(
+            manifest.clone(),
+            options,
+            &markers,
+            &interpreter,
+            &tags,
+            &client,
+            &flat_index,
+            &top_level_index,
+            &hasher,
+            &build_dispatch,
+            &EmptyInstalledPackages,
+        )?
+        .with_reporter(ResolverReporter::from(printer));
+
+        let resolution = match resolver.resolve().await {
+            Err(uv_resolver::ResolveError::NoSolution(err)) => {
+                let report = miette::Report::msg(format!("{err}"))
+                    .context("No solution found when resolving dependencies:");
+                eprint!("{report:?}");
+                for name in explain {
+                    write_explanation(name, &err.explain(name), &provenance, printer)?;
+                }
+                return Ok(ExitStatus::Failure);
+            }
+            result => result?,
+        };
 
-    let resolution = match resolver.resolve().await {
-        Err(uv_resolver::ResolveError::NoSolution(err)) => {
-            let report = miette::Report::msg(format!("{err}"))
-                .context("No solution found when resolving dependencies:");
-            eprint!("{report:?}");
-            return Ok(ExitStatus::Failure);
+        // If the existing pins couldn't be enforced, report which of them changed.
+        if enforced {
+            let resolved = resolution
+                .petgraph()
+                .node_weights()
+                .filter_map(|dist| match dist.version_or_url() {
+                    distribution_types::VersionOrUrl::Version(version) => {
+                        Some((dist.name(), version))
+                    }
+                    distribution_types::VersionOrUrl::Url(_) => None,
+                })
+                .collect::<FxHashMap<_, _>>();
+            let changed = enforced_pins
+                .iter()
+                .filter(|requirement| requirement.evaluate_markers(&markers, &[]))
+                .filter(|requirement| {
+                    pinned_version(requirement).is_some_and(|version| {
+                        resolved
+                            .get(&requirement.name)
+                            .is_some_and(|resolved| *resolved != version)
+                    })
+                })
+                .map(|requirement| requirement.name.to_string())
+                .collect::<Vec<_>>();
+            if !changed.is_empty() {
+                warn_user!(
+                    "Unable to enforce the existing pins for: {}",
+                    changed.join(", ")
+                );
+            }
         }
-        result => result,
-    }?;
+
+        resolution
+    };
 
     let s = if resolution.len() == 1 { "" } else { "s" };
     writeln!(
@@ -459,6 +900,11 @@ pub(crate) async fn pip_compile(
         )?;
     }
 
+    // Explain the requirements on any requested packages.
+    for name in explain {
+        write_explanation(name, &resolution.explain(name), &provenance, printer)?;
+    }
+
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file)?;
 
@@ -493,6 +939,48 @@ pub(crate) async fn pip_compile(
         writeln!(writer, "{}", format!("#    {relevant_markers}").green())?;
     }
 
+    if include_build_options {
+        writeln!(writer, "{}", BUILD_OPTIONS_HEADER.green())?;
+        for option in &build_options {
+            writeln!(writer, "{}", format!("#    {option}").green())?;
+        }
+    }
+
+    // Record any additional platform tags that were allowed for the resolved packages, since the
+    // pinned wheels may not be compatible with the platform otherwise.
+    if include_annotations {
+        let allowed = compatible_tags
+            .iter()
+            .filter(|(package, _)| resolution.contains(package))
+            .collect::<Vec<_>>();
+        if !allowed.is_empty() {
+            writeln!(writer, "{}", ALLOWED_PLATFORM_TAGS_HEADER.green())?;
+            for (package, platforms) in allowed {
+                for platform in platforms {
+                    writeln!(
+                        writer,
+                        "{}",
+                        format!("#    --allow-platform-tag {package}:{platform}").green()
+                    )?;
+                }
+            }
+        }
+    }
+
+    // If requested, follow any redirects for the selected artifacts, so that the annotations record
+    // the URL that each artifact is actually served from (e.g., a mirror), rather than the one
+    // listed on the index.
+    let redirects = if !include_origin {
+        FxHashMap::default()
+    } else if connectivity == Connectivity::Offline {
+        warn_user!(
+            "Redirects can't be followed in offline mode; `--emit-origin` will annotate the URLs listed on the index"
+        );
+        FxHashMap::default()
+    } else {
+        resolve_redirects(&resolution, &client).await
+    };
+
     // Write the index locations to the output channel.
     let mut wrote_index = false;
 
@@ -511,7 +999,15 @@ pub(crate) async fn pip_compile(
     // If necessary, include the `--find-links` locations.
     if include_find_links {
         for flat_index in index_locations.flat_index() {
-            writeln!(writer, "--find-links {flat_index}")?;
+            match flat_index {
+                FlatIndexLocation::Path(path)
+                    if find_links_relative_to.is_some() || find_links_absolute =>
+                {
+                    let path = emitted_find_links_path(path, find_links_relative_to)?;
+                    writeln!(writer, "--find-links {}", path.display())?;
+                }
+                _ => writeln!(writer, "--find-links {flat_index}")?,
+            }
             wrote_index = true;
         }
     }
@@ -527,11 +1023,16 @@ pub(crate) async fn pip_compile(
         DisplayResolutionGraph::new(
             &resolution,
             &no_emit_packages,
+            &prerelease_package,
             generate_hashes,
             include_extras,
             include_annotations,
             include_index_annotation,
+            include_origin,
+            redirects,
             annotation_style,
+            comments,
+            constraint_annotations,
         )
     )?;
 
@@ -552,6 +1053,24 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Summarize any changes to the pins from the existing output file.
+    if let Some(output_file) =
+        output_file.filter(|_| reuse_pins != ReusePins::Auto || report_changes)
+    {
+        write_pin_changes(
+            output_file,
+            &existing_pins,
+            &resolution,
+            &upgrade,
+            &conflicting_pins,
+            &outdated_pins,
+            &direct_requirements,
+            &markers,
+            changes,
+            printer,
+        )?;
+    }
+
     Ok(ExitStatus::Success)
 }
 
@@ -611,11 +1130,35 @@ fn cmd(
                 return Some(None);
             }
 
-            // Always skip the `--quiet` flag.
-            if arg == "--quiet" || arg == "-q" {
+            // Always skip the `--quiet` flag, including repeated short flags (e.g., `-qq`).
+            if arg == "--quiet"
+                || arg
+                    .strip_prefix('-')
+                    .is_some_and(|flags| !flags.is_empty() && flags.bytes().all(|b| b == b'q'))
+            {
+                *skip_next = None;
+                return Some(None);
+            }
+
+            // Always skip the `--explain` flag.
+            if arg.starts_with("--explain=") {
+                *skip_next = None;
+                return Some(None);
+            }
+            if arg == "--explain" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+
+            // Always skip the `--changes` flag.
+            if arg.starts_with("--changes=") {
                 *skip_next = None;
                 return Some(None);
             }
+            if arg == "--changes" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
 
             // Always skip the `--verbose` flag.
             if arg == "--verbose" || arg == "-v" {
@@ -631,6 +1174,429 @@ fn cmd(
     format!("uv {args}")
 }
 
+/// Rewrite a local `--find-links` directory for the output file, as an absolute path or, if
+/// `relative_to` is provided, as a path relative to the given directory.
+fn emitted_find_links_path(path: &Path, relative_to: Option<&Path>) -> Result<PathBuf> {
+    let path = uv_fs::absolutize_path(path)?;
+    let Some(relative_to) = relative_to else {
+        return Ok(path.into_owned());
+    };
+    let relative_to = uv_fs::absolutize_path(relative_to)?;
+    match pathdiff::diff_paths(&path, &relative_to) {
+        Some(relative) if relative.as_os_str().is_empty() => Ok(PathBuf::from(".")),
+        Some(relative) => Ok(relative),
+        None => Ok(path.into_owned()),
+    }
+}
+
+/// Read a [`MarkerEnvironment`] from a JSON file, requiring that every marker is defined.
+fn read_marker_environment(path: &Path) -> Result<MarkerEnvironment> {
+    let contents = fs_err::read_to_string(path)?;
+    serde_json::from_str(&contents).with_context(|| {
+        format!(
+            "Failed to parse marker environment from: `{}`",
+            path.user_display()
+        )
+    })
+}
+
+/// Return the version pinned by an `==` requirement, as written to the output file.
+fn pinned_version(requirement: &Requirement) -> Option<&Version> {
+    let Some(VersionOrUrl::VersionSpecifier(specifiers)) = &requirement.version_or_url else {
+        return None;
+    };
+    let [specifier] = specifiers.as_ref() else {
+        return None;
+    };
+    (*specifier.operator() == Operator::Equal).then(|| specifier.version())
+}
+
+/// Returns `true` if the given requirement excludes the given version.
+fn excludes(requirement: &Requirement, version: &Version) -> bool {
+    match &requirement.version_or_url {
+        Some(VersionOrUrl::VersionSpecifier(specifiers)) => !specifiers.contains(version),
+        Some(VersionOrUrl::Url(_)) => true,
+        None => false,
+    }
+}
+
+/// The dominant reason that a pin from the existing output file changed during resolution.
+#[derive(Debug)]
+enum PinChangeReason<'a> {
+    /// The existing pin is incompatible with a user-provided constraint.
+    Constraint,
+    /// The existing pin is incompatible with a direct requirement.
+    Requirement,
+    /// The existing pin is incompatible with the requirements of the given dependents.
+    Dependency(Vec<&'a PackageName>),
+    /// An upgrade was requested, allowing a newer release.
+    Upgrade,
+    /// The package is required directly, but wasn't pinned before.
+    NewRequirement,
+    /// The package is required by another package, but wasn't pinned before.
+    NewDependency,
+    /// The package is no longer required.
+    Removed,
+    /// None of the above; e.g., the target Python version or platform changed.
+    Resolution,
+}
+
+impl std::fmt::Display for PinChangeReason<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Constraint => write!(f, "constraint conflict"),
+            Self::Requirement => write!(f, "requirement changed"),
+            Self::Dependency(dependents) => {
+                write!(
+                    f,
+                    "requirement from {} changed",
+                    dependents.iter().join(", ")
+                )
+            }
+            Self::Upgrade => write!(f, "upgrade requested"),
+            Self::NewRequirement => write!(f, "new requirement"),
+            Self::NewDependency => write!(f, "new dependency"),
+            Self::Removed => write!(f, "no longer required"),
+            Self::Resolution => write!(f, "resolution changed"),
+        }
+    }
+}
+
+/// Write a summary of the pins from the existing output file that changed during resolution,
+/// along with the reason for each change.
+///
+/// If a `changes` path is provided, the summary is written to that file instead.
+#[allow(clippy::too_many_arguments)]
+fn write_pin_changes(
+    output_file: &Path,
+    existing_pins: &[Preference],
+    resolution: &ResolutionGraph,
+    upgrade: &Upgrade,
+    conflicting_pins: &FxHashSet<PackageName>,
+    outdated_pins: &FxHashSet<PackageName>,
+    direct_requirements: &FxHashSet<PackageName>,
+    markers: &MarkerEnvironment,
+    changes: Option<&Path>,
+    printer: Printer,
+) -> Result<()> {
+    let previous = existing_pins
+        .iter()
+        .filter(|preference| preference.requirement().evaluate_markers(markers, &[]))
+        .filter_map(|preference| {
+            pinned_version(preference.requirement()).map(|version| (preference.name(), version))
+        })
+        .collect::<FxHashMap<_, _>>();
+    let current = resolution
+        .petgraph()
+        .node_weights()
+        .filter_map(|dist| match dist.version_or_url() {
+            distribution_types::VersionOrUrl::Version(version) => Some((dist.name(), version)),
+            distribution_types::VersionOrUrl::Url(_) => None,
+        })
+        .collect::<FxHashMap<_, _>>();
+
+    // Determine the dominant reason for a change to an existing pin, preferring the most
+    // specific explanation.
+    let reason = |name: &PackageName, version: &Version| {
+        if conflicting_pins.contains(name) {
+            return PinChangeReason::Constraint;
+        }
+        if outdated_pins.contains(name) {
+            return PinChangeReason::Requirement;
+        }
+        let dependents = resolution.incompatible_dependents(name, version);
+        if !dependents.is_empty() {
+            return PinChangeReason::Dependency(dependents);
+        }
+        if upgrade.contains(name) {
+            return PinChangeReason::Upgrade;
+        }
+        PinChangeReason::Resolution
+    };
+
+    // If there was no existing output file, there's nothing to compare against.
+    let mut lines = Vec::new();
+    if !existing_pins.is_empty() {
+        for (name, version) in previous
+            .iter()
+            .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b))
+        {
+            match current.get(name) {
+                Some(current) if current == version => {}
+                Some(current) => lines.push(format!(
+                    " {} {} {} -> {} ({})",
+                    "~".yellow(),
+                    name.bold(),
+                    version,
+                    current,
+                    reason(name, version)
+                )),
+                None => lines.push(format!(
+                    " {} {}=={} ({})",
+                    "-".red(),
+                    name.bold(),
+                    version,
+                    PinChangeReason::Removed
+                )),
+            }
+        }
+        for (name, version) in current
+            .iter()
+            .filter(|(name, _)| !previous.contains_key(*name))
+            .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b))
+        {
+            let reason = if direct_requirements.contains(*name) {
+                PinChangeReason::NewRequirement
+            } else {
+                PinChangeReason::NewDependency
+            };
+            lines.push(format!(
+                " {} {}=={} ({})",
+                "+".green(),
+                name.bold(),
+                version,
+                reason
+            ));
+        }
+    }
+
+    // If requested, write the summary to a file, even if nothing changed.
+    if let Some(changes) = changes {
+        use std::io::Write;
+
+        #[allow(clippy::disallowed_types)]
+        let mut file = StripStream::new(std::fs::File::from(fs_err::File::create(changes)?));
+        for line in &lines {
+            writeln!(file, "{line}")?;
+        }
+        return Ok(());
+    }
+
+    if lines.is_empty() {
+        return Ok(());
+    }
+
+    let s = if lines.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Changed {} from {}",
+            format!("{} pin{}", lines.len(), s).bold(),
+            output_file.user_display().cyan()
+        )
+        .dimmed()
+    )?;
+    for line in lines {
+        writeln!(printer.stderr(), "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Read the packages installed in the current environment as `==` requirements, as listed by
+/// `pip freeze`.
+///
+/// Packages that were installed in editable mode or from a direct URL are skipped.
+fn read_installed_requirements(
+    python: Option<&str>,
+    python_implementation: Option<PythonImplementation>,
+    python_preference: PythonPreference,
+    system: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<Vec<Requirement>> {
+    let venv = if let Some(python) = python {
+        PythonEnvironment::from_requested_python(
+            python,
+            python_implementation,
+            python_preference,
+            cache,
+        )?
+    } else if system {
+        PythonEnvironment::from_default_python(python_implementation, python_preference, cache)?
+    } else {
+        PythonEnvironment::from_virtualenv(cache)?
+    };
+    debug!(
+        "Reading installed packages from Python {} environment at {}",
+        venv.interpreter().python_version(),
+        venv.python_executable().user_display().cyan()
+    );
+
+    let site_packages = SitePackages::from_executable(&venv)?;
+    let mut requirements = Vec::new();
+    for dist in site_packages
+        .iter()
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+    {
+        match dist {
+            InstalledDist::Registry(dist) => {
+                requirements.push(Requirement {
+                    name: dist.name().clone(),
+                    extras: vec![],
+                    version_or_url: Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
+                        VersionSpecifier::equals_version(dist.version.clone()),
+                    ))),
+                    marker: None,
+                });
+            }
+            InstalledDist::Url(dist) => {
+                let kind = if dist.editable {
+                    "in editable mode"
+                } else {
+                    "from a direct URL"
+                };
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!(
+                        "Ignoring installed package {} (installed {kind})",
+                        dist.name().bold()
+                    )
+                    .dimmed()
+                )?;
+            }
+        }
+    }
+    Ok(requirements)
+}
+
+/// The header that precedes the build options in the output file.
+const BUILD_OPTIONS_HEADER: &str = "# Compiled with the following build options:";
+
+/// The header that precedes the additional platform tags allowed for specific packages in the
+/// output file.
+const ALLOWED_PLATFORM_TAGS_HEADER: &str =
+    "# Compiled with the following platform tags allowed for specific packages:";
+
+/// Format the options that affect how source distributions are built as command-line flags.
+fn build_options(
+    setup_py: SetupPyStrategy,
+    config_settings: &ConfigSettings,
+    no_build_isolation: bool,
+    no_build_isolation_package: &[PackageName],
+    no_build: &NoBuild,
+) -> Vec<String> {
+    let mut options = Vec::new();
+    match no_build {
+        NoBuild::None => {}
+        NoBuild::All => options.push("--no-build".to_string()),
+        NoBuild::Packages(packages) => {
+            for package in packages.iter().sorted() {
+                options.push(format!("--only-binary {package}"));
+            }
+        }
+    }
+    if no_build_isolation {
+        options.push("--no-build-isolation".to_string());
+    }
+    for package in no_build_isolation_package.iter().sorted() {
+        options.push(format!("--no-build-isolation-package {package}"));
+    }
+    if setup_py == SetupPyStrategy::Setuptools {
+        options.push("--legacy-setup-py".to_string());
+    }
+    for (key, value) in config_settings.iter() {
+        options.push(format!("--config-setting {key}={value}"));
+    }
+    options
+}
+
+/// Read the build options recorded in an existing output file, if any.
+fn read_build_options(output_file: &Path) -> Result<Option<Vec<String>>> {
+    let contents = match fs_err::read_to_string(output_file) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let mut lines = contents.lines();
+    if !lines.any(|line| line == BUILD_OPTIONS_HEADER) {
+        return Ok(None);
+    }
+    Ok(Some(
+        lines
+            .map_while(|line| line.strip_prefix("#    "))
+            .map(ToString::to_string)
+            .collect(),
+    ))
+}
+
+/// Write the chain of requirements that constrained a package to the standard error stream.
+fn write_explanation(
+    name: &PackageName,
+    explained: &[ExplainedRequirement],
+    provenance: &RequirementsProvenance,
+    printer: Printer,
+) -> Result<()> {
+    /// Add the requirements on a package from the input files.
+    fn add_origins(
+        name: &PackageName,
+        provenance: &RequirementsProvenance,
+        depth: usize,
+        lines: &mut Vec<String>,
+    ) {
+        let indent = "  ".repeat(depth);
+        for (origin, requirement) in provenance.get(name) {
+            lines.push(format!("{indent}{requirement} (from {origin})"));
+        }
+    }
+
+    /// Add the requirements on a package from the resolver, along with their dependents.
+    fn add_requirements(
+        name: &PackageName,
+        explained: &[ExplainedRequirement],
+        provenance: &RequirementsProvenance,
+        depth: usize,
+        lines: &mut Vec<String>,
+    ) {
+        let indent = "  ".repeat(depth);
+        add_origins(name, provenance, depth, lines);
+        for requirement in explained {
+            match &requirement.required_by {
+                RequiredBy::User => {
+                    // If the requirement can be attributed to an input file, it was added above.
+                    if provenance.get(name).is_empty() {
+                        lines.push(format!(
+                            "{indent}{} (requested directly)",
+                            requirement.requirement
+                        ));
+                    }
+                }
+                RequiredBy::Package {
+                    name: dependent_name,
+                    dependent,
+                    via,
+                } => {
+                    lines.push(format!(
+                        "{indent}{} (required by {dependent})",
+                        requirement.requirement
+                    ));
+                    add_requirements(dependent_name, via, provenance, depth + 1, lines);
+                }
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    add_requirements(name, explained, provenance, 1, &mut lines);
+
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!("Requirements on {name}:").bold()
+    )?;
+    if lines.is_empty() {
+        writeln!(printer.stderr(), "  {name} was not required")?;
+    } else {
+        for line in lines {
+            writeln!(printer.stderr(), "{line}")?;
+        }
+    }
+
+    Ok(())
+}
+
 /// A multi-casting writer that writes to both the standard output and an output file, if present.
 #[allow(clippy::disallowed_types)]
 struct OutputWriter {
@@ -671,6 +1637,32 @@ impl OutputWriter {
     }
 }
 
+/// Remove the comments that `pip compile` itself emits, such as the header or `# via` annotations,
+/// so that recompiling an output file doesn't duplicate them.
+fn user_comments(comments: &RequirementComments) -> RequirementComments {
+    let leading = if comments
+        .leading
+        .first()
+        .is_some_and(|comment| comment.starts_with("This file was autogenerated by uv"))
+    {
+        // Skip the header, along with the command that follows it.
+        comments.leading.iter().skip(2).cloned().collect()
+    } else {
+        comments.leading.clone()
+    };
+    let trailing = comments
+        .trailing
+        .as_deref()
+        .map(|comment| {
+            comment
+                .find("# via")
+                .map_or(comment, |index| comment[..index].trim_end())
+        })
+        .filter(|comment| !comment.is_empty() && !comment.starts_with("via"))
+        .map(ToString::to_string);
+    RequirementComments { leading, trailing }
+}
+
 pub(crate) fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -696,3 +1688,37 @@ impl Deref for InMemoryIndexRef<'_> {
         }
     }
 }
+
+/// Resolve the URL that each registry artifact in the resolution is served from, after following
+/// any redirects.
+///
+/// Artifacts whose redirects can't be resolved are omitted (with a warning), such that they're
+/// annotated with the URL listed on the index.
+async fn resolve_redirects(
+    resolution: &ResolutionGraph,
+    client: &RegistryClient,
+) -> FxHashMap<Url, Url> {
+    let urls = resolution
+        .petgraph()
+        .node_weights()
+        .filter_map(|dist| dist.file()?.url.to_url())
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .collect::<FxHashSet<_>>();
+    futures::stream::iter(urls)
+        .map(|url| async move {
+            match client.resolve_redirects(&url).await {
+                Ok(origin) => Some((url, origin)),
+                Err(err) => {
+                    let mut redacted = url.clone();
+                    let _ = redacted.set_username("");
+                    let _ = redacted.set_password(None);
+                    warn_user!("Failed to resolve the origin of {redacted}: {err}");
+                    None
+                }
+            }
+        })
+        .buffer_unordered(50)
+        .filter_map(future::ready)
+        .collect()
+        .await
+}
@@ -0,0 +1,501 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::path::Path;
+
+use anstream::eprint;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use distribution_types::{IndexLocations, ResolvedDist};
+use install_wheel_rs::linker::LinkMode;
+use pep508_rs::RequirementsTxtRequirement;
+use platform_tags::Tags;
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{
+    BuildEnv, CompatibleTags, ConfigSettings, Constraints, IndexStrategy, KeyringProviderType,
+    NoBinary, NoBuild, Overrides, PythonImplementation, PythonPreference, SetupPyStrategy,
+};
+use uv_dispatch::BuildDispatch;
+use uv_fs::Simplified;
+use uv_interpreter::{find_best_python, find_required_python, PythonEnvironment};
+use uv_normalize::PackageName;
+use uv_requirements::{
+    ExtrasSpecification, LookaheadResolver, NamedRequirementsResolver, RequirementsSource,
+    RequirementsSpecification, SourceTreeResolver,
+};
+use uv_resolver::{
+    DependencyMode, ExcludeNewer, Exclusions, FlatIndex, InMemoryIndex, Manifest, OptionsBuilder,
+    PreReleaseMode, PythonRequirement, ResolutionMode, Resolver,
+};
+use uv_toolchain::PythonVersion;
+use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
+use uv_warnings::warn_user;
+
+use crate::commands::pip_operations::{save_archives, validate_extras, with_compatible_tags};
+use crate::commands::reporters::{BuildOutputReporter, ResolverReporter};
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// Resolve a set of requirements, and download the resulting distributions into a directory,
+/// without installing them.
+///
+/// If `build_wheels` is set (as in `pip wheel`), any source distributions are built into wheels,
+/// while distributions that are already wheels are copied as-is.
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
+pub(crate) async fn pip_download(
+    requirements: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    extras: &ExtrasSpecification<'_>,
+    directory: &Path,
+    build_wheels: bool,
+    resolution_mode: ResolutionMode,
+    prerelease_mode: PreReleaseMode,
+    prerelease_package: Vec<PackageName>,
+    dependency_mode: DependencyMode,
+    index_locations: IndexLocations,
+    index_strategy: IndexStrategy,
+    keyring_provider: KeyringProviderType,
+    require_hashes: bool,
+    setup_py: SetupPyStrategy,
+    connectivity: Connectivity,
+    config_settings: &ConfigSettings,
+    build_env: &BuildEnv,
+    no_build_isolation: bool,
+    no_build_isolation_package: &[PackageName],
+    reuse_build_dir: bool,
+    verbose_build: bool,
+    no_build: NoBuild,
+    no_binary: NoBinary,
+    compatible_tags: &CompatibleTags,
+    exclude_newer: Option<ExcludeNewer>,
+    python_version: Option<PythonVersion>,
+    python: Option<String>,
+    python_implementation: Option<PythonImplementation>,
+    python_preference: PythonPreference,
+    system: bool,
+    native_tls: bool,
+    http_timeout: Option<u64>,
+    netrc_file: Option<&Path>,
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    // If the user requests `extras` but does not provide a valid source (e.g., a `pyproject.toml`),
+    // return an error.
+    if !extras.is_empty() && !requirements.iter().any(RequirementsSource::allows_extras) {
+        return Err(anyhow!(
+            "Requesting extras requires a `pyproject.toml`, `setup.cfg`, or `setup.py` file."
+        ));
+    }
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
+        .keyring(keyring_provider);
+
+    // Read all requirements from the provided sources.
+    let RequirementsSpecification {
+        project,
+        requirements,
+        constraints,
+        pinned_constraints,
+        constraint_annotations: _,
+        overrides,
+        editables,
+        editable_vcs,
+        source_trees,
+        index_url,
+        extra_index_urls,
+        no_index,
+        find_links,
+        no_binary: specified_no_binary,
+        no_build: specified_no_build,
+        extras: _,
+        defined_extras,
+        requires_python,
+        provenance: _,
+    } = RequirementsSpecification::from_sources(
+        requirements,
+        constraints,
+        overrides,
+        extras,
+        &client_builder,
+    )
+    .await?;
+
+    // Editables are built from a local directory, so there's no archive to download.
+    if !editables.is_empty() {
+        return Err(anyhow!(
+            "Editable requirements can't be {}: {}",
+            if build_wheels {
+                "built into wheels"
+            } else {
+                "downloaded"
+            },
+            editables.iter().join(", ")
+        ));
+    }
+
+    // Editable VCS requirements don't have a local checkout to build in place, so download them
+    // like any other VCS requirement.
+    let requirements = requirements
+        .into_iter()
+        .chain(editable_vcs)
+        .collect::<Vec<_>>();
+
+    // If all the metadata could be statically resolved, validate that every extra is defined. If
+    // we need to resolve metadata via PEP 517, we don't know which extras are defined until much
+    // later.
+    if source_trees.is_empty() {
+        validate_extras(extras, &defined_extras, true)?;
+    }
+
+    // Read the build constraints, which apply only to the resolution of build requirements when
+    // building source distributions in isolated environments.
+    let build_constraints =
+        RequirementsSpecification::from_constraint_sources(build_constraints, &client_builder)
+            .await?;
+    if no_build_isolation && !build_constraints.is_empty() {
+        warn_user!(
+            "Build constraints have no effect when build isolation is disabled (`--no-build-isolation`)"
+        );
+    }
+    let build_constraints = Constraints::from_requirements(build_constraints);
+
+    // Allow pre-releases for any requirements that opt in via `--pre` in the input files, in
+    // addition to those passed via `--prerelease-package`.
+    let prerelease_package = prerelease_package
+        .into_iter()
+        .chain(
+            requirements
+                .iter()
+                .filter(|entry| entry.prerelease)
+                .filter_map(|entry| match &entry.requirement {
+                    RequirementsTxtRequirement::Pep508(requirement) => {
+                        Some(requirement.name.clone())
+                    }
+                    RequirementsTxtRequirement::Unnamed(_) => None,
+                }),
+        )
+        .collect::<Vec<_>>();
+
+    // Find an interpreter to use for building distributions.
+    let interpreter = if let Some(python) = python.as_ref() {
+        find_required_python(python, python_implementation, python_preference, &cache)?
+    } else {
+        find_best_python(
+            python_version.as_ref(),
+            python_implementation,
+            system,
+            python_preference,
+            &cache,
+        )?
+    };
+    debug!(
+        "Using Python {} interpreter at {} for builds",
+        interpreter.python_version(),
+        interpreter.sys_executable().user_display().cyan()
+    );
+
+    // Determine the tags and markers to use for resolution, targeting the requested Python version
+    // (if any) on the platform of the current interpreter.
+    let tags = if let Some(python_version) = python_version.as_ref() {
+        Cow::Owned(Tags::from_env(
+            interpreter.platform(),
+            (python_version.major(), python_version.minor()),
+            interpreter.implementation_name(),
+            interpreter.implementation_tuple(),
+            interpreter.gil_disabled(),
+        )?)
+    } else {
+        Cow::Borrowed(interpreter.tags()?)
+    };
+
+    // Accept any additional platform tags allowed for specific packages.
+    let tags = with_compatible_tags(&tags, compatible_tags);
+
+    let markers = python_version.as_ref().map_or_else(
+        || Cow::Borrowed(interpreter.markers()),
+        |python_version| Cow::Owned(python_version.markers(interpreter.markers())),
+    );
+
+    // Validate that the scripts are compatible with the target Python version.
+    let python_requirement = PythonRequirement::new(&interpreter, &markers);
+    for (path, python_requires) in &requires_python {
+        if !python_requires.contains(python_requirement.target()) {
+            return Err(anyhow!(
+                "The script `{}` requires Python {}, but resolution targets Python {}",
+                path.user_display(),
+                python_requires,
+                python_requirement.target()
+            ));
+        }
+    }
+
+    // Collect the set of required hashes.
+    let hasher = if require_hashes {
+        HashStrategy::from_requirements(
+            requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            pinned_constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            &markers,
+        )?
+    } else {
+        // Enforce the hashes of any hash-pinned constraints, even without `--require-hashes`.
+        HashStrategy::from_constraints(
+            pinned_constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            &markers,
+            false,
+        )?
+    };
+
+    // Incorporate any index locations from the provided sources.
+    let index_locations =
+        index_locations.combine(index_url, extra_index_urls, find_links, no_index);
+
+    // Combine the `--no-binary` and `--no-build` flags.
+    let no_binary = no_binary.combine(specified_no_binary);
+    let no_build = no_build.combine(specified_no_build);
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
+        .connectivity(connectivity)
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .keyring(keyring_provider)
+        .markers(&markers)
+        .platform(interpreter.platform())
+        .build();
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(&client, &cache);
+        let entries = client
+            .fetch(
+                index_locations.flat_index(),
+                index_locations.recursive_flat_index(),
+            )
+            .await?;
+        FlatIndex::from_entries(entries, &tags, &hasher, &no_build, &no_binary)
+    };
+
+    // Determine whether to enable build isolation.
+    let venv;
+    let build_isolation = if no_build_isolation {
+        venv = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::Shared(&venv)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        venv = PythonEnvironment::from_interpreter(interpreter.clone());
+        BuildIsolation::SharedPackage(&venv, no_build_isolation_package)
+    };
+
+    // Create a shared in-memory index.
+    let index = InMemoryIndex::default();
+
+    // Track in-flight downloads, builds, etc., across resolutions.
+    let in_flight = InFlight::default();
+
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        &cache,
+        &interpreter,
+        &index_locations,
+        &flat_index,
+        &index,
+        &in_flight,
+        setup_py,
+        config_settings,
+        build_isolation,
+        LinkMode::default(),
+        &no_build,
+        &no_binary,
+    )
+    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build())
+    .with_build_reporter(BuildOutputReporter::for_verbose_build(
+        verbose_build,
+        printer,
+    ))
+    .with_reuse_build_dir(reuse_build_dir)
+    .with_build_env(build_env.clone())
+    .with_build_constraints(build_constraints);
+
+    // Resolve the requirements from the provided sources.
+    let requirements = {
+        // Convert from unnamed to named requirements.
+        let mut requirements =
+            NamedRequirementsResolver::new(requirements, &hasher, &build_dispatch, &client, &index)
+                .with_reporter(ResolverReporter::from(printer))
+                .resolve()
+                .await?;
+
+        // Resolve any source trees into requirements.
+        if !source_trees.is_empty() {
+            let resolution = SourceTreeResolver::new(
+                source_trees,
+                extras,
+                &hasher,
+                &build_dispatch,
+                &client,
+                &index,
+            )
+            .with_reporter(ResolverReporter::from(printer))
+            .resolve()
+            .await?;
+
+            // Now that the metadata is available, validate that every extra is defined.
+            let mut defined_extras = defined_extras;
+            defined_extras.extend(resolution.extras);
+            validate_extras(extras, &defined_extras, true)?;
+
+            requirements.extend(resolution.requirements);
+        }
+
+        requirements
+    };
+
+    // Resolve the overrides from the provided sources.
+    let overrides =
+        NamedRequirementsResolver::new(overrides, &hasher, &build_dispatch, &client, &index)
+            .with_reporter(ResolverReporter::from(printer))
+            .resolve()
+            .await?;
+
+    let constraints = Constraints::from_requirements(constraints);
+    let overrides = Overrides::from_requirements(overrides);
+
+    // Determine any lookahead requirements.
+    let lookaheads = LookaheadResolver::new(
+        &requirements,
+        &constraints,
+        &overrides,
+        &[],
+        &hasher,
+        &build_dispatch,
+        &client,
+        &index,
+    )
+    .with_reporter(ResolverReporter::from(printer))
+    .resolve(&markers)
+    .await?;
+
+    // Create a manifest of the requirements.
+    let manifest = Manifest::new(
+        requirements,
+        constraints,
+        overrides,
+        Vec::new(),
+        project,
+        Vec::new(),
+        Exclusions::default(),
+        lookaheads,
+    );
+
+    let options = OptionsBuilder::new()
+        .resolution_mode(resolution_mode)
+        .prerelease_mode(prerelease_mode)
+        .prerelease_packages(prerelease_package)
+        .dependency_mode(dependency_mode)
+        .exclude_newer(exclude_newer)
+        .build();
+
+    // Resolve the dependencies.
+    let resolver = Resolver::new(
+        manifest,
+        options,
+        &markers,
+        &interpreter,
+        &tags,
+        &client,
+        &flat_index,
+        &index,
+        &hasher,
+        &build_dispatch,
+        &EmptyInstalledPackages,
+    )?
+    .with_reporter(ResolverReporter::from(printer));
+
+    let resolution = match resolver.resolve().await {
+        Err(uv_resolver::ResolveError::NoSolution(err)) => {
+            let report = miette::Report::msg(format!("{err}"))
+                .context("No solution found when resolving dependencies:");
+            eprint!("{report:?}");
+            return Ok(ExitStatus::Failure);
+        }
+        result => result,
+    }?;
+
+    let s = if resolution.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Resolved {} in {}",
+            format!("{} package{}", resolution.len(), s).bold(),
+            elapsed(start.elapsed())
+        )
+        .dimmed()
+    )?;
+
+    let dists = resolution
+        .into_distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable(dist) => Some(dist),
+            ResolvedDist::Installed(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Save each distribution to the directory.
+    let start = std::time::Instant::now();
+    let archives = save_archives(
+        &dists,
+        build_wheels,
+        &tags,
+        &hasher,
+        &client,
+        &build_dispatch,
+        directory,
+    )
+    .await?;
+
+    let s = if archives.len() == 1 { "" } else { "s" };
+    let (verb, noun) = if build_wheels {
+        ("Saved", "wheel")
+    } else {
+        ("Downloaded", "package")
+    };
+    writeln!(
+        printer.summary(),
+        "{}",
+        format!(
+            "{verb} {} to {} in {}",
+            format!("{} {noun}{s}", archives.len()).bold(),
+            directory.user_display().cyan(),
+            elapsed(start.elapsed())
+        )
+        .dimmed()
+    )?;
+    for (filename, _) in &archives {
+        writeln!(printer.stderr(), " {} {}", "+".green(), filename.bold())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
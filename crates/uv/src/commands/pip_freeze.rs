@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::path::PathBuf;
 
 use anyhow::Result;
 use itertools::Itertools;
@@ -7,32 +8,37 @@ use tracing::debug;
 
 use distribution_types::{InstalledDist, Name};
 use uv_cache::Cache;
+use uv_configuration::PythonPreference;
 use uv_fs::Simplified;
-use uv_installer::SitePackages;
+use uv_installer::{is_seed_package, SitePackages};
 use uv_interpreter::PythonEnvironment;
 
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
 /// Enumerate the installed packages in the current environment.
+#[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 pub(crate) fn pip_freeze(
     exclude_editable: bool,
+    all: bool,
+    paths: &[PathBuf],
     strict: bool,
     python: Option<&str>,
+    python_preference: PythonPreference,
     system: bool,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     // Detect the current Python interpreter.
     let venv = if let Some(python) = python {
-        PythonEnvironment::from_requested_python(python, cache)?
+        PythonEnvironment::from_requested_python(python, None, python_preference, cache)?
     } else if system {
-        PythonEnvironment::from_default_python(cache)?
+        PythonEnvironment::from_default_python(None, python_preference, cache)?
     } else {
         match PythonEnvironment::from_virtualenv(cache) {
             Ok(venv) => venv,
             Err(uv_interpreter::Error::VenvNotFound) => {
-                PythonEnvironment::from_default_python(cache)?
+                PythonEnvironment::from_default_python(None, python_preference, cache)?
             }
             Err(err) => return Err(err.into()),
         }
@@ -44,11 +50,24 @@ pub(crate) fn pip_freeze(
         venv.python_executable().user_display().cyan()
     );
 
-    // Build the installed index.
-    let site_packages = SitePackages::from_executable(&venv)?;
+    // Build the installed index, from the given directories if provided.
+    let site_packages = if paths.is_empty() {
+        SitePackages::from_executable(&venv)?
+    } else {
+        for path in paths {
+            if !path.is_dir() {
+                return Err(anyhow::anyhow!(
+                    "The path `{}` does not exist or is not a directory",
+                    path.user_display()
+                ));
+            }
+        }
+        SitePackages::from_paths(&venv, paths)?
+    };
     for dist in site_packages
         .iter()
         .filter(|dist| !(exclude_editable && dist.is_editable()))
+        .filter(|dist| all || !is_seed_package(dist.name()))
         .sorted_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())))
     {
         match dist {
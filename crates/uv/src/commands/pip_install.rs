@@ -1,36 +1,43 @@
+use std::collections::BTreeMap;
 use std::fmt::Write;
 
 use std::path::Path;
+use std::str::FromStr;
 
 use anstream::eprint;
 use anyhow::{anyhow, Context, Result};
 
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
 use tempfile::tempdir_in;
 use tracing::debug;
 
+use distribution_filename::WheelFilename;
 use distribution_types::{
     DistributionMetadata, IndexLocations, InstalledMetadata, LocalDist, LocalEditable,
     LocalEditables, Name, Resolution,
 };
 use install_wheel_rs::linker::LinkMode;
-use pep508_rs::{MarkerEnvironment, Requirement};
-use platform_tags::Tags;
+use pep508_rs::{MarkerEnvironment, Requirement, RequirementsTxtRequirement, VersionOrUrl};
+use platform_tags::{IncompatibleTag, TagCompatibility, Tags};
 use pypi_types::{Metadata23, Yanked};
-use requirements_txt::EditableRequirement;
+use requirements_txt::{EditableRequirement, RequirementEntry};
 use uv_cache::Cache;
 use uv_client::{
     BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClient, RegistryClientBuilder,
 };
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{
-    ConfigSettings, Constraints, IndexStrategy, NoBinary, NoBuild, Overrides, Reinstall,
-    SetupPyStrategy, Upgrade,
+    BuildEnv, CompatibleTags, ConfigSettings, Constraints, EditableMode, IndexStrategy, NoBinary,
+    NoBuild, Overrides, PythonImplementation, PythonPreference, Reinstall, SetupPyStrategy,
+    Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
-use uv_installer::{BuiltEditable, Downloader, Plan, Planner, ResolvedEditable, SitePackages};
+use uv_installer::{
+    is_seed_package, BuiltEditable, Downloader, Plan, Planner, ResolvedEditable, SitePackages,
+};
 use uv_interpreter::{Interpreter, PythonEnvironment};
 use uv_normalize::PackageName;
 use uv_requirements::{
@@ -44,7 +51,15 @@ use uv_resolver::{
 use uv_types::{BuildIsolation, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
-use crate::commands::reporters::{DownloadReporter, InstallReporter, ResolverReporter};
+use crate::commands::pip_operations::{
+    check_externally_managed, check_script_conflicts, check_virtualenv, report_rollback,
+    validate_extras, validate_no_index, verify_installed, warn_on_symlink, with_compatible_tags,
+    with_editable_vcs,
+};
+use crate::commands::reporters::{
+    BuildOutputReporter, DownloadReporter, InstallReporter, ResolverReporter,
+};
+use crate::commands::venv::create_missing_venv;
 use crate::commands::{compile_bytecode, elapsed, ChangeEvent, ChangeEventKind, ExitStatus};
 use crate::printer::Printer;
 
@@ -56,9 +71,13 @@ pub(crate) async fn pip_install(
     requirements: &[RequirementsSource],
     constraints: &[RequirementsSource],
     overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    convert_editable_vcs: bool,
     extras: &ExtrasSpecification<'_>,
     resolution_mode: ResolutionMode,
+    resolution_exclude: Vec<PackageName>,
     prerelease_mode: PreReleaseMode,
+    prerelease_package: Vec<PackageName>,
     dependency_mode: DependencyMode,
     upgrade: Upgrade,
     index_locations: IndexLocations,
@@ -66,22 +85,39 @@ pub(crate) async fn pip_install(
     keyring_provider: KeyringProviderType,
     reinstall: Reinstall,
     link_mode: LinkMode,
+    link_mode_package: &BTreeMap<PackageName, LinkMode>,
     compile: bool,
+    bytecode_opt_level: u8,
+    overwrite_scripts: bool,
     require_hashes: bool,
     setup_py: SetupPyStrategy,
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
+    build_env: &BuildEnv,
     no_build_isolation: bool,
+    no_build_isolation_package: &[PackageName],
+    reuse_build_dir: bool,
+    verbose_build: bool,
+    editable_mode: EditableMode,
     no_build: NoBuild,
     no_binary: NoBinary,
+    compatible_tags: &CompatibleTags,
     strict: bool,
     exclude_newer: Option<ExcludeNewer>,
     python: Option<String>,
+    python_implementation: Option<PythonImplementation>,
+    python_preference: PythonPreference,
+    allow_create: bool,
     system: bool,
     break_system_packages: bool,
+    require_virtualenv: bool,
     native_tls: bool,
+    http_timeout: Option<u64>,
+    netrc_file: Option<&Path>,
     cache: Cache,
+    exact: bool,
     dry_run: bool,
+    verify: bool,
     printer: Printer,
 ) -> Result<ExitStatus> {
     let start = std::time::Instant::now();
@@ -89,15 +125,29 @@ pub(crate) async fn pip_install(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
+    let spec = read_requirements(
+        requirements,
+        constraints,
+        overrides,
+        extras,
+        &client_builder,
+    )
+    .await?;
+
     let RequirementsSpecification {
         project,
         requirements,
         constraints,
+        pinned_constraints,
+        constraint_annotations: _,
         overrides,
         editables,
+        editable_vcs,
         source_trees,
         index_url,
         extra_index_urls,
@@ -106,20 +156,67 @@ pub(crate) async fn pip_install(
         no_binary: specified_no_binary,
         no_build: specified_no_build,
         extras: _,
-    } = read_requirements(
-        requirements,
-        constraints,
-        overrides,
-        extras,
-        &client_builder,
-    )
-    .await?;
+        defined_extras,
+        requires_python,
+        provenance,
+    } = spec;
+
+    // Editable installs are only supported for local directories, so editable VCS requirements are
+    // either rejected or treated as regular requirements.
+    let requirements = with_editable_vcs(requirements, editable_vcs, convert_editable_vcs)?;
+
+    // Read the build constraints, which apply only to the resolution of build requirements when
+    // building source distributions in isolated environments.
+    let build_constraints =
+        RequirementsSpecification::from_constraint_sources(build_constraints, &client_builder)
+            .await?;
+    if no_build_isolation && !build_constraints.is_empty() {
+        warn_user!(
+            "Build constraints have no effect when build isolation is disabled (`--no-build-isolation`)"
+        );
+    }
+    let build_constraints = Constraints::from_requirements(build_constraints);
+
+    // Allow pre-releases for any requirements that opt in via `--pre` in the input files, in
+    // addition to those passed via `--prerelease-package`.
+    let prerelease_package = prerelease_package
+        .into_iter()
+        .chain(
+            requirements
+                .iter()
+                .filter(|entry| entry.prerelease)
+                .filter_map(|entry| match &entry.requirement {
+                    RequirementsTxtRequirement::Pep508(requirement) => {
+                        Some(requirement.name.clone())
+                    }
+                    RequirementsTxtRequirement::Unnamed(_) => None,
+                }),
+        )
+        .collect::<Vec<_>>();
+
+    // Create the virtual environment that `--python` points to, if requested.
+    if allow_create {
+        if let Some(python) = python.as_deref() {
+            create_missing_venv(
+                python,
+                python_implementation,
+                python_preference,
+                &cache,
+                printer,
+            )?;
+        }
+    }
 
     // Detect the current Python interpreter.
     let venv = if let Some(python) = python.as_ref() {
-        PythonEnvironment::from_requested_python(python, &cache)?
+        PythonEnvironment::from_requested_python(
+            python,
+            python_implementation,
+            python_preference,
+            &cache,
+        )?
     } else if system {
-        PythonEnvironment::from_default_python(&cache)?
+        PythonEnvironment::from_default_python(python_implementation, python_preference, &cache)?
     } else {
         PythonEnvironment::from_virtualenv(&cache)?
     };
@@ -129,35 +226,63 @@ pub(crate) async fn pip_install(
         venv.python_executable().user_display().cyan()
     );
 
-    // If the environment is externally managed, abort.
-    if let Some(externally_managed) = venv.interpreter().is_externally_managed() {
-        if break_system_packages {
-            debug!("Ignoring externally managed environment due to `--break-system-packages`");
-        } else {
-            return if let Some(error) = externally_managed.into_error() {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan(),
-                    textwrap::indent(&error, "  ").green(),
-                ))
-            } else {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan()
-                ))
-            };
+    // If a Python implementation was requested, the environment must satisfy it.
+    if let Some(implementation) = python_implementation {
+        if !venv.interpreter().is_implementation(implementation) {
+            return Err(uv_interpreter::Error::ImplementationMismatch {
+                interpreter: venv.python_executable().to_path_buf(),
+                found: venv.interpreter().implementation_name().to_string(),
+                requested: implementation,
+            }
+            .into());
+        }
+    }
+
+    // Report any requirements that pin a package to a version that another requirement excludes,
+    // along with their sources, rather than deferring to the resolver.
+    let conflicts = provenance.conflicts(venv.interpreter().markers());
+    if !conflicts.is_empty() {
+        return Err(anyhow!("{}", conflicts.iter().join("\n")));
+    }
+
+    // Validate that the scripts are compatible with the installed Python version.
+    for (path, python_requires) in &requires_python {
+        if !python_requires.contains(venv.interpreter().python_version()) {
+            return Err(anyhow!(
+                "The script `{}` requires Python {}, but {} is installed",
+                path.user_display(),
+                python_requires,
+                venv.interpreter().python_version()
+            )
+            .into());
         }
     }
 
+    // If the environment isn't a virtual environment, abort, if required.
+    check_virtualenv(&venv, system, require_virtualenv)?;
+
+    // If the environment is externally managed, abort.
+    check_externally_managed(&venv, break_system_packages)?;
+
     let _lock = venv.lock()?;
 
+    // Determine the tags to use, accepting any additional platform tags allowed for specific
+    // packages.
+    let tags = &with_compatible_tags(venv.interpreter().tags()?, compatible_tags);
+
+    // If multiple local wheels were provided for the same package (e.g., `dist/*.whl`), retain
+    // only the wheel that's compatible with the environment.
+    let requirements = select_local_wheels(requirements, tags, printer)?;
+
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_executable(&venv)?;
 
     // If the requirements are already satisfied, we're done. Ideally, the resolver would be fast
     // enough to let us remove this check. But right now, for large environments, it's an order of
-    // magnitude faster to validate the environment than to resolve the requirements.
-    if reinstall.is_none()
+    // magnitude faster to validate the environment than to resolve the requirements. In `--exact`
+    // mode, the environment may also contain packages that need to be removed.
+    if !exact
+        && reinstall.is_none()
         && upgrade.is_none()
         && source_trees.is_empty()
         && overrides.is_empty()
@@ -166,7 +291,7 @@ pub(crate) async fn pip_install(
         let num_requirements = requirements.len() + editables.len();
         let s = if num_requirements == 1 { "" } else { "s" };
         writeln!(
-            printer.stderr(),
+            printer.summary(),
             "{}",
             format!(
                 "Audited {} in {}",
@@ -181,9 +306,8 @@ pub(crate) async fn pip_install(
         return Ok(ExitStatus::Success);
     }
 
-    // Determine the tags, markers, and interpreter to use for resolution.
+    // Determine the markers and interpreter to use for resolution.
     let interpreter = venv.interpreter().clone();
-    let tags = venv.interpreter().tags()?;
     let markers = venv.interpreter().markers();
 
     // Collect the set of required hashes.
@@ -193,10 +317,20 @@ pub(crate) async fn pip_install(
                 .iter()
                 .chain(overrides.iter())
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            pinned_constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             markers,
         )?
     } else {
-        HashStrategy::None
+        // Enforce the hashes of any hash-pinned constraints, even without `--require-hashes`.
+        HashStrategy::from_constraints(
+            pinned_constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            markers,
+            false,
+        )?
     };
 
     // Incorporate any index locations from the provided sources.
@@ -206,6 +340,8 @@ pub(crate) async fn pip_install(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -217,15 +353,22 @@ pub(crate) async fn pip_install(
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(&client, &cache);
-        let entries = client.fetch(index_locations.flat_index()).await?;
+        let entries = client
+            .fetch(
+                index_locations.flat_index(),
+                index_locations.recursive_flat_index(),
+            )
+            .await?;
         FlatIndex::from_entries(entries, tags, &hasher, &no_build, &no_binary)
     };
 
     // Determine whether to enable build isolation.
     let build_isolation = if no_build_isolation {
         BuildIsolation::Shared(&venv)
-    } else {
+    } else if no_build_isolation_package.is_empty() {
         BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&venv, no_build_isolation_package)
     };
 
     // Combine the `--no-binary` and `--no-build` flags.
@@ -254,7 +397,15 @@ pub(crate) async fn pip_install(
         &no_build,
         &no_binary,
     )
-    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build());
+    .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build())
+    .with_build_reporter(BuildOutputReporter::for_verbose_build(
+        verbose_build,
+        printer,
+    ))
+    .with_reuse_build_dir(reuse_build_dir)
+    .with_editable_mode(editable_mode)
+    .with_build_env(build_env.clone())
+    .with_build_constraints(build_constraints.clone());
 
     // Resolve the requirements from the provided sources.
     let requirements = {
@@ -272,19 +423,24 @@ pub(crate) async fn pip_install(
 
         // Resolve any source trees into requirements.
         if !source_trees.is_empty() {
-            requirements.extend(
-                SourceTreeResolver::new(
-                    source_trees,
-                    extras,
-                    &hasher,
-                    &resolve_dispatch,
-                    &client,
-                    &index,
-                )
-                .with_reporter(ResolverReporter::from(printer))
-                .resolve()
-                .await?,
-            );
+            let resolution = SourceTreeResolver::new(
+                source_trees,
+                extras,
+                &hasher,
+                &resolve_dispatch,
+                &client,
+                &index,
+            )
+            .with_reporter(ResolverReporter::from(printer))
+            .resolve()
+            .await?;
+
+            // Now that the metadata is available, validate that every extra is defined.
+            let mut defined_extras = defined_extras;
+            defined_extras.extend(resolution.extras);
+            validate_extras(extras, &defined_extras, strict)?;
+
+            requirements.extend(resolution.requirements);
         }
 
         requirements
@@ -321,11 +477,47 @@ pub(crate) async fn pip_install(
 
     let options = OptionsBuilder::new()
         .resolution_mode(resolution_mode)
+        .resolution_exclude(resolution_exclude)
         .prerelease_mode(prerelease_mode)
+        .prerelease_packages(prerelease_package)
         .dependency_mode(dependency_mode)
         .exclude_newer(exclude_newer)
         .build();
 
+    // Under `--no-index`, validate that the requirements can be satisfied from `--find-links`.
+    // Editables, and any installed distributions that the resolver may reuse, are exempt.
+    let exclusions = Exclusions::new(reinstall.clone(), upgrade.clone());
+    let provided = editables
+        .iter()
+        .map(|editable| editable.metadata.name.clone())
+        .chain(
+            requirements
+                .iter()
+                .filter(|requirement| !exclusions.contains(&requirement.name))
+                .filter(|requirement| {
+                    let [dist] = site_packages.get_packages(&requirement.name)[..] else {
+                        return false;
+                    };
+                    match &requirement.version_or_url {
+                        Some(VersionOrUrl::VersionSpecifier(specifiers)) => {
+                            specifiers.contains(dist.version())
+                        }
+                        Some(VersionOrUrl::Url(_)) => false,
+                        None => true,
+                    }
+                })
+                .map(|requirement| requirement.name.clone()),
+        )
+        .collect();
+    validate_no_index(
+        &requirements,
+        &overrides,
+        &provided,
+        &index_locations,
+        &flat_index,
+        markers,
+    )?;
+
     // Resolve the requirements.
     let resolution = match resolve(
         requirements,
@@ -383,6 +575,14 @@ pub(crate) async fn pip_install(
             &no_binary,
         )
         .with_options(OptionsBuilder::new().exclude_newer(exclude_newer).build())
+        .with_build_reporter(BuildOutputReporter::for_verbose_build(
+            verbose_build,
+            printer,
+        ))
+        .with_reuse_build_dir(reuse_build_dir)
+        .with_editable_mode(editable_mode)
+        .with_build_env(build_env.clone())
+        .with_build_constraints(build_constraints)
     };
 
     // Sync the environment.
@@ -393,7 +593,10 @@ pub(crate) async fn pip_install(
         &reinstall,
         &no_binary,
         link_mode,
+        link_mode_package,
         compile,
+        bytecode_opt_level,
+        overwrite_scripts,
         &index_locations,
         &hasher,
         tags,
@@ -402,7 +605,9 @@ pub(crate) async fn pip_install(
         &install_dispatch,
         &cache,
         &venv,
+        exact,
         dry_run,
+        verify,
         printer,
     )
     .await?;
@@ -442,25 +647,11 @@ async fn read_requirements(
     )
     .await?;
 
-    // If all the metadata could be statically resolved, validate that every extra was used. If we
-    // need to resolve metadata via PEP 517, we don't know which extras are used until much later.
+    // If all the metadata could be statically resolved, validate that every extra is defined. If
+    // we need to resolve metadata via PEP 517, we don't know which extras are defined until much
+    // later.
     if spec.source_trees.is_empty() {
-        if let ExtrasSpecification::Some(extras) = extras {
-            let mut unused_extras = extras
-                .iter()
-                .filter(|extra| !spec.extras.contains(extra))
-                .collect::<Vec<_>>();
-            if !unused_extras.is_empty() {
-                unused_extras.sort_unstable();
-                unused_extras.dedup();
-                let s = if unused_extras.len() == 1 { "" } else { "s" };
-                return Err(anyhow!(
-                    "Requested extra{s} not found: {}",
-                    unused_extras.iter().join(", ")
-                )
-                .into());
-            }
-        }
+        validate_extras(extras, &spec.defined_extras, true)?;
     }
 
     Ok(spec)
@@ -564,8 +755,14 @@ async fn resolve(
         .map(Preference::from_requirement)
         .collect();
 
-    // Collect constraints and overrides.
-    let constraints = Constraints::from_requirements(constraints);
+    // Collect constraints and overrides, treating any version specifiers provided via
+    // `--upgrade-package` as constraints.
+    let constraints = Constraints::from_requirements(
+        constraints
+            .into_iter()
+            .chain(upgrade.constraints().cloned())
+            .collect(),
+    );
     let overrides = Overrides::from_requirements(overrides);
 
     // Map the editables to their metadata.
@@ -658,7 +855,10 @@ async fn install(
     reinstall: &Reinstall,
     no_binary: &NoBinary,
     link_mode: LinkMode,
+    link_mode_package: &BTreeMap<PackageName, LinkMode>,
     compile: bool,
+    bytecode_opt_level: u8,
+    overwrite_scripts: bool,
     index_urls: &IndexLocations,
     hasher: &HashStrategy,
     tags: &Tags,
@@ -667,7 +867,9 @@ async fn install(
     build_dispatch: &BuildDispatch<'_>,
     cache: &Cache,
     venv: &PythonEnvironment,
+    exact: bool,
     dry_run: bool,
+    verify: bool,
     printer: Printer,
 ) -> Result<(), Error> {
     let start = std::time::Instant::now();
@@ -682,7 +884,7 @@ async fn install(
 
     // Partition into those that should be linked from the cache (`local`), those that need to be
     // downloaded (`remote`), and those that should be removed (`extraneous`).
-    let plan = Planner::with_requirements(&requirements)
+    let mut plan = Planner::with_requirements(&requirements)
         .with_editable_requirements(&editables)
         .build(
             site_packages,
@@ -696,6 +898,21 @@ async fn install(
         )
         .context("Failed to determine installation plan")?;
 
+    // In `--exact` mode, remove any packages that aren't part of the resolution, preserving any
+    // seed packages. Otherwise, leave them in place.
+    if exact {
+        plan.extraneous.retain(|dist_info| {
+            if is_seed_package(dist_info.name()) {
+                debug!("Preserving seed package: {dist_info}");
+                false
+            } else {
+                true
+            }
+        });
+    } else {
+        plan.extraneous.clear();
+    }
+
     if dry_run {
         return report_dry_run(resolution, plan, start, printer);
     }
@@ -705,14 +922,14 @@ async fn install(
         remote,
         reinstalls,
         installed: _,
-        extraneous: _,
+        extraneous,
     } = plan;
 
     // Nothing to do.
-    if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() {
+    if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty() {
         let s = if resolution.len() == 1 { "" } else { "s" };
         writeln!(
-            printer.stderr(),
+            printer.summary(),
             "{}",
             format!(
                 "Audited {} in {}",
@@ -764,61 +981,99 @@ async fn install(
         wheels
     };
 
-    // Remove any existing installations.
-    if !reinstalls.is_empty() {
+    let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
+
+    // Refuse to overwrite scripts owned by other packages, unless requested.
+    check_script_conflicts(
+        venv,
+        &wheels,
+        extraneous.iter().chain(&reinstalls),
+        overwrite_scripts,
+    )?;
+
+    // Remove any existing installations and install the resolved distributions as a single
+    // transaction, such that a failure partway through restores the original environment.
+    let transaction = uv_installer::Transaction::new(venv)?;
+    let result = (|| -> Result<(), Error> {
+        // Remove any packages that aren't part of the resolution. Unlike a reinstall, a package
+        // that can't be removed is an error, since the environment wouldn't match the resolution.
+        for dist_info in &extraneous {
+            let file_count = transaction
+                .park(dist_info)
+                .map_err(uv_installer::UninstallError::from)?;
+            debug!(
+                "Uninstalled {} ({} file{})",
+                dist_info.name(),
+                file_count,
+                if file_count == 1 { "" } else { "s" },
+            );
+        }
+
+        // Remove any existing installations.
         for dist_info in &reinstalls {
-            match uv_installer::uninstall(dist_info).await {
-                Ok(summary) => {
+            match transaction.park(dist_info) {
+                Ok(file_count) => {
                     debug!(
-                        "Uninstalled {} ({} file{}, {} director{})",
+                        "Uninstalled {} ({} file{})",
                         dist_info.name(),
-                        summary.file_count,
-                        if summary.file_count == 1 { "" } else { "s" },
-                        summary.dir_count,
-                        if summary.dir_count == 1 { "y" } else { "ies" },
+                        file_count,
+                        if file_count == 1 { "" } else { "s" },
                     );
                 }
-                Err(uv_installer::UninstallError::Uninstall(
-                    install_wheel_rs::Error::MissingRecord(_),
-                )) => {
+                Err(install_wheel_rs::Error::MissingRecord(_)) => {
                     warn_user!(
                         "Failed to uninstall package at {} due to missing RECORD file. Installation may result in an incomplete environment.",
                         dist_info.path().user_display().cyan(),
                     );
                 }
-                Err(err) => return Err(err.into()),
+                Err(err) => return Err(uv_installer::UninstallError::from(err).into()),
             }
         }
-    }
 
-    // Install the resolved distributions.
-    let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
-    if !wheels.is_empty() {
-        let start = std::time::Instant::now();
-        uv_installer::Installer::new(venv)
-            .with_link_mode(link_mode)
-            .with_reporter(InstallReporter::from(printer).with_length(wheels.len() as u64))
-            .install(&wheels)?;
+        // Install the resolved distributions.
+        if !wheels.is_empty() {
+            let start = std::time::Instant::now();
+            warn_on_symlink(&wheels, link_mode, link_mode_package);
+            uv_installer::Installer::new(venv)
+                .with_link_mode(link_mode)
+                .with_link_mode_package(link_mode_package.clone())
+                .with_reporter(InstallReporter::from(printer).with_length(wheels.len() as u64))
+                .with_transaction(&transaction)
+                .install(&wheels)?;
+
+            let s = if wheels.len() == 1 { "" } else { "s" };
+            writeln!(
+                printer.summary(),
+                "{}",
+                format!(
+                    "Installed {} in {}",
+                    format!("{} package{}", wheels.len(), s).bold(),
+                    elapsed(start.elapsed())
+                )
+                .dimmed()
+            )?;
+        }
 
-        let s = if wheels.len() == 1 { "" } else { "s" };
-        writeln!(
-            printer.stderr(),
-            "{}",
-            format!(
-                "Installed {} in {}",
-                format!("{} package{}", wheels.len(), s).bold(),
-                elapsed(start.elapsed())
-            )
-            .dimmed()
-        )?;
+        Ok(())
+    })();
+    if let Err(err) = result {
+        report_rollback(transaction, printer)?;
+        return Err(err);
+    }
+    transaction.commit()?;
+
+    // Verify that the installed files match their `RECORD`.
+    if verify {
+        verify_installed(venv, &wheels)?;
     }
 
     if compile {
-        compile_bytecode(venv, cache, printer).await?;
+        compile_bytecode(venv, bytecode_opt_level, cache, printer).await?;
     }
 
-    for event in reinstalls
+    for event in extraneous
         .into_iter()
+        .chain(reinstalls)
         .map(|distribution| ChangeEvent {
             dist: LocalDist::from(distribution),
             kind: ChangeEventKind::Removed,
@@ -869,11 +1124,12 @@ async fn install(
             remote,
             reinstalls,
             installed: _,
-            extraneous: _,
+            extraneous,
         } = plan;
 
         // Nothing to do.
-        if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() {
+        if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty()
+        {
             let s = if resolution.len() == 1 { "" } else { "s" };
             writeln!(
                 printer.stderr(),
@@ -917,15 +1173,17 @@ async fn install(
             remote
         };
 
-        // Remove any existing installations.
-        if !reinstalls.is_empty() {
-            let s = if reinstalls.len() == 1 { "" } else { "s" };
+        // Remove any existing installations, along with any packages that aren't part of the
+        // resolution.
+        let uninstalls = reinstalls.len() + extraneous.len();
+        if uninstalls > 0 {
+            let s = if uninstalls == 1 { "" } else { "s" };
             writeln!(
                 printer.stderr(),
                 "{}",
                 format!(
                     "Would uninstall {}",
-                    format!("{} package{}", reinstalls.len(), s).bold(),
+                    format!("{uninstalls} package{s}").bold(),
                 )
                 .dimmed()
             )?;
@@ -943,8 +1201,9 @@ async fn install(
             )?;
         }
 
-        for event in reinstalls
+        for event in extraneous
             .into_iter()
+            .chain(reinstalls)
             .map(|distribution| DryRunEvent {
                 name: distribution.name().clone(),
                 version: distribution.installed_version().to_string(),
@@ -1069,3 +1328,116 @@ enum Error {
     #[error(transparent)]
     Anyhow(#[from] anyhow::Error),
 }
+
+/// When multiple local wheels are provided for the same package (e.g., `uv pip install
+/// dist/*.whl`), retain only the wheel that's compatible with the target environment, reporting
+/// any wheels that were skipped.
+///
+/// Returns an error if none, or more than one, of the wheels for a given package are compatible.
+fn select_local_wheels(
+    requirements: Vec<RequirementEntry>,
+    tags: &Tags,
+    printer: Printer,
+) -> Result<Vec<RequirementEntry>> {
+    // Group the local wheels by package name.
+    let mut wheels: FxHashMap<PackageName, Vec<(usize, WheelFilename)>> = FxHashMap::default();
+    for (index, entry) in requirements.iter().enumerate() {
+        if let Some(filename) = local_wheel(&entry.requirement) {
+            wheels
+                .entry(filename.name.clone())
+                .or_default()
+                .push((index, filename));
+        }
+    }
+
+    let mut skipped = FxHashSet::default();
+    for (name, wheels) in wheels
+        .into_iter()
+        .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b))
+    {
+        if wheels.len() < 2 {
+            continue;
+        }
+
+        let (compatible, incompatible): (Vec<_>, Vec<_>) = wheels
+            .into_iter()
+            .partition(|(_, filename)| filename.is_compatible(tags));
+
+        match compatible.as_slice() {
+            [_] => {}
+            [] => {
+                return Err(anyhow!(
+                    "None of the wheels provided for `{name}` are compatible with the current environment:\n{}",
+                    incompatible
+                        .iter()
+                        .map(|(_, filename)| format!(
+                            "- {filename} ({})",
+                            incompatibility(filename, tags)
+                        ))
+                        .join("\n")
+                ));
+            }
+            _ => {
+                return Err(anyhow!(
+                    "Multiple wheels provided for `{name}` are compatible with the current environment:\n{}",
+                    compatible
+                        .iter()
+                        .map(|(_, filename)| format!("- {filename}"))
+                        .join("\n")
+                ));
+            }
+        }
+
+        for (index, filename) in incompatible {
+            writeln!(
+                printer.stderr(),
+                "Skipping {} as {}",
+                filename.to_string().cyan(),
+                incompatibility(&filename, tags)
+            )?;
+            skipped.insert(index);
+        }
+    }
+
+    Ok(requirements
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| !skipped.contains(index))
+        .map(|(_, entry)| entry)
+        .collect())
+}
+
+/// Return the [`WheelFilename`] for a requirement, if it refers to a local wheel.
+fn local_wheel(requirement: &RequirementsTxtRequirement) -> Option<WheelFilename> {
+    let url = match requirement {
+        RequirementsTxtRequirement::Pep508(requirement) => {
+            match requirement.version_or_url.as_ref()? {
+                VersionOrUrl::Url(url) => url,
+                VersionOrUrl::VersionSpecifier(_) => return None,
+            }
+        }
+        RequirementsTxtRequirement::Unnamed(requirement) => &requirement.url,
+    };
+    if url.scheme() != "file" {
+        return None;
+    }
+    let filename = url.path_segments()?.last()?;
+    WheelFilename::from_str(filename).ok()
+}
+
+/// Describe why a wheel is incompatible with the given tags.
+fn incompatibility(filename: &WheelFilename, tags: &Tags) -> &'static str {
+    match filename.compatibility(tags) {
+        TagCompatibility::Incompatible(IncompatibleTag::Python) => {
+            "its Python tag isn't supported by the environment"
+        }
+        TagCompatibility::Incompatible(IncompatibleTag::Abi) => {
+            "its ABI tag isn't supported by the environment"
+        }
+        TagCompatibility::Incompatible(IncompatibleTag::Platform) => {
+            "its platform tag isn't supported by the environment"
+        }
+        TagCompatibility::Incompatible(IncompatibleTag::Invalid) => "its tags are invalid",
+        TagCompatibility::Compatible(_) => "it's compatible with the environment",
+    }
+}
@@ -10,6 +10,7 @@ use unicode_width::UnicodeWidthStr;
 
 use distribution_types::{InstalledDist, Name};
 use uv_cache::Cache;
+use uv_configuration::PythonPreference;
 use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_interpreter::PythonEnvironment;
@@ -29,20 +30,21 @@ pub(crate) fn pip_list(
     format: &ListFormat,
     strict: bool,
     python: Option<&str>,
+    python_preference: PythonPreference,
     system: bool,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
     // Detect the current Python interpreter.
     let venv = if let Some(python) = python {
-        PythonEnvironment::from_requested_python(python, cache)?
+        PythonEnvironment::from_requested_python(python, None, python_preference, cache)?
     } else if system {
-        PythonEnvironment::from_default_python(cache)?
+        PythonEnvironment::from_default_python(None, python_preference, cache)?
     } else {
         match PythonEnvironment::from_virtualenv(cache) {
             Ok(venv) => venv,
             Err(uv_interpreter::Error::VenvNotFound) => {
-                PythonEnvironment::from_default_python(cache)?
+                PythonEnvironment::from_default_python(None, python_preference, cache)?
             }
             Err(err) => return Err(err.into()),
         }
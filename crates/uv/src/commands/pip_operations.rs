@@ -0,0 +1,537 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::Context;
+use futures::{StreamExt, TryStreamExt};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::{FxHashMap, FxHashSet};
+use tracing::debug;
+
+use distribution_types::{
+    CachedDist, Dist, IndexLocations, InstalledDist, InstalledMetadata, LocalDist, Name, SourceDist,
+};
+use install_wheel_rs::linker::{dist_info_script_names, wheel_script_names, LinkMode};
+use install_wheel_rs::RecordDiscrepancy;
+use pep508_rs::{MarkerEnvironment, Requirement, VersionOrUrl};
+use platform_tags::Tags;
+use pypi_types::HashDigest;
+use requirements_txt::RequirementEntry;
+use uv_client::RegistryClient;
+use uv_configuration::CompatibleTags;
+use uv_dispatch::BuildDispatch;
+use uv_distribution::DistributionDatabase;
+use uv_fs::Simplified;
+use uv_installer::{SitePackages, Transaction};
+use uv_interpreter::PythonEnvironment;
+use uv_normalize::{edit_distance, ExtraName, PackageName};
+use uv_requirements::ExtrasSpecification;
+use uv_resolver::FlatIndex;
+use uv_types::HashStrategy;
+use uv_warnings::warn_user;
+
+use crate::printer::Printer;
+
+/// Roll back a failed installation, and report the distributions that were restored.
+///
+/// If the rollback itself fails, the failure is reported as a warning, such that the caller can
+/// surface the error that caused the rollback.
+pub(super) fn report_rollback(transaction: Transaction, printer: Printer) -> std::fmt::Result {
+    let restored = match transaction.rollback() {
+        Ok(restored) => restored,
+        Err(err) => {
+            warn_user!("{err:#}");
+            return Ok(());
+        }
+    };
+    let s = if restored.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Rolled back the installation, restoring {}",
+            format!("{} package{}", restored.len(), s).bold(),
+        )
+        .dimmed()
+    )?;
+    for dist in restored
+        .into_iter()
+        .map(LocalDist::from)
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+    {
+        writeln!(
+            printer.stderr(),
+            " {} {}{}",
+            "~".yellow(),
+            dist.name().as_ref().bold(),
+            dist.installed_version().to_string().dimmed()
+        )?;
+    }
+    Ok(())
+}
+
+/// Save the archives of the given distributions to a directory, as-is, or, if `build_wheels` is
+/// set, with any source distributions built into wheels.
+///
+/// Returns the filename of each archive, along with its hashes, sorted by filename.
+pub(super) async fn save_archives(
+    dists: &[Dist],
+    build_wheels: bool,
+    tags: &Tags,
+    hasher: &HashStrategy,
+    client: &RegistryClient,
+    build_dispatch: &BuildDispatch<'_>,
+    directory: &Path,
+) -> anyhow::Result<Vec<(String, Vec<HashDigest>)>> {
+    // Git and local directory requirements don't have an archive to download, so reject them
+    // before downloading anything, unless they're being built into wheels.
+    if !build_wheels {
+        let unavailable = dists
+            .iter()
+            .filter(|dist| match dist {
+                Dist::Source(SourceDist::Git(_)) => true,
+                Dist::Source(SourceDist::Path(sdist)) => sdist.path.is_dir(),
+                _ => false,
+            })
+            .collect::<Vec<_>>();
+        if !unavailable.is_empty() {
+            let s = if unavailable.len() == 1 { "" } else { "s" };
+            return Err(anyhow::anyhow!(
+                "Only wheels and source archives can be downloaded, but found Git or local directory requirement{s}: {}. Use `uv pip wheel` to build them into wheels instead.",
+                unavailable.iter().map(|dist| format!("`{dist}`")).join(", ")
+            ));
+        }
+    }
+
+    fs_err::create_dir_all(directory)?;
+    let database = DistributionDatabase::new(client, build_dispatch);
+    let mut archives = futures::stream::iter(dists)
+        .map(|dist| {
+            let database = &database;
+            async move {
+                let archive = match dist {
+                    Dist::Source(source) if build_wheels => {
+                        database
+                            .build_archive(source, tags, hasher.get(dist), directory)
+                            .await
+                    }
+                    _ => {
+                        database
+                            .download_archive(dist, hasher.get(dist), directory)
+                            .await
+                    }
+                };
+                let (path, digests) =
+                    archive.with_context(|| format!("Failed to download: {dist}"))?;
+                let filename = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .expect("archives are saved under a UTF-8 filename")
+                    .to_string();
+                Ok::<_, anyhow::Error>((filename, digests))
+            }
+        })
+        .buffer_unordered(50)
+        .try_collect::<Vec<_>>()
+        .await?;
+    archives.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(archives)
+}
+
+/// Refuse to modify an externally managed environment (PEP 668), rendering the error message
+/// provided by the distributor, unless `--break-system-packages` was provided.
+///
+/// Virtual environments are never externally managed.
+pub(super) fn check_externally_managed(
+    venv: &PythonEnvironment,
+    break_system_packages: bool,
+) -> anyhow::Result<()> {
+    let Some(externally_managed) = venv.interpreter().is_externally_managed() else {
+        return Ok(());
+    };
+
+    if break_system_packages {
+        debug!("Ignoring externally managed environment due to `--break-system-packages`");
+        return Ok(());
+    }
+
+    Err(if let Some(error) = externally_managed.into_error() {
+        anyhow::anyhow!(
+            "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
+            venv.root().user_display().cyan(),
+            textwrap::indent(&error, "  ").green(),
+        )
+    } else {
+        anyhow::anyhow!(
+            "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
+            venv.root().user_display().cyan()
+        )
+    })
+}
+
+/// Abort if the environment isn't a virtual environment and `--require-virtualenv` was provided,
+/// unless the user explicitly opted into the system Python with `--system`.
+pub(super) fn check_virtualenv(
+    venv: &PythonEnvironment,
+    system: bool,
+    require_virtualenv: bool,
+) -> anyhow::Result<()> {
+    if !require_virtualenv || venv.interpreter().is_virtualenv() {
+        return Ok(());
+    }
+
+    if system {
+        debug!("Ignoring `--require-virtualenv` due to `--system`");
+        return Ok(());
+    }
+
+    Err(anyhow::anyhow!(
+        "The interpreter at {} is not in a virtual environment, but `--require-virtualenv` was provided. Create a virtual environment with `uv venv`, or pass `--system` to use the system Python.",
+        venv.root().user_display().cyan()
+    ))
+}
+
+/// Detect entrypoint scripts that would be overwritten by a script of the same name from a
+/// different package.
+///
+/// Packages that are being removed or reinstalled don't count as owners, since their scripts are
+/// removed before the new distributions are installed. If `overwrite_scripts` is set, each conflict
+/// is reported as a warning; otherwise, the installation is aborted.
+pub(super) fn check_script_conflicts<'a>(
+    venv: &PythonEnvironment,
+    wheels: &[CachedDist],
+    removed: impl IntoIterator<Item = &'a InstalledDist>,
+    overwrite_scripts: bool,
+) -> anyhow::Result<()> {
+    let python_minor = venv.interpreter().python_minor();
+    let installing = wheels.iter().map(Name::name).collect::<FxHashSet<_>>();
+    let removed = removed
+        .into_iter()
+        .map(InstalledDist::path)
+        .collect::<FxHashSet<_>>();
+
+    // Map each existing script to the package that owns it.
+    let site_packages = SitePackages::from_executable(venv)?;
+    let mut owners: FxHashMap<String, PackageName> = FxHashMap::default();
+    for dist in site_packages.iter() {
+        if installing.contains(dist.name()) || removed.contains(dist.path()) {
+            continue;
+        }
+        // A package with unreadable entry points shouldn't block the install; its scripts just
+        // can't be checked for conflicts.
+        let scripts = match dist_info_script_names(dist.path(), python_minor) {
+            Ok(scripts) => scripts,
+            Err(err) => {
+                warn_user!(
+                    "Failed to read the entry points of `{}`; its scripts won't be checked for conflicts: {err}",
+                    dist.name()
+                );
+                continue;
+            }
+        };
+        for script in scripts {
+            owners.insert(script, dist.name().clone());
+        }
+    }
+
+    // Check each new script against the existing owners, and against the other wheels.
+    let mut conflicts = Vec::new();
+    for wheel in wheels {
+        for script in wheel_script_names(wheel.path(), python_minor)? {
+            match owners.get(&script) {
+                Some(owner) if owner != wheel.name() => {
+                    conflicts.push((script.clone(), owner.clone(), wheel.name().clone()));
+                }
+                _ => {}
+            }
+            owners.insert(script, wheel.name().clone());
+        }
+    }
+
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+
+    if overwrite_scripts {
+        for (script, owner, package) in conflicts {
+            warn_user!(
+                "The script `{script}` from `{package}` overwrites the script of the same name from `{owner}`"
+            );
+        }
+        return Ok(());
+    }
+
+    Err(anyhow::anyhow!(
+        "The following scripts would be overwritten by a package other than the one that installed them:\n{}\nPass `--overwrite-scripts` to overwrite them anyway.",
+        conflicts
+            .into_iter()
+            .map(|(script, owner, package)| format!(
+                "  - `{}` from `{owner}` (overwritten by `{package}`)",
+                script.bold()
+            ))
+            .join("\n")
+    ))
+}
+
+/// Verify that the files of the given (just installed) wheels match the sizes and hashes recorded
+/// in their `RECORD`, failing with a suggestion to reinstall any package whose files are missing
+/// or modified.
+pub(super) fn verify_installed(
+    venv: &PythonEnvironment,
+    wheels: &[CachedDist],
+) -> anyhow::Result<()> {
+    let site_packages = SitePackages::from_executable(venv)?;
+
+    let mut discrepancies = Vec::new();
+    let mut affected = Vec::new();
+    for wheel in wheels {
+        for dist in site_packages.get_packages(wheel.name()) {
+            let found = install_wheel_rs::verify_record(dist.path())?;
+            if found.is_empty() {
+                continue;
+            }
+            let root = dist.path().parent().unwrap_or(dist.path());
+            for discrepancy in found {
+                let path = discrepancy
+                    .path()
+                    .strip_prefix(root)
+                    .unwrap_or(discrepancy.path());
+                let reason = match discrepancy {
+                    RecordDiscrepancy::Missing { .. } => "is missing".to_string(),
+                    RecordDiscrepancy::Size {
+                        expected, actual, ..
+                    } => {
+                        format!("has a size of {actual} bytes, but {expected} bytes were recorded")
+                    }
+                    RecordDiscrepancy::Hash { .. } => "doesn't match its recorded hash".to_string(),
+                };
+                discrepancies.push(format!(
+                    "  - `{}` of `{}=={}` {reason}",
+                    path.user_display(),
+                    dist.name(),
+                    dist.version()
+                ));
+            }
+            affected.push((dist.name(), dist.version()));
+        }
+    }
+
+    if affected.is_empty() {
+        debug!(
+            "Verified the files of {} installed package(s)",
+            wheels.len()
+        );
+        return Ok(());
+    }
+
+    Err(anyhow::anyhow!(
+        "The following installed files don't match their `RECORD`:\n{}\nTo repair the affected packages, run: `uv pip install {} {}`",
+        discrepancies.join("\n"),
+        affected
+            .iter()
+            .map(|(name, _)| format!("--reinstall-package {name}"))
+            .join(" "),
+        affected
+            .iter()
+            .map(|(name, version)| format!("{name}=={version}"))
+            .join(" "),
+    ))
+}
+
+/// Warn if any of the given wheels will be symlinked from the cache, since any modification to
+/// the installed files would then modify the cache as well.
+pub(super) fn warn_on_symlink(
+    wheels: &[CachedDist],
+    link_mode: LinkMode,
+    link_mode_package: &BTreeMap<PackageName, LinkMode>,
+) {
+    let symlinked = wheels
+        .iter()
+        .filter(|wheel| {
+            matches!(
+                link_mode_package
+                    .get(wheel.name())
+                    .copied()
+                    .unwrap_or(link_mode),
+                LinkMode::Symlink
+            )
+        })
+        .count();
+    if symlinked == 0 {
+        return;
+    }
+
+    let s = if symlinked == 1 { "" } else { "s" };
+    warn_user!(
+        "Symlinking {symlinked} package{s} from the cache. Modifying an installed package will modify the cache, and removing the cache will break the environment. Use `link-mode-package` to copy any packages that modify their own files at runtime."
+    );
+}
+
+/// Extend the compatible tags with the additional platform tags allowed for specific packages
+/// (e.g., via `--allow-platform-tag`).
+pub(super) fn with_compatible_tags(tags: &Tags, compatible_tags: &CompatibleTags) -> Tags {
+    tags.clone().with_package_platforms(
+        compatible_tags
+            .iter()
+            .map(|(package, platforms)| (package.clone(), platforms.to_vec())),
+    )
+}
+
+/// Add the editable requirements for VCS URLs (e.g., `-e git+https://...#egg=example`) to the
+/// requirements as regular, non-editable requirements if `--convert-editable-vcs` is set.
+///
+/// Otherwise, fail with an error that explains the supported alternatives, since editable installs
+/// are only supported for local directories.
+pub(super) fn with_editable_vcs(
+    mut requirements: Vec<RequirementEntry>,
+    editable_vcs: Vec<RequirementEntry>,
+    convert_editable_vcs: bool,
+) -> anyhow::Result<Vec<RequirementEntry>> {
+    if editable_vcs.is_empty() {
+        return Ok(requirements);
+    }
+
+    let s = if editable_vcs.len() == 1 { "" } else { "s" };
+    let editable_vcs_list = editable_vcs
+        .iter()
+        .map(|entry| format!("`{}`", entry.requirement))
+        .join(", ");
+    if !convert_editable_vcs {
+        return Err(anyhow::anyhow!(
+            "Editable installs are only supported for local directories, but found editable VCS requirement{s}: {editable_vcs_list}. \
+            Pass `--convert-editable-vcs` to install them as regular (non-editable) requirements, \
+            or clone the repository and install the local directory in editable mode (e.g., `-e ./path/to/repository`)"
+        ));
+    }
+
+    warn_user!(
+        "Treating editable VCS requirement{s} as regular (non-editable) requirement{s}: {editable_vcs_list}"
+    );
+    requirements.extend(editable_vcs);
+    Ok(requirements)
+}
+
+/// Validate that each requested extra is defined by the project, suggesting the closest defined
+/// extra for any that aren't.
+///
+/// If `strict` is set, unknown extras are an error; otherwise, they're reported as a warning.
+pub(super) fn validate_extras(
+    extras: &ExtrasSpecification<'_>,
+    defined: &FxHashSet<ExtraName>,
+    strict: bool,
+) -> anyhow::Result<()> {
+    let ExtrasSpecification::Some(extras) = extras else {
+        return Ok(());
+    };
+
+    let mut unknown = extras
+        .iter()
+        .filter(|extra| !defined.contains(*extra))
+        .collect::<Vec<_>>();
+    if unknown.is_empty() {
+        return Ok(());
+    }
+    unknown.sort_unstable();
+    unknown.dedup();
+
+    let s = if unknown.len() == 1 { "" } else { "s" };
+    let message = format!(
+        "Requested extra{s} not found: {}",
+        unknown
+            .iter()
+            .map(|extra| match closest_extra(extra, defined) {
+                Some(closest) => format!("{extra} (did you mean `{closest}`?)"),
+                None => extra.to_string(),
+            })
+            .join(", ")
+    );
+    if strict {
+        return Err(anyhow::anyhow!(message));
+    }
+    warn_user!("{message}");
+    Ok(())
+}
+
+/// Under `--no-index`, validate that each registry requirement has a candidate distribution in the
+/// `--find-links` locations, so that an unsatisfiable requirement is reported upfront rather than
+/// as a resolver failure.
+///
+/// Requirements that are overridden are checked via their overrides, and packages that are
+/// `provided` without an index lookup (e.g., editables) are skipped.
+pub(super) fn validate_no_index<'a>(
+    requirements: &[Requirement],
+    overrides: impl IntoIterator<Item = &'a Requirement>,
+    provided: &FxHashSet<PackageName>,
+    index_locations: &IndexLocations,
+    flat_index: &FlatIndex,
+    markers: &MarkerEnvironment,
+) -> anyhow::Result<()> {
+    // If index lookups are enabled, or if any `--find-links` entries couldn't be fetched due to a
+    // lack of connectivity, we can't tell upfront what's available.
+    if index_locations.index().is_some() || flat_index.offline() {
+        return Ok(());
+    }
+
+    let mut overrides_by_name: FxHashMap<&PackageName, Vec<&Requirement>> = FxHashMap::default();
+    for requirement in overrides {
+        overrides_by_name
+            .entry(&requirement.name)
+            .or_default()
+            .push(requirement);
+    }
+
+    let mut unsatisfiable = requirements
+        .iter()
+        .filter(|requirement| !overrides_by_name.contains_key(&requirement.name))
+        .chain(overrides_by_name.values().flatten().copied())
+        .filter(|requirement| !provided.contains(&requirement.name))
+        .filter(|requirement| requirement.evaluate_markers(markers, &[]))
+        .filter(|requirement| match &requirement.version_or_url {
+            Some(VersionOrUrl::Url(_)) => false,
+            Some(VersionOrUrl::VersionSpecifier(specifiers)) => flat_index
+                .get(&requirement.name)
+                .map_or(true, |distributions| {
+                    !distributions
+                        .iter()
+                        .any(|(version, _)| specifiers.contains(version))
+                }),
+            None => flat_index.get(&requirement.name).is_none(),
+        })
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    if unsatisfiable.is_empty() {
+        return Ok(());
+    }
+    unsatisfiable.sort_unstable();
+    unsatisfiable.dedup();
+
+    let s = if unsatisfiable.len() == 1 { "" } else { "s" };
+    let locations = if index_locations.flat_index().next().is_some() {
+        "no matching distributions were found in the provided `--find-links` locations"
+    } else {
+        "no `--find-links` locations were provided"
+    };
+    Err(anyhow::anyhow!(
+        "Index lookups are disabled (`--no-index`), but {locations} for the following requirement{s}: {}",
+        unsatisfiable
+            .iter()
+            .map(|requirement| format!("`{requirement}`"))
+            .join(", ")
+    ))
+}
+
+/// Return the defined extra closest to `extra` by edit distance, if any is close enough to be a
+/// likely typo.
+fn closest_extra<'a>(
+    extra: &ExtraName,
+    defined: &'a FxHashSet<ExtraName>,
+) -> Option<&'a ExtraName> {
+    let threshold = (extra.as_ref().chars().count() / 3).max(1);
+    defined
+        .iter()
+        .map(|candidate| (edit_distance(extra.as_ref(), candidate.as_ref()), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
@@ -1,18 +1,26 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::fmt::Write;
+use std::path::Path;
 
 use anstream::eprint;
 use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
 use tracing::debug;
 
 use distribution_types::{
-    IndexLocations, InstalledMetadata, LocalDist, LocalEditable, LocalEditables, Name, ResolvedDist,
+    CachedDist, Dist, DistributionMetadata, IndexLocations, InstalledDist, InstalledMetadata,
+    InstalledVersion, LocalDist, LocalEditable, LocalEditables, Name, ResolvedDist, VersionOrUrl,
 };
 use install_wheel_rs::linker::LinkMode;
-
+use pep440_rs::Operator;
+use pep508_rs::{MarkerEnvironment, Requirement};
 use platform_tags::Tags;
-use pypi_types::Yanked;
+use pypi_types::{HashAlgorithm, Yanked};
 use requirements_txt::EditableRequirement;
 use uv_cache::{ArchiveTarget, ArchiveTimestamp, Cache};
 use uv_client::{
@@ -20,31 +28,54 @@ use uv_client::{
 };
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, NoBinary, NoBuild, Reinstall, SetupPyStrategy,
+    BuildEnv, CompatibleTags, ConfigSettings, Constraints, EditableMode, IndexStrategy, NoBinary,
+    NoBuild, Overrides, PythonImplementation, PythonPreference, Reinstall, SetupPyStrategy,
 };
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
-use uv_installer::{is_dynamic, Downloader, Plan, Planner, ResolvedEditable, SitePackages};
+use uv_installer::{
+    is_dynamic, is_seed_package, Diagnostic, Downloader, Plan, Planner, ResolvedEditable,
+    SitePackages,
+};
 use uv_interpreter::{Interpreter, PythonEnvironment};
+use uv_normalize::PackageName;
 use uv_requirements::{
-    ExtrasSpecification, NamedRequirementsResolver, RequirementsSource, RequirementsSpecification,
-    SourceTreeResolver,
+    ExtrasSpecification, NamedRequirementsResolver, RequirementOrigin, RequirementsProvenance,
+    RequirementsSource, RequirementsSpecification, SourceTreeResolver,
+};
+use uv_resolver::{
+    DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, OptionsBuilder, Resolver,
 };
-use uv_resolver::{DependencyMode, FlatIndex, InMemoryIndex, Manifest, OptionsBuilder, Resolver};
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy, InFlight};
 use uv_warnings::warn_user;
 
-use crate::commands::reporters::{DownloadReporter, InstallReporter, ResolverReporter};
-use crate::commands::{compile_bytecode, elapsed, ChangeEvent, ChangeEventKind, ExitStatus};
+use crate::commands::pip_operations::{
+    check_externally_managed, check_script_conflicts, check_virtualenv, report_rollback,
+    save_archives, verify_installed, warn_on_symlink, with_compatible_tags, with_editable_vcs,
+};
+use crate::commands::reporters::{
+    BuildOutputReporter, DownloadReporter, InstallReporter, ResolverReporter,
+};
+use crate::commands::venv::create_missing_venv;
+use crate::commands::{
+    compile_bytecode, elapsed, ChangeEvent, ChangeEventKind, ExitStatus, SyncFormat,
+};
 use crate::printer::Printer;
 
 /// Install a set of locked requirements into the current Python environment.
 #[allow(clippy::too_many_arguments, clippy::fn_params_excessive_bools)]
 pub(crate) async fn pip_sync(
     sources: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    build_constraints: &[RequirementsSource],
+    convert_editable_vcs: bool,
     reinstall: &Reinstall,
     link_mode: LinkMode,
+    link_mode_package: &BTreeMap<PackageName, LinkMode>,
     compile: bool,
+    bytecode_opt_level: u8,
+    overwrite_scripts: bool,
     require_hashes: bool,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
@@ -52,14 +83,36 @@ pub(crate) async fn pip_sync(
     setup_py: SetupPyStrategy,
     connectivity: Connectivity,
     config_settings: &ConfigSettings,
+    build_env: &BuildEnv,
     no_build_isolation: bool,
+    no_build_isolation_package: &[PackageName],
+    reuse_build_dir: bool,
+    verbose_build: bool,
+    editable_mode: EditableMode,
     no_build: NoBuild,
     no_binary: NoBinary,
+    compatible_tags: &CompatibleTags,
     strict: bool,
+    no_deps: bool,
+    exact: bool,
+    no_remove: bool,
+    keep: &[PackageName],
+    dry_run: bool,
+    format: SyncFormat,
+    fail_on_change: bool,
+    download_only: Option<&Path>,
+    build_wheels: bool,
+    verify: bool,
     python: Option<String>,
+    python_implementation: Option<PythonImplementation>,
+    python_preference: PythonPreference,
+    allow_create: bool,
     system: bool,
     break_system_packages: bool,
+    require_virtualenv: bool,
     native_tls: bool,
+    http_timeout: Option<u64>,
+    netrc_file: Option<&Path>,
     cache: Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -68,24 +121,55 @@ pub(crate) async fn pip_sync(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
     let RequirementsSpecification {
         project: _,
         requirements,
-        constraints: _,
-        overrides: _,
+        constraints,
+        pinned_constraints,
+        constraint_annotations: _,
+        overrides,
         editables,
+        editable_vcs,
         source_trees,
         extras: _,
+        defined_extras: _,
         index_url,
         extra_index_urls,
         no_index,
         find_links,
         no_binary: specified_no_binary,
         no_build: specified_no_build,
-    } = RequirementsSpecification::from_simple_sources(sources, &client_builder).await?;
+        requires_python,
+        provenance,
+    } = RequirementsSpecification::from_sources(
+        sources,
+        constraints,
+        overrides,
+        &ExtrasSpecification::None,
+        &client_builder,
+    )
+    .await?;
+
+    // Editable installs are only supported for local directories, so editable VCS requirements are
+    // either rejected or treated as regular requirements.
+    let requirements = with_editable_vcs(requirements, editable_vcs, convert_editable_vcs)?;
+
+    // Read the build constraints, which apply only to the resolution of build requirements when
+    // building source distributions in isolated environments.
+    let build_constraints =
+        RequirementsSpecification::from_constraint_sources(build_constraints, &client_builder)
+            .await?;
+    if no_build_isolation && !build_constraints.is_empty() {
+        warn_user!(
+            "Build constraints have no effect when build isolation is disabled (`--no-build-isolation`)"
+        );
+    }
+    let build_constraints = Constraints::from_requirements(build_constraints);
 
     // Validate that the requirements are non-empty.
     let num_requirements = requirements.len() + source_trees.len() + editables.len();
@@ -94,11 +178,29 @@ pub(crate) async fn pip_sync(
         return Ok(ExitStatus::Success);
     }
 
+    // Create the virtual environment that `--python` points to, if requested.
+    if allow_create {
+        if let Some(python) = python.as_deref() {
+            create_missing_venv(
+                python,
+                python_implementation,
+                python_preference,
+                &cache,
+                printer,
+            )?;
+        }
+    }
+
     // Detect the current Python interpreter.
     let venv = if let Some(python) = python.as_ref() {
-        PythonEnvironment::from_requested_python(python, &cache)?
+        PythonEnvironment::from_requested_python(
+            python,
+            python_implementation,
+            python_preference,
+            &cache,
+        )?
     } else if system {
-        PythonEnvironment::from_default_python(&cache)?
+        PythonEnvironment::from_default_python(python_implementation, python_preference, &cache)?
     } else {
         PythonEnvironment::from_virtualenv(&cache)?
     };
@@ -108,42 +210,65 @@ pub(crate) async fn pip_sync(
         venv.python_executable().user_display().cyan()
     );
 
-    // If the environment is externally managed, abort.
-    if let Some(externally_managed) = venv.interpreter().is_externally_managed() {
-        if break_system_packages {
-            debug!("Ignoring externally managed environment due to `--break-system-packages`");
-        } else {
-            return if let Some(error) = externally_managed.into_error() {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan(),
-                    textwrap::indent(&error, "  ").green(),
-                ))
-            } else {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan()
-                ))
-            };
+    // If a Python implementation was requested, the environment must satisfy it.
+    if let Some(implementation) = python_implementation {
+        if !venv.interpreter().is_implementation(implementation) {
+            return Err(uv_interpreter::Error::ImplementationMismatch {
+                interpreter: venv.python_executable().to_path_buf(),
+                found: venv.interpreter().implementation_name().to_string(),
+                requested: implementation,
+            }
+            .into());
+        }
+    }
+
+    // Validate that the scripts are compatible with the installed Python version.
+    for (path, python_requires) in &requires_python {
+        if !python_requires.contains(venv.interpreter().python_version()) {
+            return Err(anyhow!(
+                "The script `{}` requires Python {}, but {} is installed",
+                path.user_display(),
+                python_requires,
+                venv.interpreter().python_version()
+            ));
         }
     }
 
+    // If the environment isn't a virtual environment (if required) or is externally managed,
+    // abort, unless the environment won't be modified.
+    if download_only.is_none() {
+        check_virtualenv(&venv, system, require_virtualenv)?;
+        check_externally_managed(&venv, break_system_packages)?;
+    }
+
     let _lock = venv.lock()?;
 
-    // Determine the current environment markers.
-    let tags = venv.interpreter().tags()?;
+    // Determine the current environment markers, and the tags to use, accepting any additional
+    // platform tags allowed for specific packages.
+    let tags = &with_compatible_tags(venv.interpreter().tags()?, compatible_tags);
     let markers = venv.interpreter().markers();
 
     // Collect the set of required hashes.
     let hasher = if require_hashes {
         HashStrategy::from_requirements(
             requirements
+                .iter()
+                .chain(overrides.iter())
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            pinned_constraints
                 .iter()
                 .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
             markers,
         )?
     } else {
-        HashStrategy::None
+        // Enforce the hashes of any hash-pinned constraints, even without `--require-hashes`.
+        HashStrategy::from_constraints(
+            pinned_constraints
+                .iter()
+                .map(|entry| (&entry.requirement, entry.hashes.as_slice())),
+            markers,
+            false,
+        )?
     };
 
     // Incorporate any index locations from the provided sources.
@@ -153,6 +278,8 @@ pub(crate) async fn pip_sync(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -164,7 +291,12 @@ pub(crate) async fn pip_sync(
     // Resolve the flat indexes from `--find-links`.
     let flat_index = {
         let client = FlatIndexClient::new(&client, &cache);
-        let entries = client.fetch(index_locations.flat_index()).await?;
+        let entries = client
+            .fetch(
+                index_locations.flat_index(),
+                index_locations.recursive_flat_index(),
+            )
+            .await?;
         FlatIndex::from_entries(entries, tags, &hasher, &no_build, &no_binary)
     };
 
@@ -177,8 +309,10 @@ pub(crate) async fn pip_sync(
     // Determine whether to enable build isolation.
     let build_isolation = if no_build_isolation {
         BuildIsolation::Shared(&venv)
-    } else {
+    } else if no_build_isolation_package.is_empty() {
         BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&venv, no_build_isolation_package)
     };
 
     // Combine the `--no-binary` and `--no-build` flags.
@@ -203,7 +337,15 @@ pub(crate) async fn pip_sync(
         link_mode,
         &no_build,
         &no_binary,
-    );
+    )
+    .with_build_reporter(BuildOutputReporter::for_verbose_build(
+        verbose_build,
+        printer,
+    ))
+    .with_reuse_build_dir(reuse_build_dir)
+    .with_editable_mode(editable_mode)
+    .with_build_env(build_env.clone())
+    .with_build_constraints(build_constraints);
 
     // Convert from unnamed to named requirements.
     let requirements = {
@@ -227,13 +369,64 @@ pub(crate) async fn pip_sync(
                 )
                 .with_reporter(ResolverReporter::from(printer))
                 .resolve()
-                .await?,
+                .await?
+                .requirements,
             );
         }
 
-        requirements
+        // Apply any overrides, which replace the pinned requirements from the input files.
+        if overrides.is_empty() {
+            requirements
+        } else {
+            let overrides = Overrides::from_requirements(
+                NamedRequirementsResolver::new(
+                    overrides,
+                    &hasher,
+                    &build_dispatch,
+                    &client,
+                    &index,
+                )
+                .with_reporter(ResolverReporter::from(printer))
+                .resolve()
+                .await?,
+            );
+            overrides.apply(&requirements).cloned().collect()
+        }
     };
 
+    // Detect any packages that the input files require at different versions.
+    let requirements = deduplicate_requirements(requirements, markers, strict, &provenance)?;
+
+    // Validate that the pinned requirements satisfy the constraints.
+    let constraints = Constraints::from_requirements(constraints);
+    validate_constraints(&requirements, &constraints, markers)?;
+
+    // With `--download-only`, save the distributions to a directory rather than installing them.
+    if let Some(directory) = download_only {
+        if !editables.is_empty() {
+            return Err(anyhow!(
+                "Editable requirements can't be downloaded with `--download-only`: {}",
+                editables.iter().join(", ")
+            ));
+        }
+        return download(
+            directory,
+            requirements,
+            constraints,
+            build_wheels,
+            venv.interpreter(),
+            tags,
+            &client,
+            &flat_index,
+            &index,
+            &hasher,
+            &build_dispatch,
+            start,
+            printer,
+        )
+        .await;
+    }
+
     // Resolve any editables.
     let resolved_editables = resolve_editables(
         editables,
@@ -256,7 +449,7 @@ pub(crate) async fn pip_sync(
         remote,
         reinstalls,
         installed: _,
-        extraneous,
+        mut extraneous,
     } = Planner::with_requirements(&requirements)
         .with_editable_requirements(&resolved_editables.editables)
         .build(
@@ -271,11 +464,61 @@ pub(crate) async fn pip_sync(
         )
         .context("Failed to determine installation plan")?;
 
+    // In `--exact` mode, preserve any seed packages that aren't listed in the input files,
+    // regardless of how the environment was created.
+    if exact {
+        extraneous.retain(|dist_info| {
+            if is_seed_package(dist_info.name()) {
+                debug!("Preserving seed package: {dist_info}");
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    // Preserve any packages that aren't listed in the input files, if requested.
+    if no_remove {
+        for dist_info in &extraneous {
+            debug!("Preserving unlisted package due to `--no-remove`: {dist_info}");
+        }
+        extraneous.clear();
+    } else if !keep.is_empty() {
+        extraneous.retain(|dist_info| {
+            if keep.contains(dist_info.name()) {
+                debug!("Preserving kept package: {dist_info}");
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    // A kept package that the input files would replace is an error, rather than being silently
+    // upgraded or downgraded.
+    if let Some(dist_info) = reinstalls.iter().find(|dist_info| {
+        keep.contains(dist_info.name())
+            && match reinstall {
+                Reinstall::None => true,
+                Reinstall::All => false,
+                Reinstall::Packages(packages) => !packages.contains(dist_info.name()),
+            }
+    }) {
+        return Err(anyhow!(
+            "`{}` is installed and marked with `--keep`, but the input files require a different version: {}",
+            dist_info.name(),
+            requirements
+                .iter()
+                .filter(|requirement| requirement.name == *dist_info.name())
+                .join(", ")
+        ));
+    }
+
     // Nothing to do.
     if remote.is_empty() && cached.is_empty() && reinstalls.is_empty() && extraneous.is_empty() {
         let s = if num_requirements == 1 { "" } else { "s" };
         writeln!(
-            printer.stderr(),
+            printer.summary(),
             "{}",
             format!(
                 "Audited {} in {}",
@@ -285,6 +528,10 @@ pub(crate) async fn pip_sync(
             .dimmed()
         )?;
 
+        if dry_run {
+            return report_dry_run(&DryRunPlan::default(), format, fail_on_change, printer);
+        }
+
         return Ok(ExitStatus::Success);
     }
 
@@ -294,9 +541,8 @@ pub(crate) async fn pip_sync(
     } else {
         let start = std::time::Instant::now();
 
-        // Determine the tags, markers, and interpreter to use for resolution.
+        // Determine the markers and interpreter to use for resolution.
         let interpreter = venv.interpreter();
-        let tags = interpreter.tags()?;
         let markers = interpreter.markers();
 
         // Resolve with `--no-deps`.
@@ -307,9 +553,21 @@ pub(crate) async fn pip_sync(
         // Create a bound on the progress bar, since we know the number of packages upfront.
         let reporter = ResolverReporter::from(printer).with_length(remote.len() as u64);
 
+        // Create a manifest of the requirements, respecting any constraints.
+        let manifest = Manifest::new(
+            remote,
+            constraints,
+            Overrides::default(),
+            Vec::new(),
+            None,
+            Vec::new(),
+            Exclusions::default(),
+            Vec::new(),
+        );
+
         // Run the resolver.
         let resolver = Resolver::new(
-            Manifest::simple(remote),
+            manifest,
             options,
             markers,
             interpreter,
@@ -355,6 +613,12 @@ pub(crate) async fn pip_sync(
             .collect::<Vec<_>>()
     };
 
+    // In a dry run, report the plan without modifying the environment.
+    if dry_run {
+        let plan = DryRunPlan::from_plan(&remote, &cached, &reinstalls, &extraneous);
+        return report_dry_run(&plan, format, fail_on_change, printer);
+    }
+
     // Download, build, and unzip any missing distributions.
     let wheels = if remote.is_empty() {
         Vec::new()
@@ -384,75 +648,102 @@ pub(crate) async fn pip_sync(
         wheels
     };
 
-    // Remove any unnecessary packages.
-    if !extraneous.is_empty() || !reinstalls.is_empty() {
-        let start = std::time::Instant::now();
+    let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
 
-        for dist_info in extraneous.iter().chain(reinstalls.iter()) {
-            match uv_installer::uninstall(dist_info).await {
-                Ok(summary) => {
-                    debug!(
-                        "Uninstalled {} ({} file{}, {} director{})",
-                        dist_info.name(),
-                        summary.file_count,
-                        if summary.file_count == 1 { "" } else { "s" },
-                        summary.dir_count,
-                        if summary.dir_count == 1 { "y" } else { "ies" },
-                    );
-                }
-                Err(uv_installer::UninstallError::Uninstall(
-                    install_wheel_rs::Error::MissingRecord(_),
-                )) => {
-                    warn_user!(
-                        "Failed to uninstall package at {} due to missing RECORD file. Installation may result in an incomplete environment.",
-                        dist_info.path().user_display().cyan(),
-                    );
+    // Refuse to overwrite scripts owned by other packages, unless requested.
+    check_script_conflicts(
+        &venv,
+        &wheels,
+        extraneous.iter().chain(&reinstalls),
+        overwrite_scripts,
+    )?;
+
+    // Remove any unnecessary packages and install the resolved distributions as a single
+    // transaction, such that a failure partway through restores the original environment.
+    let transaction = uv_installer::Transaction::new(&venv)?;
+    let result = (|| -> Result<()> {
+        // Remove any unnecessary packages.
+        if !extraneous.is_empty() || !reinstalls.is_empty() {
+            let start = std::time::Instant::now();
+
+            for dist_info in extraneous.iter().chain(reinstalls.iter()) {
+                match transaction.park(dist_info) {
+                    Ok(file_count) => {
+                        debug!(
+                            "Uninstalled {} ({} file{})",
+                            dist_info.name(),
+                            file_count,
+                            if file_count == 1 { "" } else { "s" },
+                        );
+                    }
+                    // In `--exact` mode, a package that can't be removed is an error, since the
+                    // environment would no longer match the input files.
+                    Err(install_wheel_rs::Error::MissingRecord(_)) if !exact => {
+                        warn_user!(
+                            "Failed to uninstall package at {} due to missing RECORD file. Installation may result in an incomplete environment.",
+                            dist_info.path().user_display().cyan(),
+                        );
+                    }
+                    Err(err) => return Err(err.into()),
                 }
-                Err(err) => return Err(err.into()),
             }
+
+            let s = if extraneous.len() + reinstalls.len() == 1 {
+                ""
+            } else {
+                "s"
+            };
+            writeln!(
+                printer.summary(),
+                "{}",
+                format!(
+                    "Uninstalled {} in {}",
+                    format!("{} package{}", extraneous.len() + reinstalls.len(), s).bold(),
+                    elapsed(start.elapsed())
+                )
+                .dimmed()
+            )?;
         }
 
-        let s = if extraneous.len() + reinstalls.len() == 1 {
-            ""
-        } else {
-            "s"
-        };
-        writeln!(
-            printer.stderr(),
-            "{}",
-            format!(
-                "Uninstalled {} in {}",
-                format!("{} package{}", extraneous.len() + reinstalls.len(), s).bold(),
-                elapsed(start.elapsed())
-            )
-            .dimmed()
-        )?;
-    }
+        // Install the resolved distributions.
+        if !wheels.is_empty() {
+            let start = std::time::Instant::now();
+            warn_on_symlink(&wheels, link_mode, link_mode_package);
+            uv_installer::Installer::new(&venv)
+                .with_link_mode(link_mode)
+                .with_link_mode_package(link_mode_package.clone())
+                .with_reporter(InstallReporter::from(printer).with_length(wheels.len() as u64))
+                .with_transaction(&transaction)
+                .install(&wheels)?;
 
-    // Install the resolved distributions.
-    let wheels = wheels.into_iter().chain(cached).collect::<Vec<_>>();
-    if !wheels.is_empty() {
-        let start = std::time::Instant::now();
-        uv_installer::Installer::new(&venv)
-            .with_link_mode(link_mode)
-            .with_reporter(InstallReporter::from(printer).with_length(wheels.len() as u64))
-            .install(&wheels)?;
+            let s = if wheels.len() == 1 { "" } else { "s" };
+            writeln!(
+                printer.summary(),
+                "{}",
+                format!(
+                    "Installed {} in {}",
+                    format!("{} package{}", wheels.len(), s).bold(),
+                    elapsed(start.elapsed())
+                )
+                .dimmed()
+            )?;
+        }
 
-        let s = if wheels.len() == 1 { "" } else { "s" };
-        writeln!(
-            printer.stderr(),
-            "{}",
-            format!(
-                "Installed {} in {}",
-                format!("{} package{}", wheels.len(), s).bold(),
-                elapsed(start.elapsed())
-            )
-            .dimmed()
-        )?;
+        Ok(())
+    })();
+    if let Err(err) = result {
+        report_rollback(transaction, printer)?;
+        return Err(err);
+    }
+    transaction.commit()?;
+
+    // Verify that the installed files match their `RECORD`.
+    if verify {
+        verify_installed(&venv, &wheels)?;
     }
 
     if compile {
-        compile_bytecode(&venv, &cache, printer).await?;
+        compile_bytecode(&venv, bytecode_opt_level, &cache, printer).await?;
     }
 
     // Report on any changes in the environment.
@@ -497,10 +788,43 @@ pub(crate) async fn pip_sync(
         }
     }
 
-    // Validate that the environment is consistent.
-    if strict {
+    // In `--exact` mode, verify that every installed package is listed in the input files.
+    if exact {
+        let site_packages = SitePackages::from_executable(&venv)?;
+        let unlisted = site_packages
+            .iter()
+            .filter(|dist_info| {
+                !is_seed_package(dist_info.name())
+                    && !keep.contains(dist_info.name())
+                    && requirements
+                        .iter()
+                        .all(|requirement| requirement.name != *dist_info.name())
+                    && resolved_editables
+                        .editables
+                        .iter()
+                        .all(|editable| editable.name() != dist_info.name())
+            })
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if !unlisted.is_empty() {
+            return Err(anyhow!(
+                "The environment contains packages that aren't listed in the input files:\n{}",
+                unlisted
+                    .iter()
+                    .map(|dist_info| format!("  - {dist_info}"))
+                    .join("\n")
+            ));
+        }
+    }
+
+    // Validate that the environment is consistent. With `--no-deps`, the input files are expected
+    // to be complete, so warn about any dependencies that they're missing.
+    if strict || no_deps {
         let site_packages = SitePackages::from_executable(&venv)?;
         for diagnostic in site_packages.diagnostics()? {
+            if !strict && !matches!(diagnostic, Diagnostic::MissingDependency { .. }) {
+                continue;
+            }
             writeln!(
                 printer.stderr(),
                 "{}{} {}",
@@ -541,6 +865,454 @@ pub(crate) async fn pip_sync(
     Ok(ExitStatus::Success)
 }
 
+/// The changes that a dry run of `pip sync` would make to the environment, grouped by action.
+#[derive(Debug, Default, Serialize)]
+struct DryRunPlan {
+    install: Vec<DryRunChange>,
+    reinstall: Vec<DryRunChange>,
+    upgrade: Vec<DryRunChange>,
+    downgrade: Vec<DryRunChange>,
+    remove: Vec<DryRunChange>,
+}
+
+/// A change to a single package in a [`DryRunPlan`].
+#[derive(Debug, Serialize)]
+struct DryRunChange {
+    name: String,
+    /// The currently installed version, if the package is installed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<String>,
+    /// The version (or, absent a version, the URL) that would be installed, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    to: Option<String>,
+}
+
+impl DryRunPlan {
+    /// Group the distributions in an installation plan by the action that would be taken on each
+    /// package.
+    fn from_plan(
+        remote: &[Dist],
+        cached: &[CachedDist],
+        reinstalls: &[InstalledDist],
+        extraneous: &[InstalledDist],
+    ) -> Self {
+        let mut plan = Self::default();
+
+        let installs = remote
+            .iter()
+            .map(|dist| {
+                let to = match dist.version_or_url() {
+                    VersionOrUrl::Version(version) => version.to_string(),
+                    VersionOrUrl::Url(url) => url.to_string(),
+                };
+                (dist.name(), dist.version(), to)
+            })
+            .chain(cached.iter().map(|dist| {
+                let (InstalledVersion::Version(version) | InstalledVersion::Url(_, version)) =
+                    dist.installed_version();
+                (dist.name(), Some(version), version.to_string())
+            }))
+            .collect::<Vec<_>>();
+
+        for &(name, version, ref to) in &installs {
+            let Some(installed) = reinstalls.iter().find(|dist| dist.name() == name) else {
+                plan.install.push(DryRunChange {
+                    name: name.to_string(),
+                    from: None,
+                    to: Some(to.clone()),
+                });
+                continue;
+            };
+            let change = DryRunChange {
+                name: name.to_string(),
+                from: Some(installed.version().to_string()),
+                to: Some(to.clone()),
+            };
+            match version.map(|version| version.cmp(installed.version())) {
+                Some(Ordering::Greater) => plan.upgrade.push(change),
+                Some(Ordering::Less) => plan.downgrade.push(change),
+                Some(Ordering::Equal) | None => plan.reinstall.push(change),
+            }
+        }
+
+        // Any installed distribution that isn't replaced by a new one is removed.
+        for dist in extraneous.iter().chain(
+            reinstalls
+                .iter()
+                .filter(|dist| installs.iter().all(|(name, ..)| *name != dist.name())),
+        ) {
+            plan.remove.push(DryRunChange {
+                name: dist.name().to_string(),
+                from: Some(dist.version().to_string()),
+                to: None,
+            });
+        }
+
+        for changes in plan.groups_mut() {
+            changes.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        }
+
+        plan
+    }
+
+    /// Returns `true` if the plan would make no changes to the environment.
+    fn is_empty(&self) -> bool {
+        self.groups().iter().all(|(_, changes)| changes.is_empty())
+    }
+
+    /// Return each action alongside the changes it would make, in display order.
+    fn groups(&self) -> [(&'static str, &[DryRunChange]); 5] {
+        [
+            ("install", self.install.as_slice()),
+            ("reinstall", self.reinstall.as_slice()),
+            ("upgrade", self.upgrade.as_slice()),
+            ("downgrade", self.downgrade.as_slice()),
+            ("remove", self.remove.as_slice()),
+        ]
+    }
+
+    fn groups_mut(&mut self) -> [&mut Vec<DryRunChange>; 5] {
+        [
+            &mut self.install,
+            &mut self.reinstall,
+            &mut self.upgrade,
+            &mut self.downgrade,
+            &mut self.remove,
+        ]
+    }
+}
+
+/// Report the changes that a dry run would make to the environment.
+///
+/// Returns a failure status if `fail_on_change` is set and the plan is non-empty.
+fn report_dry_run(
+    plan: &DryRunPlan,
+    format: SyncFormat,
+    fail_on_change: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    match format {
+        SyncFormat::Text => {
+            if plan.is_empty() {
+                writeln!(printer.stderr(), "Would make no changes")?;
+            }
+            for (action, changes) in plan.groups() {
+                if changes.is_empty() {
+                    continue;
+                }
+                let s = if changes.len() == 1 { "" } else { "s" };
+                writeln!(
+                    printer.stderr(),
+                    "{}",
+                    format!(
+                        "Would {action} {}",
+                        format!("{} package{s}", changes.len()).bold()
+                    )
+                    .dimmed()
+                )?;
+                for change in changes {
+                    match (&change.from, &change.to) {
+                        (None, Some(to)) => {
+                            writeln!(
+                                printer.stderr(),
+                                " {} {} {}",
+                                "+".green(),
+                                change.name.bold(),
+                                to.dimmed()
+                            )?;
+                        }
+                        (Some(from), None) => {
+                            writeln!(
+                                printer.stderr(),
+                                " {} {} {}",
+                                "-".red(),
+                                change.name.bold(),
+                                from.dimmed()
+                            )?;
+                        }
+                        (Some(from), Some(to)) if from == to => {
+                            writeln!(
+                                printer.stderr(),
+                                " {} {} {}",
+                                "~".yellow(),
+                                change.name.bold(),
+                                to.dimmed()
+                            )?;
+                        }
+                        (from, to) => {
+                            writeln!(
+                                printer.stderr(),
+                                " {} {} {} -> {}",
+                                "~".yellow(),
+                                change.name.bold(),
+                                from.as_deref().unwrap_or_default().dimmed(),
+                                to.as_deref().unwrap_or_default().dimmed()
+                            )?;
+                        }
+                    }
+                }
+            }
+        }
+        SyncFormat::Json => {
+            writeln!(printer.stdout(), "{}", serde_json::to_string(plan)?)?;
+        }
+    }
+
+    if fail_on_change && !plan.is_empty() {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Remove any duplicate requirements on the same package, considering only those requirements
+/// whose markers apply to the current environment.
+///
+/// Identical duplicates are removed quietly. If the duplicates differ (in their version, URL, or
+/// extras), each requirement is reported alongside the input file that introduced it: under
+/// `--strict`, as an error; otherwise, as a warning that names the requirement that's used (the
+/// first) and the requirements that are ignored.
+fn deduplicate_requirements(
+    requirements: Vec<Requirement>,
+    markers: &MarkerEnvironment,
+    strict: bool,
+    provenance: &RequirementsProvenance,
+) -> Result<Vec<Requirement>> {
+    let mut seen = FxHashMap::default();
+    let mut conflicts = Vec::new();
+    let requirements = requirements
+        .into_iter()
+        .filter(|requirement| {
+            if !requirement.evaluate_markers(markers, &[]) {
+                return true;
+            }
+            match seen.entry(requirement.name.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(requirement.clone());
+                    true
+                }
+                Entry::Occupied(entry) => {
+                    if (entry.get().version_or_url != requirement.version_or_url
+                        || entry.get().extras != requirement.extras)
+                        && !conflicts.contains(&requirement.name)
+                    {
+                        conflicts.push(requirement.name.clone());
+                    }
+                    false
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for name in conflicts {
+        // Attribute each of the conflicting requirements to its source.
+        let origins = provenance
+            .get(&name)
+            .iter()
+            .filter(|(origin, requirement)| {
+                matches!(origin, RequirementOrigin::Requirement(_))
+                    && requirement.evaluate_markers(markers, &[])
+            })
+            .collect::<Vec<_>>();
+
+        if strict {
+            let mut message = String::new();
+            for (origin, requirement) in &origins {
+                write!(message, "\n  - `{requirement}` (from {origin})")?;
+            }
+            return Err(anyhow!(
+                "Conflicting requirements for `{name}` in the input files:{message}"
+            ));
+        }
+
+        let used = &seen[&name];
+        let mut message = format!("using `{used}`");
+        if let Some((origin, _)) = origins.iter().find(|(_, requirement)| requirement == used) {
+            write!(message, " (from {origin})")?;
+        }
+        message.push_str(" and ignoring:");
+        for (origin, requirement) in origins
+            .iter()
+            .filter(|(_, requirement)| requirement != used)
+        {
+            write!(message, "\n  - `{requirement}` (from {origin})")?;
+        }
+        warn_user!("Conflicting requirements for `{name}` in the input files; {message}");
+    }
+
+    Ok(requirements)
+}
+
+/// Validate that every pinned requirement (e.g., `foo==1.0.0`) satisfies the applicable
+/// constraints.
+///
+/// Unpinned requirements are instead bounded by the constraints during resolution.
+fn validate_constraints(
+    requirements: &[Requirement],
+    constraints: &Constraints,
+    markers: &MarkerEnvironment,
+) -> Result<()> {
+    for requirement in requirements {
+        let Some(pep508_rs::VersionOrUrl::VersionSpecifier(specifiers)) =
+            &requirement.version_or_url
+        else {
+            continue;
+        };
+        let [specifier] = &**specifiers else {
+            continue;
+        };
+        if *specifier.operator() != Operator::Equal {
+            continue;
+        }
+
+        for constraint in constraints.get(&requirement.name).into_iter().flatten() {
+            if !constraint.evaluate_markers(markers, &[]) {
+                continue;
+            }
+            let Some(pep508_rs::VersionOrUrl::VersionSpecifier(constraint_specifiers)) =
+                &constraint.version_or_url
+            else {
+                continue;
+            };
+            if !constraint_specifiers.contains(specifier.version()) {
+                return Err(anyhow!(
+                    "Requirement `{requirement}` does not satisfy the constraint `{constraint}`"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Download the distributions for a set of pinned requirements into a directory, alongside an
+/// `index.html` page that lists them, such that the directory can be used with `--find-links`.
+///
+/// If `build_wheels` is set, source distributions are built into wheels; otherwise, they're saved
+/// as-is.
+#[allow(clippy::too_many_arguments)]
+async fn download(
+    directory: &Path,
+    requirements: Vec<Requirement>,
+    constraints: Constraints,
+    build_wheels: bool,
+    interpreter: &Interpreter,
+    tags: &Tags,
+    client: &RegistryClient,
+    flat_index: &FlatIndex,
+    index: &InMemoryIndex,
+    hasher: &HashStrategy,
+    build_dispatch: &BuildDispatch<'_>,
+    start: std::time::Instant,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Resolve the pinned requirements to distributions, with `--no-deps`.
+    let options = OptionsBuilder::new()
+        .dependency_mode(DependencyMode::Direct)
+        .build();
+    let reporter = ResolverReporter::from(printer).with_length(requirements.len() as u64);
+    let manifest = Manifest::new(
+        requirements,
+        constraints,
+        Overrides::default(),
+        Vec::new(),
+        None,
+        Vec::new(),
+        Exclusions::default(),
+        Vec::new(),
+    );
+    let resolver = Resolver::new(
+        manifest,
+        options,
+        interpreter.markers(),
+        interpreter,
+        tags,
+        client,
+        flat_index,
+        index,
+        hasher,
+        build_dispatch,
+        &EmptyInstalledPackages,
+    )?
+    .with_reporter(reporter);
+
+    let resolution = match resolver.resolve().await {
+        Err(uv_resolver::ResolveError::NoSolution(err)) => {
+            let report = miette::Report::msg(format!("{err}"))
+                .context("No solution found when resolving dependencies:");
+            eprint!("{report:?}");
+            return Ok(ExitStatus::Failure);
+        }
+        result => result,
+    }?;
+
+    let s = if resolution.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Resolved {} in {}",
+            format!("{} package{}", resolution.len(), s).bold(),
+            elapsed(start.elapsed())
+        )
+        .dimmed()
+    )?;
+
+    let dists = resolution
+        .into_distributions()
+        .filter_map(|dist| match dist {
+            ResolvedDist::Installable(dist) => Some(dist),
+            ResolvedDist::Installed(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Save each distribution to the directory.
+    let start = std::time::Instant::now();
+    let archives = save_archives(
+        &dists,
+        build_wheels,
+        tags,
+        hasher,
+        client,
+        build_dispatch,
+        directory,
+    )
+    .await?;
+
+    // Write an index page that lists the archives, along with their hashes.
+    let mut html = String::from("<!DOCTYPE html>\n<html>\n  <body>\n");
+    for (filename, digests) in &archives {
+        let fragment = digests
+            .iter()
+            .find(|digest| digest.algorithm == HashAlgorithm::Sha256)
+            .map(|digest| format!("#{}={}", digest.algorithm, digest.digest))
+            .unwrap_or_default();
+        writeln!(
+            html,
+            "    <a href=\"{filename}{fragment}\">{filename}</a><br/>"
+        )?;
+    }
+    html.push_str("  </body>\n</html>\n");
+    fs_err::write(directory.join("index.html"), html)?;
+
+    let s = if archives.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Downloaded {} to {} in {}",
+            format!("{} package{}", archives.len(), s).bold(),
+            directory.user_display().cyan(),
+            elapsed(start.elapsed())
+        )
+        .dimmed()
+    )?;
+    for (filename, _) in &archives {
+        writeln!(printer.stderr(), " {} {}", "+".green(), filename.bold())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
+
 #[derive(Debug)]
 struct ResolvedEditables {
     /// The set of resolved editables, including both those that were already installed and those
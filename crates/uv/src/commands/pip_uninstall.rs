@@ -1,18 +1,21 @@
 use std::fmt::Write;
+use std::path::Path;
 
 use anyhow::Result;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use tracing::debug;
 
-use distribution_types::{InstalledMetadata, Name};
+use distribution_types::{InstalledDist, InstalledMetadata, Name};
 use pep508_rs::{Requirement, RequirementsTxtRequirement, UnnamedRequirement};
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::KeyringProviderType;
+use uv_configuration::{KeyringProviderType, PythonPreference};
 use uv_fs::Simplified;
 use uv_interpreter::PythonEnvironment;
+use uv_warnings::warn_user;
 
+use crate::commands::pip_operations::{check_externally_managed, check_virtualenv};
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
@@ -21,12 +24,19 @@ use uv_requirements::{RequirementsSource, RequirementsSpecification};
 #[allow(clippy::too_many_arguments)]
 pub(crate) async fn pip_uninstall(
     sources: &[RequirementsSource],
+    all: bool,
+    exclude_editable: bool,
+    force: bool,
     python: Option<String>,
+    python_preference: PythonPreference,
     system: bool,
     break_system_packages: bool,
+    require_virtualenv: bool,
     cache: Cache,
     connectivity: Connectivity,
     native_tls: bool,
+    http_timeout: Option<u64>,
+    netrc_file: Option<&Path>,
     keyring_provider: KeyringProviderType,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -34,6 +44,8 @@ pub(crate) async fn pip_uninstall(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
         .keyring(keyring_provider);
 
     // Read all requirements from the provided sources.
@@ -41,9 +53,9 @@ pub(crate) async fn pip_uninstall(
 
     // Detect the current Python interpreter.
     let venv = if let Some(python) = python.as_ref() {
-        PythonEnvironment::from_requested_python(python, &cache)?
+        PythonEnvironment::from_requested_python(python, None, python_preference, &cache)?
     } else if system {
-        PythonEnvironment::from_default_python(&cache)?
+        PythonEnvironment::from_default_python(None, python_preference, &cache)?
     } else {
         PythonEnvironment::from_virtualenv(&cache)?
     };
@@ -53,25 +65,8 @@ pub(crate) async fn pip_uninstall(
         venv.python_executable().user_display().cyan(),
     );
 
-    // If the environment is externally managed, abort.
-    if let Some(externally_managed) = venv.interpreter().is_externally_managed() {
-        if break_system_packages {
-            debug!("Ignoring externally managed environment due to `--break-system-packages`");
-        } else {
-            return if let Some(error) = externally_managed.into_error() {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed, and indicates the following:\n\n{}\n\nConsider creating a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan(),
-                    textwrap::indent(&error, "  ").green(),
-                ))
-            } else {
-                Err(anyhow::anyhow!(
-                    "The interpreter at {} is externally managed. Instead, create a virtual environment with `uv venv`.",
-                    venv.root().user_display().cyan()
-                ))
-            };
-        }
-    }
+    // If the environment isn't a virtual environment, abort, if required.
+    check_virtualenv(&venv, system, require_virtualenv)?;
 
     let _lock = venv.lock()?;
 
@@ -111,7 +106,16 @@ pub(crate) async fn pip_uninstall(
     };
 
     // Map to the local distributions.
-    let distributions = {
+    let distributions = if all {
+        // Uninstall every installed distribution, optionally preserving editables.
+        let mut distributions = site_packages
+            .iter()
+            .filter(|dist| !(exclude_editable && dist.is_editable()))
+            .collect::<Vec<_>>();
+        distributions
+            .sort_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())));
+        distributions
+    } else {
         let mut distributions = Vec::with_capacity(names.len() + urls.len());
 
         // Identify all packages that are installed.
@@ -152,9 +156,51 @@ pub(crate) async fn pip_uninstall(
         distributions
     };
 
+    // If the environment is externally managed, abort, naming any distributions that are owned by
+    // a system package manager.
+    if venv.interpreter().is_externally_managed().is_some() {
+        let managed = distributions
+            .iter()
+            .filter_map(|dist| match system_installer(dist) {
+                Ok(Some(installer)) => Some(Ok((dist, installer))),
+                Ok(None) => None,
+                Err(err) => Some(Err(err)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if break_system_packages {
+            for (dist, installer) in &managed {
+                warn_user!(
+                    "Uninstalling `{}`, which was installed by `{installer}`; this may break packages managed by `{installer}`",
+                    dist.name()
+                );
+            }
+        } else if !managed.is_empty() {
+            let packages = managed
+                .iter()
+                .map(|(dist, installer)| {
+                    format!(
+                        "  - {}{} (installed by {})",
+                        dist.name(),
+                        dist.installed_version(),
+                        installer.cyan()
+                    )
+                })
+                .join("\n");
+            return Err(anyhow::anyhow!(
+                "The interpreter at {} is externally managed, and the following packages are owned by the system package manager:\n{}\nUninstall them with the system package manager instead, or pass `--break-system-packages` to remove them anyway.",
+                venv.root().user_display().cyan(),
+                packages
+            ));
+        }
+    }
+
+    // Otherwise, if the environment is externally managed, abort.
+    check_externally_managed(&venv, break_system_packages)?;
+
     if distributions.is_empty() {
         writeln!(
-            printer.stderr(),
+            printer.summary(),
             "{}{} No packages to uninstall.",
             "warning".yellow().bold(),
             ":".bold(),
@@ -162,9 +208,9 @@ pub(crate) async fn pip_uninstall(
         return Ok(ExitStatus::Success);
     }
 
-    // Uninstall each package.
-    for distribution in &distributions {
-        let summary = uv_installer::uninstall(distribution).await?;
+    // Uninstall the packages.
+    let summaries = uv_installer::uninstall_all(&distributions, force).await?;
+    for (distribution, summary) in distributions.iter().zip(&summaries) {
         debug!(
             "Uninstalled {} ({} file{}, {} director{})",
             distribution.name(),
@@ -173,10 +219,17 @@ pub(crate) async fn pip_uninstall(
             summary.dir_count,
             if summary.dir_count == 1 { "y" } else { "ies" },
         );
+        for path in &summary.modified {
+            warn_user!(
+                "Preserving `{}`, which was modified after `{}` was installed (use `--force` to remove it)",
+                path.user_display(),
+                distribution.name()
+            );
+        }
     }
 
     writeln!(
-        printer.stderr(),
+        printer.summary(),
         "{}",
         format!(
             "Uninstalled {} in {}",
@@ -203,3 +256,19 @@ pub(crate) async fn pip_uninstall(
 
     Ok(ExitStatus::Success)
 }
+
+/// Installers that are Python packaging tools, rather than system package managers.
+const PYTHON_INSTALLERS: &[&str] = &["pip", "uv", "poetry", "pdm"];
+
+/// Return the system package manager that installed the given distribution, as recorded in its
+/// `INSTALLER` file, if it was installed by something other than a Python packaging tool.
+fn system_installer(dist: &InstalledDist) -> Result<Option<String>> {
+    let Some(installer) = dist.installer()? else {
+        return Ok(None);
+    };
+    let installer = installer.trim();
+    if installer.is_empty() || PYTHON_INSTALLERS.contains(&installer) {
+        return Ok(None);
+    }
+    Ok(Some(installer.to_string()))
+}
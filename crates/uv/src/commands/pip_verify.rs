@@ -0,0 +1,476 @@
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Instant;
+
+use anyhow::Result;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use serde::Serialize;
+use tracing::debug;
+
+use distribution_types::{InstalledDist, Name};
+use install_wheel_rs::RecordDiscrepancy;
+use pep440_rs::Version;
+use pep508_rs::{MarkerEnvironment, Requirement, RequirementsTxtRequirement, VersionOrUrl};
+use pypi_types::HashDigest;
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::{KeyringProviderType, PythonPreference};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_interpreter::PythonEnvironment;
+use uv_normalize::PackageName;
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
+
+use crate::commands::{elapsed, ExitStatus, VerifyFormat};
+use crate::printer::Printer;
+
+/// Verify that the installed packages are intact (i.e., that the files listed in their `RECORD`
+/// exist and match the recorded sizes and hashes), and, if any requirements files are provided,
+/// that the installed packages exactly match them.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn pip_verify(
+    sources: &[RequirementsSource],
+    format: VerifyFormat,
+    python: Option<&str>,
+    python_preference: PythonPreference,
+    system: bool,
+    cache: &Cache,
+    connectivity: Connectivity,
+    native_tls: bool,
+    http_timeout: Option<u64>,
+    netrc_file: Option<&Path>,
+    keyring_provider: KeyringProviderType,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = Instant::now();
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .default_timeout(http_timeout)
+        .netrc_file(netrc_file)
+        .keyring(keyring_provider);
+
+    // Read all requirements from the provided sources.
+    let spec = if sources.is_empty() {
+        None
+    } else {
+        Some(RequirementsSpecification::from_simple_sources(sources, &client_builder).await?)
+    };
+
+    // Detect the current Python interpreter.
+    let venv = if let Some(python) = python {
+        PythonEnvironment::from_requested_python(python, None, python_preference, cache)?
+    } else if system {
+        PythonEnvironment::from_default_python(None, python_preference, cache)?
+    } else {
+        match PythonEnvironment::from_virtualenv(cache) {
+            Ok(venv) => venv,
+            Err(uv_interpreter::Error::VenvNotFound) => {
+                PythonEnvironment::from_default_python(None, python_preference, cache)?
+            }
+            Err(err) => return Err(err.into()),
+        }
+    };
+
+    debug!(
+        "Using Python {} environment at {}",
+        venv.interpreter().python_version(),
+        venv.python_executable().user_display().cyan()
+    );
+
+    // Build the installed index.
+    let site_packages = SitePackages::from_executable(&venv)?;
+    let markers = venv.interpreter().markers();
+
+    let mut drift = Vec::new();
+
+    if let Some(spec) = spec {
+        drift.extend(compare_requirements(&spec, &site_packages, markers)?);
+    }
+
+    // Verify that the files of each installed distribution are intact.
+    let mut dists = site_packages.iter().collect::<Vec<_>>();
+    dists.sort_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())));
+    for dist in dists {
+        match install_wheel_rs::verify_record(dist.path()) {
+            Ok(discrepancies) => {
+                let root = dist.path().parent().unwrap_or(dist.path());
+                drift.extend(discrepancies.into_iter().map(|discrepancy| {
+                    Drift::Record {
+                        name: dist.name().clone(),
+                        version: dist.version().clone(),
+                        path: discrepancy
+                            .path()
+                            .strip_prefix(root)
+                            .unwrap_or(discrepancy.path())
+                            .to_path_buf(),
+                        discrepancy,
+                    }
+                }));
+            }
+            Err(install_wheel_rs::Error::MissingRecord(_)) => {
+                drift.push(Drift::MissingRecord {
+                    name: dist.name().clone(),
+                    version: dist.version().clone(),
+                });
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    let count = site_packages.iter().count();
+    let s = if count == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Verified {} in {}",
+            format!("{count} package{s}").bold(),
+            elapsed(start.elapsed())
+        )
+        .dimmed()
+    )?;
+
+    if matches!(format, VerifyFormat::Json) {
+        let entries = drift.iter().map(Entry::from).collect::<Vec<_>>();
+        let output = serde_json::to_string(&entries)?;
+        writeln!(printer.stdout(), "{output}")?;
+    }
+
+    if drift.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}",
+            if sources.is_empty() {
+                "All installed packages are intact"
+            } else {
+                "All installed packages match the requirements"
+            }
+            .to_string()
+            .dimmed()
+        )?;
+
+        Ok(ExitStatus::Success)
+    } else {
+        let discrepancies = if drift.len() == 1 {
+            "discrepancy"
+        } else {
+            "discrepancies"
+        };
+        writeln!(
+            printer.stderr(),
+            "{}",
+            format!(
+                "Found {}",
+                format!("{} {}", drift.len(), discrepancies).bold()
+            )
+            .dimmed()
+        )?;
+
+        if matches!(format, VerifyFormat::Text) {
+            for drift in &drift {
+                writeln!(printer.stderr(), "{}", drift.message().bold())?;
+            }
+        }
+
+        // Suggest reinstalling any packages whose files are missing or modified.
+        let corrupted = drift
+            .iter()
+            .filter_map(|drift| match drift {
+                Drift::Record { name, version, .. } | Drift::MissingRecord { name, version } => {
+                    Some((name, version))
+                }
+                _ => None,
+            })
+            .unique()
+            .collect::<Vec<_>>();
+        if !corrupted.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "To repair the affected packages, run: `uv pip install {} {}`",
+                    corrupted
+                        .iter()
+                        .map(|(name, _)| format!("--reinstall-package {name}"))
+                        .join(" "),
+                    corrupted
+                        .iter()
+                        .map(|(name, version)| format!("{name}=={version}"))
+                        .join(" "),
+                )
+                .dimmed()
+            )?;
+        }
+
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Compare the installed distributions against the requirements, returning any required packages
+/// that are missing or don't match, and any installed packages that aren't required.
+fn compare_requirements(
+    spec: &RequirementsSpecification,
+    site_packages: &SitePackages,
+    markers: &MarkerEnvironment,
+) -> Result<Vec<Drift>> {
+    let mut drift = Vec::new();
+    let mut expected = FxHashSet::default();
+
+    // Compare each named requirement against the installed distributions.
+    for entry in &spec.requirements {
+        match &entry.requirement {
+            RequirementsTxtRequirement::Pep508(requirement) => {
+                // If the requirement isn't relevant for the current platform, skip it.
+                if !requirement.evaluate_markers(markers, &[]) {
+                    continue;
+                }
+                expected.insert(requirement.name.clone());
+
+                let installed = site_packages.get_packages(&requirement.name);
+                if installed.is_empty() {
+                    drift.push(Drift::Missing {
+                        requirement: requirement.to_string(),
+                    });
+                    continue;
+                }
+
+                let hashes = entry
+                    .hashes
+                    .iter()
+                    .filter_map(|hash| HashDigest::from_str(hash).ok())
+                    .collect::<Vec<_>>();
+
+                for dist in installed {
+                    if let Some(drift_entry) = compare(dist, requirement, &hashes)? {
+                        drift.push(drift_entry);
+                    }
+                }
+            }
+            RequirementsTxtRequirement::Unnamed(requirement) => {
+                let installed = site_packages.get_urls(requirement.url.raw());
+                if installed.is_empty() {
+                    drift.push(Drift::Missing {
+                        requirement: requirement.to_string(),
+                    });
+                }
+                expected.extend(installed.into_iter().map(|dist| dist.name().clone()));
+            }
+        }
+    }
+
+    // Compare each editable against the installed distributions.
+    for editable in &spec.editables {
+        let installed = site_packages.get_editables(editable.raw());
+        if installed.is_empty() {
+            drift.push(Drift::Missing {
+                requirement: format!("-e {}", editable.url.verbatim()),
+            });
+        }
+        expected.extend(installed.into_iter().map(|dist| dist.name().clone()));
+    }
+
+    // Identify any installed distributions that aren't required.
+    let mut extra = site_packages
+        .iter()
+        .filter(|dist| !expected.contains(dist.name()))
+        .collect::<Vec<_>>();
+    extra.sort_unstable_by(|a, b| a.name().cmp(b.name()).then(a.version().cmp(b.version())));
+    drift.extend(extra.into_iter().map(|dist| Drift::Extra {
+        name: dist.name().clone(),
+        version: dist.version().clone(),
+    }));
+
+    Ok(drift)
+}
+
+/// Compare an installed distribution against the requirement that names it.
+///
+/// Hashes can only be compared for distributions that recorded them at install time (i.e.,
+/// archives installed from a direct URL); all other distributions are reported as unverifiable.
+fn compare(
+    dist: &InstalledDist,
+    requirement: &Requirement,
+    hashes: &[HashDigest],
+) -> Result<Option<Drift>> {
+    if let Some(VersionOrUrl::VersionSpecifier(specifiers)) = &requirement.version_or_url {
+        if !specifiers.contains(dist.version()) {
+            return Ok(Some(Drift::Version {
+                name: dist.name().clone(),
+                version: dist.version().clone(),
+                requirement: requirement.to_string(),
+            }));
+        }
+    }
+
+    if !hashes.is_empty() {
+        let installed = dist.hashes()?;
+        if installed.is_empty() {
+            return Ok(Some(Drift::UnverifiableHash {
+                name: dist.name().clone(),
+                version: dist.version().clone(),
+            }));
+        } else if !installed.iter().any(|hash| hashes.contains(hash)) {
+            return Ok(Some(Drift::Hash {
+                name: dist.name().clone(),
+                version: dist.version().clone(),
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+/// A difference between the installed packages and the requirements.
+#[derive(Debug)]
+enum Drift {
+    /// A required package is not installed.
+    Missing { requirement: String },
+    /// An installed package is not required.
+    Extra { name: PackageName, version: Version },
+    /// An installed package doesn't satisfy the required version.
+    Version {
+        name: PackageName,
+        version: Version,
+        requirement: String,
+    },
+    /// An installed package doesn't match any of the required hashes.
+    Hash { name: PackageName, version: Version },
+    /// An installed package is required to match a set of hashes, but didn't record its hashes at
+    /// install time, so they can't be compared.
+    UnverifiableHash { name: PackageName, version: Version },
+    /// A file of an installed package doesn't match its `RECORD`.
+    Record {
+        name: PackageName,
+        version: Version,
+        /// The path to the file, relative to the `site-packages` directory.
+        path: PathBuf,
+        discrepancy: RecordDiscrepancy,
+    },
+    /// An installed package is missing its `RECORD` file.
+    MissingRecord { name: PackageName, version: Version },
+}
+
+impl Drift {
+    /// Convert the drift into a user-facing message.
+    fn message(&self) -> String {
+        match self {
+            Self::Missing { requirement } => {
+                format!("The requirement `{requirement}` is not installed.")
+            }
+            Self::Extra { name, version } => {
+                format!("The package `{name}=={version}` is installed, but not required.")
+            }
+            Self::Version {
+                name,
+                version,
+                requirement,
+            } => format!(
+                "The package `{name}=={version}` is installed, but `{requirement}` is required."
+            ),
+            Self::Hash { name, version } => format!(
+                "The package `{name}=={version}` is installed, but doesn't match any of the required hashes."
+            ),
+            Self::UnverifiableHash { name, version } => format!(
+                "The package `{name}=={version}` is installed, but its hashes can't be verified, as none were recorded at install time."
+            ),
+            Self::Record {
+                name,
+                version,
+                path,
+                discrepancy,
+            } => match discrepancy {
+                RecordDiscrepancy::Missing { .. } => format!(
+                    "The file `{}` of `{name}=={version}` is missing.",
+                    path.display()
+                ),
+                RecordDiscrepancy::Size {
+                    expected, actual, ..
+                } => format!(
+                    "The file `{}` of `{name}=={version}` has a size of {actual} bytes, but {expected} bytes were recorded.",
+                    path.display()
+                ),
+                RecordDiscrepancy::Hash { .. } => format!(
+                    "The file `{}` of `{name}=={version}` doesn't match its recorded hash.",
+                    path.display()
+                ),
+            },
+            Self::MissingRecord { name, version } => {
+                format!("The package `{name}=={version}` is missing its `RECORD` file.")
+            }
+        }
+    }
+}
+
+/// An entry in a JSON list of discrepancies.
+#[derive(Debug, Serialize)]
+struct Entry {
+    /// The kind of discrepancy (e.g., `missing-file`).
+    kind: &'static str,
+    /// The installed package with the discrepancy, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    package: Option<String>,
+    /// The installed version of the package, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    /// The requirement that isn't satisfied, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requirement: Option<String>,
+    /// The file with the discrepancy (relative to `site-packages`), if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    /// A user-facing description of the discrepancy.
+    message: String,
+}
+
+impl From<&Drift> for Entry {
+    fn from(drift: &Drift) -> Self {
+        let (kind, package, requirement, path) = match drift {
+            Drift::Missing { requirement } => ("missing", None, Some(requirement.clone()), None),
+            Drift::Extra { name, version } => ("extra", Some((name, version)), None, None),
+            Drift::Version {
+                name,
+                version,
+                requirement,
+            } => (
+                "version",
+                Some((name, version)),
+                Some(requirement.clone()),
+                None,
+            ),
+            Drift::Hash { name, version } => ("hash", Some((name, version)), None, None),
+            Drift::UnverifiableHash { name, version } => {
+                ("unverifiable-hash", Some((name, version)), None, None)
+            }
+            Drift::Record {
+                name,
+                version,
+                path,
+                discrepancy,
+            } => (
+                match discrepancy {
+                    RecordDiscrepancy::Missing { .. } => "missing-file",
+                    RecordDiscrepancy::Size { .. } => "file-size",
+                    RecordDiscrepancy::Hash { .. } => "file-hash",
+                },
+                Some((name, version)),
+                None,
+                Some(path.display().to_string()),
+            ),
+            Drift::MissingRecord { name, version } => {
+                ("missing-record", Some((name, version)), None, None)
+            }
+        };
+        Self {
+            kind,
+            package: package.map(|(name, _)| name.to_string()),
+            version: package.map(|(_, version)| version.to_string()),
+            requirement,
+            path,
+            message: drift.message(),
+        }
+    }
+}
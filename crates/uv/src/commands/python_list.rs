@@ -0,0 +1,93 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_configuration::PythonPreference;
+use uv_fs::Simplified;
+use uv_interpreter::{find_all_pythons, DiscoveredPython, InterpreterSource};
+
+use crate::commands::{ExitStatus, PythonListFormat};
+use crate::printer::Printer;
+
+/// List the Python interpreters that `uv` discovers.
+pub(crate) fn python_list(
+    python_preference: PythonPreference,
+    format: PythonListFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let pythons = find_all_pythons(python_preference, cache)?;
+
+    match format {
+        PythonListFormat::Text => {
+            if pythons.is_empty() {
+                writeln!(printer.stderr(), "No Python interpreters found")?;
+                return Ok(ExitStatus::Success);
+            }
+
+            let rows = pythons
+                .iter()
+                .map(|python| {
+                    (
+                        format!(
+                            "{}-{}",
+                            python.interpreter.implementation_name(),
+                            python.interpreter.python_version()
+                        ),
+                        python.source.to_string(),
+                        python
+                            .interpreter
+                            .sys_executable()
+                            .user_display()
+                            .to_string(),
+                    )
+                })
+                .collect_vec();
+            let version_width = rows.iter().map(|row| row.0.len()).max().unwrap_or_default();
+            let source_width = rows.iter().map(|row| row.1.len()).max().unwrap_or_default();
+
+            for (version, source, path) in rows {
+                writeln!(
+                    printer.stdout(),
+                    "{version:version_width$}  {source:source_width$}  {}",
+                    path.cyan()
+                )?;
+            }
+        }
+        PythonListFormat::Json => {
+            let entries = pythons.iter().map(Entry::from).collect_vec();
+            let output = serde_json::to_string(&entries)?;
+            writeln!(printer.stdout(), "{output}")?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// An entry in a JSON list of discovered interpreters.
+#[derive(Serialize, Debug)]
+struct Entry {
+    implementation: String,
+    version: String,
+    source: InterpreterSource,
+    path: String,
+}
+
+impl From<&DiscoveredPython> for Entry {
+    fn from(python: &DiscoveredPython) -> Self {
+        Self {
+            implementation: python.interpreter.implementation_name().to_string(),
+            version: python.interpreter.python_version().to_string(),
+            source: python.source,
+            path: python
+                .interpreter
+                .sys_executable()
+                .simplified_display()
+                .to_string(),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::fmt::Write;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
@@ -319,6 +320,29 @@ impl uv_distribution::Reporter for ResolverReporter {
     }
 }
 
+/// Streams the output of build backends to stderr, with each line prefixed by its source (e.g.,
+/// `[build_wheel]`).
+#[derive(Debug)]
+pub(crate) struct BuildOutputReporter {
+    printer: Printer,
+}
+
+impl BuildOutputReporter {
+    /// Return a [`uv_build::Reporter`] for the build backend output, if `--verbose-build` is set.
+    pub(crate) fn for_verbose_build(
+        verbose_build: bool,
+        printer: Printer,
+    ) -> Option<Arc<dyn uv_build::Reporter>> {
+        verbose_build.then(|| Arc::new(Self { printer }) as Arc<dyn uv_build::Reporter>)
+    }
+}
+
+impl uv_build::Reporter for BuildOutputReporter {
+    fn on_build_output(&self, source: &str, line: &str) {
+        let _ = writeln!(self.printer.stderr(), "[{source}] {line}");
+    }
+}
+
 /// Like [`std::fmt::Display`], but with colors.
 trait ColorDisplay {
     fn to_color_string(&self) -> String;
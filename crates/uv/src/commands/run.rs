@@ -5,6 +5,7 @@ use anyhow::Result;
 use owo_colors::OwoColorize;
 use tempfile::{tempdir_in, TempDir};
 use tracing::debug;
+use uv_configuration::PythonPreference;
 use uv_fs::Simplified;
 use uv_interpreter::PythonEnvironment;
 
@@ -95,9 +96,9 @@ fn environment_for_run(isolated: bool, cache: &Cache) -> Result<RunEnvironment>
     // TODO(zanieb): Populate `python` from the user
     let python = None;
     let python_env = if let Some(python) = python {
-        PythonEnvironment::from_requested_python(python, cache)?
+        PythonEnvironment::from_requested_python(python, None, PythonPreference::default(), cache)?
     } else {
-        PythonEnvironment::from_default_python(cache)?
+        PythonEnvironment::from_default_python(None, PythonPreference::default(), cache)?
     };
 
     // Create a virtual environment directory
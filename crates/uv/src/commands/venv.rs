@@ -17,10 +17,13 @@ use pep508_rs::Requirement;
 use uv_cache::Cache;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::KeyringProviderType;
-use uv_configuration::{ConfigSettings, IndexStrategy, NoBinary, NoBuild, SetupPyStrategy};
+use uv_configuration::{
+    ConfigSettings, IndexStrategy, NoBinary, NoBuild, PythonImplementation, PythonPreference,
+    SetupPyStrategy,
+};
 use uv_dispatch::BuildDispatch;
 use uv_fs::Simplified;
-use uv_interpreter::{find_default_python, find_requested_python, Error};
+use uv_interpreter::{find_default_python, find_requested_python, is_discovery_request, Error};
 use uv_resolver::{ExcludeNewer, FlatIndex, InMemoryIndex, OptionsBuilder};
 use uv_types::{BuildContext, BuildIsolation, HashStrategy, InFlight};
 
@@ -43,6 +46,8 @@ pub(crate) async fn venv(
     seed: bool,
     exclude_newer: Option<ExcludeNewer>,
     native_tls: bool,
+    http_timeout: Option<u64>,
+    netrc_file: Option<&Path>,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -59,6 +64,8 @@ pub(crate) async fn venv(
         seed,
         exclude_newer,
         native_tls,
+        http_timeout,
+        netrc_file,
         cache,
         printer,
     )
@@ -106,17 +113,19 @@ async fn venv_impl(
     seed: bool,
     exclude_newer: Option<ExcludeNewer>,
     native_tls: bool,
+    http_timeout: Option<u64>,
+    netrc_file: Option<&Path>,
     cache: &Cache,
     printer: Printer,
 ) -> miette::Result<ExitStatus> {
     // Locate the Python interpreter.
     let interpreter = if let Some(python_request) = python_request {
-        find_requested_python(python_request, cache)
+        find_requested_python(python_request, None, PythonPreference::default(), cache)
             .into_diagnostic()?
             .ok_or(Error::NoSuchPython(python_request.to_string()))
             .into_diagnostic()?
     } else {
-        find_default_python(cache).into_diagnostic()?
+        find_default_python(None, PythonPreference::default(), cache).into_diagnostic()?
     };
 
     writeln!(
@@ -150,6 +159,8 @@ async fn venv_impl(
         // Instantiate a client.
         let client = RegistryClientBuilder::new(cache.clone())
             .native_tls(native_tls)
+            .default_timeout(http_timeout)
+            .netrc_file(netrc_file)
             .index_urls(index_locations.index_urls())
             .index_strategy(index_strategy)
             .keyring(keyring_provider)
@@ -163,7 +174,10 @@ async fn venv_impl(
             let tags = interpreter.tags().map_err(VenvError::Tags)?;
             let client = FlatIndexClient::new(&client, cache);
             let entries = client
-                .fetch(index_locations.flat_index())
+                .fetch(
+                    index_locations.flat_index(),
+                    index_locations.recursive_flat_index(),
+                )
                 .await
                 .map_err(VenvError::FlatIndex)?;
             FlatIndex::from_entries(
@@ -272,6 +286,80 @@ async fn venv_impl(
     Ok(ExitStatus::Success)
 }
 
+/// Create the virtual environment that a `--python` request points to, if it doesn't exist (as
+/// with `--allow-create`).
+///
+/// The request may name the environment itself (e.g., `.venv-docs`) or an interpreter within it
+/// (e.g., `.venv-docs/bin/python`). Version requests and executable names (e.g., `3.12` or
+/// `python3.12`) are left to interpreter discovery. The environment is created as by `uv venv`,
+/// with the default interpreter for the given implementation and preference, and without seed
+/// packages.
+pub(crate) fn create_missing_venv(
+    python: &str,
+    python_implementation: Option<PythonImplementation>,
+    python_preference: PythonPreference,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<()> {
+    let path = Path::new(python);
+    if path.exists() || is_discovery_request(python)? {
+        return Ok(());
+    }
+
+    // Determine the root of the environment from the path to its interpreter (i.e., a trailing
+    // `bin/python` or `Scripts/python.exe`), if necessary.
+    let is_interpreter = path
+        .file_name()
+        .is_some_and(|name| name.to_string_lossy().starts_with("python"))
+        && path
+            .parent()
+            .and_then(Path::file_name)
+            .is_some_and(|name| name == "bin" || name == "Scripts");
+    let root = if is_interpreter {
+        path.parent().and_then(Path::parent).unwrap_or(path)
+    } else {
+        path
+    };
+
+    if root.exists() {
+        // Never create an environment over an existing directory. If the directory is already a
+        // virtual environment, let discovery report the missing interpreter.
+        if root.join("pyvenv.cfg").is_file() {
+            return Ok(());
+        }
+        return Err(anyhow::anyhow!(
+            "The path `{}` exists, but is not a virtual environment; refusing to create one with `--allow-create`",
+            root.user_display()
+        ));
+    }
+
+    // Locate the Python interpreter, as `uv venv` would.
+    let interpreter = find_default_python(python_implementation, python_preference, cache)?;
+    writeln!(
+        printer.stderr(),
+        "Using Python {} interpreter at: {}",
+        interpreter.python_version(),
+        interpreter.sys_executable().user_display().cyan()
+    )?;
+    writeln!(
+        printer.stderr(),
+        "Creating virtualenv at: {}",
+        root.user_display().cyan()
+    )?;
+
+    // Extra cfg for pyvenv.cfg to specify uv version
+    let extra_cfg = vec![("uv".to_string(), env!("CARGO_PKG_VERSION").to_string())];
+    uv_virtualenv::create_venv(
+        root,
+        interpreter,
+        uv_virtualenv::Prompt::None,
+        false,
+        extra_cfg,
+    )?;
+
+    Ok(())
+}
+
 /// Quote a path, if necessary, for safe use in a POSIX-compatible shell command.
 fn shlex_posix(executable: impl AsRef<Path>) -> String {
     // Convert to a display path.
@@ -1,16 +1,52 @@
+use std::path::Path;
+
 use anyhow::Result;
+use serde::Serialize;
 
 use crate::commands::VersionFormat;
+use crate::version::{BuildInfo, VersionInfo};
+
+/// The version of uv, alongside the details of the build and the resolved runtime settings, as
+/// captured in bug reports.
+#[derive(Serialize)]
+struct VerboseVersionInfo<'a> {
+    #[serde(flatten)]
+    version: VersionInfo,
+    build: BuildInfo,
+    /// Whether the platform's native certificate store is used for TLS.
+    native_tls: bool,
+    /// The resolved cache directory.
+    cache_dir: &'a Path,
+}
 
 /// Display version information
-pub(crate) fn version(output_format: VersionFormat, buffer: &mut dyn std::io::Write) -> Result<()> {
+pub(crate) fn version(
+    output_format: VersionFormat,
+    verbose: bool,
+    native_tls: bool,
+    cache_dir: &Path,
+    buffer: &mut dyn std::io::Write,
+) -> Result<()> {
     let version_info = crate::version::version();
 
     match output_format {
         VersionFormat::Text => {
             writeln!(buffer, "uv {}", &version_info)?;
+            // Only include the build details when requested, to preserve the output of scripts
+            // that parse the version.
+            if verbose {
+                writeln!(buffer, "{}", crate::version::build_info())?;
+                writeln!(buffer, "native-tls: {native_tls}")?;
+                writeln!(buffer, "cache-dir: {}", cache_dir.display())?;
+            }
         }
         VersionFormat::Json => {
+            let version_info = VerboseVersionInfo {
+                version: version_info,
+                build: crate::version::build_info(),
+                native_tls,
+                cache_dir,
+            };
             serde_json::to_writer_pretty(&mut *buffer, &version_info)?;
             // Add a trailing newline
             writeln!(buffer)?;
@@ -1,7 +1,12 @@
-use anyhow::{anyhow, Result};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
 use clap::{Args, ValueEnum};
 
+use distribution_types::{FlatIndexLocation, IndexUrl};
+use uv_configuration::PackageNameSpecifier;
 use uv_warnings::warn_user;
+use uv_workspace::{Options, PipOptions};
 
 pub(crate) trait CompatArgs {
     fn validate(&self) -> Result<()>;
@@ -350,3 +355,169 @@ impl CompatArgs for VenvCompatArgs {
         Ok(())
     }
 }
+
+/// Settings read from the `pip` environment variables (e.g., `PIP_INDEX_URL`).
+///
+/// For drop-in compatibility, uv honors a subset of the variables that `pip` reads, with lower
+/// precedence than the `UV_*` environment variables, the configuration files, and the
+/// command-line. Any other `PIP_*` variables are ignored.
+#[derive(Debug, Default)]
+pub(crate) struct PipEnv {
+    /// The options read from the environment.
+    pub(crate) options: Options,
+    /// The request timeout read from `PIP_TIMEOUT`, in seconds.
+    pub(crate) timeout: Option<u64>,
+    /// The names of the variables that were honored.
+    pub(crate) variables: Vec<&'static str>,
+}
+
+impl PipEnv {
+    /// Read the `pip` environment variables from the current environment.
+    pub(crate) fn from_env() -> Result<Self> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let mut variables = Vec::new();
+        let mut read = |name: &'static str| {
+            let value = lookup(name).filter(|value| !value.trim().is_empty())?;
+            variables.push(name);
+            Some(value)
+        };
+
+        // `pip` splits list-valued variables on whitespace; the binary options additionally
+        // accept a comma-separated list of packages.
+        let index_url = read("PIP_INDEX_URL")
+            .map(|value| parse::<IndexUrl>("PIP_INDEX_URL", value.trim()))
+            .transpose()?;
+        let extra_index_url = read("PIP_EXTRA_INDEX_URL")
+            .map(|value| {
+                value
+                    .split_whitespace()
+                    .map(|url| parse::<IndexUrl>("PIP_EXTRA_INDEX_URL", url))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?;
+        let find_links = read("PIP_FIND_LINKS")
+            .map(|value| {
+                value
+                    .split_whitespace()
+                    .map(|location| parse::<FlatIndexLocation>("PIP_FIND_LINKS", location))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?;
+        let no_binary = read("PIP_NO_BINARY")
+            .map(|value| parse_specifiers("PIP_NO_BINARY", &value))
+            .transpose()?;
+        let only_binary = read("PIP_ONLY_BINARY")
+            .map(|value| parse_specifiers("PIP_ONLY_BINARY", &value))
+            .transpose()?;
+        let timeout = read("PIP_TIMEOUT")
+            .map(|value| {
+                let seconds = parse::<f64>("PIP_TIMEOUT", value.trim())?;
+                if !seconds.is_finite() || seconds < 0.0 {
+                    return Err(anyhow!(
+                        "Invalid value for `PIP_TIMEOUT`: expected a non-negative number of seconds, got `{value}`"
+                    ));
+                }
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                Ok(seconds.ceil() as u64)
+            })
+            .transpose()?;
+
+        let pip = PipOptions {
+            index_url,
+            extra_index_url,
+            find_links,
+            no_binary,
+            only_binary,
+            ..PipOptions::default()
+        };
+        Ok(Self {
+            options: Options {
+                pip: Some(pip),
+                ..Options::default()
+            },
+            timeout,
+            variables,
+        })
+    }
+
+    /// Returns `true` if none of the `pip` environment variables were set.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.variables.is_empty()
+    }
+}
+
+/// Parse the value of a `pip` environment variable.
+fn parse<T>(name: &str, value: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    T::from_str(value).with_context(|| format!("Invalid value for `{name}`: `{value}`"))
+}
+
+/// Parse a list of package name specifiers (e.g., `:all:` or `foo,bar`), as accepted by
+/// `PIP_NO_BINARY` and `PIP_ONLY_BINARY`.
+fn parse_specifiers(name: &str, value: &str) -> Result<Vec<PackageNameSpecifier>> {
+    value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|specifier| !specifier.is_empty())
+        .map(|specifier| parse::<PackageNameSpecifier>(name, specifier))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pip_env() -> Result<()> {
+        let lookup = |name: &str| match name {
+            "PIP_INDEX_URL" => Some("https://test.pypi.org/simple".to_string()),
+            "PIP_EXTRA_INDEX_URL" => {
+                Some("https://download.pytorch.org/whl/cpu  https://example.com/simple".to_string())
+            }
+            "PIP_NO_BINARY" => Some("foo, bar".to_string()),
+            "PIP_ONLY_BINARY" => Some(":all:".to_string()),
+            "PIP_TIMEOUT" => Some("7.5".to_string()),
+            "PIP_FIND_LINKS" => Some(String::new()),
+            "PIP_REQUIRE_VIRTUALENV" => Some("1".to_string()),
+            _ => None,
+        };
+        let pip_env = PipEnv::from_lookup(lookup)?;
+        assert_eq!(
+            pip_env.variables,
+            vec![
+                "PIP_INDEX_URL",
+                "PIP_EXTRA_INDEX_URL",
+                "PIP_NO_BINARY",
+                "PIP_ONLY_BINARY",
+                "PIP_TIMEOUT"
+            ]
+        );
+        assert_eq!(pip_env.timeout, Some(8));
+        let pip = pip_env.options.pip.unwrap();
+        assert!(pip.index_url.is_some());
+        assert_eq!(pip.extra_index_url.map(|urls| urls.len()), Some(2));
+        assert!(pip.find_links.is_none());
+        assert_eq!(pip.no_binary.map(|specifiers| specifiers.len()), Some(2));
+        assert_eq!(pip.only_binary.map(|specifiers| specifiers.len()), Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn pip_env_invalid() {
+        let lookup = |name: &str| (name == "PIP_TIMEOUT").then(|| "soon".to_string());
+        assert!(PipEnv::from_lookup(lookup).is_err());
+        let lookup = |name: &str| (name == "PIP_INDEX_URL").then(|| "not a url".to_string());
+        assert!(PipEnv::from_lookup(lookup).is_err());
+    }
+
+    #[test]
+    fn pip_env_empty() -> Result<()> {
+        assert!(PipEnv::from_lookup(|_| None)?.is_empty());
+        Ok(())
+    }
+}
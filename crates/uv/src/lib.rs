@@ -0,0 +1,1251 @@
+//! The `uv` command-line interface.
+//!
+//! The binary is a thin wrapper around [`main`]. The resolved [`settings`] (and the [`cli`]
+//! arguments from which they're derived) are exposed for tools that embed uv's `pip` interface.
+
+use std::env;
+use std::fmt::Write;
+use std::io::stdout;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anstream::eprintln;
+use anyhow::Result;
+use clap::error::{ContextKind, ContextValue};
+use clap::{CommandFactory, FromArgMatches};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::{debug, instrument};
+
+use distribution_types::IndexLocations;
+use uv_cache::{Cache, Refresh};
+use uv_client::Connectivity;
+use uv_configuration::{NoBinary, NoBuild, Reinstall, SetupPyStrategy, Upgrade};
+use uv_fs::Simplified;
+use uv_requirements::{ExtrasSpecification, RequirementsSource};
+use uv_resolver::DependencyMode;
+use uv_warnings::warn_user;
+
+use crate::cli::{
+    CacheCommand, CacheNamespace, Cli, Commands, PipCommand, PipNamespace, PythonCommand,
+    PythonNamespace, ShowSettings,
+};
+#[cfg(feature = "self-update")]
+use crate::cli::{SelfCommand, SelfNamespace};
+use crate::commands::ExitStatus;
+use crate::compat::{CompatArgs, PipEnv};
+use crate::printer::Printer;
+use crate::settings::{
+    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipDownloadSettings,
+    PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings,
+    PipUninstallSettings, PipVerifySettings, PipWheelSettings, PythonListSettings, SettingFields,
+    SettingSources,
+};
+
+pub mod cli;
+mod commands;
+mod compat;
+mod logging;
+mod printer;
+pub mod settings;
+mod shell;
+mod version;
+
+pub use crate::commands::ListFormat;
+
+/// Print the resolved settings under `--show-settings`, returning `true` if `uv` should exit
+/// rather than run the command.
+///
+/// Commands without settings of their own (e.g., `uv cache clean`) print only the global and cache
+/// settings.
+fn show_settings(
+    sources: Option<&SettingSources>,
+    globals: &GlobalSettings,
+    cache: &CacheSettings,
+    settings: Option<&dyn SettingFields>,
+    printer: Printer,
+) -> Result<bool> {
+    let Some(sources) = sources else {
+        return Ok(false);
+    };
+    write!(
+        printer.stdout(),
+        "{}{}",
+        sources.annotate(globals),
+        sources.annotate(cache)
+    )?;
+    if let Some(settings) = settings {
+        write!(printer.stdout(), "{}", sources.annotate(settings))?;
+    }
+    Ok(globals.show_settings == Some(ShowSettings::Exit))
+}
+
+#[instrument]
+async fn run() -> Result<ExitStatus> {
+    // Retain the matched arguments, to report whether each setting was provided on the command-line
+    // or via an environment variable.
+    let parsed = Cli::command().try_get_matches().and_then(|matches| {
+        let cli = Cli::from_arg_matches(&matches).map_err(|err| err.format(&mut Cli::command()))?;
+        Ok((cli, matches))
+    });
+    let (cli, matches) = match parsed {
+        Ok(parsed) => parsed,
+        Err(mut err) => {
+            if let Some(ContextValue::String(subcommand)) = err.get(ContextKind::InvalidSubcommand)
+            {
+                match subcommand.as_str() {
+                    "compile" | "lock" => {
+                        err.insert(
+                            ContextKind::SuggestedSubcommand,
+                            ContextValue::String("uv pip compile".to_string()),
+                        );
+                    }
+                    "sync" => {
+                        err.insert(
+                            ContextKind::SuggestedSubcommand,
+                            ContextValue::String("uv pip sync".to_string()),
+                        );
+                    }
+                    "install" | "add" => {
+                        err.insert(
+                            ContextKind::SuggestedSubcommand,
+                            ContextValue::String("uv pip install".to_string()),
+                        );
+                    }
+                    "uninstall" | "remove" => {
+                        err.insert(
+                            ContextKind::SuggestedSubcommand,
+                            ContextValue::String("uv pip uninstall".to_string()),
+                        );
+                    }
+                    "freeze" => {
+                        err.insert(
+                            ContextKind::SuggestedSubcommand,
+                            ContextValue::String("uv pip freeze".to_string()),
+                        );
+                    }
+                    "list" => {
+                        err.insert(
+                            ContextKind::SuggestedSubcommand,
+                            ContextValue::String("uv pip list".to_string()),
+                        );
+                    }
+                    "show" => {
+                        err.insert(
+                            ContextKind::SuggestedSubcommand,
+                            ContextValue::String("uv pip show".to_string()),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            err.exit()
+        }
+    };
+
+    // Configure the `warn!` macros, which control user-facing warnings in the CLI. This precedes
+    // loading the workspace settings, which may itself emit warnings.
+    if cli.global_args.quiet < 2 {
+        uv_warnings::enable();
+    }
+
+    // Load the workspace settings. If a configuration file is specified on the command-line, it's
+    // used exclusively. Otherwise, the configuration file in the current directory or its parents
+    // is layered over the user configuration file, such that any settings it omits fall back to
+    // those of the user. Discovery is skipped entirely with `--no-config`. Unknown keys in any
+    // configuration file are an error, unless `--no-strict-config` is provided.
+    let strict = !cli.global_args.no_strict_config;
+    let (workspace, user) = if let Some(config_file) = cli.global_args.config_file.as_ref() {
+        (
+            Some(uv_workspace::Workspace::from_file(config_file, strict)?),
+            None,
+        )
+    } else if cli.global_args.no_config {
+        (None, None)
+    } else {
+        (
+            uv_workspace::Workspace::find(env::current_dir()?, strict)?,
+            uv_workspace::Workspace::user(strict)?,
+        )
+    };
+    // Read the `pip` environment variables, which are layered beneath the configuration files.
+    let pip_env = if cli.global_args.no_pip_env {
+        PipEnv::default()
+    } else {
+        PipEnv::from_env()?
+    };
+    // Under `--show-settings`, retain each configuration layer to report the source of each setting.
+    let sources = cli.global_args.show_settings.is_some().then(|| {
+        SettingSources::new(
+            &matches,
+            workspace
+                .as_ref()
+                .map(|workspace| workspace.options.clone()),
+            user.as_ref().map(|user| user.options.clone()),
+            (!pip_env.is_empty()).then(|| pip_env.options.clone()),
+        )
+    });
+    let config_files = workspace
+        .iter()
+        .chain(user.iter())
+        .map(|workspace| workspace.file.clone())
+        .collect::<Vec<_>>();
+    let workspace = match (workspace, user) {
+        (Some(workspace), Some(user)) => Some(workspace.combine(user)),
+        (workspace, user) => workspace.or(user),
+    };
+    let workspace = if pip_env.is_empty() {
+        workspace
+    } else if let Some(workspace) = workspace {
+        Some(uv_workspace::Workspace {
+            options: workspace.options.combine(pip_env.options),
+            ..workspace
+        })
+    } else {
+        // Absent a configuration file, the `pip` environment variables are the only layer.
+        let root = env::current_dir()?;
+        Some(uv_workspace::Workspace {
+            options: pip_env.options,
+            file: PathBuf::new(),
+            root,
+        })
+    };
+
+    // Resolve the global settings.
+    let globals = GlobalSettings::resolve(cli.global_args, workspace.as_ref(), pip_env.timeout);
+
+    // Configure the `tracing` crate, which controls internal logging.
+    #[cfg(feature = "tracing-durations-export")]
+    let (duration_layer, _duration_guard) = logging::setup_duration()?;
+    #[cfg(not(feature = "tracing-durations-export"))]
+    let duration_layer = None::<tracing_subscriber::layer::Identity>;
+    logging::setup_logging(
+        match globals.verbose {
+            0 => logging::Level::Default,
+            1 => logging::Level::Verbose,
+            2.. => logging::Level::ExtraVerbose,
+        },
+        duration_layer,
+    )?;
+
+    if config_files.is_empty() {
+        debug!("No configuration file found");
+    }
+    for config_file in &config_files {
+        debug!("Using configuration from `{}`", config_file.user_display());
+    }
+
+    // Configure the `Printer`, which controls user-facing output in the CLI.
+    let printer = if globals.quiet > 1 {
+        Printer::Silent
+    } else if globals.quiet > 0 {
+        Printer::Quiet
+    } else if globals.verbose > 0 {
+        Printer::Verbose
+    } else {
+        Printer::Default
+    };
+
+    anstream::ColorChoice::write_global(globals.color.into());
+
+    if !pip_env.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}{} Using {} from the environment for compatibility with pip (use `--no-pip-env` to ignore)",
+            "info".cyan().bold(),
+            ":".bold(),
+            pip_env
+                .variables
+                .iter()
+                .map(|variable| format!("`{variable}`"))
+                .join(", ")
+        )?;
+    }
+    if let Some(netrc_file) = &globals.netrc_file {
+        if !netrc_file.is_file() {
+            warn_user!(
+                "Ignoring `.netrc` credentials. File does not exist: {}",
+                netrc_file.user_display().cyan()
+            );
+        }
+    }
+
+    miette::set_hook(Box::new(|_| {
+        Box::new(
+            miette::MietteHandlerOpts::new()
+                .break_words(false)
+                .word_separator(textwrap::WordSeparator::AsciiSpace)
+                .word_splitter(textwrap::WordSplitter::NoHyphenation)
+                .wrap_lines(env::var("UV_NO_WRAP").map(|_| false).unwrap_or(true))
+                .build(),
+        )
+    }))?;
+
+    // Resolve the cache settings.
+    let cache_settings = CacheSettings::resolve(cli.cache_args, workspace.as_ref());
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir.clone())?;
+
+    // Handle `--show-settings` for the commands without settings of their own, before running
+    // them (e.g., before `uv cache clean` removes the cache).
+    if !matches!(
+        cli.command,
+        Commands::Pip(_) | Commands::Python(_) | Commands::Venv(_)
+    ) && show_settings(sources.as_ref(), &globals, &cache_settings, None, printer)?
+    {
+        return Ok(ExitStatus::Success);
+    }
+
+    match cli.command {
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Compile(args),
+        }) => {
+            args.compat_args.validate()?;
+
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipCompileSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            let cache = cache.with_refresh(Refresh::from_args(args.refresh, args.refresh_package));
+            let requirements = args
+                .src_file
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Vec<_>>();
+            let constraints = args
+                .constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let overrides = args
+                .r#override
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Vec<_>>();
+            let build_constraints = args
+                .build_constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let index_urls = IndexLocations::new(
+                args.shared.index_url,
+                args.shared.extra_index_url,
+                args.shared.find_links,
+                args.shared.no_index,
+            )
+            .with_recursive_flat_index(args.shared.find_links_recursive);
+            // TODO(charlie): Move into `PipCompileSettings::resolve`.
+            let extras = if args.shared.all_extras {
+                ExtrasSpecification::All
+            } else if args.shared.extra.is_empty() {
+                ExtrasSpecification::None
+            } else {
+                ExtrasSpecification::Some(&args.shared.extra)
+            };
+            if let Some(requirement) = args
+                .upgrade_package
+                .iter()
+                .find(|requirement| args.no_upgrade_package.contains(&requirement.name))
+            {
+                anyhow::bail!(
+                    "`{}` was provided to both `--upgrade-package` and `--no-upgrade-package`",
+                    requirement.name
+                );
+            }
+            let upgrade = Upgrade::from_args(args.upgrade, args.upgrade_package);
+            let no_build = NoBuild::from_args(args.shared.only_binary, args.shared.no_build);
+            let dependency_mode = if args.shared.no_deps {
+                DependencyMode::Direct
+            } else {
+                DependencyMode::Transitive
+            };
+            let setup_py = if args.shared.legacy_setup_py {
+                SetupPyStrategy::Setuptools
+            } else {
+                SetupPyStrategy::Pep517
+            };
+
+            commands::pip_compile(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                args.convert_editable_vcs,
+                args.constraint_from_freeze,
+                args.reuse_pins,
+                extras,
+                args.shared.output_file.as_deref(),
+                args.shared.resolution,
+                args.shared.resolution_exclude,
+                args.shared.prerelease,
+                args.shared.prerelease_package,
+                dependency_mode,
+                upgrade,
+                args.no_upgrade_package,
+                args.shared.generate_hashes,
+                args.shared.no_emit_package,
+                args.shared.no_strip_extras,
+                !args.shared.no_annotate,
+                !args.shared.no_header,
+                args.shared.custom_compile_command,
+                &args.explain,
+                args.changes.as_deref(),
+                args.shared.emit_index_url,
+                args.shared.emit_find_links,
+                args.shared.emit_find_links_relative_to.as_deref(),
+                args.shared.emit_find_links_absolute,
+                args.shared.emit_marker_expression,
+                args.shared.emit_index_annotation,
+                args.shared.emit_origin,
+                args.shared.emit_build_options,
+                index_urls,
+                args.shared.index_strategy,
+                args.shared.keyring_provider,
+                setup_py,
+                args.shared.config_setting,
+                &args.shared.build_env,
+                if args.shared.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                args.shared.no_build_isolation,
+                &args.shared.no_build_isolation_package,
+                args.shared.reuse_build_dir,
+                args.verbose_build,
+                no_build,
+                &args.shared.compatible_tags,
+                args.shared.python_version,
+                args.marker_environment.as_deref(),
+                args.shared.exclude_newer,
+                args.shared.annotation_style,
+                args.shared.link_mode,
+                args.shared.python,
+                args.shared.python_implementation,
+                args.shared.python_preference,
+                args.shared.system,
+                globals.native_tls,
+                globals.http_timeout,
+                globals.netrc_file.as_deref(),
+                globals.quiet > 0,
+                cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Sync(args),
+        }) => {
+            args.compat_args.validate()?;
+
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipSyncSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            let cache = cache.with_refresh(Refresh::from_args(args.refresh, args.refresh_package));
+            let index_urls = IndexLocations::new(
+                args.shared.index_url,
+                args.shared.extra_index_url,
+                args.shared.find_links,
+                args.shared.no_index,
+            )
+            .with_recursive_flat_index(args.shared.find_links_recursive);
+            let sources = args
+                .src_file
+                .into_iter()
+                .map(RequirementsSource::from_requirements_file)
+                .collect::<Vec<_>>();
+            let constraints = args
+                .constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let overrides = args
+                .r#override
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Vec<_>>();
+            let build_constraints = args
+                .build_constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let reinstall = Reinstall::from_args(args.reinstall, args.reinstall_package);
+            let no_binary = NoBinary::from_args(args.shared.no_binary);
+            let no_build = NoBuild::from_args(args.shared.only_binary, args.shared.no_build);
+            let setup_py = if args.shared.legacy_setup_py {
+                SetupPyStrategy::Setuptools
+            } else {
+                SetupPyStrategy::Pep517
+            };
+
+            commands::pip_sync(
+                &sources,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                args.convert_editable_vcs,
+                &reinstall,
+                args.shared.link_mode,
+                &args.shared.link_mode_package,
+                args.shared.compile_bytecode,
+                args.shared.bytecode_opt_level,
+                args.shared.overwrite_scripts,
+                args.shared.require_hashes,
+                index_urls,
+                args.shared.index_strategy,
+                args.shared.keyring_provider,
+                setup_py,
+                if args.shared.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                &args.shared.config_setting,
+                &args.shared.build_env,
+                args.shared.no_build_isolation,
+                &args.shared.no_build_isolation_package,
+                args.shared.reuse_build_dir,
+                args.verbose_build,
+                args.editable_mode,
+                no_build,
+                no_binary,
+                &args.shared.compatible_tags,
+                args.shared.strict,
+                args.shared.no_deps,
+                args.exact,
+                args.shared.no_remove,
+                &args.shared.keep,
+                args.dry_run,
+                args.format,
+                args.fail_on_change,
+                args.download_only.as_deref(),
+                args.build_wheels,
+                args.verify,
+                args.shared.python,
+                args.shared.python_implementation,
+                args.shared.python_preference,
+                args.allow_create,
+                args.shared.system,
+                args.shared.break_system_packages,
+                args.shared.require_virtualenv,
+                globals.native_tls,
+                globals.http_timeout,
+                globals.netrc_file.as_deref(),
+                cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Install(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipInstallSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            let cache = cache.with_refresh(Refresh::from_args(args.refresh, args.refresh_package));
+            let requirements = args
+                .package
+                .into_iter()
+                .map(RequirementsSource::from_package)
+                .chain(args.editable.into_iter().map(RequirementsSource::Editable))
+                .chain(
+                    args.requirement
+                        .into_iter()
+                        .map(RequirementsSource::from_requirements_file),
+                )
+                .collect::<Vec<_>>();
+            let constraints = args
+                .constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let overrides = args
+                .r#override
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Vec<_>>();
+            let build_constraints = args
+                .build_constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let index_urls = IndexLocations::new(
+                args.shared.index_url,
+                args.shared.extra_index_url,
+                args.shared.find_links,
+                args.shared.no_index,
+            )
+            .with_recursive_flat_index(args.shared.find_links_recursive);
+            let extras = if args.shared.all_extras {
+                ExtrasSpecification::All
+            } else if args.shared.extra.is_empty() {
+                ExtrasSpecification::None
+            } else {
+                ExtrasSpecification::Some(&args.shared.extra)
+            };
+            let reinstall = Reinstall::from_args(args.reinstall, args.reinstall_package);
+            let upgrade = Upgrade::from_args(args.upgrade, args.upgrade_package);
+            let no_binary = NoBinary::from_args(args.shared.no_binary);
+            let no_build = NoBuild::from_args(args.shared.only_binary, args.shared.no_build);
+            let dependency_mode = if args.shared.no_deps {
+                DependencyMode::Direct
+            } else {
+                DependencyMode::Transitive
+            };
+            let setup_py = if args.shared.legacy_setup_py {
+                SetupPyStrategy::Setuptools
+            } else {
+                SetupPyStrategy::Pep517
+            };
+
+            commands::pip_install(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                args.convert_editable_vcs,
+                &extras,
+                args.shared.resolution,
+                args.shared.resolution_exclude,
+                args.shared.prerelease,
+                args.shared.prerelease_package,
+                dependency_mode,
+                upgrade,
+                index_urls,
+                args.shared.index_strategy,
+                args.shared.keyring_provider,
+                reinstall,
+                args.shared.link_mode,
+                &args.shared.link_mode_package,
+                args.shared.compile_bytecode,
+                args.shared.bytecode_opt_level,
+                args.shared.overwrite_scripts,
+                args.shared.require_hashes,
+                setup_py,
+                if args.shared.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                &args.shared.config_setting,
+                &args.shared.build_env,
+                args.shared.no_build_isolation,
+                &args.shared.no_build_isolation_package,
+                args.shared.reuse_build_dir,
+                args.verbose_build,
+                args.editable_mode,
+                no_build,
+                no_binary,
+                &args.shared.compatible_tags,
+                args.shared.strict,
+                args.shared.exclude_newer,
+                args.shared.python,
+                args.shared.python_implementation,
+                args.shared.python_preference,
+                args.allow_create,
+                args.shared.system,
+                args.shared.break_system_packages,
+                args.shared.require_virtualenv,
+                globals.native_tls,
+                globals.http_timeout,
+                globals.netrc_file.as_deref(),
+                cache,
+                args.exact,
+                args.dry_run,
+                args.verify,
+                printer,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Download(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipDownloadSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            let cache = cache.with_refresh(Refresh::from_args(args.refresh, args.refresh_package));
+            let requirements = args
+                .package
+                .into_iter()
+                .map(RequirementsSource::from_package)
+                .chain(
+                    args.requirement
+                        .into_iter()
+                        .map(RequirementsSource::from_requirements_file),
+                )
+                .collect::<Vec<_>>();
+            let constraints = args
+                .constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let overrides = args
+                .r#override
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Vec<_>>();
+            let build_constraints = args
+                .build_constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let index_urls = IndexLocations::new(
+                args.shared.index_url,
+                args.shared.extra_index_url,
+                args.shared.find_links,
+                args.shared.no_index,
+            )
+            .with_recursive_flat_index(args.shared.find_links_recursive);
+            let extras = if args.shared.all_extras {
+                ExtrasSpecification::All
+            } else if args.shared.extra.is_empty() {
+                ExtrasSpecification::None
+            } else {
+                ExtrasSpecification::Some(&args.shared.extra)
+            };
+            let no_binary = NoBinary::from_args(args.shared.no_binary);
+            let no_build = NoBuild::from_args(args.shared.only_binary, args.shared.no_build);
+            let dependency_mode = if args.shared.no_deps {
+                DependencyMode::Direct
+            } else {
+                DependencyMode::Transitive
+            };
+            let setup_py = if args.shared.legacy_setup_py {
+                SetupPyStrategy::Setuptools
+            } else {
+                SetupPyStrategy::Pep517
+            };
+
+            commands::pip_download(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                &extras,
+                args.dest.as_deref().unwrap_or_else(|| Path::new(".")),
+                false,
+                args.shared.resolution,
+                args.shared.prerelease,
+                args.shared.prerelease_package,
+                dependency_mode,
+                index_urls,
+                args.shared.index_strategy,
+                args.shared.keyring_provider,
+                args.shared.require_hashes,
+                setup_py,
+                if args.shared.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                &args.shared.config_setting,
+                &args.shared.build_env,
+                args.shared.no_build_isolation,
+                &args.shared.no_build_isolation_package,
+                args.shared.reuse_build_dir,
+                args.verbose_build,
+                no_build,
+                no_binary,
+                &args.shared.compatible_tags,
+                args.shared.exclude_newer,
+                args.shared.python_version,
+                args.shared.python,
+                args.shared.python_implementation,
+                args.shared.python_preference,
+                args.shared.system,
+                globals.native_tls,
+                globals.http_timeout,
+                globals.netrc_file.as_deref(),
+                cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Wheel(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipWheelSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            let cache = cache.with_refresh(Refresh::from_args(args.refresh, args.refresh_package));
+            let requirements = args
+                .package
+                .into_iter()
+                .map(RequirementsSource::from_package)
+                .chain(
+                    args.requirement
+                        .into_iter()
+                        .map(RequirementsSource::from_requirements_file),
+                )
+                .collect::<Vec<_>>();
+            let constraints = args
+                .constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let overrides = args
+                .r#override
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Vec<_>>();
+            let build_constraints = args
+                .build_constraint
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let index_urls = IndexLocations::new(
+                args.shared.index_url,
+                args.shared.extra_index_url,
+                args.shared.find_links,
+                args.shared.no_index,
+            )
+            .with_recursive_flat_index(args.shared.find_links_recursive);
+            let extras = if args.shared.all_extras {
+                ExtrasSpecification::All
+            } else if args.shared.extra.is_empty() {
+                ExtrasSpecification::None
+            } else {
+                ExtrasSpecification::Some(&args.shared.extra)
+            };
+            let no_binary = NoBinary::from_args(args.shared.no_binary);
+            let no_build = NoBuild::from_args(args.shared.only_binary, args.shared.no_build);
+            let dependency_mode = if args.shared.no_deps {
+                DependencyMode::Direct
+            } else {
+                DependencyMode::Transitive
+            };
+            let setup_py = if args.shared.legacy_setup_py {
+                SetupPyStrategy::Setuptools
+            } else {
+                SetupPyStrategy::Pep517
+            };
+
+            commands::pip_download(
+                &requirements,
+                &constraints,
+                &overrides,
+                &build_constraints,
+                &extras,
+                args.wheel_dir.as_deref().unwrap_or_else(|| Path::new(".")),
+                true,
+                args.shared.resolution,
+                args.shared.prerelease,
+                args.shared.prerelease_package,
+                dependency_mode,
+                index_urls,
+                args.shared.index_strategy,
+                args.shared.keyring_provider,
+                args.shared.require_hashes,
+                setup_py,
+                if args.shared.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                &args.shared.config_setting,
+                &args.shared.build_env,
+                args.shared.no_build_isolation,
+                &args.shared.no_build_isolation_package,
+                args.shared.reuse_build_dir,
+                args.verbose_build,
+                no_build,
+                no_binary,
+                &args.shared.compatible_tags,
+                args.shared.exclude_newer,
+                args.shared.python_version,
+                args.shared.python,
+                args.shared.python_implementation,
+                args.shared.python_preference,
+                args.shared.system,
+                globals.native_tls,
+                globals.http_timeout,
+                globals.netrc_file.as_deref(),
+                cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Uninstall(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipUninstallSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            let sources = args
+                .package
+                .into_iter()
+                .map(RequirementsSource::from_package)
+                .chain(
+                    args.requirement
+                        .into_iter()
+                        .map(RequirementsSource::from_requirements_txt),
+                )
+                .collect::<Vec<_>>();
+            commands::pip_uninstall(
+                &sources,
+                args.all,
+                args.exclude_editable,
+                args.force,
+                args.shared.python,
+                args.shared.python_preference,
+                args.shared.system,
+                args.shared.break_system_packages,
+                args.shared.require_virtualenv,
+                cache,
+                if args.shared.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                globals.native_tls,
+                globals.http_timeout,
+                globals.netrc_file.as_deref(),
+                args.shared.keyring_provider,
+                printer,
+            )
+            .await
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Freeze(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipFreezeSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            commands::pip_freeze(
+                args.exclude_editable,
+                args.all,
+                &args.path,
+                args.shared.strict,
+                args.shared.python.as_deref(),
+                args.shared.python_preference,
+                args.shared.system,
+                &cache,
+                printer,
+            )
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::List(args),
+        }) => {
+            args.compat_args.validate()?;
+
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipListSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            commands::pip_list(
+                args.editable,
+                args.exclude_editable,
+                &args.exclude,
+                &args.format,
+                args.shared.strict,
+                args.shared.python.as_deref(),
+                args.shared.python_preference,
+                args.shared.system,
+                &cache,
+                printer,
+            )
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Show(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipShowSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            commands::pip_show(
+                args.package,
+                args.shared.strict,
+                args.shared.python.as_deref(),
+                args.shared.python_preference,
+                args.shared.system,
+                &cache,
+                printer,
+            )
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Check(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipCheckSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            commands::pip_check(
+                args.format,
+                args.shared.python.as_deref(),
+                args.shared.python_preference,
+                args.shared.system,
+                &cache,
+                printer,
+            )
+        }
+        Commands::Pip(PipNamespace {
+            command: PipCommand::Verify(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PipVerifySettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            let sources = args
+                .requirement
+                .into_iter()
+                .map(RequirementsSource::from_requirements_txt)
+                .collect::<Vec<_>>();
+            commands::pip_verify(
+                &sources,
+                args.format,
+                args.shared.python.as_deref(),
+                args.shared.python_preference,
+                args.shared.system,
+                &cache,
+                if args.shared.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                globals.native_tls,
+                globals.http_timeout,
+                globals.netrc_file.as_deref(),
+                args.shared.keyring_provider,
+                printer,
+            )
+            .await
+        }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Clean(args),
+        })
+        | Commands::Clean(args) => commands::cache_clean(&args.package, &cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Prune,
+        }) => commands::cache_prune(&cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Dir,
+        }) => {
+            commands::cache_dir(&cache);
+            Ok(ExitStatus::Success)
+        }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::List(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = PythonListSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            commands::python_list(args.shared.python_preference, args.format, &cache, printer)
+        }
+        Commands::Venv(args) => {
+            args.compat_args.validate()?;
+
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::VenvSettings::resolve(args, workspace);
+            if show_settings(
+                sources.as_ref(),
+                &globals,
+                &cache_settings,
+                Some(&args),
+                printer,
+            )? {
+                return Ok(ExitStatus::Success);
+            }
+
+            let index_locations = IndexLocations::new(
+                args.shared.index_url,
+                args.shared.extra_index_url,
+                args.shared.find_links,
+                args.shared.no_index,
+            );
+
+            // Since we use ".venv" as the default name, we use "." as the default prompt.
+            let prompt = args.prompt.or_else(|| {
+                if args.name == PathBuf::from(".venv") {
+                    Some(".".to_string())
+                } else {
+                    None
+                }
+            });
+
+            commands::venv(
+                &args.name,
+                args.shared.python.as_deref(),
+                args.shared.link_mode,
+                &index_locations,
+                args.shared.index_strategy,
+                args.shared.keyring_provider,
+                uv_virtualenv::Prompt::from_args(prompt),
+                args.system_site_packages,
+                if args.shared.offline {
+                    Connectivity::Offline
+                } else {
+                    Connectivity::Online
+                },
+                args.seed,
+                args.shared.exclude_newer,
+                globals.native_tls,
+                globals.http_timeout,
+                globals.netrc_file.as_deref(),
+                &cache,
+                printer,
+            )
+            .await
+        }
+        Commands::Run(args) => commands::run(args.command, args.args, args.isolated, &cache).await,
+        #[cfg(feature = "self-update")]
+        Commands::Self_(SelfNamespace {
+            command: SelfCommand::Update,
+        }) => commands::self_update(printer).await,
+        Commands::Version { output_format } => {
+            commands::version(
+                output_format,
+                globals.verbose > 0,
+                globals.native_tls,
+                cache.root(),
+                &mut stdout(),
+            )?;
+            Ok(ExitStatus::Success)
+        }
+        Commands::GenerateShellCompletion { shell } => commands::generate_shell_completion(shell),
+        Commands::Complete(args) => commands::complete_packages(&args.prefix, &cache, printer),
+    }
+}
+
+/// Run the `uv` command-line interface, returning the process exit code.
+pub fn main() -> ExitCode {
+    let result = if let Ok(stack_size) = env::var("UV_STACK_SIZE") {
+        // Artificially limit the stack size to test for stack overflows. Windows has a default stack size of 1MB,
+        // which is lower than the linux and mac default.
+        // https://learn.microsoft.com/en-us/cpp/build/reference/stack-stack-allocations?view=msvc-170
+        let stack_size = stack_size.parse().expect("Invalid stack size");
+        let tokio_main = move || {
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .thread_stack_size(stack_size)
+                .build()
+                .expect("Failed building the Runtime")
+                .block_on(run())
+        };
+        std::thread::Builder::new()
+            .stack_size(stack_size)
+            .spawn(tokio_main)
+            .expect("Tokio executor failed, was there a panic?")
+            .join()
+            .expect("Tokio executor failed, was there a panic?")
+    } else {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("Failed building the Runtime")
+            .block_on(run())
+    };
+
+    match result {
+        Ok(code) => code.into(),
+        Err(err) => {
+            let mut causes = err.chain();
+            eprintln!("{}: {}", "error".red().bold(), causes.next().unwrap());
+            for err in causes {
+                eprintln!("  {}: {}", "Caused by".red().bold(), err);
+            }
+            ExitStatus::Error.into()
+        }
+    }
+}
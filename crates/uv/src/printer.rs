@@ -5,8 +5,11 @@ use indicatif::ProgressDrawTarget;
 pub(crate) enum Printer {
     /// A printer that prints to standard streams (e.g., stdout).
     Default,
-    /// A printer that suppresses all output.
+    /// A printer that suppresses all output other than warnings and a summary of the changes
+    /// (e.g., "Installed 12 packages").
     Quiet,
+    /// A printer that suppresses all output.
+    Silent,
     /// A printer that prints all output, including debug messages.
     Verbose,
 }
@@ -17,6 +20,7 @@ impl Printer {
         match self {
             Self::Default => ProgressDrawTarget::stderr(),
             Self::Quiet => ProgressDrawTarget::hidden(),
+            Self::Silent => ProgressDrawTarget::hidden(),
             // Confusingly, hide the progress bar when in verbose mode.
             // Otherwise, it gets interleaved with debug messages.
             Self::Verbose => ProgressDrawTarget::hidden(),
@@ -28,6 +32,7 @@ impl Printer {
         match self {
             Self::Default => Stdout::Enabled,
             Self::Quiet => Stdout::Disabled,
+            Self::Silent => Stdout::Disabled,
             Self::Verbose => Stdout::Enabled,
         }
     }
@@ -37,6 +42,18 @@ impl Printer {
         match self {
             Self::Default => Stderr::Enabled,
             Self::Quiet => Stderr::Disabled,
+            Self::Silent => Stderr::Disabled,
+            Self::Verbose => Stderr::Enabled,
+        }
+    }
+
+    /// Return the [`Stderr`] for the summary of a command's changes, which is retained in
+    /// [`Printer::Quiet`] mode.
+    pub(crate) fn summary(self) -> Stderr {
+        match self {
+            Self::Default => Stderr::Enabled,
+            Self::Quiet => Stderr::Enabled,
+            Self::Silent => Stderr::Disabled,
             Self::Verbose => Stderr::Enabled,
         }
     }
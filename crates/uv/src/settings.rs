@@ -1,59 +1,143 @@
+//! The resolved settings for each `uv` command.
+//!
+//! Each settings struct is split into two groups: CLI-only settings, which can only be provided
+//! on the command-line, and [`PipSharedSettings`], which can also be provided via the `[tool.uv]`
+//! section of a `pyproject.toml` or `uv.toml` file. In the latter case, command-line arguments take
+//! precedence over the workspace configuration.
+//!
+//! The settings are typically derived from the [`crate::cli`] arguments via `resolve`, but can be
+//! constructed via `new` when embedding `uv`:
+//!
+//! ```
+//! use uv::settings::{PipInstallSettings, PipSharedSettings};
+//! use uv_workspace::PipOptions;
+//!
+//! // Start from the shared settings, which default the CLI-only settings.
+//! let mut settings = PipInstallSettings::new(PipSharedSettings::combine(
+//!     PipOptions {
+//!         python: Some("3.12".to_string()),
+//!         no_deps: Some(true),
+//!         ..PipOptions::default()
+//!     },
+//!     None,
+//! ));
+//!
+//! // Then, override any of the CLI-only settings.
+//! settings.package = vec!["flask>=3".to_string()];
+//! settings.dry_run = true;
+//!
+//! assert_eq!(settings.shared.python.as_deref(), Some("3.12"));
+//! assert!(settings.shared.no_deps);
+//! assert!(!settings.shared.system);
+//! ```
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::{Debug, Display, Formatter};
 use std::path::PathBuf;
 
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory};
+use rustc_hash::FxHashSet;
+
 use distribution_types::{FlatIndexLocation, IndexUrl};
 use install_wheel_rs::linker::LinkMode;
+use pep508_rs::Requirement;
 use uv_cache::CacheArgs;
-use uv_configuration::{ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier};
+use uv_configuration::{
+    BuildEnv, CompatibleTags, ConfigSettings, EditableMode, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, PythonImplementation, PythonPreference,
+};
 use uv_normalize::{ExtraName, PackageName};
 use uv_resolver::{AnnotationStyle, ExcludeNewer, PreReleaseMode, ResolutionMode};
 use uv_toolchain::PythonVersion;
-use uv_workspace::{PipOptions, Workspace};
+use uv_warnings::warn_user;
+use uv_workspace::{Options, PipOptions, Workspace};
 
 use crate::cli::{
-    ColorChoice, GlobalArgs, Maybe, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs,
-    PipListArgs, PipShowArgs, PipSyncArgs, PipUninstallArgs, VenvArgs,
+    Cli, ColorChoice, GlobalArgs, Maybe, PipCheckArgs, PipCompileArgs, PipDownloadArgs,
+    PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipUninstallArgs,
+    PipVerifyArgs, PipWheelArgs, PythonListArgs, ShowSettings, VenvArgs,
+};
+use crate::commands::{
+    CheckFormat, FreezeConstraintMode, ListFormat, PythonListFormat, ReusePins, SyncFormat,
+    VerifyFormat,
 };
-use crate::commands::ListFormat;
 
 /// The resolved global settings to use for any invocation of the CLI.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct GlobalSettings {
-    pub(crate) quiet: bool,
-    pub(crate) verbose: u8,
-    pub(crate) color: ColorChoice,
-    pub(crate) native_tls: bool,
+#[non_exhaustive]
+pub struct GlobalSettings {
+    pub quiet: u8,
+    pub verbose: u8,
+    pub color: ColorChoice,
+    pub native_tls: bool,
+    pub http_timeout: Option<u64>,
+    pub netrc_file: Option<PathBuf>,
+    pub show_settings: Option<ShowSettings>,
 }
 
 impl GlobalSettings {
     /// Resolve the [`GlobalSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: GlobalArgs, workspace: Option<&Workspace>) -> Self {
+    ///
+    /// The `pip` request timeout (i.e., `PIP_TIMEOUT`), if any, is used as the HTTP timeout in the
+    /// absence of `UV_HTTP_TIMEOUT`.
+    pub fn resolve(
+        args: GlobalArgs,
+        workspace: Option<&Workspace>,
+        pip_timeout: Option<u64>,
+    ) -> Self {
         Self {
             quiet: args.quiet,
             verbose: args.verbose,
             color: if args.no_color {
                 ColorChoice::Never
+            } else if matches!(args.color, ColorChoice::Auto) {
+                color_from_env()
             } else {
                 args.color
             },
             native_tls: flag(args.native_tls, args.no_native_tls)
                 .or(workspace.and_then(|workspace| workspace.options.native_tls))
                 .unwrap_or(false),
+            http_timeout: pip_timeout,
+            netrc_file: args.netrc_file,
+            show_settings: args.show_settings,
         }
     }
 }
 
+/// Resolve the [`ColorChoice`] from the standard environment variables, in the absence of an
+/// explicit `--color`.
+///
+/// `NO_COLOR` (<https://no-color.org>) takes precedence over `FORCE_COLOR` and `CLICOLOR_FORCE`
+/// (<https://bixense.com/clicolors>); variables set to an empty string are ignored.
+fn color_from_env() -> ColorChoice {
+    let is_set = |name: &str| env::var_os(name).is_some_and(|value| !value.is_empty());
+    if is_set("NO_COLOR") {
+        ColorChoice::Never
+    } else if is_set("FORCE_COLOR")
+        || env::var_os("CLICOLOR_FORCE").is_some_and(|value| !value.is_empty() && value != "0")
+    {
+        ColorChoice::Always
+    } else {
+        ColorChoice::Auto
+    }
+}
+
 /// The resolved cache settings to use for any invocation of the CLI.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct CacheSettings {
-    pub(crate) no_cache: Option<bool>,
-    pub(crate) cache_dir: Option<PathBuf>,
+#[non_exhaustive]
+pub struct CacheSettings {
+    pub no_cache: Option<bool>,
+    pub cache_dir: Option<PathBuf>,
 }
 
 impl CacheSettings {
     /// Resolve the [`CacheSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: CacheArgs, workspace: Option<&Workspace>) -> Self {
+    pub fn resolve(args: CacheArgs, workspace: Option<&Workspace>) -> Self {
         Self {
             no_cache: args
                 .no_cache
@@ -68,35 +152,51 @@ impl CacheSettings {
 /// The resolved settings to use for a `pip compile` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct PipCompileSettings {
+#[non_exhaustive]
+pub struct PipCompileSettings {
     // CLI-only settings.
-    pub(crate) src_file: Vec<PathBuf>,
-    pub(crate) constraint: Vec<PathBuf>,
-    pub(crate) r#override: Vec<PathBuf>,
-    pub(crate) refresh: bool,
-    pub(crate) refresh_package: Vec<PackageName>,
-    pub(crate) upgrade: bool,
-    pub(crate) upgrade_package: Vec<PackageName>,
+    pub src_file: Vec<PathBuf>,
+    pub constraint: Vec<PathBuf>,
+    pub r#override: Vec<PathBuf>,
+    pub build_constraint: Vec<PathBuf>,
+    pub convert_editable_vcs: bool,
+    pub constraint_from_freeze: Option<FreezeConstraintMode>,
+    pub reuse_pins: ReusePins,
+    pub refresh: bool,
+    pub refresh_package: Vec<PackageName>,
+    pub upgrade: bool,
+    pub upgrade_package: Vec<Requirement>,
+    pub no_upgrade_package: Vec<PackageName>,
+    pub explain: Vec<PackageName>,
+    pub changes: Option<PathBuf>,
+    pub verbose_build: bool,
+    pub marker_environment: Option<PathBuf>,
 
     // Shared settings.
-    pub(crate) shared: PipSharedSettings,
+    pub shared: PipSharedSettings,
 }
 
 impl PipCompileSettings {
     /// Resolve the [`PipCompileSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: PipCompileArgs, workspace: Option<Workspace>) -> Self {
+    pub fn resolve(args: PipCompileArgs, workspace: Option<Workspace>) -> Self {
         let PipCompileArgs {
             src_file,
             constraint,
             r#override,
+            build_constraint,
+            convert_editable_vcs,
+            constraint_from_freeze,
+            reuse_pins,
             extra,
             all_extras,
             no_all_extras,
             no_deps,
             deps,
             resolution,
+            resolution_exclude,
             prerelease,
             pre,
+            prerelease_package,
             output_file,
             no_strip_extras,
             strip_extras,
@@ -106,6 +206,8 @@ impl PipCompileSettings {
             header,
             annotation_style,
             custom_compile_command,
+            explain,
+            changes,
             offline,
             no_offline,
             refresh,
@@ -117,32 +219,51 @@ impl PipCompileSettings {
             index_strategy,
             keyring_provider,
             find_links,
+            find_links_recursive,
+            no_find_links_recursive,
             python,
+            python_implementation,
+            python_preference,
             system,
             no_system,
             upgrade,
             upgrade_package,
+            no_upgrade_package,
             generate_hashes,
             no_generate_hashes,
             legacy_setup_py,
             no_legacy_setup_py,
             no_build_isolation,
+            no_build_isolation_package,
             build_isolation,
+            no_clean,
+            clean,
             no_build,
             build,
             only_binary,
+            allow_platform_tag,
             config_setting,
+            build_env,
+            verbose_build,
             python_version,
+            marker_environment,
             exclude_newer,
             no_emit_package,
             emit_index_url,
             no_emit_index_url,
             emit_find_links,
             no_emit_find_links,
+            emit_find_links_relative_to,
+            emit_find_links_absolute,
+            no_emit_find_links_absolute,
             emit_marker_expression,
             no_emit_marker_expression,
             emit_index_annotation,
             no_emit_index_annotation,
+            emit_origin,
+            no_emit_origin,
+            emit_build_options,
+            no_emit_build_options,
             compat_args: _,
         } = args;
 
@@ -151,15 +272,26 @@ impl PipCompileSettings {
             src_file,
             constraint,
             r#override,
+            build_constraint,
+            convert_editable_vcs,
+            constraint_from_freeze,
+            reuse_pins,
             refresh,
             refresh_package: refresh_package.unwrap_or_default(),
             upgrade,
             upgrade_package: upgrade_package.unwrap_or_default(),
+            no_upgrade_package: no_upgrade_package.unwrap_or_default(),
+            explain,
+            changes,
+            verbose_build,
+            marker_environment,
 
             // Shared settings.
             shared: PipSharedSettings::combine(
                 PipOptions {
                     python,
+                    python_implementation,
+                    python_preference,
                     system: flag(system, no_system),
                     offline: flag(offline, no_offline),
                     index_url: index_url.and_then(Maybe::into_option),
@@ -171,20 +303,28 @@ impl PipCompileSettings {
                     }),
                     no_index: Some(no_index),
                     find_links,
+                    find_links_recursive: flag(find_links_recursive, no_find_links_recursive),
                     index_strategy,
                     keyring_provider,
                     no_build: flag(no_build, build),
                     only_binary,
+                    compatible_tags: allow_platform_tag.map(|compatible_tags| {
+                        compatible_tags.into_iter().collect::<CompatibleTags>()
+                    }),
                     no_build_isolation: flag(no_build_isolation, build_isolation),
+                    no_build_isolation_package,
+                    reuse_build_dir: flag(no_clean, clean),
                     extra,
                     all_extras: flag(all_extras, no_all_extras),
                     no_deps: flag(no_deps, deps),
                     resolution,
+                    resolution_exclude,
                     prerelease: if pre {
                         Some(PreReleaseMode::Allow)
                     } else {
                         prerelease
                     },
+                    prerelease_package,
                     output_file,
                     no_strip_extras: flag(no_strip_extras, strip_extras),
                     no_annotate: flag(no_annotate, annotate),
@@ -195,18 +335,27 @@ impl PipCompileSettings {
                     config_settings: config_setting.map(|config_settings| {
                         config_settings.into_iter().collect::<ConfigSettings>()
                     }),
+                    build_env: build_env
+                        .map(|build_env| build_env.into_iter().collect::<BuildEnv>()),
                     python_version,
                     exclude_newer,
                     no_emit_package,
                     emit_index_url: flag(emit_index_url, no_emit_index_url),
                     emit_find_links: flag(emit_find_links, no_emit_find_links),
+                    emit_find_links_relative_to,
+                    emit_find_links_absolute: flag(
+                        emit_find_links_absolute,
+                        no_emit_find_links_absolute,
+                    ),
                     emit_marker_expression: flag(emit_marker_expression, no_emit_marker_expression),
                     emit_index_annotation: flag(emit_index_annotation, no_emit_index_annotation),
+                    emit_origin: flag(emit_origin, no_emit_origin),
+                    emit_build_options: flag(emit_build_options, no_emit_build_options),
                     annotation_style,
                     link_mode,
                     ..PipOptions::default()
                 },
-                workspace,
+                pip_command(workspace, "compile"),
             ),
         }
     }
@@ -215,73 +364,143 @@ impl PipCompileSettings {
 /// The resolved settings to use for a `pip sync` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct PipSyncSettings {
+#[non_exhaustive]
+pub struct PipSyncSettings {
     // CLI-only settings.
-    pub(crate) src_file: Vec<PathBuf>,
-    pub(crate) reinstall: bool,
-    pub(crate) reinstall_package: Vec<PackageName>,
-    pub(crate) refresh: bool,
-    pub(crate) refresh_package: Vec<PackageName>,
+    pub src_file: Vec<PathBuf>,
+    pub constraint: Vec<PathBuf>,
+    pub r#override: Vec<PathBuf>,
+    pub build_constraint: Vec<PathBuf>,
+    pub convert_editable_vcs: bool,
+    pub reinstall: bool,
+    pub reinstall_package: Vec<PackageName>,
+    pub exact: bool,
+    pub dry_run: bool,
+    pub format: SyncFormat,
+    pub fail_on_change: bool,
+    pub download_only: Option<PathBuf>,
+    pub build_wheels: bool,
+    pub verify: bool,
+    pub refresh: bool,
+    pub refresh_package: Vec<PackageName>,
+    pub verbose_build: bool,
+    pub editable_mode: EditableMode,
+    pub allow_create: bool,
 
     // Shared settings.
-    pub(crate) shared: PipSharedSettings,
+    pub shared: PipSharedSettings,
 }
 
 impl PipSyncSettings {
     /// Resolve the [`PipSyncSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: PipSyncArgs, workspace: Option<Workspace>) -> Self {
+    pub fn resolve(args: PipSyncArgs, workspace: Option<Workspace>) -> Self {
         let PipSyncArgs {
             src_file,
+            constraint,
+            r#override,
+            build_constraint,
+            convert_editable_vcs,
             reinstall,
             reinstall_package,
+            exact,
+            inexact,
+            dry_run,
+            format,
+            fail_on_change,
+            download_only,
+            build_wheels,
+            verify,
             offline,
             refresh,
             no_offline,
             refresh_package,
+            no_deps,
+            deps,
+            no_remove,
+            remove,
+            keep,
             link_mode,
             index_url,
             extra_index_url,
             find_links,
+            find_links_recursive,
+            no_find_links_recursive,
             no_index,
             index_strategy,
             require_hashes,
             no_require_hashes,
             keyring_provider,
             python,
+            python_implementation,
+            python_preference,
+            allow_create,
             system,
             no_system,
             break_system_packages,
             no_break_system_packages,
+            require_virtualenv,
+            no_require_virtualenv,
             legacy_setup_py,
             no_legacy_setup_py,
             no_build_isolation,
+            no_build_isolation_package,
             build_isolation,
+            no_clean,
+            clean,
             no_build,
             build,
             no_binary,
             only_binary,
+            allow_platform_tag,
             compile_bytecode,
             no_compile_bytecode,
+            bytecode_opt_level,
+            overwrite_scripts,
+            no_overwrite_scripts,
             config_setting,
+            build_env,
+            verbose_build,
+            editable_mode,
             strict,
             no_strict,
             compat_args: _,
         } = args;
 
+        // `--exact` takes precedence over `no-remove` in the configuration file, since the
+        // environment must match the input files. (On the command-line, the two conflict.)
+        let exact = flag(exact, inexact).unwrap_or_default();
+
         Self {
             // CLI-only settings.
             src_file,
+            constraint,
+            r#override,
+            build_constraint,
+            convert_editable_vcs,
             reinstall,
             reinstall_package,
+            exact,
+            dry_run,
+            format,
+            fail_on_change,
+            download_only,
+            build_wheels,
+            verify,
             refresh,
             refresh_package,
+            verbose_build,
+            editable_mode: editable_mode.unwrap_or_default(),
+            allow_create,
 
             // Shared settings.
             shared: PipSharedSettings::combine(
                 PipOptions {
                     python,
+                    python_implementation,
+                    python_preference,
                     system: flag(system, no_system),
                     break_system_packages: flag(break_system_packages, no_break_system_packages),
+                    require_virtualenv: flag(require_virtualenv, no_require_virtualenv),
                     offline: flag(offline, no_offline),
                     index_url: index_url.and_then(Maybe::into_option),
                     extra_index_url: extra_index_url.map(|extra_index_urls| {
@@ -292,23 +511,40 @@ impl PipSyncSettings {
                     }),
                     no_index: Some(no_index),
                     find_links,
+                    find_links_recursive: flag(find_links_recursive, no_find_links_recursive),
                     index_strategy,
                     keyring_provider,
                     no_build: flag(no_build, build),
                     no_binary,
                     only_binary,
+                    compatible_tags: allow_platform_tag.map(|compatible_tags| {
+                        compatible_tags.into_iter().collect::<CompatibleTags>()
+                    }),
                     no_build_isolation: flag(no_build_isolation, build_isolation),
+                    no_build_isolation_package,
+                    reuse_build_dir: flag(no_clean, clean),
                     strict: flag(strict, no_strict),
+                    no_deps: flag(no_deps, deps),
+                    no_remove: if exact {
+                        Some(false)
+                    } else {
+                        flag(no_remove || inexact, remove)
+                    },
+                    keep,
                     legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
                     config_settings: config_setting.map(|config_settings| {
                         config_settings.into_iter().collect::<ConfigSettings>()
                     }),
+                    build_env: build_env
+                        .map(|build_env| build_env.into_iter().collect::<BuildEnv>()),
                     link_mode,
                     compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+                    bytecode_opt_level,
+                    overwrite_scripts: flag(overwrite_scripts, no_overwrite_scripts),
                     require_hashes: flag(require_hashes, no_require_hashes),
                     ..PipOptions::default()
                 },
-                workspace,
+                pip_command(workspace, "sync"),
             ),
         }
     }
@@ -317,33 +553,70 @@ impl PipSyncSettings {
 /// The resolved settings to use for a `pip install` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct PipInstallSettings {
+#[non_exhaustive]
+pub struct PipInstallSettings {
     // CLI-only settings.
-    pub(crate) package: Vec<String>,
-    pub(crate) requirement: Vec<PathBuf>,
-    pub(crate) editable: Vec<String>,
-    pub(crate) constraint: Vec<PathBuf>,
-    pub(crate) r#override: Vec<PathBuf>,
-    pub(crate) upgrade: bool,
-    pub(crate) upgrade_package: Vec<PackageName>,
-    pub(crate) reinstall: bool,
-    pub(crate) reinstall_package: Vec<PackageName>,
-    pub(crate) refresh: bool,
-    pub(crate) refresh_package: Vec<PackageName>,
-    pub(crate) dry_run: bool,
+    pub package: Vec<String>,
+    pub requirement: Vec<PathBuf>,
+    pub editable: Vec<String>,
+    pub constraint: Vec<PathBuf>,
+    pub r#override: Vec<PathBuf>,
+    pub build_constraint: Vec<PathBuf>,
+    pub convert_editable_vcs: bool,
+    pub upgrade: bool,
+    pub upgrade_package: Vec<Requirement>,
+    pub reinstall: bool,
+    pub reinstall_package: Vec<PackageName>,
+    pub refresh: bool,
+    pub refresh_package: Vec<PackageName>,
+    pub exact: bool,
+    pub dry_run: bool,
+    pub verify: bool,
+    pub verbose_build: bool,
+    pub editable_mode: EditableMode,
+    pub allow_create: bool,
     // Shared settings.
-    pub(crate) shared: PipSharedSettings,
+    pub shared: PipSharedSettings,
 }
 
 impl PipInstallSettings {
+    /// Create [`PipInstallSettings`] from the given shared settings, with every CLI-only setting
+    /// set to its command-line default.
+    pub fn new(shared: PipSharedSettings) -> Self {
+        Self {
+            package: vec![],
+            requirement: vec![],
+            editable: vec![],
+            constraint: vec![],
+            r#override: vec![],
+            build_constraint: vec![],
+            convert_editable_vcs: false,
+            upgrade: false,
+            upgrade_package: vec![],
+            reinstall: false,
+            reinstall_package: vec![],
+            refresh: false,
+            refresh_package: vec![],
+            exact: false,
+            dry_run: false,
+            verify: false,
+            verbose_build: false,
+            editable_mode: EditableMode::default(),
+            allow_create: false,
+            shared,
+        }
+    }
+
     /// Resolve the [`PipInstallSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: PipInstallArgs, workspace: Option<Workspace>) -> Self {
+    pub fn resolve(args: PipInstallArgs, workspace: Option<Workspace>) -> Self {
         let PipInstallArgs {
             package,
             requirement,
             editable,
             constraint,
             r#override,
+            build_constraint,
+            convert_editable_vcs,
             extra,
             all_extras,
             no_all_extras,
@@ -359,36 +632,58 @@ impl PipInstallSettings {
             deps,
             link_mode,
             resolution,
+            resolution_exclude,
             prerelease,
             pre,
+            prerelease_package,
             index_url,
             extra_index_url,
             find_links,
+            find_links_recursive,
+            no_find_links_recursive,
             no_index,
             index_strategy,
             require_hashes,
             no_require_hashes,
             keyring_provider,
             python,
+            python_implementation,
+            python_preference,
+            allow_create,
             system,
             no_system,
             break_system_packages,
             no_break_system_packages,
+            require_virtualenv,
+            no_require_virtualenv,
             legacy_setup_py,
             no_legacy_setup_py,
             no_build_isolation,
+            no_build_isolation_package,
             build_isolation,
+            no_clean,
+            clean,
             no_build,
             build,
             no_binary,
             only_binary,
+            allow_platform_tag,
             compile_bytecode,
             no_compile_bytecode,
+            bytecode_opt_level,
+            overwrite_scripts,
+            no_overwrite_scripts,
             config_setting,
+            build_env,
+            verbose_build,
+            editable_mode,
             strict,
             no_strict,
             exclude_newer,
+            exact,
+            inexact,
             dry_run,
+            verify,
         } = args;
 
         Self {
@@ -398,20 +693,30 @@ impl PipInstallSettings {
             editable,
             constraint,
             r#override,
+            build_constraint,
+            convert_editable_vcs,
             upgrade,
             upgrade_package: upgrade_package.unwrap_or_default(),
             reinstall,
             reinstall_package: reinstall_package.unwrap_or_default(),
             refresh,
             refresh_package: refresh_package.unwrap_or_default(),
+            exact: flag(exact, inexact).unwrap_or_default(),
             dry_run,
+            verify,
+            verbose_build,
+            editable_mode: editable_mode.unwrap_or_default(),
+            allow_create,
 
             // Shared settings.
             shared: PipSharedSettings::combine(
                 PipOptions {
                     python,
+                    python_implementation,
+                    python_preference,
                     system: flag(system, no_system),
                     break_system_packages: flag(break_system_packages, no_break_system_packages),
+                    require_virtualenv: flag(require_virtualenv, no_require_virtualenv),
                     offline: flag(offline, no_offline),
                     index_url: index_url.and_then(Maybe::into_option),
                     extra_index_url: extra_index_url.map(|extra_index_urls| {
@@ -422,33 +727,333 @@ impl PipInstallSettings {
                     }),
                     no_index: Some(no_index),
                     find_links,
+                    find_links_recursive: flag(find_links_recursive, no_find_links_recursive),
                     index_strategy,
                     keyring_provider,
                     no_build: flag(no_build, build),
                     no_binary,
                     only_binary,
+                    compatible_tags: allow_platform_tag.map(|compatible_tags| {
+                        compatible_tags.into_iter().collect::<CompatibleTags>()
+                    }),
                     no_build_isolation: flag(no_build_isolation, build_isolation),
+                    no_build_isolation_package,
+                    reuse_build_dir: flag(no_clean, clean),
                     strict: flag(strict, no_strict),
                     extra,
                     all_extras: flag(all_extras, no_all_extras),
                     no_deps: flag(no_deps, deps),
                     resolution,
+                    resolution_exclude,
                     prerelease: if pre {
                         Some(PreReleaseMode::Allow)
                     } else {
                         prerelease
                     },
+                    prerelease_package,
                     legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
                     config_settings: config_setting.map(|config_settings| {
                         config_settings.into_iter().collect::<ConfigSettings>()
                     }),
+                    build_env: build_env
+                        .map(|build_env| build_env.into_iter().collect::<BuildEnv>()),
                     exclude_newer,
                     link_mode,
                     compile_bytecode: flag(compile_bytecode, no_compile_bytecode),
+                    bytecode_opt_level,
+                    overwrite_scripts: flag(overwrite_scripts, no_overwrite_scripts),
                     require_hashes: flag(require_hashes, no_require_hashes),
                     ..PipOptions::default()
                 },
-                workspace,
+                pip_command(workspace, "install"),
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip download` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PipDownloadSettings {
+    // CLI-only settings.
+    pub package: Vec<String>,
+    pub requirement: Vec<PathBuf>,
+    pub dest: Option<PathBuf>,
+    pub constraint: Vec<PathBuf>,
+    pub r#override: Vec<PathBuf>,
+    pub build_constraint: Vec<PathBuf>,
+    pub refresh: bool,
+    pub refresh_package: Vec<PackageName>,
+    pub verbose_build: bool,
+    // Shared settings.
+    pub shared: PipSharedSettings,
+}
+
+impl PipDownloadSettings {
+    /// Resolve the [`PipDownloadSettings`] from the CLI and workspace configuration.
+    pub fn resolve(args: PipDownloadArgs, workspace: Option<Workspace>) -> Self {
+        let PipDownloadArgs {
+            package,
+            requirement,
+            dest,
+            constraint,
+            r#override,
+            build_constraint,
+            extra,
+            all_extras,
+            no_all_extras,
+            offline,
+            no_offline,
+            refresh,
+            refresh_package,
+            no_deps,
+            deps,
+            resolution,
+            prerelease,
+            pre,
+            prerelease_package,
+            index_url,
+            extra_index_url,
+            find_links,
+            find_links_recursive,
+            no_find_links_recursive,
+            no_index,
+            index_strategy,
+            require_hashes,
+            no_require_hashes,
+            keyring_provider,
+            python,
+            python_preference,
+            python_implementation,
+            system,
+            no_system,
+            python_version,
+            legacy_setup_py,
+            no_legacy_setup_py,
+            no_build_isolation,
+            build_isolation,
+            no_build_isolation_package,
+            no_clean,
+            clean,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+            allow_platform_tag,
+            config_setting,
+            build_env,
+            verbose_build,
+            exclude_newer,
+        } = args;
+
+        Self {
+            // CLI-only settings.
+            package,
+            requirement,
+            dest,
+            constraint,
+            r#override,
+            build_constraint,
+            refresh,
+            refresh_package: refresh_package.unwrap_or_default(),
+            verbose_build,
+
+            // Shared settings.
+            shared: PipSharedSettings::combine(
+                PipOptions {
+                    python,
+                    python_implementation,
+                    python_preference,
+                    system: flag(system, no_system),
+                    offline: flag(offline, no_offline),
+                    index_url: index_url.and_then(Maybe::into_option),
+                    extra_index_url: extra_index_url.map(|extra_index_urls| {
+                        extra_index_urls
+                            .into_iter()
+                            .filter_map(Maybe::into_option)
+                            .collect()
+                    }),
+                    no_index: Some(no_index),
+                    find_links,
+                    find_links_recursive: flag(find_links_recursive, no_find_links_recursive),
+                    index_strategy,
+                    keyring_provider,
+                    no_build: flag(no_build, build),
+                    no_binary,
+                    only_binary,
+                    compatible_tags: allow_platform_tag.map(|compatible_tags| {
+                        compatible_tags.into_iter().collect::<CompatibleTags>()
+                    }),
+                    no_build_isolation: flag(no_build_isolation, build_isolation),
+                    no_build_isolation_package,
+                    reuse_build_dir: flag(no_clean, clean),
+                    extra,
+                    all_extras: flag(all_extras, no_all_extras),
+                    no_deps: flag(no_deps, deps),
+                    resolution,
+                    prerelease: if pre {
+                        Some(PreReleaseMode::Allow)
+                    } else {
+                        prerelease
+                    },
+                    prerelease_package,
+                    legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
+                    config_settings: config_setting.map(|config_settings| {
+                        config_settings.into_iter().collect::<ConfigSettings>()
+                    }),
+                    build_env: build_env
+                        .map(|build_env| build_env.into_iter().collect::<BuildEnv>()),
+                    python_version,
+                    exclude_newer,
+                    require_hashes: flag(require_hashes, no_require_hashes),
+                    ..PipOptions::default()
+                },
+                pip_command(workspace, "download"),
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip wheel` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PipWheelSettings {
+    // CLI-only settings.
+    pub package: Vec<String>,
+    pub requirement: Vec<PathBuf>,
+    pub wheel_dir: Option<PathBuf>,
+    pub constraint: Vec<PathBuf>,
+    pub r#override: Vec<PathBuf>,
+    pub build_constraint: Vec<PathBuf>,
+    pub refresh: bool,
+    pub refresh_package: Vec<PackageName>,
+    pub verbose_build: bool,
+    // Shared settings.
+    pub shared: PipSharedSettings,
+}
+
+impl PipWheelSettings {
+    /// Resolve the [`PipWheelSettings`] from the CLI and workspace configuration.
+    pub fn resolve(args: PipWheelArgs, workspace: Option<Workspace>) -> Self {
+        let PipWheelArgs {
+            package,
+            requirement,
+            wheel_dir,
+            constraint,
+            r#override,
+            build_constraint,
+            extra,
+            all_extras,
+            no_all_extras,
+            offline,
+            no_offline,
+            refresh,
+            refresh_package,
+            no_deps,
+            deps,
+            resolution,
+            prerelease,
+            pre,
+            prerelease_package,
+            index_url,
+            extra_index_url,
+            find_links,
+            find_links_recursive,
+            no_find_links_recursive,
+            no_index,
+            index_strategy,
+            require_hashes,
+            no_require_hashes,
+            keyring_provider,
+            python,
+            python_preference,
+            python_implementation,
+            system,
+            no_system,
+            python_version,
+            legacy_setup_py,
+            no_legacy_setup_py,
+            no_build_isolation,
+            build_isolation,
+            no_build_isolation_package,
+            no_clean,
+            clean,
+            no_build,
+            build,
+            no_binary,
+            only_binary,
+            allow_platform_tag,
+            config_setting,
+            build_env,
+            verbose_build,
+            exclude_newer,
+        } = args;
+
+        Self {
+            // CLI-only settings.
+            package,
+            requirement,
+            wheel_dir,
+            constraint,
+            r#override,
+            build_constraint,
+            refresh,
+            refresh_package: refresh_package.unwrap_or_default(),
+            verbose_build,
+
+            // Shared settings.
+            shared: PipSharedSettings::combine(
+                PipOptions {
+                    python,
+                    python_implementation,
+                    python_preference,
+                    system: flag(system, no_system),
+                    offline: flag(offline, no_offline),
+                    index_url: index_url.and_then(Maybe::into_option),
+                    extra_index_url: extra_index_url.map(|extra_index_urls| {
+                        extra_index_urls
+                            .into_iter()
+                            .filter_map(Maybe::into_option)
+                            .collect()
+                    }),
+                    no_index: Some(no_index),
+                    find_links,
+                    find_links_recursive: flag(find_links_recursive, no_find_links_recursive),
+                    index_strategy,
+                    keyring_provider,
+                    no_build: flag(no_build, build),
+                    no_binary,
+                    only_binary,
+                    compatible_tags: allow_platform_tag.map(|compatible_tags| {
+                        compatible_tags.into_iter().collect::<CompatibleTags>()
+                    }),
+                    no_build_isolation: flag(no_build_isolation, build_isolation),
+                    no_build_isolation_package,
+                    reuse_build_dir: flag(no_clean, clean),
+                    extra,
+                    all_extras: flag(all_extras, no_all_extras),
+                    no_deps: flag(no_deps, deps),
+                    resolution,
+                    prerelease: if pre {
+                        Some(PreReleaseMode::Allow)
+                    } else {
+                        prerelease
+                    },
+                    prerelease_package,
+                    legacy_setup_py: flag(legacy_setup_py, no_legacy_setup_py),
+                    config_settings: config_setting.map(|config_settings| {
+                        config_settings.into_iter().collect::<ConfigSettings>()
+                    }),
+                    build_env: build_env
+                        .map(|build_env| build_env.into_iter().collect::<BuildEnv>()),
+                    python_version,
+                    exclude_newer,
+                    require_hashes: flag(require_hashes, no_require_hashes),
+                    ..PipOptions::default()
+                },
+                pip_command(workspace, "wheel"),
             ),
         }
     }
@@ -457,26 +1062,36 @@ impl PipInstallSettings {
 /// The resolved settings to use for a `pip uninstall` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct PipUninstallSettings {
+#[non_exhaustive]
+pub struct PipUninstallSettings {
     // CLI-only settings.
-    pub(crate) package: Vec<String>,
-    pub(crate) requirement: Vec<PathBuf>,
+    pub package: Vec<String>,
+    pub requirement: Vec<PathBuf>,
+    pub all: bool,
+    pub exclude_editable: bool,
+    pub force: bool,
     // Shared settings.
-    pub(crate) shared: PipSharedSettings,
+    pub shared: PipSharedSettings,
 }
 
 impl PipUninstallSettings {
     /// Resolve the [`PipUninstallSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: PipUninstallArgs, workspace: Option<Workspace>) -> Self {
+    pub fn resolve(args: PipUninstallArgs, workspace: Option<Workspace>) -> Self {
         let PipUninstallArgs {
             package,
             requirement,
+            all,
+            exclude_editable,
+            force,
             python,
+            python_preference,
             keyring_provider,
             system,
             no_system,
             break_system_packages,
             no_break_system_packages,
+            require_virtualenv,
+            no_require_virtualenv,
             offline,
             no_offline,
         } = args;
@@ -485,18 +1100,23 @@ impl PipUninstallSettings {
             // CLI-only settings.
             package,
             requirement,
+            all,
+            exclude_editable,
+            force,
 
             // Shared settings.
             shared: PipSharedSettings::combine(
                 PipOptions {
                     python,
+                    python_preference,
                     system: flag(system, no_system),
                     break_system_packages: flag(break_system_packages, no_break_system_packages),
+                    require_virtualenv: flag(require_virtualenv, no_require_virtualenv),
                     offline: flag(offline, no_offline),
                     keyring_provider,
                     ..PipOptions::default()
                 },
-                workspace,
+                pip_command(workspace, "uninstall"),
             ),
         }
     }
@@ -505,21 +1125,27 @@ impl PipUninstallSettings {
 /// The resolved settings to use for a `pip freeze` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct PipFreezeSettings {
+#[non_exhaustive]
+pub struct PipFreezeSettings {
     // CLI-only settings.
-    pub(crate) exclude_editable: bool,
+    pub exclude_editable: bool,
+    pub all: bool,
+    pub path: Vec<PathBuf>,
     // Shared settings.
-    pub(crate) shared: PipSharedSettings,
+    pub shared: PipSharedSettings,
 }
 
 impl PipFreezeSettings {
     /// Resolve the [`PipFreezeSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: PipFreezeArgs, workspace: Option<Workspace>) -> Self {
+    pub fn resolve(args: PipFreezeArgs, workspace: Option<Workspace>) -> Self {
         let PipFreezeArgs {
             exclude_editable,
+            all,
+            path,
             strict,
             no_strict,
             python,
+            python_preference,
             system,
             no_system,
         } = args;
@@ -527,16 +1153,19 @@ impl PipFreezeSettings {
         Self {
             // CLI-only settings.
             exclude_editable,
+            all,
+            path,
 
             // Shared settings.
             shared: PipSharedSettings::combine(
                 PipOptions {
                     python,
+                    python_preference,
                     system: flag(system, no_system),
                     strict: flag(strict, no_strict),
                     ..PipOptions::default()
                 },
-                workspace,
+                pip_command(workspace, "freeze"),
             ),
         }
     }
@@ -545,20 +1174,21 @@ impl PipFreezeSettings {
 /// The resolved settings to use for a `pip list` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct PipListSettings {
+#[non_exhaustive]
+pub struct PipListSettings {
     // CLI-only settings.
-    pub(crate) editable: bool,
-    pub(crate) exclude_editable: bool,
-    pub(crate) exclude: Vec<PackageName>,
-    pub(crate) format: ListFormat,
+    pub editable: bool,
+    pub exclude_editable: bool,
+    pub exclude: Vec<PackageName>,
+    pub format: ListFormat,
 
     // CLI-only settings.
-    pub(crate) shared: PipSharedSettings,
+    pub shared: PipSharedSettings,
 }
 
 impl PipListSettings {
     /// Resolve the [`PipListSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: PipListArgs, workspace: Option<Workspace>) -> Self {
+    pub fn resolve(args: PipListArgs, workspace: Option<Workspace>) -> Self {
         let PipListArgs {
             editable,
             exclude_editable,
@@ -567,6 +1197,7 @@ impl PipListSettings {
             strict,
             no_strict,
             python,
+            python_preference,
             system,
             no_system,
             compat_args: _,
@@ -583,11 +1214,12 @@ impl PipListSettings {
             shared: PipSharedSettings::combine(
                 PipOptions {
                     python,
+                    python_preference,
                     system: flag(system, no_system),
                     strict: flag(strict, no_strict),
                     ..PipOptions::default()
                 },
-                workspace,
+                pip_command(workspace, "list"),
             ),
         }
     }
@@ -596,22 +1228,24 @@ impl PipListSettings {
 /// The resolved settings to use for a `pip show` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct PipShowSettings {
+#[non_exhaustive]
+pub struct PipShowSettings {
     // CLI-only settings.
-    pub(crate) package: Vec<PackageName>,
+    pub package: Vec<PackageName>,
 
     // CLI-only settings.
-    pub(crate) shared: PipSharedSettings,
+    pub shared: PipSharedSettings,
 }
 
 impl PipShowSettings {
     /// Resolve the [`PipShowSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: PipShowArgs, workspace: Option<Workspace>) -> Self {
+    pub fn resolve(args: PipShowArgs, workspace: Option<Workspace>) -> Self {
         let PipShowArgs {
             package,
             strict,
             no_strict,
             python,
+            python_preference,
             system,
             no_system,
         } = args;
@@ -624,11 +1258,12 @@ impl PipShowSettings {
             shared: PipSharedSettings::combine(
                 PipOptions {
                     python,
+                    python_preference,
                     system: flag(system, no_system),
                     strict: flag(strict, no_strict),
                     ..PipOptions::default()
                 },
-                workspace,
+                pip_command(workspace, "show"),
             ),
         }
     }
@@ -637,31 +1272,88 @@ impl PipShowSettings {
 /// The resolved settings to use for a `pip check` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct PipCheckSettings {
+#[non_exhaustive]
+pub struct PipCheckSettings {
     // CLI-only settings.
+    pub format: CheckFormat,
 
     // Shared settings.
-    pub(crate) shared: PipSharedSettings,
+    pub shared: PipSharedSettings,
 }
 
 impl PipCheckSettings {
     /// Resolve the [`PipCheckSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: PipCheckArgs, workspace: Option<Workspace>) -> Self {
+    pub fn resolve(args: PipCheckArgs, workspace: Option<Workspace>) -> Self {
         let PipCheckArgs {
+            format,
             python,
+            python_preference,
             system,
             no_system,
         } = args;
 
         Self {
+            // CLI-only settings.
+            format,
+
             // Shared settings.
             shared: PipSharedSettings::combine(
                 PipOptions {
                     python,
+                    python_preference,
                     system: flag(system, no_system),
                     ..PipOptions::default()
                 },
-                workspace,
+                pip_command(workspace, "check"),
+            ),
+        }
+    }
+}
+
+/// The resolved settings to use for a `pip verify` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PipVerifySettings {
+    // CLI-only settings.
+    pub requirement: Vec<PathBuf>,
+    pub format: VerifyFormat,
+
+    // Shared settings.
+    pub shared: PipSharedSettings,
+}
+
+impl PipVerifySettings {
+    /// Resolve the [`PipVerifySettings`] from the CLI and workspace configuration.
+    pub fn resolve(args: PipVerifyArgs, workspace: Option<Workspace>) -> Self {
+        let PipVerifyArgs {
+            requirement,
+            format,
+            python,
+            python_preference,
+            keyring_provider,
+            system,
+            no_system,
+            offline,
+            no_offline,
+        } = args;
+
+        Self {
+            // CLI-only settings.
+            requirement,
+            format,
+
+            // Shared settings.
+            shared: PipSharedSettings::combine(
+                PipOptions {
+                    python,
+                    python_preference,
+                    system: flag(system, no_system),
+                    offline: flag(offline, no_offline),
+                    keyring_provider,
+                    ..PipOptions::default()
+                },
+                pip_command(workspace, "verify"),
             ),
         }
     }
@@ -670,20 +1362,21 @@ impl PipCheckSettings {
 /// The resolved settings to use for a `pip check` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct VenvSettings {
+#[non_exhaustive]
+pub struct VenvSettings {
     // CLI-only settings.
-    pub(crate) seed: bool,
-    pub(crate) name: PathBuf,
-    pub(crate) prompt: Option<String>,
-    pub(crate) system_site_packages: bool,
+    pub seed: bool,
+    pub name: PathBuf,
+    pub prompt: Option<String>,
+    pub system_site_packages: bool,
 
     // CLI-only settings.
-    pub(crate) shared: PipSharedSettings,
+    pub shared: PipSharedSettings,
 }
 
 impl VenvSettings {
     /// Resolve the [`VenvSettings`] from the CLI and workspace configuration.
-    pub(crate) fn resolve(args: VenvArgs, workspace: Option<Workspace>) -> Self {
+    pub fn resolve(args: VenvArgs, workspace: Option<Workspace>) -> Self {
         let VenvArgs {
             python,
             system,
@@ -737,77 +1430,146 @@ impl VenvSettings {
     }
 }
 
+/// The resolved settings to use for a `python list` invocation.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PythonListSettings {
+    // CLI-only settings.
+    pub format: PythonListFormat,
+
+    // Shared settings.
+    pub shared: PipSharedSettings,
+}
+
+impl PythonListSettings {
+    /// Resolve the [`PythonListSettings`] from the CLI and workspace configuration.
+    pub fn resolve(args: PythonListArgs, workspace: Option<Workspace>) -> Self {
+        let PythonListArgs {
+            python_preference,
+            output_format,
+        } = args;
+
+        Self {
+            // CLI-only settings.
+            format: output_format,
+
+            // Shared settings.
+            shared: PipSharedSettings::combine(
+                PipOptions {
+                    python_preference,
+                    ..PipOptions::default()
+                },
+                workspace,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for an invocation of the `pip` CLI.
 ///
 /// Represents the shared settings that are used across all `pip` commands.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
-pub(crate) struct PipSharedSettings {
-    pub(crate) python: Option<String>,
-    pub(crate) system: bool,
-    pub(crate) break_system_packages: bool,
-    pub(crate) offline: bool,
-    pub(crate) index_url: Option<IndexUrl>,
-    pub(crate) extra_index_url: Vec<IndexUrl>,
-    pub(crate) no_index: bool,
-    pub(crate) find_links: Vec<FlatIndexLocation>,
-    pub(crate) index_strategy: IndexStrategy,
-    pub(crate) keyring_provider: KeyringProviderType,
-    pub(crate) no_build: bool,
-    pub(crate) no_binary: Vec<PackageNameSpecifier>,
-    pub(crate) only_binary: Vec<PackageNameSpecifier>,
-    pub(crate) no_build_isolation: bool,
-    pub(crate) strict: bool,
-    pub(crate) extra: Vec<ExtraName>,
-    pub(crate) all_extras: bool,
-    pub(crate) no_deps: bool,
-    pub(crate) resolution: ResolutionMode,
-    pub(crate) prerelease: PreReleaseMode,
-    pub(crate) output_file: Option<PathBuf>,
-    pub(crate) no_strip_extras: bool,
-    pub(crate) no_annotate: bool,
-    pub(crate) no_header: bool,
-    pub(crate) custom_compile_command: Option<String>,
-    pub(crate) generate_hashes: bool,
-    pub(crate) legacy_setup_py: bool,
-    pub(crate) config_setting: ConfigSettings,
-    pub(crate) python_version: Option<PythonVersion>,
-    pub(crate) exclude_newer: Option<ExcludeNewer>,
-    pub(crate) no_emit_package: Vec<PackageName>,
-    pub(crate) emit_index_url: bool,
-    pub(crate) emit_find_links: bool,
-    pub(crate) emit_marker_expression: bool,
-    pub(crate) emit_index_annotation: bool,
-    pub(crate) annotation_style: AnnotationStyle,
-    pub(crate) link_mode: LinkMode,
-    pub(crate) compile_bytecode: bool,
-    pub(crate) require_hashes: bool,
+#[non_exhaustive]
+pub struct PipSharedSettings {
+    pub python: Option<String>,
+    pub python_implementation: Option<PythonImplementation>,
+    pub python_preference: PythonPreference,
+    pub system: bool,
+    pub break_system_packages: bool,
+    pub require_virtualenv: bool,
+    pub offline: bool,
+    pub index_url: Option<IndexUrl>,
+    pub extra_index_url: Vec<IndexUrl>,
+    pub no_index: bool,
+    pub find_links: Vec<FlatIndexLocation>,
+    pub find_links_recursive: bool,
+    pub index_strategy: IndexStrategy,
+    pub keyring_provider: KeyringProviderType,
+    pub no_build: bool,
+    pub no_binary: Vec<PackageNameSpecifier>,
+    pub only_binary: Vec<PackageNameSpecifier>,
+    pub compatible_tags: CompatibleTags,
+    pub no_build_isolation: bool,
+    pub no_build_isolation_package: Vec<PackageName>,
+    pub reuse_build_dir: bool,
+    pub strict: bool,
+    pub extra: Vec<ExtraName>,
+    pub all_extras: bool,
+    pub no_deps: bool,
+    pub no_remove: bool,
+    pub keep: Vec<PackageName>,
+    pub resolution: ResolutionMode,
+    pub resolution_exclude: Vec<PackageName>,
+    pub prerelease: PreReleaseMode,
+    pub prerelease_package: Vec<PackageName>,
+    pub output_file: Option<PathBuf>,
+    pub no_strip_extras: bool,
+    pub no_annotate: bool,
+    pub no_header: bool,
+    pub custom_compile_command: Option<String>,
+    pub generate_hashes: bool,
+    pub legacy_setup_py: bool,
+    pub config_setting: ConfigSettings,
+    pub build_env: BuildEnv,
+    pub python_version: Option<PythonVersion>,
+    pub exclude_newer: Option<ExcludeNewer>,
+    pub no_emit_package: Vec<PackageName>,
+    pub emit_index_url: bool,
+    pub emit_find_links: bool,
+    pub emit_find_links_relative_to: Option<PathBuf>,
+    pub emit_find_links_absolute: bool,
+    pub emit_marker_expression: bool,
+    pub emit_index_annotation: bool,
+    pub emit_origin: bool,
+    pub emit_build_options: bool,
+    pub annotation_style: AnnotationStyle,
+    pub link_mode: LinkMode,
+    pub link_mode_package: BTreeMap<PackageName, LinkMode>,
+    pub compile_bytecode: bool,
+    pub bytecode_opt_level: u8,
+    pub overwrite_scripts: bool,
+    pub require_hashes: bool,
 }
 
 impl PipSharedSettings {
     /// Resolve the [`PipSharedSettings`] from the CLI and workspace configuration.
-    pub(crate) fn combine(args: PipOptions, workspace: Option<Workspace>) -> Self {
+    ///
+    /// Any value set in `args` takes precedence over the `[tool.uv.pip]` section of the
+    /// `workspace`; values set in neither fall back to their defaults.
+    pub fn combine(args: PipOptions, workspace: Option<Workspace>) -> Self {
         let PipOptions {
             python,
+            python_implementation,
+            python_preference,
             system,
             break_system_packages,
+            require_virtualenv,
             offline,
             index_url,
             extra_index_url,
             no_index,
             find_links,
+            find_links_recursive,
             index_strategy,
             keyring_provider,
             no_build,
             no_binary,
             only_binary,
+            compatible_tags,
             no_build_isolation,
+            no_build_isolation_package,
+            reuse_build_dir,
             strict,
             extra,
             all_extras,
             no_deps,
+            no_remove,
+            keep,
             resolution,
+            resolution_exclude,
             prerelease,
+            prerelease_package,
             output_file,
             no_strip_extras,
             no_annotate,
@@ -816,17 +1578,38 @@ impl PipSharedSettings {
             generate_hashes,
             legacy_setup_py,
             config_settings,
+            build_env,
             python_version,
             exclude_newer,
             no_emit_package,
             emit_index_url,
             emit_find_links,
+            emit_find_links_relative_to,
+            emit_find_links_absolute,
             emit_marker_expression,
             emit_index_annotation,
+            emit_origin,
+            emit_build_options,
             annotation_style,
             link_mode,
+            link_mode_package,
             compile_bytecode,
+            bytecode_opt_level,
+            overwrite_scripts,
             require_hashes,
+            // Any per-command section is layered over the shared options by the command's
+            // `resolve`, via `pip_command`.
+            compile: _,
+            sync: _,
+            install: _,
+            uninstall: _,
+            freeze: _,
+            list: _,
+            show: _,
+            check: _,
+            download: _,
+            wheel: _,
+            verify: _,
         } = workspace
             .and_then(|workspace| workspace.options.pip)
             .unwrap_or_default();
@@ -835,8 +1618,18 @@ impl PipSharedSettings {
             extra: args.extra.or(extra).unwrap_or_default(),
             all_extras: args.all_extras.or(all_extras).unwrap_or_default(),
             no_deps: args.no_deps.or(no_deps).unwrap_or_default(),
+            no_remove: args.no_remove.or(no_remove).unwrap_or_default(),
+            keep: args.keep.or(keep).unwrap_or_default(),
             resolution: args.resolution.or(resolution).unwrap_or_default(),
+            resolution_exclude: args
+                .resolution_exclude
+                .or(resolution_exclude)
+                .unwrap_or_default(),
             prerelease: args.prerelease.or(prerelease).unwrap_or_default(),
+            prerelease_package: args
+                .prerelease_package
+                .or(prerelease_package)
+                .unwrap_or_default(),
             output_file: args.output_file.or(output_file),
             no_strip_extras: args.no_strip_extras.or(no_strip_extras).unwrap_or_default(),
             no_annotate: args.no_annotate.or(no_annotate).unwrap_or_default(),
@@ -856,20 +1649,40 @@ impl PipSharedSettings {
                 .or(keyring_provider)
                 .unwrap_or_default(),
             find_links: args.find_links.or(find_links).unwrap_or_default(),
+            find_links_recursive: args
+                .find_links_recursive
+                .or(find_links_recursive)
+                .unwrap_or_default(),
             generate_hashes: args.generate_hashes.or(generate_hashes).unwrap_or_default(),
             legacy_setup_py: args.legacy_setup_py.or(legacy_setup_py).unwrap_or_default(),
             no_build_isolation: args
                 .no_build_isolation
                 .or(no_build_isolation)
                 .unwrap_or_default(),
+            no_build_isolation_package: args
+                .no_build_isolation_package
+                .into_iter()
+                .flatten()
+                .chain(no_build_isolation_package.into_iter().flatten())
+                .collect(),
+            reuse_build_dir: args.reuse_build_dir.or(reuse_build_dir).unwrap_or_default(),
             no_build: args.no_build.or(no_build).unwrap_or_default(),
             only_binary: args.only_binary.or(only_binary).unwrap_or_default(),
+            compatible_tags: args.compatible_tags.or(compatible_tags).unwrap_or_default(),
             config_setting: args.config_settings.or(config_settings).unwrap_or_default(),
+            build_env: args.build_env.or(build_env).unwrap_or_default(),
             python_version: args.python_version.or(python_version),
             exclude_newer: args.exclude_newer.or(exclude_newer),
             no_emit_package: args.no_emit_package.or(no_emit_package).unwrap_or_default(),
             emit_index_url: args.emit_index_url.or(emit_index_url).unwrap_or_default(),
             emit_find_links: args.emit_find_links.or(emit_find_links).unwrap_or_default(),
+            emit_find_links_relative_to: args
+                .emit_find_links_relative_to
+                .or(emit_find_links_relative_to),
+            emit_find_links_absolute: args
+                .emit_find_links_absolute
+                .or(emit_find_links_absolute)
+                .unwrap_or_default(),
             emit_marker_expression: args
                 .emit_marker_expression
                 .or(emit_marker_expression)
@@ -878,24 +1691,538 @@ impl PipSharedSettings {
                 .emit_index_annotation
                 .or(emit_index_annotation)
                 .unwrap_or_default(),
+            emit_origin: args.emit_origin.or(emit_origin).unwrap_or_default(),
+            emit_build_options: args
+                .emit_build_options
+                .or(emit_build_options)
+                .unwrap_or_default(),
             link_mode: args.link_mode.or(link_mode).unwrap_or_default(),
+            link_mode_package: args
+                .link_mode_package
+                .or(link_mode_package)
+                .unwrap_or_default(),
             require_hashes: args.require_hashes.or(require_hashes).unwrap_or_default(),
             python: args.python.or(python),
+            python_implementation: args.python_implementation.or(python_implementation),
+            python_preference: args
+                .python_preference
+                .or(python_preference)
+                .unwrap_or_default(),
             system: args.system.or(system).unwrap_or_default(),
             break_system_packages: args
                 .break_system_packages
                 .or(break_system_packages)
                 .unwrap_or_default(),
+            require_virtualenv: args
+                .require_virtualenv
+                .or(require_virtualenv)
+                .unwrap_or_default(),
             no_binary: args.no_binary.or(no_binary).unwrap_or_default(),
             compile_bytecode: args
                 .compile_bytecode
                 .or(compile_bytecode)
                 .unwrap_or_default(),
+            bytecode_opt_level: args
+                .bytecode_opt_level
+                .or(bytecode_opt_level)
+                .unwrap_or_default(),
+            overwrite_scripts: args.overwrite_scripts.or(overwrite_scripts).unwrap_or(true),
             strict: args.strict.or(strict).unwrap_or_default(),
         }
     }
 }
 
+/// The source from which a resolved setting was read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SettingSource {
+    /// The setting was provided on the command-line.
+    Cli,
+    /// The setting was read from an environment variable.
+    Env,
+    /// The setting was read from the workspace configuration file.
+    Workspace,
+    /// The setting was read from the user configuration file.
+    User,
+    /// The setting was read from a `pip` environment variable (e.g., `PIP_INDEX_URL`).
+    PipEnv,
+    /// The setting was not provided, and so uses its default value.
+    Default,
+}
+
+impl Display for SettingSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cli => write!(f, "cli"),
+            Self::Env => write!(f, "env"),
+            Self::Workspace => write!(f, "workspace"),
+            Self::User => write!(f, "user"),
+            Self::PipEnv => write!(f, "pip-env"),
+            Self::Default => write!(f, "default"),
+        }
+    }
+}
+
+/// Tracks the source of each resolved setting, for `--show-settings`.
+///
+/// Settings are resolved by layering the command-line arguments (and their environment variables)
+/// over the workspace configuration, the workspace configuration over the user configuration, and
+/// the user configuration over the `pip` environment variables; the source of a setting is the
+/// first layer that provides it. Within the configuration files, any per-command sections (e.g.,
+/// `[tool.uv.pip.compile]`) take precedence over the shared options.
+#[derive(Debug, Default, Clone)]
+pub struct SettingSources {
+    /// The arguments that were provided on the command-line or via environment variables, keyed by
+    /// their `clap` ID (e.g., `index_url`).
+    args: BTreeMap<String, SettingSource>,
+    /// The `pip` subcommand being run (e.g., `compile`), if any.
+    command: Option<String>,
+    /// The options read from the workspace configuration file, if any.
+    workspace: Option<Options>,
+    /// The options read from the user configuration file, if any.
+    user: Option<Options>,
+    /// The options read from the `pip` environment variables, if any.
+    pip_env: Option<Options>,
+}
+
+impl SettingSources {
+    /// Determine the setting sources from the parsed command-line arguments and the configuration
+    /// layers.
+    pub fn new(
+        matches: &ArgMatches,
+        workspace: Option<Options>,
+        user: Option<Options>,
+        pip_env: Option<Options>,
+    ) -> Self {
+        let command = matches
+            .subcommand()
+            .filter(|(name, _)| *name == "pip")
+            .and_then(|(_, pip)| pip.subcommand_name())
+            .map(ToString::to_string);
+
+        let mut args = BTreeMap::new();
+        let mut matches = Some(matches);
+        while let Some(current) = matches {
+            for id in current.ids() {
+                let source = match current.value_source(id.as_str()) {
+                    Some(ValueSource::CommandLine) => SettingSource::Cli,
+                    Some(ValueSource::EnvVariable) => SettingSource::Env,
+                    _ => continue,
+                };
+                args.insert(id.to_string(), source);
+            }
+            matches = current.subcommand().map(|(_, matches)| matches);
+        }
+
+        Self {
+            args,
+            command,
+            workspace,
+            user,
+            pip_env,
+        }
+    }
+
+    /// Return the source of the setting with the given name (e.g., `index_url`).
+    pub fn source(&self, name: &str) -> SettingSource {
+        let ids = arg_ids(name);
+        let sources = ids
+            .iter()
+            .filter_map(|id| self.args.get(id))
+            .collect::<Vec<_>>();
+        if sources.contains(&&SettingSource::Cli) {
+            return SettingSource::Cli;
+        }
+        if sources.contains(&&SettingSource::Env) {
+            return SettingSource::Env;
+        }
+
+        // The configuration files use the plural `config-settings`.
+        let option = match name {
+            "config_setting" => "config_settings",
+            name => name,
+        };
+        // The per-command sections (e.g., `[tool.uv.pip.compile]`) of every configuration file
+        // take precedence over the shared options.
+        let in_section = |options: &Option<Options>| {
+            self.command
+                .as_deref()
+                .and_then(|command| options.as_ref()?.pip.as_ref()?.command(command))
+                .is_some_and(|section| section.is_set(option))
+        };
+        if in_section(&self.workspace) {
+            SettingSource::Workspace
+        } else if in_section(&self.user) {
+            SettingSource::User
+        } else if self
+            .workspace
+            .as_ref()
+            .is_some_and(|options| options.is_set(option))
+        {
+            SettingSource::Workspace
+        } else if self
+            .user
+            .as_ref()
+            .is_some_and(|options| options.is_set(option))
+        {
+            SettingSource::User
+        } else if self
+            .pip_env
+            .as_ref()
+            .is_some_and(|options| options.is_set(option))
+        {
+            SettingSource::PipEnv
+        } else {
+            SettingSource::Default
+        }
+    }
+
+    /// Render a settings struct, annotating each field with its source.
+    ///
+    /// The fields of any nested settings struct (e.g., [`PipSharedSettings`]) are annotated in
+    /// turn. A field whose value spans multiple lines is annotated on its closing line.
+    pub fn annotate(&self, settings: &dyn SettingFields) -> String {
+        let mut annotated = format!("{} {{\n", settings.name());
+        self.annotate_fields(settings, 1, &mut annotated);
+        annotated.push_str("}\n");
+        annotated
+    }
+
+    /// Render the fields of a settings struct at the given depth, annotating each with its source.
+    fn annotate_fields(&self, settings: &dyn SettingFields, depth: usize, annotated: &mut String) {
+        let indent = "    ".repeat(depth);
+        for (name, field) in settings.fields() {
+            match field {
+                SettingField::Value(value) => {
+                    let value = format!("{value:#?}").replace('\n', &format!("\n{indent}"));
+                    let source = self.source(name);
+                    annotated.push_str(&format!("{indent}{name}: {value}, # {source}\n"));
+                }
+                SettingField::Nested(nested) => {
+                    annotated.push_str(&format!("{indent}{name}: {} {{\n", nested.name()));
+                    self.annotate_fields(nested, depth + 1, annotated);
+                    annotated.push_str(&format!("{indent}}},\n"));
+                }
+            }
+        }
+    }
+}
+
+/// A settings struct whose fields can be displayed, along with their sources, under
+/// `--show-settings`.
+pub trait SettingFields {
+    /// The name of the settings struct (e.g., `PipCompileSettings`).
+    fn name(&self) -> &'static str;
+
+    /// The fields of the settings struct, in declaration order.
+    fn fields(&self) -> Vec<(&'static str, SettingField<'_>)>;
+}
+
+/// A field of a settings struct.
+pub enum SettingField<'a> {
+    /// A value, displayed via its [`Debug`] representation.
+    Value(&'a dyn Debug),
+    /// A nested settings struct (e.g., [`PipSharedSettings`]), whose fields are displayed in turn.
+    Nested(&'a dyn SettingFields),
+}
+
+/// Implement [`SettingFields`] for a settings struct, given each of its fields. Nested settings
+/// structs are marked as `nested`.
+///
+/// The struct is destructured exhaustively, such that adding a field without listing it here is a
+/// compile error.
+macro_rules! impl_setting_fields {
+    ($name:ident { $($field:ident $(: $nested:ident)?),* $(,)? }) => {
+        impl SettingFields for $name {
+            fn name(&self) -> &'static str {
+                stringify!($name)
+            }
+
+            fn fields(&self) -> Vec<(&'static str, SettingField<'_>)> {
+                let $name { $($field),* } = self;
+                vec![$((
+                    stringify!($field).trim_start_matches("r#"),
+                    impl_setting_fields!(@field $field $($nested)?),
+                )),*]
+            }
+        }
+    };
+    (@field $field:ident nested) => {
+        SettingField::Nested($field)
+    };
+    (@field $field:ident) => {
+        SettingField::Value($field)
+    };
+}
+
+impl_setting_fields!(GlobalSettings {
+    quiet,
+    verbose,
+    color,
+    native_tls,
+    http_timeout,
+    netrc_file,
+    show_settings
+});
+impl_setting_fields!(CacheSettings {
+    no_cache,
+    cache_dir
+});
+impl_setting_fields!(PipCompileSettings {
+    src_file,
+    constraint,
+    r#override,
+    build_constraint,
+    convert_editable_vcs,
+    constraint_from_freeze,
+    reuse_pins,
+    refresh,
+    refresh_package,
+    upgrade,
+    upgrade_package,
+    no_upgrade_package,
+    explain,
+    changes,
+    verbose_build,
+    marker_environment,
+    shared: nested
+});
+impl_setting_fields!(PipSyncSettings {
+    src_file,
+    constraint,
+    r#override,
+    build_constraint,
+    convert_editable_vcs,
+    reinstall,
+    reinstall_package,
+    exact,
+    dry_run,
+    format,
+    fail_on_change,
+    download_only,
+    build_wheels,
+    verify,
+    refresh,
+    refresh_package,
+    verbose_build,
+    editable_mode,
+    allow_create,
+    shared: nested
+});
+impl_setting_fields!(PipInstallSettings {
+    package,
+    requirement,
+    editable,
+    constraint,
+    r#override,
+    build_constraint,
+    convert_editable_vcs,
+    upgrade,
+    upgrade_package,
+    reinstall,
+    reinstall_package,
+    refresh,
+    refresh_package,
+    exact,
+    dry_run,
+    verify,
+    verbose_build,
+    editable_mode,
+    allow_create,
+    shared: nested
+});
+impl_setting_fields!(PipDownloadSettings {
+    package,
+    requirement,
+    dest,
+    constraint,
+    r#override,
+    build_constraint,
+    refresh,
+    refresh_package,
+    verbose_build,
+    shared: nested
+});
+impl_setting_fields!(PipWheelSettings {
+    package,
+    requirement,
+    wheel_dir,
+    constraint,
+    r#override,
+    build_constraint,
+    refresh,
+    refresh_package,
+    verbose_build,
+    shared: nested
+});
+impl_setting_fields!(PipUninstallSettings {
+    package,
+    requirement,
+    all,
+    exclude_editable,
+    force,
+    shared: nested
+});
+impl_setting_fields!(PipFreezeSettings {
+    exclude_editable,
+    all,
+    path,
+    shared: nested
+});
+impl_setting_fields!(PipListSettings {
+    editable,
+    exclude_editable,
+    exclude,
+    format,
+    shared: nested
+});
+impl_setting_fields!(PipShowSettings {
+    package,
+    shared: nested
+});
+impl_setting_fields!(PipCheckSettings {
+    format,
+    shared: nested
+});
+impl_setting_fields!(PipVerifySettings {
+    requirement,
+    format,
+    shared: nested
+});
+impl_setting_fields!(VenvSettings {
+    seed,
+    name,
+    prompt,
+    system_site_packages,
+    shared: nested
+});
+impl_setting_fields!(PythonListSettings {
+    format,
+    shared: nested
+});
+impl_setting_fields!(PipSharedSettings {
+    python,
+    python_implementation,
+    python_preference,
+    system,
+    break_system_packages,
+    require_virtualenv,
+    offline,
+    index_url,
+    extra_index_url,
+    no_index,
+    find_links,
+    find_links_recursive,
+    index_strategy,
+    keyring_provider,
+    no_build,
+    no_binary,
+    only_binary,
+    compatible_tags,
+    no_build_isolation,
+    no_build_isolation_package,
+    reuse_build_dir,
+    strict,
+    extra,
+    all_extras,
+    no_deps,
+    no_remove,
+    keep,
+    resolution,
+    resolution_exclude,
+    prerelease,
+    prerelease_package,
+    output_file,
+    no_strip_extras,
+    no_annotate,
+    no_header,
+    custom_compile_command,
+    generate_hashes,
+    legacy_setup_py,
+    config_setting,
+    build_env,
+    python_version,
+    exclude_newer,
+    no_emit_package,
+    emit_index_url,
+    emit_find_links,
+    emit_find_links_relative_to,
+    emit_find_links_absolute,
+    emit_marker_expression,
+    emit_index_annotation,
+    emit_origin,
+    emit_build_options,
+    annotation_style,
+    link_mode,
+    link_mode_package,
+    compile_bytecode,
+    bytecode_opt_level,
+    overwrite_scripts,
+    require_hashes
+});
+
+/// Return the IDs of the arguments that may provide the setting with the given name (e.g.,
+/// `index_url`).
+///
+/// A setting may be provided by either of a pair of flags (e.g., `--system` and `--no-system`), or
+/// by an argument with a different name (e.g., `--pre`).
+fn arg_ids(name: &str) -> Vec<String> {
+    let mut ids = vec![name.to_string(), format!("no_{name}")];
+    if let Some(name) = name.strip_prefix("no_") {
+        ids.push(name.to_string());
+    }
+    ids.extend(
+        match name {
+            "prerelease" => &["pre"][..],
+            "format" => &["output_format"][..],
+            "config_settings" => &["config_setting"][..],
+            "compatible_tags" => &["allow_platform_tag"][..],
+            "reuse_build_dir" => &["no_clean", "clean"][..],
+            _ => &[][..],
+        }
+        .iter()
+        .map(ToString::to_string),
+    );
+    ids
+}
+
+/// Layer the section for the given `pip` subcommand (e.g., `[tool.uv.pip.compile]`) over the
+/// shared `[tool.uv.pip]` options, warning on any option in the section that the subcommand
+/// doesn't accept.
+fn pip_command(workspace: Option<Workspace>, command: &str) -> Option<Workspace> {
+    let workspace = workspace?;
+    if let Some(section) = workspace
+        .options
+        .pip
+        .as_ref()
+        .and_then(|pip| pip.command(command))
+    {
+        let cli = Cli::command();
+        let args = cli
+            .find_subcommand("pip")
+            .and_then(|pip| pip.find_subcommand(command))
+            .map(|command| {
+                command
+                    .get_arguments()
+                    .map(|arg| arg.get_id().to_string())
+                    .collect::<FxHashSet<_>>()
+            })
+            .unwrap_or_default();
+        for field in section.set_fields() {
+            if !arg_ids(field).iter().any(|id| args.contains(id)) {
+                warn_user!(
+                    "The `{}` option in the `pip.{command}` section has no effect, as it isn't used by `uv pip {command}`",
+                    field.replace('_', "-")
+                );
+            }
+        }
+    }
+    Some(Workspace {
+        options: workspace.options.for_pip_command(command),
+        ..workspace
+    })
+}
+
 /// Given a boolean flag pair (like `--upgrade` and `--no-upgrade`), resolve the value of the flag.
 fn flag(yes: bool, no: bool) -> Option<bool> {
     match (yes, no) {
@@ -45,6 +45,29 @@ impl From<VersionInfo> for clap::builder::Str {
     }
 }
 
+/// Information about how uv was built.
+#[derive(Serialize)]
+pub(crate) struct BuildInfo {
+    /// The target triple uv was compiled for, such as "x86_64-unknown-linux-gnu".
+    target: String,
+    /// The date on which uv was built, or the date given by `SOURCE_DATE_EPOCH` for reproducible
+    /// builds.
+    build_date: Option<String>,
+    /// The Cargo features uv was compiled with.
+    features: Vec<&'static str>,
+}
+
+impl fmt::Display for BuildInfo {
+    /// Formatted build information, one `key: value` pair per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "target: {}", self.target)?;
+        if let Some(ref build_date) = self.build_date {
+            writeln!(f, "build-date: {build_date}")?;
+        }
+        write!(f, "features: {}", self.features.join(", "))
+    }
+}
+
 /// Returns information about uv's version.
 pub(crate) fn version() -> VersionInfo {
     // Environment variables are only read at compile-time
@@ -74,11 +97,34 @@ pub(crate) fn version() -> VersionInfo {
     }
 }
 
+/// Returns information about how uv was built.
+pub(crate) fn build_info() -> BuildInfo {
+    let features = [
+        ("python", cfg!(feature = "python")),
+        ("python-patch", cfg!(feature = "python-patch")),
+        ("pypy", cfg!(feature = "pypy")),
+        ("pypi", cfg!(feature = "pypi")),
+        ("git", cfg!(feature = "git")),
+        ("maturin", cfg!(feature = "maturin")),
+        ("self-update", cfg!(feature = "self-update")),
+    ]
+    .into_iter()
+    .filter_map(|(name, enabled)| enabled.then_some(name))
+    .collect();
+
+    BuildInfo {
+        // Set by `build.rs`
+        target: env!("RUST_HOST_TARGET").to_string(),
+        build_date: option_env!("UV_BUILD_DATE").map(ToString::to_string),
+        features,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use insta::{assert_json_snapshot, assert_snapshot};
 
-    use super::{CommitInfo, VersionInfo};
+    use super::{BuildInfo, CommitInfo, VersionInfo};
 
     #[test]
     fn version_formatting() {
@@ -144,4 +190,18 @@ mod tests {
         }
         "###);
     }
+
+    #[test]
+    fn build_info_formatting() {
+        let build_info = BuildInfo {
+            target: "x86_64-unknown-linux-gnu".to_string(),
+            build_date: Some("2023-10-20".to_string()),
+            features: vec!["pypi", "self-update"],
+        };
+        assert_snapshot!(build_info, @r###"
+        target: x86_64-unknown-linux-gnu
+        build-date: 2023-10-20
+        features: pypi, self-update
+        "###);
+    }
 }
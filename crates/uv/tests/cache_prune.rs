@@ -156,3 +156,34 @@ fn prune_stale_symlink() -> Result<()> {
 
     Ok(())
 }
+
+/// `cache prune --show-settings` should print the settings without pruning the cache.
+#[test]
+fn prune_show_settings() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), prune_command(&context).arg("--show-settings"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GlobalSettings {
+        quiet: 0, # default
+        verbose: 0, # default
+        color: Auto, # default
+        native_tls: false, # default
+        http_timeout: None, # default
+        netrc_file: None, # default
+        show_settings: Some(
+            Exit,
+        ), # cli
+    }
+    CacheSettings {
+        no_cache: None, # default
+        cache_dir: Some(
+            "[CACHE_DIR]/",
+        ), # cli
+    }
+
+    ----- stderr -----
+    "###);
+}
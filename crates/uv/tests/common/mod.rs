@@ -4,6 +4,7 @@
 use assert_cmd::assert::{Assert, OutputAssertExt};
 use assert_cmd::Command;
 use assert_fs::assert::PathAssert;
+use assert_fs::fixture::PathCreateDir;
 
 use assert_fs::fixture::PathChild;
 use regex::Regex;
@@ -16,6 +17,7 @@ use std::str::FromStr;
 use uv_interpreter::find_requested_python;
 
 use uv_cache::Cache;
+use uv_configuration::PythonPreference;
 use uv_fs::Simplified;
 use uv_toolchain::{toolchains_for_version, PythonVersion};
 
@@ -395,7 +397,10 @@ pub fn python_path_with_versions(
             .collect::<Vec<_>>();
             if inner.is_empty() {
                 // Fallback to a system lookup if we failed to find one in the toolchain directory
-                if let Some(interpreter) = find_requested_python(python_version, &cache).unwrap() {
+                if let Some(interpreter) =
+                    find_requested_python(python_version, None, PythonPreference::default(), &cache)
+                        .unwrap()
+                {
                     vec![interpreter
                         .sys_executable()
                         .parent()
@@ -413,6 +418,36 @@ pub fn python_path_with_versions(
     Ok(env::join_paths(selected_pythons)?)
 }
 
+/// Create a directory of managed toolchains with the requested Python versions available.
+///
+/// Generally this should be used with `UV_BOOTSTRAP_DIR`.
+pub fn toolchain_dir_with_versions(
+    temp_dir: &assert_fs::TempDir,
+    python_versions: &[&str],
+) -> anyhow::Result<PathBuf> {
+    let toolchain_dir = temp_dir.child("toolchains");
+    toolchain_dir.create_dir_all()?;
+    for python_version in python_versions {
+        let toolchains = toolchains_for_version(
+            &PythonVersion::from_str(python_version)
+                .expect("Tests should use a valid Python version"),
+        )
+        .expect("Tests are run on a supported platform");
+        if toolchains.is_empty() {
+            panic!("Could not find a managed toolchain for Python {python_version} for test");
+        }
+        for toolchain in toolchains {
+            let name = toolchain
+                .path()
+                .file_name()
+                .expect("Toolchains must be in a named directory");
+            uv_fs::replace_symlink(toolchain.path(), toolchain_dir.child(name))?;
+        }
+    }
+
+    Ok(toolchain_dir.to_path_buf())
+}
+
 /// Execute the command and format its output status, stdout and stderr into a snapshot string.
 ///
 /// This function is derived from `insta_cmd`s `spawn_with_info`.
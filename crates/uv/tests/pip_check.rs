@@ -223,3 +223,223 @@ fn check_multiple_incompatible_packages() -> Result<()> {
 
     Ok(())
 }
+
+// requests 2.31.0 requires idna (<4,>=2.5)
+// this test force-installs idna 2.4 to trigger a failure, and lists the incompatibilities as JSON.
+#[test]
+fn check_incompatible_packages_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0")?;
+
+    uv_snapshot!(install_command(&context)
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Downloaded 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    uv_snapshot!(check_command(&context).arg("--format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    []
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    All installed packages are compatible
+    "###
+    );
+
+    let requirements_txt_idna = context.temp_dir.child("requirements_idna.txt");
+    requirements_txt_idna.write_str("idna==2.4")?;
+
+    uv_snapshot!(install_command(&context)
+        .arg("-r")
+        .arg("requirements_idna.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - idna==3.6
+     + idna==2.4
+    warning: The package `requests` requires `idna<4,>=2.5`, but `2.4` is installed.
+    "###
+    );
+
+    uv_snapshot!(check_command(&context).arg("--format").arg("json"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [{"kind":"incompatible-dependency","package":"requests","requirement":"idna<4,>=2.5","installed":"2.4","message":"The package `requests` requires `idna<4,>=2.5`, but `2.4` is installed."}]
+
+    ----- stderr -----
+    Checked 5 packages in [TIME]
+    Found 1 incompatibility
+    "###
+    );
+
+    Ok(())
+}
+
+// requests 2.31.0 requires idna (<4,>=2.5)
+// this test manually removes idna from the environment to trigger a failure.
+#[test]
+fn check_missing_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("requests==2.31.0")?;
+
+    uv_snapshot!(install_command(&context)
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Downloaded 5 packages in [TIME]
+    Installed 5 packages in [TIME]
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + requests==2.31.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // Remove `idna` without going through the installer.
+    let site_packages = context.site_packages();
+    fs_err::remove_dir_all(site_packages.join("idna"))?;
+    fs_err::remove_dir_all(site_packages.join("idna-3.6.dist-info"))?;
+
+    uv_snapshot!(check_command(&context), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 4 packages in [TIME]
+    Found 1 incompatibility
+    The package `requests` requires `idna<4,>=2.5`, but it's not installed.
+    "###
+    );
+
+    Ok(())
+}
+
+/// A dependency that's gated behind an extra is only required if another installed package
+/// requests that extra.
+#[test]
+fn check_extra_gated_dependency() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    // Create a package with an extra, and a package that requests it.
+    let example = context.temp_dir.child("example");
+    example.child("pyproject.toml").write_str(
+        r#"[project]
+name = "example"
+version = "0.0.0"
+dependencies = []
+requires-python = ">=3.8"
+
+[project.optional-dependencies]
+dev = [
+  "iniconfig==2.0.0"
+]
+"#,
+    )?;
+    let parent = context.temp_dir.child("parent");
+    parent.child("pyproject.toml").write_str(
+        r#"[project]
+name = "parent"
+version = "0.0.0"
+dependencies = ["example[dev]"]
+requires-python = ">=3.8"
+"#,
+    )?;
+
+    uv_snapshot!(context.filters(), install_command(&context)
+        .arg("./example"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + example==0.0.0 (from file://[TEMP_DIR]/example)
+    "###
+    );
+
+    // Nothing requests the `dev` extra, so `iniconfig` isn't required.
+    uv_snapshot!(check_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    All installed packages are compatible
+    "###
+    );
+
+    uv_snapshot!(context.filters(), install_command(&context)
+        .arg("./example")
+        .arg("./parent"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + parent==0.0.0 (from file://[TEMP_DIR]/parent)
+    "###
+    );
+
+    // Remove `iniconfig` without going through the installer.
+    let site_packages = context.site_packages();
+    fs_err::remove_dir_all(site_packages.join("iniconfig"))?;
+    fs_err::remove_dir_all(site_packages.join("iniconfig-2.0.0.dist-info"))?;
+
+    // `parent` requests the `dev` extra, so `iniconfig` is required.
+    uv_snapshot!(check_command(&context), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 2 packages in [TIME]
+    Found 1 incompatibility
+    The package `example` requires `iniconfig==2.0.0 ; extra == 'dev'`, but it's not installed.
+    "###
+    );
+
+    Ok(())
+}
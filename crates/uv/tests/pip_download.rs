@@ -0,0 +1,225 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext, EXCLUDE_NEWER};
+
+mod common;
+
+/// Create a `pip download` command with options shared across scenarios.
+fn command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("pip")
+        .arg("download")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .arg("--exclude-newer")
+        .arg(EXCLUDE_NEWER)
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (2 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// Download a set of packages into a directory, without installing them.
+#[test]
+fn download() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0\ntomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--dest")
+        .arg("downloads"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages to downloads in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+     + tomli-2.0.1-py3-none-any.whl
+    "###
+    );
+
+    let downloads = context.temp_dir.child("downloads");
+    downloads
+        .child("iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    downloads
+        .child("tomli-2.0.1-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    // The environment is left untouched.
+    context.assert_command("import iniconfig").failure();
+
+    Ok(())
+}
+
+/// Download source distributions with `--no-binary`, and skip dependencies with `--no-deps`.
+#[test]
+fn download_no_binary_no_deps() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(command(&context)
+        .arg("anyio==4.3.0")
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("--no-deps")
+        .arg("-d")
+        .arg("downloads"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages to downloads in [TIME]
+     + anyio-4.3.0-py3-none-any.whl
+     + iniconfig-2.0.0.tar.gz
+    "###
+    );
+
+    Ok(())
+}
+
+/// Re-download previously downloaded archives from the cache with `--offline`.
+#[test]
+fn download_offline() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(command(&context)
+        .arg("iniconfig==2.0.0")
+        .arg("--dest")
+        .arg("downloads"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package to downloads in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+    "###
+    );
+
+    uv_snapshot!(command(&context)
+        .arg("iniconfig==2.0.0")
+        .arg("--dest")
+        .arg("offline")
+        .arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package to offline in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("offline")
+        .child("iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    Ok(())
+}
+
+/// Local directory requirements don't have an archive to download, so they're rejected before
+/// anything is downloaded.
+#[test]
+fn download_local_directory() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("iniconfig==2.0.0")
+        .arg(context.workspace_root.join("scripts/packages/poetry_editable"))
+        .arg("--no-deps")
+        .arg("--dest")
+        .arg("downloads"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: Only wheels and source archives can be downloaded, but found Git or local directory requirement: `poetry-editable @ file://[WORKSPACE]/scripts/packages/poetry_editable`. Use `uv pip wheel` to build them into wheels instead.
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("downloads")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
+/// Raise an error when a script's `requires-python` isn't satisfied by the target version.
+#[test]
+fn download_pep723_script_requires_python() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let script = context.temp_dir.child("script.py");
+    script.write_str(indoc! {r#"
+        # /// script
+        # requires-python = "<3.11"
+        # dependencies = ["iniconfig==2.0.0"]
+        # ///
+    "#})?;
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("-r")
+        .arg("script.py")
+        .arg("--dest")
+        .arg("downloads"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The script `script.py` requires Python <3.11, but resolution targets Python 3.12.[X]
+    "###
+    );
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("-r")
+        .arg("script.py")
+        .arg("--python-version")
+        .arg("3.10")
+        .arg("--dest")
+        .arg("downloads"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package to downloads in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+    "###
+    );
+
+    Ok(())
+}
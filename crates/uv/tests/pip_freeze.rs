@@ -109,8 +109,8 @@ fn freeze_duplicate() -> Result<()> {
         context1.site_packages().join("pip-22.1.1.dist-info"),
     )?;
 
-    // Run `pip freeze`.
-    uv_snapshot!(context1.filters(), command(&context1).arg("--strict"), @r###"
+    // Run `pip freeze`, including `pip` itself.
+    uv_snapshot!(context1.filters(), command(&context1).arg("--all").arg("--strict"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -210,3 +210,258 @@ fn freeze_with_editable() -> Result<()> {
 
     Ok(())
 }
+
+/// Freeze a Git dependency installed from a branch, and reinstall it from the frozen output.
+#[test]
+#[cfg(feature = "git")]
+fn freeze_git_round_trip() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(
+        "uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage",
+    )?;
+
+    // Run `pip sync`.
+    sync_command(&context)
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    // The frozen output should include the resolved commit, rather than the default branch.
+    uv_snapshot!(command(&context)
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979
+
+    ----- stderr -----
+    "###
+    );
+
+    // Reinstall from the frozen output into a fresh environment.
+    let output = command(&context).output()?;
+    let frozen = context.temp_dir.child("frozen.txt");
+    frozen.write_str(&String::from_utf8(output.stdout)?)?;
+
+    let context2 = TestContext::new("3.12");
+    sync_command(&context2)
+        .arg(frozen.path())
+        .assert()
+        .success();
+
+    uv_snapshot!(command(&context2)
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    uv-public-pypackage @ git+https://github.com/astral-test/uv-public-pypackage@0dacfd662c64cb4ceb16e6cf65a157a8b715b979
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Freeze a local wheel, which should be recorded as an archive rather than a directory.
+#[test]
+fn freeze_local_archive() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let wheel = context
+        .workspace_root
+        .join("scripts/links/tqdm-1000.0.0-py3-none-any.whl");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!("tqdm @ file://{}", wheel.display()))?;
+
+    // Run `pip sync`.
+    sync_command(&context)
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    let direct_url = fs_err::read_to_string(
+        context
+            .site_packages()
+            .join("tqdm-1000.0.0.dist-info")
+            .join("direct_url.json"),
+    )?;
+    assert!(direct_url.contains("\"archive_info\""), "{direct_url}");
+    assert!(!direct_url.contains("\"dir_info\""), "{direct_url}");
+
+    // Run `pip freeze`.
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    tqdm @ file://[WORKSPACE]/scripts/links/tqdm-1000.0.0-py3-none-any.whl
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Omit `pip`, `setuptools`, `wheel`, `distribute`, and `uv` from the output, unless `--all` is
+/// provided.
+#[test]
+fn freeze_all() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "MarkupSafe==2.1.3\nsetuptools==69.2.0\n-e {}",
+        context
+            .workspace_root
+            .join("scripts/packages/poetry_editable")
+            .display()
+    ))?;
+
+    // Run `pip sync`.
+    sync_command(&context)
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    // Run `pip freeze`.
+    uv_snapshot!(context.filters(), command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    markupsafe==2.1.3
+    -e file://[WORKSPACE]/scripts/packages/poetry_editable
+
+    ----- stderr -----
+    "###
+    );
+
+    // Include `setuptools` with `--all`.
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("--all"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    markupsafe==2.1.3
+    -e file://[WORKSPACE]/scripts/packages/poetry_editable
+    setuptools==69.2.0
+
+    ----- stderr -----
+    "###
+    );
+
+    // Exclude the editable package, but include `setuptools`.
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("--all")
+        .arg("--exclude-editable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    markupsafe==2.1.3
+    setuptools==69.2.0
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// List the packages in standalone `site-packages` directories, rather than the environment.
+#[test]
+fn freeze_path() -> Result<()> {
+    use crate::common::copy_dir_all;
+
+    let context = TestContext::new("3.12");
+
+    // Sync `MarkupSafe`, and copy the site-packages to a standalone directory.
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+    sync_command(&context)
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+    let first = context.temp_dir.child("first");
+    copy_dir_all(context.site_packages(), &first)?;
+
+    // Sync `tomli`, and copy the site-packages to another standalone directory.
+    requirements_txt.write_str("tomli==2.0.1")?;
+    sync_command(&context)
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+    let second = context.temp_dir.child("second");
+    copy_dir_all(context.site_packages(), &second)?;
+
+    // List the packages in a single directory, ignoring the environment.
+    uv_snapshot!(context.filters(), command(&context).arg("--path").arg(first.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    markupsafe==2.1.3
+
+    ----- stderr -----
+    "###
+    );
+
+    // List the packages across multiple directories.
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("--path")
+        .arg(first.path())
+        .arg("--path")
+        .arg(second.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    markupsafe==2.1.3
+    tomli==2.0.1
+
+    ----- stderr -----
+    "###
+    );
+
+    // The environment itself only contains `tomli`.
+    uv_snapshot!(context.filters(), command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    tomli==2.0.1
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Warn about a missing `.netrc` file only if it was provided explicitly via `--netrc-file`.
+#[test]
+fn freeze_missing_netrc_file() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), command(&context)
+        .arg("--netrc-file")
+        .arg("missing-netrc"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Ignoring `.netrc` credentials. File does not exist: missing-netrc
+    "###
+    );
+
+    uv_snapshot!(context.filters(), command(&context)
+        .env("NETRC", "missing-netrc"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+}
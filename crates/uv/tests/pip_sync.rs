@@ -247,6 +247,104 @@ fn install_hardlink() -> Result<()> {
     Ok(())
 }
 
+/// Install a package into a virtual environment using symlink semantics.
+#[test]
+#[cfg(unix)]
+fn install_symlink() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--link-mode")
+        .arg("symlink")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    warning: Symlinking 1 package from the cache. Modifying an installed package will modify the cache, and removing the cache will break the environment. Use `link-mode-package` to copy any packages that modify their own files at runtime.
+    Installed 1 package in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    context.assert_command("import markupsafe").success();
+
+    // The package contents are symlinked, but the metadata is copied.
+    let site_packages = context.site_packages();
+    assert!(
+        fs::symlink_metadata(site_packages.join("markupsafe").join("__init__.py"))?
+            .file_type()
+            .is_symlink()
+    );
+    assert!(!fs::symlink_metadata(
+        site_packages
+            .join("markupsafe-2.1.3.dist-info")
+            .join("RECORD")
+    )?
+    .file_type()
+    .is_symlink());
+
+    Ok(())
+}
+
+/// Copy specific packages when installing with symlink semantics, via `link-mode-package`.
+#[test]
+#[cfg(unix)]
+fn install_symlink_link_mode_package() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [tool.uv.pip]
+        link-mode = "symlink"
+        link-mode-package = { markupsafe = "copy" }
+    "#})?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    warning: Symlinking 1 package from the cache. Modifying an installed package will modify the cache, and removing the cache will break the environment. Use `link-mode-package` to copy any packages that modify their own files at runtime.
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
+    "###
+    );
+
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
+
+    let site_packages = context.site_packages();
+    assert!(
+        !fs::symlink_metadata(site_packages.join("markupsafe").join("__init__.py"))?
+            .file_type()
+            .is_symlink()
+    );
+    assert!(
+        fs::symlink_metadata(site_packages.join("tomli").join("__init__.py"))?
+            .file_type()
+            .is_symlink()
+    );
+
+    Ok(())
+}
+
 /// Install multiple packages into a virtual environment.
 #[test]
 fn install_many() -> Result<()> {
@@ -432,62 +530,565 @@ fn install_sequential() -> Result<()> {
 
     uv_snapshot!(command(&context)
         .arg("requirements.txt")
-        .arg("--strict"), @r###"
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tomli==2.0.1
+    "###
+    );
+
+    context
+        .assert_command("import markupsafe; import tomli")
+        .success();
+
+    Ok(())
+}
+
+/// Install a package into a virtual environment, then install a second package into the same
+/// virtual environment.
+#[test]
+fn upgrade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("tomli==2.0.0")?;
+
+    command(&context)
+        .arg("requirements.txt")
+        .arg("--strict")
+        .assert()
+        .success();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("tomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - tomli==2.0.0
+     + tomli==2.0.1
+    "###
+    );
+
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
+/// Sync a set of pinned requirements, using an overrides file to downgrade one of them.
+#[test]
+fn override_downgrade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    command(&context)
+        .arg("requirements.txt")
+        .arg("--strict")
+        .assert()
+        .success();
+
+    // Overrides for packages that aren't in the input files should be ignored.
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("tomli==2.0.0\nanyio==4.0.0")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--override")
+        .arg("overrides.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - tomli==2.0.1
+     + tomli==2.0.0
+    "###
+    );
+
+    context
+        .assert_command("import markupsafe; import tomli")
+        .success();
+
+    Ok(())
+}
+
+/// Sync a pinned requirement that falls outside of a constraint, which should fail without
+/// modifying the environment.
+#[test]
+fn constraint_excludes_pin() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("tomli<2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--constraint")
+        .arg("constraints.txt")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Requirement `tomli==2.0.1` does not satisfy the constraint `tomli<2.0.1`
+    "###
+    );
+
+    context.assert_command("import tomli").failure();
+
+    // A constraint that admits the pinned version has no effect.
+    constraints_txt.write_str("tomli<3")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--constraint")
+        .arg("constraints.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + markupsafe==2.1.3
+     + tomli==2.0.1
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install a package with `--no-deps`, which warns about (but doesn't install) the dependencies
+/// that are missing from the input file.
+#[test]
+fn no_deps() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.3.0")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--no-deps"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.3.0
+    warning: The package `anyio` requires `idna>=2.8`, but it's not installed.
+    warning: The package `anyio` requires `sniffio>=1.1`, but it's not installed.
+    "###
+    );
+
+    context.assert_command("import idna").failure();
+    context.assert_command("import sniffio").failure();
+
+    Ok(())
+}
+
+/// Sync with `--exact`, which removes any unlisted packages, but preserves seed packages.
+#[test]
+fn exact() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\npip==24.0\ntomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Downloaded 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + markupsafe==2.1.3
+     + pip==24.0
+     + tomli==2.0.1
+    "###
+    );
+
+    // Sync a subset of the packages; the extra package is removed, but `pip` is preserved.
+    requirements_txt.write_str("tomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--exact"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "###
+    );
+
+    context.assert_command("import markupsafe").failure();
+    context.assert_command("import pip").success();
+    context.assert_command("import tomli").success();
+
+    // A seed package that's listed in the input files is synced like any other package.
+    requirements_txt.write_str("pip==23.3.1\ntomli==2.0.1")?;
+
+    command(&context)
+        .arg("requirements.txt")
+        .arg("--exact")
+        .assert()
+        .success();
+
+    context
+        .assert_command("import pip; assert pip.__version__ == '23.3.1'")
+        .success();
+    context.assert_command("import tomli").success();
+
+    Ok(())
+}
+
+/// Preview the changes to the environment with `--dry-run`, grouped by action.
+#[test]
+fn dry_run() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    command(&context).arg("requirements.txt").assert().success();
+
+    // Install one package, upgrade another, and remove a third.
+    requirements_txt.write_str("iniconfig==2.0.0\nMarkupSafe==2.1.4")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Would install 1 package
+     + iniconfig 2.0.0
+    Would upgrade 1 package
+     ~ markupsafe 2.1.3 -> 2.1.4
+    Would remove 1 package
+     - tomli 2.0.1
+    "###
+    );
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {"install":[{"name":"iniconfig","to":"2.0.0"}],"reinstall":[],"upgrade":[{"name":"markupsafe","from":"2.1.3","to":"2.1.4"}],"downgrade":[],"remove":[{"name":"tomli","from":"2.0.1"}]}
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###
+    );
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--fail-on-change"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Would install 1 package
+     + iniconfig 2.0.0
+    Would upgrade 1 package
+     ~ markupsafe 2.1.3 -> 2.1.4
+    Would remove 1 package
+     - tomli 2.0.1
+    "###
+    );
+
+    // The environment is left untouched.
+    context.assert_command("import iniconfig").failure();
+    context.assert_installed("markupsafe", "2.1.3");
+    context.assert_command("import tomli").success();
+
+    // An environment that's already in sync passes `--fail-on-change`.
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--dry-run")
+        .arg("--fail-on-change"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 2 packages in [TIME]
+    Would make no changes
+    "###
+    );
+
+    Ok(())
+}
+
+/// Preserve packages that aren't listed in the input files with `--no-remove` and `--keep`.
+#[test]
+fn no_remove_and_keep() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    command(&context).arg("requirements.txt").assert().success();
+
+    // With `--no-remove`, unlisted packages are left in place.
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--no-remove"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    context.assert_command("import iniconfig").success();
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
+
+    // With `--keep`, only the listed packages are preserved.
+    requirements_txt.write_str("tomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--keep")
+        .arg("markupsafe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "###
+    );
+
+    context.assert_command("import iniconfig").failure();
+    context.assert_command("import markupsafe").success();
+
+    // A kept package can't be replaced by a different version.
+    requirements_txt.write_str("MarkupSafe==2.1.4\ntomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--keep")
+        .arg("markupsafe"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `markupsafe` is installed and marked with `--keep`, but the input files require a different version: markupsafe==2.1.4
+    "###
+    );
+
+    context.assert_installed("markupsafe", "2.1.3");
+
+    Ok(())
+}
+
+/// Preserve packages that aren't listed in the input files with `--inexact`.
+#[test]
+fn inexact() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    command(&context).arg("requirements.txt").assert().success();
+
+    // `--inexact` overrides an earlier `--exact`, so the unlisted package is left in place.
+    requirements_txt.write_str("tomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--exact")
+        .arg("--inexact"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    context.assert_command("import markupsafe").success();
+    context.assert_command("import tomli").success();
+
+    // `--inexact` conflicts with `--remove`.
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--inexact")
+        .arg("--remove"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--inexact' cannot be used with '--remove'
+
+    Usage: uv pip sync --cache-dir [CACHE_DIR] --inexact <SRC_FILE>...
+
+    For more information, try '--help'.
+    "###
+    );
+
+    Ok(())
+}
+
+/// `--exact` on the command-line takes precedence over `no-remove` in the configuration file.
+#[test]
+fn exact_overrides_configured_no_remove() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.temp_dir.child("uv.toml").write_str(indoc! {r"
+        [pip]
+        no-remove = true
+    "})?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    command(&context).arg("requirements.txt").assert().success();
+
+    // Without `--exact`, the configured `no-remove` leaves the unlisted package in place.
+    requirements_txt.write_str("tomli==2.0.1")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    context.assert_command("import markupsafe").success();
+
+    // With `--exact`, the unlisted package is removed.
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--exact"), @r###"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Downloaded 1 package in [TIME]
-    Installed 1 package in [TIME]
-     + tomli==2.0.1
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
     "###
     );
 
-    context
-        .assert_command("import markupsafe; import tomli")
-        .success();
+    context.assert_command("import markupsafe").failure();
+    context.assert_command("import tomli").success();
 
     Ok(())
 }
 
-/// Install a package into a virtual environment, then install a second package into the same
-/// virtual environment.
+/// Restore the original environment if a package fails to install partway through a sync.
 #[test]
-fn upgrade() -> Result<()> {
+fn rollback_on_failed_install() -> Result<()> {
     let context = TestContext::new("3.12");
 
     let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("tomli==2.0.0")?;
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
 
-    command(&context)
-        .arg("requirements.txt")
-        .arg("--strict")
-        .assert()
-        .success();
+    command(&context).arg("requirements.txt").assert().success();
 
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_str("tomli==2.0.1")?;
+    // Block the installation of `iniconfig` by placing a file where its package directory belongs.
+    fs::write(context.site_packages().join("iniconfig"), "")?;
 
-    uv_snapshot!(command(&context)
-        .arg("requirements.txt")
-        .arg("--strict"), @r###"
-    success: true
-    exit_code: 0
+    requirements_txt.write_str("MarkupSafe==2.1.4\niniconfig==2.0.0")?;
+
+    let filters: Vec<_> = [(r"(?s)Caused by: .*", "Caused by: [ERROR]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect();
+
+    uv_snapshot!(filters, command(&context)
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 2
     ----- stdout -----
 
     ----- stderr -----
-    Resolved 1 package in [TIME]
-    Downloaded 1 package in [TIME]
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
     Uninstalled 1 package in [TIME]
-    Installed 1 package in [TIME]
-     - tomli==2.0.0
-     + tomli==2.0.1
+    Rolled back the installation, restoring 1 package
+     ~ markupsafe==2.1.3
+    error: Failed to install: iniconfig-2.0.0-py3-none-any.whl (iniconfig==2.0.0)
+      Caused by: [ERROR]
     "###
     );
 
-    context.assert_command("import tomli").success();
+    // The original environment is intact, and no staged files are left behind.
+    context.assert_installed("markupsafe", "2.1.3");
+    context.assert_command("import iniconfig").failure();
+    assert!(fs::read_dir(context.site_packages())?
+        .filter_map(Result::ok)
+        .all(|entry| !entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with(".uv-transaction-")));
 
     Ok(())
 }
@@ -1856,8 +2457,35 @@ fn duplicate_package_overlap() -> Result<()> {
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to determine installation plan
-      Caused by: Detected duplicate package in requirements: markupsafe
+    error: Conflicting requirements for `markupsafe` in the input files:
+      - `markupsafe==2.1.3` (from requirements.txt)
+      - `markupsafe==2.1.2` (from requirements.txt)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Report duplicate requirements that differ only in their extras, rather than silently dropping
+/// the extras of the later requirement.
+#[test]
+fn duplicate_package_extras() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0\nanyio[trio]==4.0.0")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Conflicting requirements for `anyio` in the input files:
+      - `anyio==4.0.0` (from requirements.txt)
+      - `anyio[trio]==4.0.0` (from requirements.txt)
     "###
     );
 
@@ -1890,6 +2518,69 @@ fn duplicate_package_disjoint() -> Result<()> {
     Ok(())
 }
 
+/// Warn when multiple input files pin the same package to different versions, and fail under
+/// `--strict`. Identical pins and pins with mutually exclusive markers are accepted quietly.
+#[test]
+fn duplicate_package_across_files() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_a = context.temp_dir.child("requirements-a.txt");
+    requirements_a.write_str("urllib3==2.2.1\niniconfig==2.0.0")?;
+    let requirements_b = context.temp_dir.child("requirements-b.txt");
+    requirements_b.write_str("urllib3==2.2.0\niniconfig==2.0.0")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements-a.txt")
+        .arg("requirements-b.txt")
+        .arg("--strict"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Conflicting requirements for `urllib3` in the input files:
+      - `urllib3==2.2.1` (from requirements-a.txt)
+      - `urllib3==2.2.0` (from requirements-b.txt)
+    "###
+    );
+
+    uv_snapshot!(command(&context)
+        .arg("requirements-a.txt")
+        .arg("requirements-b.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Conflicting requirements for `urllib3` in the input files; using `urllib3==2.2.1` (from requirements-a.txt) and ignoring:
+      - `urllib3==2.2.0` (from requirements-b.txt)
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + urllib3==2.2.1
+    "###
+    );
+
+    // Mutually exclusive markers don't conflict.
+    requirements_b.write_str("urllib3==1.26.18 ; python_version < '3.8'\niniconfig==2.0.0")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements-a.txt")
+        .arg("requirements-b.txt")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 2 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Verify that we can force reinstall of packages.
 #[test]
 fn reinstall() -> Result<()> {
@@ -2263,6 +2954,46 @@ fn sync_editable() -> Result<()> {
     Ok(())
 }
 
+/// Pass `--config-setting` through to the PEP 660 `build_editable` hook.
+#[test]
+fn sync_editable_config_settings() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&indoc::formatdoc! {r"
+        iniconfig==2.0.0
+        -e {workspace_root}/scripts/packages/setuptools_editable
+        ",
+        workspace_root = context.workspace_root.simplified_display(),
+    })?;
+
+    // Install the editable package with `editable_mode=compat`.
+    uv_snapshot!(context.filters(), command(&context)
+        .arg(requirements_txt.path())
+        .arg("-C")
+        .arg("editable_mode=compat"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Built 1 editable in [TIME]
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + setuptools-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/setuptools_editable)
+    "###
+    );
+
+    // In `compat` mode, setuptools should _not_ install a `finder.py` import hook.
+    let finder = context
+        .site_packages()
+        .join("__editable___setuptools_editable_0_1_0_finder.py");
+    assert!(!finder.exists());
+
+    Ok(())
+}
 #[test]
 fn sync_editable_and_registry() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2756,8 +3487,8 @@ fn repeat_requirement() -> Result<()> {
     Ok(())
 }
 
-/// Sync with a repeated, but conflicting `anyio` requirement. The second requirement should cause
-/// an error.
+/// Sync with a repeated, but conflicting `anyio` requirement. The first requirement should be
+/// used, with a warning.
 #[test]
 fn conflicting_requirement() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -2766,13 +3497,17 @@ fn conflicting_requirement() -> Result<()> {
 
     uv_snapshot!(command(&context)
         .arg("requirements.in"), @r###"
-    success: false
-    exit_code: 2
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Failed to determine installation plan
-      Caused by: Detected duplicate package in requirements: anyio
+    warning: Conflicting requirements for `anyio` in the input files; using `anyio` (from requirements.in) and ignoring:
+      - `anyio==4.0.0` (from requirements.in)
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + anyio==4.3.0
     "###);
 
     Ok(())
@@ -2933,25 +3668,155 @@ requires-python = ">=3.8"
     Built 1 editable in [TIME]
     Uninstalled 1 package in [TIME]
     Installed 1 package in [TIME]
-     - example==0.0.0 (from file://[TEMP_DIR]/editable)
-     + example==0.0.0 (from file://[TEMP_DIR]/editable)
+     - example==0.0.0 (from file://[TEMP_DIR]/editable)
+     + example==0.0.0 (from file://[TEMP_DIR]/editable)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Install with bytecode compilation.
+#[test]
+fn compile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    assert!(context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__")
+        .join("__init__.cpython-312.pyc")
+        .exists());
+
+    context.assert_command("import markupsafe").success();
+
+    Ok(())
+}
+
+/// Install with bytecode compilation at optimization level 2, as with `python -OO`.
+#[test]
+fn compile_optimization_level() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--bytecode-opt-level")
+        .arg("2")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 3 files in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    let pycache = context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__");
+    assert!(pycache.join("__init__.cpython-312.opt-2.pyc").exists());
+    assert!(!pycache.join("__init__.cpython-312.pyc").exists());
+
+    context.assert_command("import markupsafe").success();
+
+    Ok(())
+}
+
+/// Install with bytecode compilation, where `site-packages` contains a vendored Python 2 file that
+/// fails to compile. The failure should be summarized, rather than failing the install.
+#[test]
+fn compile_invalid_source() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let vendored = context.site_packages().join("vendored");
+    fs_err::create_dir_all(&vendored)?;
+    fs_err::write(vendored.join("legacy.py"), "print \"hello\"\n")?;
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--compile")
+        .arg("--strict"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+    Bytecode compiled 4 files in [TIME]
+    warning: Failed to bytecode-compile 1 file:
+      - vendored/legacy.py: SyntaxError: Missing parentheses in call to 'print'. Did you mean print(...)?
+     + markupsafe==2.1.3
     "###
     );
 
+    assert!(context
+        .site_packages()
+        .join("markupsafe")
+        .join("__pycache__")
+        .join("__init__.cpython-312.pyc")
+        .exists());
+
+    context.assert_command("import markupsafe").success();
+
     Ok(())
 }
 
-/// Install with bytecode compilation.
+/// Enable bytecode compilation via a `[pip.sync]` section, which doesn't apply to other commands.
 #[test]
-fn compile() -> Result<()> {
+fn compile_pip_command_configuration() -> Result<()> {
     let context = TestContext::new("3.12");
 
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(indoc::indoc! {r"
+        [pip.sync]
+        compile-bytecode = true
+
+        [pip.compile]
+        generate-hashes = true
+    "})?;
+
     let requirements_txt = context.temp_dir.child("requirements.txt");
     requirements_txt.write_str("MarkupSafe==2.1.3")?;
 
     uv_snapshot!(command(&context)
         .arg("requirements.txt")
-        .arg("--compile")
         .arg("--strict"), @r###"
     success: true
     exit_code: 0
@@ -2966,15 +3831,6 @@ fn compile() -> Result<()> {
     "###
     );
 
-    assert!(context
-        .site_packages()
-        .join("markupsafe")
-        .join("__pycache__")
-        .join("__init__.cpython-312.pyc")
-        .exists());
-
-    context.assert_command("import markupsafe").success();
-
     Ok(())
 }
 
@@ -3368,6 +4224,45 @@ fn require_hashes_source_no_binary() -> Result<()> {
     Ok(())
 }
 
+/// Reinstall a source distribution in hash-checking mode. The wheel built from the verified
+/// source distribution should be reused from the cache, rather than downloaded and built again.
+#[test]
+fn require_hashes_source_build_cached() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt
+        .write_str("anyio==4.0.0 --hash=sha256:f7ed51751b2c2add651e5747c891b47e26d2a21be5d32d9311dfe9692f3e5d7a")?;
+
+    command(&context)
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--require-hashes")
+        .assert()
+        .success();
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg(":all:")
+        .arg("--reinstall")
+        .arg("--require-hashes"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - anyio==4.0.0
+     + anyio==4.0.0
+    "###
+    );
+
+    Ok(())
+}
+
 /// Include the hash for _just_ the source distribution, with `--binary-only`.
 #[test]
 fn require_hashes_source_only_binary() -> Result<()> {
@@ -4734,3 +5629,271 @@ fn require_hashes_registry_invalid_hash() -> Result<()> {
 
     Ok(())
 }
+
+/// Save the distributions for a requirements file to a directory with `--download-only`, then
+/// install from that directory without network access.
+#[test]
+fn download_only() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(indoc! {r"
+        anyio==4.0.0
+        iniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374
+    "})?;
+
+    // Build `anyio` from source, such that the directory only contains wheels.
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg("anyio")
+        .arg("--download-only")
+        .arg("wheelhouse")
+        .arg("--build-wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages to wheelhouse in [TIME]
+     + anyio-4.0.0-py3-none-any.whl
+     + iniconfig-2.0.0-py3-none-any.whl
+    "###
+    );
+
+    // The environment is left untouched.
+    context.assert_command("import iniconfig").failure();
+
+    // The index page includes the hash of each archive.
+    context
+        .temp_dir
+        .child("wheelhouse")
+        .child("index.html")
+        .assert(predicates::str::contains(
+            r#"<a href="iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374">"#,
+        ));
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--find-links")
+        .arg("wheelhouse")
+        .arg("--no-index")
+        .arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Downloaded 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + anyio==4.0.0
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Save a source distribution as-is with `--download-only`.
+#[test]
+fn download_only_source_dist() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("anyio==4.0.0")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--no-binary")
+        .arg("anyio")
+        .arg("--download-only")
+        .arg("wheelhouse"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Downloaded 1 package to wheelhouse in [TIME]
+     + anyio-4.0.0.tar.gz
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("wheelhouse")
+        .child("anyio-4.0.0.tar.gz")
+        .assert(predicates::path::exists());
+
+    Ok(())
+}
+
+/// With `--quiet`, only the summary of the changes should be printed; with `-qq`, nothing at all.
+#[test]
+fn install_quiet() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("--quiet"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 1 package in [TIME]
+    "###
+    );
+
+    // Re-running the sync audits the environment.
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("-q"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Audited 1 package in [TIME]
+    "###
+    );
+
+    // Sync a different version, which removes the existing installation.
+    requirements_txt.write_str("MarkupSafe==2.1.2")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("-q"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+    "###
+    );
+
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    uv_snapshot!(command(&context)
+        .arg("requirements.txt")
+        .arg("-qq"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    context.assert_command("import markupsafe").success();
+
+    Ok(())
+}
+
+/// Create the virtual environment that `--python` points to with `--allow-create`, but refuse to
+/// create one over an existing directory that isn't a virtual environment.
+#[test]
+#[cfg(unix)]
+fn allow_create_missing_venv() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let python_path = python_path_with_versions(&context.temp_dir, &["3.12"])
+        .expect("Failed to create Python test path");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    let filters = [(r"interpreter at: .+", "interpreter at: [PATH]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    // Without `--allow-create`, the missing environment is an error.
+    command(&context)
+        .arg("requirements.txt")
+        .arg("--python")
+        .arg(".venv-docs/bin/python")
+        .env("UV_TEST_PYTHON_PATH", &python_path)
+        .assert()
+        .failure();
+
+    uv_snapshot!(filters, command(&context)
+        .arg("requirements.txt")
+        .arg("--python")
+        .arg(".venv-docs/bin/python")
+        .arg("--allow-create")
+        .env("UV_TEST_PYTHON_PATH", &python_path), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Creating virtualenv at: .venv-docs
+    Resolved 1 package in [TIME]
+    Downloaded 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    let venv = context.temp_dir.child(".venv-docs");
+    venv.child("pyvenv.cfg").assert(predicates::path::is_file());
+    check_command(&venv, "import markupsafe", &context.temp_dir);
+
+    // The request may also name the environment directory itself.
+    uv_snapshot!(filters, command(&context)
+        .arg("requirements.txt")
+        .arg("--python")
+        .arg(".venv-bare")
+        .arg("--allow-create")
+        .env("UV_TEST_PYTHON_PATH", &python_path), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using Python 3.12.[X] interpreter at: [PATH]
+    Creating virtualenv at: .venv-bare
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + markupsafe==2.1.3
+    "###
+    );
+
+    let venv = context.temp_dir.child(".venv-bare");
+    venv.child("pyvenv.cfg").assert(predicates::path::is_file());
+    check_command(&venv, "import markupsafe", &context.temp_dir);
+
+    // A directory that isn't a virtual environment is left untouched.
+    context.temp_dir.child("not-a-venv").create_dir_all()?;
+
+    uv_snapshot!(filters, command(&context)
+        .arg("requirements.txt")
+        .arg("--python")
+        .arg("not-a-venv/bin/python")
+        .arg("--allow-create")
+        .env("UV_TEST_PYTHON_PATH", &python_path), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The path `not-a-venv` exists, but is not a virtual environment; refusing to create one with `--allow-create`
+    "###
+    );
+
+    context
+        .temp_dir
+        .child("not-a-venv")
+        .child("pyvenv.cfg")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
@@ -66,9 +66,9 @@ fn no_arguments() -> Result<()> {
 
     ----- stderr -----
     error: the following required arguments were not provided:
-      <PACKAGE|--requirement <REQUIREMENT>>
+      <PACKAGE|--requirement <REQUIREMENT>|--all>
 
-    Usage: uv pip uninstall <PACKAGE|--requirement <REQUIREMENT>>
+    Usage: uv pip uninstall <PACKAGE|--requirement <REQUIREMENT>|--all>
 
     For more information, try '--help'.
     "###
@@ -227,6 +227,74 @@ fn missing_record() -> Result<()> {
     Ok(())
 }
 
+/// Preserve files that were modified after installation, unless `--force` is provided.
+#[test]
+fn uninstall_modified_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3")?;
+
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    // Modify an installed file. Remove it first, so as not to modify the (linked) cache entry.
+    let init = context
+        .site_packages()
+        .join("markupsafe")
+        .join("__init__.py");
+    fs_err::remove_file(&init)?;
+    fs_err::write(&init, "# Modified by the user.\n")?;
+
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("MarkupSafe"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Preserving `[SITE_PACKAGES]/markupsafe/__init__.py`, which was modified after `markupsafe` was installed (use `--force` to remove it)
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "###
+    );
+
+    // The modified file is preserved, while the unmodified files are removed.
+    assert_eq!(fs_err::read_to_string(&init)?, "# Modified by the user.\n");
+    assert!(!context
+        .site_packages()
+        .join("markupsafe")
+        .join("_native.py")
+        .exists());
+
+    // With `--force`, the modified file is removed.
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+    fs_err::remove_file(&init)?;
+    fs_err::write(&init, "# Modified by the user.\n")?;
+
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("MarkupSafe")
+        .arg("--force"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - markupsafe==2.1.3
+    "###
+    );
+
+    assert!(!init.exists());
+
+    Ok(())
+}
+
 #[test]
 fn uninstall_editable_by_name() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -274,6 +342,69 @@ fn uninstall_editable_by_name() -> Result<()> {
     Ok(())
 }
 
+/// Uninstall all packages with `--all`, preserving editables with `--exclude-editable`.
+#[test]
+fn uninstall_all_exclude_editable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str(&format!(
+        "-e {}\nMarkupSafe==2.1.3\ntomli==2.0.1",
+        context
+            .workspace_root
+            .join("scripts/packages/poetry_editable")
+            .as_os_str()
+            .to_str()
+            .expect("Path is valid unicode")
+    ))?;
+    sync_command(&context)
+        .arg(requirements_txt.path())
+        .assert()
+        .success();
+
+    // Uninstall everything except the editable.
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("--all")
+        .arg("--exclude-editable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 2 packages in [TIME]
+     - markupsafe==2.1.3
+     - tomli==2.0.1
+    "###
+    );
+
+    Command::new(venv_to_interpreter(&context.venv))
+        .arg("-c")
+        .arg("import poetry_editable")
+        .assert()
+        .success();
+
+    Command::new(venv_to_interpreter(&context.venv))
+        .arg("-c")
+        .arg("import markupsafe")
+        .assert()
+        .failure();
+
+    // Without `--exclude-editable`, the editable is removed too.
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("--all"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+     - poetry-editable==0.1.0 (from file://[WORKSPACE]/scripts/packages/poetry_editable)
+    "###
+    );
+
+    Ok(())
+}
+
 #[test]
 fn uninstall_by_path() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -418,3 +549,166 @@ fn uninstall_duplicate() -> Result<()> {
 
     Ok(())
 }
+
+/// With `--quiet`, only the summary of the changes should be printed; with `-qq`, nothing at all.
+#[test]
+fn uninstall_quiet() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("MarkupSafe==2.1.3\ntomli==2.0.1")?;
+
+    sync_command(&context)
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(uninstall_command(&context)
+        .arg("MarkupSafe")
+        .arg("--quiet"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 1 package in [TIME]
+    "###
+    );
+
+    uv_snapshot!(uninstall_command(&context)
+        .arg("tomli")
+        .arg("-qq"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// Create a mock system interpreter at `root` whose standard library is marked as externally
+/// managed, returning the path to its executable.
+#[cfg(unix)]
+fn mock_externally_managed_interpreter(root: &std::path::Path) -> Result<std::path::PathBuf> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let stdlib = root.join("lib").join("python3.12");
+    fs_err::create_dir_all(stdlib.join("site-packages"))?;
+    fs_err::write(
+        stdlib.join("EXTERNALLY-MANAGED"),
+        "[externally-managed]\nError=To remove system packages, use apt.\n",
+    )?;
+
+    let json = format!(
+        r#"{{
+            "result": "success",
+            "platform": {{"os": {{"name": "manylinux", "major": 2, "minor": 38}}, "arch": "x86_64"}},
+            "markers": {{
+                "implementation_name": "cpython",
+                "implementation_version": "3.12.0",
+                "os_name": "posix",
+                "platform_machine": "x86_64",
+                "platform_python_implementation": "CPython",
+                "platform_release": "6.5.0-13-generic",
+                "platform_system": "Linux",
+                "platform_version": "",
+                "python_full_version": "3.12.0",
+                "python_version": "3.12",
+                "sys_platform": "linux"
+            }},
+            "base_exec_prefix": "{root}",
+            "base_prefix": "{root}",
+            "prefix": "{root}",
+            "sys_executable": "{root}/bin/python",
+            "stdlib": "{stdlib}",
+            "scheme": {{
+                "data": "{root}",
+                "include": "{root}/include",
+                "platlib": "{stdlib}/site-packages",
+                "purelib": "{stdlib}/site-packages",
+                "scripts": "{root}/bin"
+            }},
+            "virtualenv": {{
+                "data": "",
+                "include": "include",
+                "platlib": "lib/python3.12/site-packages",
+                "purelib": "lib/python3.12/site-packages",
+                "scripts": "bin"
+            }},
+            "gil_disabled": false
+        }}"#,
+        root = root.display(),
+        stdlib = stdlib.display(),
+    );
+
+    let executable = root.join("bin").join("python");
+    fs_err::create_dir_all(executable.parent().unwrap())?;
+    fs_err::write(&executable, format!("#!/bin/sh\necho '{json}'\n"))?;
+    fs_err::set_permissions(&executable, std::fs::Permissions::from_mode(0o770))?;
+    Ok(executable)
+}
+
+/// Refuse to uninstall a package owned by the system package manager from an externally managed
+/// environment, unless `--break-system-packages` is provided.
+#[test]
+#[cfg(unix)]
+fn uninstall_system_package_externally_managed() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let root = context.temp_dir.child("system");
+    let executable = mock_externally_managed_interpreter(&root)?;
+
+    // Add a distribution that was installed by the system package manager.
+    let site_packages = root.join("lib").join("python3.12").join("site-packages");
+    let dist_info = site_packages.join("iniconfig-2.0.0.dist-info");
+    fs_err::create_dir_all(&dist_info)?;
+    fs_err::write(
+        dist_info.join("METADATA"),
+        "Metadata-Version: 2.1\nName: iniconfig\nVersion: 2.0.0\n",
+    )?;
+    fs_err::write(dist_info.join("INSTALLER"), "debian\n")?;
+    fs_err::write(
+        dist_info.join("RECORD"),
+        "iniconfig-2.0.0.dist-info/METADATA,,\niniconfig-2.0.0.dist-info/INSTALLER,,\niniconfig-2.0.0.dist-info/RECORD,,\n",
+    )?;
+
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("iniconfig")
+        .arg("--python")
+        .arg(&executable), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The interpreter at system is externally managed, and the following packages are owned by the system package manager:
+      - iniconfig==2.0.0 (installed by debian)
+    Uninstall them with the system package manager instead, or pass `--break-system-packages` to remove them anyway.
+    "###
+    );
+
+    assert!(dist_info.exists());
+
+    uv_snapshot!(context.filters(), uninstall_command(&context)
+        .arg("iniconfig")
+        .arg("--python")
+        .arg(&executable)
+        .arg("--break-system-packages"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Uninstalling `iniconfig`, which was installed by `debian`; this may break packages managed by `debian`
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "###
+    );
+
+    assert!(!dist_info.exists());
+
+    Ok(())
+}
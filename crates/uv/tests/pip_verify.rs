@@ -0,0 +1,317 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::{get_bin, uv_snapshot, TestContext};
+
+mod common;
+
+/// Create a `pip verify` command with options shared across scenarios.
+fn verify_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("pip")
+        .arg("verify")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    command
+}
+
+/// The environment exactly matches the requirements file.
+#[test]
+fn verify_matching() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0\nsniffio==1.3.1")?;
+
+    context
+        .install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    uv_snapshot!(verify_command(&context)
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 2 packages in [TIME]
+    All installed packages match the requirements
+    "###
+    );
+
+    Ok(())
+}
+
+/// A required package is not installed.
+#[test]
+fn verify_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.install().arg("iniconfig==2.0.0").assert().success();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0\nsniffio==1.3.1")?;
+
+    uv_snapshot!(verify_command(&context)
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 1 discrepancy
+    The requirement `sniffio==1.3.1` is not installed.
+    "###
+    );
+
+    Ok(())
+}
+
+/// An installed package is not listed in the requirements file.
+#[test]
+fn verify_extra() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .install()
+        .arg("iniconfig==2.0.0")
+        .arg("sniffio==1.3.1")
+        .assert()
+        .success();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(verify_command(&context)
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 2 packages in [TIME]
+    Found 1 discrepancy
+    The package `sniffio==1.3.1` is installed, but not required.
+    "###
+    );
+
+    Ok(())
+}
+
+/// An installed package doesn't match the pinned version.
+#[test]
+fn verify_wrong_version() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.install().arg("iniconfig==1.1.1").assert().success();
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    uv_snapshot!(verify_command(&context)
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 1 discrepancy
+    The package `iniconfig==1.1.1` is installed, but `iniconfig==2.0.0` is required.
+    "###
+    );
+
+    Ok(())
+}
+
+/// An installed archive doesn't match the hashes in the requirements file.
+#[test]
+fn verify_hash_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+
+    context
+        .install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .assert()
+        .success();
+
+    // The recorded hash matches.
+    uv_snapshot!(verify_command(&context)
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    All installed packages match the requirements
+    "###
+    );
+
+    // The recorded hash doesn't match.
+    requirements_txt.write_str("iniconfig @ https://files.pythonhosted.org/packages/ef/a6/62565a6e1cf69e10f5727360368e451d4b7f58beeac6173dc9db836a5b46/iniconfig-2.0.0-py3-none-any.whl --hash=sha256:d8ec9bcc4a4a5f6d5f2c4ba6a8aa5b80bec4ef94fa5e8e1d4c5a5d4c8d7a5b2e")?;
+
+    uv_snapshot!(verify_command(&context)
+        .arg("-r")
+        .arg("requirements.txt"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 1 discrepancy
+    The package `iniconfig==2.0.0` is installed, but doesn't match any of the required hashes.
+    "###
+    );
+
+    Ok(())
+}
+
+/// A package installed from a registry doesn't record its hashes, so they can't be verified.
+#[test]
+fn verify_hash_unverifiable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0 --hash=sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374")?;
+
+    context
+        .install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--require-hashes")
+        .assert()
+        .success();
+
+    uv_snapshot!(verify_command(&context)
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--format")
+        .arg("json"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [{"kind":"unverifiable-hash","package":"iniconfig","version":"2.0.0","message":"The package `iniconfig==2.0.0` is installed, but its hashes can't be verified, as none were recorded at install time."}]
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 1 discrepancy
+    "###
+    );
+
+    Ok(())
+}
+
+/// Without a requirements file, only the files of the installed packages are verified.
+#[test]
+fn verify_intact() {
+    let context = TestContext::new("3.12");
+
+    context
+        .install()
+        .arg("iniconfig==2.0.0")
+        .arg("sniffio==1.3.1")
+        .assert()
+        .success();
+
+    uv_snapshot!(verify_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 2 packages in [TIME]
+    All installed packages are intact
+    "###
+    );
+}
+
+/// A file listed in the `RECORD` of an installed package is missing or modified.
+#[test]
+fn verify_record_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context.install().arg("iniconfig==2.0.0").assert().success();
+
+    // Remove one file, and modify another without changing its size.
+    let package = context.site_packages().join("iniconfig");
+    fs_err::remove_file(package.join("py.typed"))?;
+    let version = fs_err::read_to_string(package.join("_version.py"))?;
+    fs_err::write(
+        package.join("_version.py"),
+        version.replace("2.0.0", "2.0.1"),
+    )?;
+
+    uv_snapshot!(verify_command(&context), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 2 discrepancies
+    The file `iniconfig/_version.py` of `iniconfig==2.0.0` doesn't match its recorded hash.
+    The file `iniconfig/py.typed` of `iniconfig==2.0.0` is missing.
+    To repair the affected packages, run: `uv pip install --reinstall-package iniconfig iniconfig==2.0.0`
+    "###
+    );
+
+    // The discrepancies can be reported as JSON.
+    uv_snapshot!(verify_command(&context).arg("--format").arg("json"), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    [{"kind":"file-hash","package":"iniconfig","version":"2.0.0","path":"iniconfig/_version.py","message":"The file `iniconfig/_version.py` of `iniconfig==2.0.0` doesn't match its recorded hash."},{"kind":"missing-file","package":"iniconfig","version":"2.0.0","path":"iniconfig/py.typed","message":"The file `iniconfig/py.typed` of `iniconfig==2.0.0` is missing."}]
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    Found 2 discrepancies
+    To repair the affected packages, run: `uv pip install --reinstall-package iniconfig iniconfig==2.0.0`
+    "###
+    );
+
+    // Reinstalling the package repairs it.
+    context
+        .install()
+        .arg("--reinstall-package")
+        .arg("iniconfig")
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    uv_snapshot!(verify_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Verified 1 package in [TIME]
+    All installed packages are intact
+    "###
+    );
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext, EXCLUDE_NEWER};
+
+mod common;
+
+/// Create a `pip wheel` command with options shared across scenarios.
+fn command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("pip")
+        .arg("wheel")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .arg("--exclude-newer")
+        .arg(EXCLUDE_NEWER)
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+
+    if cfg!(all(windows, debug_assertions)) {
+        // TODO(konstin): Reduce stack usage in debug mode enough that the tests pass with the
+        // default windows stack of 1MB
+        command.env("UV_STACK_SIZE", (2 * 1024 * 1024).to_string());
+    }
+
+    command
+}
+
+/// Build a wheel for a source-only package, copying the wheels of its dependencies as-is.
+#[test]
+fn wheel_source_tree() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let project = context.temp_dir.child("example");
+    project.child("pyproject.toml").write_str(
+        r#"[project]
+name = "example"
+version = "0.1.0"
+dependencies = ["iniconfig==2.0.0"]
+
+[build-system]
+requires = ["setuptools>=42"]
+build-backend = "setuptools.build_meta"
+"#,
+    )?;
+    project.child("example/__init__.py").touch()?;
+
+    uv_snapshot!(command(&context)
+        .arg("./example")
+        .arg("--wheel-dir")
+        .arg("wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Saved 2 wheels to wheels in [TIME]
+     + example-0.1.0-py3-none-any.whl
+     + iniconfig-2.0.0-py3-none-any.whl
+    "###
+    );
+
+    let wheels = context.temp_dir.child("wheels");
+    wheels
+        .child("example-0.1.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    wheels
+        .child("iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    // The environment is left untouched.
+    context.assert_command("import example").failure();
+
+    Ok(())
+}
+
+/// Build a wheel from the source distribution of a registry package with `--no-binary`.
+#[test]
+fn wheel_no_binary() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(command(&context)
+        .arg("iniconfig==2.0.0")
+        .arg("--no-binary")
+        .arg("iniconfig")
+        .arg("-w")
+        .arg("wheels"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Saved 1 wheel to wheels in [TIME]
+     + iniconfig-2.0.0-py3-none-any.whl
+    "###
+    );
+
+    Ok(())
+}
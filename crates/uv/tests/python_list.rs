@@ -0,0 +1,65 @@
+#![cfg(feature = "python")]
+
+use std::process::Command;
+
+use anyhow::Result;
+
+use crate::common::{get_bin, python_path_with_versions, uv_snapshot, TestContext};
+
+mod common;
+
+/// Create a `python list` command with options shared across scenarios.
+fn command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("python")
+        .arg("list")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+    command
+}
+
+/// List the active virtual environment alongside the interpreters found on the system.
+#[test]
+fn list() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let python_path = python_path_with_versions(&context.temp_dir, &["3.12"])?;
+
+    let filters: Vec<_> = [
+        (r"system  \S+", "system  [PYTHON]"),
+        (r#""path":"[^"]+""#, r#""path":"[PATH]""#),
+    ]
+    .into_iter()
+    .chain(context.filters())
+    .collect();
+
+    uv_snapshot!(filters, command(&context)
+        .env("UV_TEST_PYTHON_PATH", &python_path), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    cpython-3.12.[X]  venv    [VENV]/bin/python
+    cpython-3.12.[X]  system  [PYTHON]
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(filters, command(&context)
+        .arg("--output-format")
+        .arg("json")
+        .env("UV_TEST_PYTHON_PATH", &python_path), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [{"implementation":"cpython","version":"3.12.[X]","source":"venv","path":"[PATH]"},{"implementation":"cpython","version":"3.12.[X]","source":"system","path":"[PATH]"}]
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+
+use common::uv_snapshot;
+
+use crate::common::{get_bin, TestContext};
+
+mod common;
+
+/// Create a `__complete` command, as invoked by the shell completion scripts.
+fn complete_command(context: &TestContext) -> Command {
+    let mut command = Command::new(get_bin());
+    command
+        .arg("__complete")
+        .arg("--cache-dir")
+        .arg(context.cache_dir.path())
+        .env("VIRTUAL_ENV", context.venv.as_os_str())
+        .env("UV_NO_WRAP", "1")
+        .current_dir(&context.temp_dir);
+    command
+}
+
+/// Complete the names of the packages installed in the active environment.
+#[test]
+fn complete_installed_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .install()
+        .arg("anyio==4.3.0")
+        .arg("iniconfig==2.0.0")
+        .assert()
+        .success();
+
+    // Without a prefix, every installed package is listed.
+    uv_snapshot!(complete_command(&context), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    anyio
+    idna
+    iniconfig
+    sniffio
+
+    ----- stderr -----
+    "###
+    );
+
+    // The prefix is normalized before matching.
+    uv_snapshot!(complete_command(&context).arg("I"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    idna
+    iniconfig
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(complete_command(&context).arg("flask"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+/// The generated completion scripts should call back into `uv __complete` for the options and
+/// arguments that accept installed package names, alongside the static completions.
+#[test]
+fn generate_dynamic_completions() -> Result<()> {
+    let bash = Command::new(get_bin())
+        .arg("generate-shell-completion")
+        .arg("bash")
+        .output()?;
+    assert!(bash.status.success());
+    let bash = String::from_utf8(bash.stdout)?;
+    assert!(bash.contains("complete -F _uv_complete_packages -o bashdefault -o default uv"));
+    assert!(bash.contains("--refresh-package|--reinstall-package|--upgrade-package|-P)"));
+    // Values of `ValueEnum` options are completed statically.
+    assert!(bash.contains("lowest-direct"));
+
+    let zsh = Command::new(get_bin())
+        .arg("generate-shell-completion")
+        .arg("zsh")
+        .output()?;
+    assert!(zsh.status.success());
+    assert!(String::from_utf8(zsh.stdout)?.contains("compdef _uv_complete_packages uv"));
+
+    let fish = Command::new(get_bin())
+        .arg("generate-shell-completion")
+        .arg("fish")
+        .output()?;
+    assert!(fish.status.success());
+    assert!(String::from_utf8(fish.stdout)?.contains(
+        "complete -c uv -l upgrade-package -s P -x -a \"(uv __complete (commandline -ct))\""
+    ));
+
+    Ok(())
+}